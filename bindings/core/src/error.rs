@@ -21,6 +21,9 @@ pub enum Error {
     /// Wallet errors.
     #[error("{0}")]
     Wallet(#[from] iota_sdk::wallet::Error),
+    /// Fountain coding errors.
+    #[error("{0}")]
+    Fountain(#[from] iota_sdk::utils::fountain::Error),
     /// Prefix hex errors.
     #[error("{0}")]
     PrefixHex(#[from] prefix_hex::Error),
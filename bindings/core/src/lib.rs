@@ -95,6 +95,7 @@ pub(crate) trait OmittedDebug {
 }
 impl OmittedDebug for String {}
 impl OmittedDebug for SecretManagerDto {}
+impl<T: OmittedDebug> OmittedDebug for Vec<T> {}
 impl<T: OmittedDebug> OmittedDebug for Option<T> {
     fn omitted_fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
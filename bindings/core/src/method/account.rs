@@ -1,12 +1,12 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "participation")]
 use iota_sdk::{
     client::node_manager::node::Node,
     types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventType},
     wallet::account::types::participation::ParticipationEventRegistrationOptions,
 };
+#[cfg(feature = "participation")]
 use iota_sdk::{
     client::{
         api::{input_selection::BurnDto, PreparedTransactionDataDto, SignedTransactionDataDto},
@@ -20,7 +20,7 @@ use iota_sdk::{
     wallet::{
         account::{
             ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions, MintNftParams,
-            OutputParams, OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            OutputParams, OutputsToClaim, SyncOptions, TransactionHistoryFilter, TransactionOptionsDto,
         },
         SendNativeTokensParams, SendNftParams, SendParams,
     },
@@ -303,6 +303,12 @@ pub enum AccountMethod {
     SignTransactionEssence {
         prepared_transaction_data: PreparedTransactionDataDto,
     },
+    /// Simulate a prepared transaction and report its net effect.
+    /// Expected response: [`TransactionSimulation`](crate::Response::TransactionSimulation)
+    #[serde(rename_all = "camelCase")]
+    SimulateTransaction {
+        prepared_transaction_data: PreparedTransactionDataDto,
+    },
     /// Validate the transaction, submit it to a node and store it in the account.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -319,6 +325,9 @@ pub enum AccountMethod {
     /// Returns all transaction of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     Transactions,
+    /// Returns one entry per transfer of the account
+    /// Expected response: [`TransactionHistory`](crate::Response::TransactionHistory)
+    TransactionHistory { filter: Option<TransactionHistoryFilter> },
     /// Returns all unspent outputs of the account
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
@@ -128,6 +128,13 @@ pub enum ClientMethod {
     /// Returns the unhealthy nodes.
     #[cfg(not(target_family = "wasm"))]
     UnhealthyNodes,
+    /// Returns the request statistics and circuit breaker state of each node.
+    NodeStats,
+    /// Returns the hit and miss metrics of the cache of immutable node responses.
+    CacheMetrics,
+    /// Removes all responses from the cache of immutable node responses.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    ClearCache,
     /// Extension method which provides request methods for plugins.
     #[serde(rename_all = "camelCase")]
     CallPluginRoute {
@@ -68,6 +68,10 @@ pub enum SecretManagerMethod {
         /// Mnemonic
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// Optional BIP-39 passphrase
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
     },
 }
 
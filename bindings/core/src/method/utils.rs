@@ -2,18 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use derivative::Derivative;
-use iota_sdk::types::block::{
-    address::{Bech32Address, Hrp},
-    output::{dto::OutputDto, AliasId, NftId, OutputId, RentStructure},
-    payload::{
-        dto::MilestonePayloadDto,
-        transaction::{
-            dto::{TransactionEssenceDto, TransactionPayloadDto},
-            TransactionId,
+use iota_sdk::{
+    client::slip39::Slip39Group,
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::{dto::OutputDto, AliasId, NftId, OutputId, RentStructure},
+        payload::{
+            dto::MilestonePayloadDto,
+            transaction::{
+                dto::{TransactionEssenceDto, TransactionPayloadDto},
+                TransactionId,
+            },
         },
+        signature::dto::Ed25519SignatureDto,
+        BlockDto,
     },
-    signature::dto::Ed25519SignatureDto,
-    BlockDto,
 };
 use serde::{Deserialize, Serialize};
 
@@ -79,6 +82,37 @@ pub enum UtilsMethod {
         /// Mnemonic
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// Optional BIP-39 passphrase
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
+    },
+    /// Splits a mnemonic into groups of SLIP-39 mnemonic shares.
+    /// Expected response: [`Slip39Shares`](crate::Response::Slip39Shares)
+    #[serde(rename_all = "camelCase")]
+    MnemonicToSlip39Shares {
+        /// Mnemonic
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        mnemonic: String,
+        /// Optional passphrase to encrypt the shares with
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
+        /// The number of groups required to recover the mnemonic
+        group_threshold: u8,
+        /// The member threshold and member count of each group
+        groups: Vec<Slip39Group>,
+    },
+    /// Recovers a mnemonic from SLIP-39 mnemonic shares.
+    /// Expected response: [`GeneratedMnemonic`](crate::Response::GeneratedMnemonic)
+    Slip39SharesToMnemonic {
+        /// SLIP-39 mnemonic shares
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        shares: Vec<String>,
+        /// Optional passphrase the shares were encrypted with
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
     },
     /// Returns a block ID (Blake2b256 hash of block bytes) from a block
     BlockId {
@@ -159,4 +193,21 @@ pub enum UtilsMethod {
     /// Returns the hex representation of the serialized output bytes.
     #[serde(rename_all = "camelCase")]
     OutputHexBytes { output: OutputDto },
+    /// Encodes hex encoded packed bytes into fountain-coded UR parts, suited for animated QR codes.
+    /// Expected response: [`FountainParts`](crate::Response::FountainParts)
+    #[serde(rename_all = "camelCase")]
+    EncodeFountainParts {
+        /// The hex encoded bytes to encode
+        bytes: String,
+        /// The maximum length of the data of a single part
+        max_fragment_length: usize,
+        /// The UR type of the parts, e.g. `iota-prepared-tx`
+        ur_type: String,
+        /// The number of parts to generate, defaults to the number of fragments and can be at most 10 times the number
+        /// of fragments
+        count: Option<u32>,
+    },
+    /// Decodes fountain-coded UR parts, received in any order, back into the hex encoded bytes.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    DecodeFountainParts { parts: Vec<String> },
 }
@@ -7,6 +7,8 @@ use std::path::PathBuf;
 use derivative::Derivative;
 #[cfg(feature = "events")]
 use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::Contact;
 use iota_sdk::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
     types::block::address::Hrp,
@@ -41,6 +43,9 @@ pub enum WalletMethod {
         bech32_hrp: Option<Hrp>,
         /// Account addresses.
         addresses: Option<Vec<AccountAddress>>,
+        /// Whether the account is watch-only, it requires the addresses to be set.
+        #[serde(default)]
+        watch_only: bool,
     },
     /// Read account.
     /// Expected response: [`Account`](crate::Response::Account)
@@ -57,6 +62,26 @@ pub enum WalletMethod {
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     GetChrysalisData,
+    /// Add a contact to the address book.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    AddContact { contact: Contact },
+    /// Replace the contact with the given label.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    UpdateContact { label: String, contact: Contact },
+    /// Remove the contact with the given label from the address book.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    RemoveContact { label: String },
+    /// Read the contacts of the address book.
+    /// Expected response: [`Contacts`](crate::Response::Contacts)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    GetContacts,
     /// Consume an account method.
     /// Returns [`Response`](crate::Response)
     #[serde(rename_all = "camelCase")]
@@ -166,6 +191,14 @@ pub enum WalletMethod {
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     GetLedgerNanoStatus,
+    /// Log into the PKCS#11 token with the user PIN.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    SetPkcs11Pin {
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        pin: String,
+    },
     /// Set the stronghold password.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -187,6 +220,22 @@ pub enum WalletMethod {
     StoreMnemonic {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// Optional BIP-39 passphrase
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
+    },
+    /// Recover a mnemonic from SLIP-39 mnemonic shares and store it into the Stronghold vault.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    StoreSlip39Shares {
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        shares: Vec<String>,
+        /// Optional passphrase the shares were encrypted with
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
     },
     /// Start background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
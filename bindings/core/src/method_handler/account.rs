@@ -52,7 +52,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::Output(OutputDto::from(&output))
         }
         AccountMethod::GetIncomingTransaction { transaction_id } => {
-            let transaction = account.get_incoming_transaction(&transaction_id).await;
+            let transaction = account.try_get_incoming_transaction(&transaction_id).await?;
 
             transaction.map_or_else(
                 || Response::Transaction(None),
@@ -60,7 +60,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             )
         }
         AccountMethod::GetOutput { output_id } => {
-            let output_data = account.get_output(&output_id).await;
+            let output_data = account.try_get_output(&output_id).await?;
             Response::OutputData(output_data.as_ref().map(OutputDataDto::from).map(Box::new))
         }
         #[cfg(feature = "participation")]
@@ -89,7 +89,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::AccountParticipationOverview(overview)
         }
         AccountMethod::GetTransaction { transaction_id } => {
-            let transaction = account.get_transaction(&transaction_id).await;
+            let transaction = account.try_get_transaction(&transaction_id).await?;
             Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
         }
         #[cfg(feature = "participation")]
@@ -98,7 +98,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::VotingPower(voting_power.to_string())
         }
         AccountMethod::IncomingTransactions => {
-            let transactions = account.incoming_transactions().await;
+            let transactions = account.try_incoming_transactions().await?;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
         AccountMethod::Outputs { filter_options } => {
@@ -320,6 +320,14 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SignedTransactionData(SignedTransactionDataDto::from(&signed_transaction_data))
         }
+        AccountMethod::SimulateTransaction {
+            prepared_transaction_data,
+        } => {
+            let simulation = account
+                .simulate_transaction(&PreparedTransactionData::try_from_dto(prepared_transaction_data)?)
+                .await?;
+            Response::TransactionSimulation(simulation)
+        }
         AccountMethod::SubmitAndStoreTransaction {
             signed_transaction_data,
         } => {
@@ -334,9 +342,12 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
         }
         AccountMethod::Sync { options } => Response::Balance(account.sync(options).await?),
         AccountMethod::Transactions => {
-            let transactions = account.transactions().await;
+            let transactions = account.try_transactions().await?;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
+        AccountMethod::TransactionHistory { filter } => {
+            Response::TransactionHistory(account.transaction_history(filter).await?)
+        }
         AccountMethod::UnspentOutputs { filter_options } => {
             let outputs = account.unspent_outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
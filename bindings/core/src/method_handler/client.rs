@@ -261,6 +261,12 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         }
         #[cfg(not(target_family = "wasm"))]
         ClientMethod::UnhealthyNodes => Response::UnhealthyNodes(client.unhealthy_nodes().await.into_iter().collect()),
+        ClientMethod::NodeStats => Response::NodeStats(client.node_stats().await),
+        ClientMethod::CacheMetrics => Response::CacheMetrics(client.cache_metrics().await),
+        ClientMethod::ClearCache => {
+            client.clear_cache().await;
+            Response::Ok
+        }
         ClientMethod::GetHealth { url } => Response::Bool(client.get_health(&url).await?),
         ClientMethod::GetNodeInfo { url, auth } => Response::NodeInfo(Client::get_node_info(&url, auth).await?),
         ClientMethod::GetInfo => Response::Info(client.get_info().await?),
@@ -71,10 +71,13 @@ pub(crate) async fn call_secret_manager_method_internal(
             }
         }
         #[cfg(feature = "stronghold")]
-        SecretManagerMethod::StoreMnemonic { mnemonic } => {
+        SecretManagerMethod::StoreMnemonic { mnemonic, passphrase } => {
             let mnemonic = crypto::keys::bip39::Mnemonic::from(mnemonic);
+            let passphrase = crypto::keys::bip39::Passphrase::from(passphrase.unwrap_or_default());
             if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
-                secret_manager.store_mnemonic(mnemonic).await?;
+                secret_manager
+                    .store_mnemonic_with_passphrase(mnemonic, passphrase)
+                    .await?;
                 Response::Ok
             } else {
                 return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crypto::keys::bip39::Mnemonic;
+use crypto::keys::bip39::{Mnemonic, Passphrase};
 use iota_sdk::{
     client::{hex_public_key_to_bech32_address, hex_to_bech32, verify_mnemonic, Client},
     types::{
@@ -15,11 +15,15 @@ use iota_sdk::{
         },
         TryFromDto,
     },
+    utils::fountain::{Decoder, Encoder, Error as FountainError},
 };
 use packable::PackableExt;
 
 use crate::{method::UtilsMethod, response::Response, Result};
 
+// Parts after the fragments only make up for missed ones, so their number is bounded by the size of the message.
+const MAX_FOUNTAIN_PARTS_PER_FRAGMENT: u32 = 10;
+
 /// Call a utils method.
 pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response> {
     let response = match method {
@@ -35,10 +39,30 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
         UtilsMethod::ParseBech32Address { address } => Response::ParsedBech32Address(AddressDto::from(address.inner())),
         UtilsMethod::IsAddressValid { address } => Response::Bool(Address::is_valid_bech32(&address)),
         UtilsMethod::GenerateMnemonic => Response::GeneratedMnemonic(Client::generate_mnemonic()?.to_string()),
-        UtilsMethod::MnemonicToHexSeed { mnemonic } => {
+        UtilsMethod::MnemonicToHexSeed { mnemonic, passphrase } => {
+            let mnemonic = Mnemonic::from(mnemonic);
+            let passphrase = Passphrase::from(passphrase.unwrap_or_default());
+            Response::MnemonicHexSeed(Client::mnemonic_to_hex_seed_with_passphrase(mnemonic, passphrase)?)
+        }
+        UtilsMethod::MnemonicToSlip39Shares {
+            mnemonic,
+            passphrase,
+            group_threshold,
+            groups,
+        } => {
             let mnemonic = Mnemonic::from(mnemonic);
-            Response::MnemonicHexSeed(Client::mnemonic_to_hex_seed(mnemonic)?)
+            let shares =
+                Client::mnemonic_to_slip39_shares(mnemonic, &passphrase.unwrap_or_default(), group_threshold, &groups)?;
+            Response::Slip39Shares(
+                shares
+                    .into_iter()
+                    .map(|group| group.into_iter().map(|share| share.to_string()).collect())
+                    .collect(),
+            )
         }
+        UtilsMethod::Slip39SharesToMnemonic { shares, passphrase } => Response::GeneratedMnemonic(
+            Client::slip39_shares_to_mnemonic(&shares, &passphrase.unwrap_or_default())?.to_string(),
+        ),
         UtilsMethod::BlockId { block } => {
             let block = Block::try_from_dto(block)?;
             Response::BlockId(block.id())
@@ -114,6 +138,29 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let output = Output::try_from_dto(output)?;
             Response::HexBytes(prefix_hex::encode(output.pack_to_vec()))
         }
+        UtilsMethod::EncodeFountainParts {
+            bytes,
+            max_fragment_length,
+            ur_type,
+            count,
+        } => {
+            let mut encoder = Encoder::new(&prefix_hex::decode::<Vec<u8>>(bytes)?, max_fragment_length)?;
+            let count = count.unwrap_or_else(|| encoder.sequence_count());
+            if count > encoder.sequence_count().saturating_mul(MAX_FOUNTAIN_PARTS_PER_FRAGMENT) {
+                return Err(FountainError::InvalidPartCount(count).into());
+            }
+            Response::FountainParts((0..count).map(|_| encoder.next_part().to_ur(&ur_type)).collect())
+        }
+        UtilsMethod::DecodeFountainParts { parts } => {
+            let mut decoder = Decoder::new();
+            for part in parts {
+                if decoder.receive_ur(&part)? {
+                    break;
+                }
+            }
+            let message = decoder.message().ok_or(FountainError::IncompleteMessage)?;
+            Response::HexBytes(prefix_hex::encode(message))
+        }
     };
     Ok(response)
 }
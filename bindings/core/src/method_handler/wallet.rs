@@ -18,8 +18,9 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             alias,
             bech32_hrp,
             addresses,
+            watch_only,
         } => {
-            let mut builder = wallet.create_account();
+            let mut builder = wallet.create_account().with_watch_only(watch_only);
 
             if let Some(alias) = alias {
                 builder = builder.with_alias(alias);
@@ -64,6 +65,23 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Accounts(account_dtos)
         }
         WalletMethod::GetChrysalisData => Response::ChrysalisData(wallet.get_chrysalis_data().await?),
+        #[cfg(feature = "storage")]
+        WalletMethod::AddContact { contact } => {
+            wallet.add_contact(contact).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "storage")]
+        WalletMethod::UpdateContact { label, contact } => {
+            wallet.update_contact(&label, contact).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "storage")]
+        WalletMethod::RemoveContact { label } => {
+            wallet.remove_contact(&label).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "storage")]
+        WalletMethod::GetContacts => Response::Contacts(wallet.contacts().await?),
         WalletMethod::CallAccountMethod { account_id, method } => {
             let account = wallet.get_account(account_id).await?;
             call_account_method_internal(&account, method).await?
@@ -156,6 +174,11 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
 
             Response::Bech32Address(address.to_bech32(bech32_hrp))
         }
+        #[cfg(feature = "pkcs11")]
+        WalletMethod::SetPkcs11Pin { pin } => {
+            wallet.set_pkcs11_pin(pin).await?;
+            Response::Ok
+        }
         #[cfg(feature = "stronghold")]
         WalletMethod::SetStrongholdPassword { password } => {
             wallet.set_stronghold_password(password).await?;
@@ -170,8 +193,17 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Ok
         }
         #[cfg(feature = "stronghold")]
-        WalletMethod::StoreMnemonic { mnemonic } => {
-            wallet.store_mnemonic(mnemonic.into()).await?;
+        WalletMethod::StoreMnemonic { mnemonic, passphrase } => {
+            wallet
+                .store_mnemonic_with_passphrase(mnemonic.into(), passphrase.unwrap_or_default())
+                .await?;
+            Response::Ok
+        }
+        #[cfg(feature = "stronghold")]
+        WalletMethod::StoreSlip39Shares { shares, passphrase } => {
+            wallet
+                .store_slip39_shares(&shares, &passphrase.unwrap_or_default())
+                .await?;
             Response::Ok
         }
         WalletMethod::StartBackgroundSync {
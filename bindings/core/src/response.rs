@@ -8,10 +8,13 @@ use std::collections::HashSet;
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::Contact;
 use iota_sdk::{
     client::{
         api::{PreparedTransactionDataDto, SignedTransactionDataDto},
-        node_manager::node::Node,
+        cache::CacheMetrics,
+        node_manager::{node::Node, stats::NodeStats},
         NetworkInfo, NodeInfoWrapper,
     },
     types::{
@@ -39,7 +42,7 @@ use iota_sdk::{
     },
     wallet::account::{
         types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto},
-        AccountDetailsDto, PreparedCreateNativeTokenTransactionDto,
+        AccountDetailsDto, PreparedCreateNativeTokenTransactionDto, TransactionHistoryEntry, TransactionSimulation,
     },
 };
 use serde::Serialize;
@@ -105,6 +108,12 @@ pub enum Response {
     #[cfg(not(target_family = "wasm"))]
     UnhealthyNodes(HashSet<Node>),
     /// Response for:
+    /// - [`NodeStats`](crate::method::ClientMethod::NodeStats)
+    NodeStats(Vec<NodeStats>),
+    /// Response for:
+    /// - [`CacheMetrics`](crate::method::ClientMethod::CacheMetrics)
+    CacheMetrics(Option<CacheMetrics>),
+    /// Response for:
     /// - [`GetNodeInfo`](crate::method::ClientMethod::GetNodeInfo)
     NodeInfo(NodeInfo),
     /// Response for:
@@ -203,6 +212,9 @@ pub enum Response {
     /// - [`MnemonicToHexSeed`](crate::method::UtilsMethod::MnemonicToHexSeed)
     MnemonicHexSeed(#[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))] String),
     /// Response for:
+    /// - [`MnemonicToSlip39Shares`](crate::method::UtilsMethod::MnemonicToSlip39Shares)
+    Slip39Shares(#[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))] Vec<Vec<String>>),
+    /// Response for:
     /// - [`MilestoneId`](crate::method::UtilsMethod::MilestoneId)
     MilestoneId(MilestoneId),
     /// Response for:
@@ -228,8 +240,12 @@ pub enum Response {
     NodeInfoWrapper(NodeInfoWrapper),
     /// Response for [`Bech32ToHex`](crate::method::UtilsMethod::Bech32ToHex)
     HexAddress(String),
-    /// Response for [`OutputHexBytes`](crate::method::UtilsMethod::OutputHexBytes)
+    /// Response for:
+    /// - [`OutputHexBytes`](crate::method::UtilsMethod::OutputHexBytes)
+    /// - [`DecodeFountainParts`](crate::method::UtilsMethod::DecodeFountainParts)
     HexBytes(String),
+    /// Response for [`EncodeFountainParts`](crate::method::UtilsMethod::EncodeFountainParts)
+    FountainParts(Vec<String>),
     /// Response for [`CallPluginRoute`](crate::method::ClientMethod::CallPluginRoute)
     CustomJson(serde_json::Value),
 
@@ -252,6 +268,7 @@ pub enum Response {
     Faucet(String),
     /// Response for:
     /// - [`GenerateMnemonic`](crate::method::UtilsMethod::GenerateMnemonic)
+    /// - [`Slip39SharesToMnemonic`](crate::method::UtilsMethod::Slip39SharesToMnemonic)
     GeneratedMnemonic(#[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))] String),
     /// Response for
     /// - [`GetLedgerNanoStatus`](crate::method::SecretManagerMethod::GetLedgerNanoStatus)
@@ -274,6 +291,7 @@ pub enum Response {
     Bool(bool),
     /// Response for:
     /// - [`Backup`](crate::method::WalletMethod::Backup),
+    /// - [`ClearCache`](crate::method::ClientMethod::ClearCache),
     /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
@@ -282,6 +300,7 @@ pub enum Response {
     /// - [`SetAlias`](crate::method::AccountMethod::SetAlias),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
     /// - [`SetDefaultSyncOptions`](crate::method::AccountMethod::SetDefaultSyncOptions),
+    /// - [`SetPkcs11Pin`](crate::method::WalletMethod::SetPkcs11Pin),
     /// - [`SetStrongholdPassword`](crate::method::WalletMethod::SetStrongholdPassword),
     /// - [`SetStrongholdPasswordClearInterval`](crate::method::WalletMethod::SetStrongholdPasswordClearInterval),
     /// - [`StartBackgroundSync`](crate::method::WalletMethod::StartBackgroundSync),
@@ -314,6 +333,11 @@ pub enum Response {
     /// - [`GetChrysalisData`](crate::method::WalletMethod::GetChrysalisData)
     ChrysalisData(Option<HashMap<String, String>>),
     /// Response for:
+    /// - [`GetContacts`](crate::method::WalletMethod::GetContacts)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    Contacts(Vec<Contact>),
+    /// Response for:
     /// - [`MinimumRequiredStorageDeposit`](crate::method::ClientMethod::MinimumRequiredStorageDeposit)
     /// - [`ComputeStorageDeposit`](crate::method::UtilsMethod::ComputeStorageDeposit)
     MinimumRequiredStorageDeposit(String),
@@ -356,8 +380,14 @@ pub enum Response {
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     Transactions(Vec<TransactionDto>),
     /// Response for:
+    /// - [`TransactionHistory`](crate::method::AccountMethod::TransactionHistory)
+    TransactionHistory(Vec<TransactionHistoryEntry>),
+    /// Response for:
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
     SignedTransactionData(SignedTransactionDataDto),
+    /// Response for:
+    /// - [`SimulateTransaction`](crate::method::AccountMethod::SimulateTransaction)
+    TransactionSimulation(TransactionSimulation),
     /// GenerateAddress response.
     /// Response for:
     /// - [`GenerateEd25519Addresses`](crate::method::AccountMethod::GenerateEd25519Addresses)
@@ -39,6 +39,7 @@ async fn create_account() -> Result<()> {
             alias: None,
             bech32_hrp: None,
             addresses: None,
+            watch_only: false,
         })
         .await;
 
@@ -107,6 +108,7 @@ async fn verify_accounts() -> Result<()> {
                     alias: Some(alias.to_owned()),
                     bech32_hrp: None,
                     addresses: None,
+                    watch_only: false,
                 })
                 .await,
         );
@@ -205,6 +207,7 @@ async fn client_from_wallet() -> Result<()> {
             alias: None,
             bech32_hrp: None,
             addresses: None,
+            watch_only: false,
         })
         .await;
 
@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::secret::SecretManagerDto;
+use iota_sdk::client::secret::{MnemonicWithPassphraseDto, SecretManagerDto};
 use iota_sdk_bindings_core::{ClientMethod, Response, UtilsMethod, WalletOptions};
 
 #[test]
@@ -29,10 +29,20 @@ fn method_interface_secrets_debug() {
 
     let client_method = UtilsMethod::MnemonicToHexSeed {
         mnemonic: "mnemonic".to_string(),
+        passphrase: Some("passphrase".to_string()),
     };
     assert_eq!(
         format!("{:?}", client_method),
-        "MnemonicToHexSeed { mnemonic: <omitted> }"
+        "MnemonicToHexSeed { mnemonic: <omitted>, passphrase: Some(<omitted>) }"
+    );
+
+    let secret_manager = SecretManagerDto::MnemonicWithPassphrase(MnemonicWithPassphraseDto {
+        mnemonic: "mnemonic".to_string().into(),
+        passphrase: "passphrase".to_string().into(),
+    });
+    assert_eq!(
+        format!("{:?}", secret_manager),
+        "MnemonicWithPassphrase(MnemonicWithPassphraseDto { .. })"
     );
 
     let wallet_method = UtilsMethod::VerifyMnemonic {
@@ -10,6 +10,8 @@ import {
     PreparedTransactionData,
     INetworkInfo,
     INode,
+    INodeStats,
+    ICacheMetrics,
     IAuth,
     BasicOutputBuilderParams,
     AliasOutputBuilderParams,
@@ -1094,6 +1096,37 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Return the request statistics and circuit breaker state of each node.
+     */
+    async nodeStats(): Promise<INodeStats[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'nodeStats',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Return the hit and miss metrics of the cache of immutable node responses, if it's enabled.
+     */
+    async cacheMetrics(): Promise<ICacheMetrics | undefined> {
+        const response = await this.methodHandler.callMethod({
+            name: 'cacheMetrics',
+        });
+
+        return JSON.parse(response).payload ?? undefined;
+    }
+
+    /**
+     * Remove all responses from the cache of immutable node responses.
+     */
+    async clearCache(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'clearCache',
+        });
+    }
+
     /**
      * Build a basic output.
      *
@@ -75,12 +75,14 @@ export class SecretManager {
      * Store a mnemonic in the Stronghold vault.
      *
      * @param mnemonic The mnemonic to store.
+     * @param passphrase An optional BIP-39 passphrase, which isn't stored.
      */
-    async storeMnemonic(mnemonic: string): Promise<void> {
+    async storeMnemonic(mnemonic: string, passphrase?: string): Promise<void> {
         const response = await this.methodHandler.callMethod({
             name: 'storeMnemonic',
             data: {
                 mnemonic,
+                passphrase,
             },
         });
 
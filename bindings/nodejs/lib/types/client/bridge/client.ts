@@ -408,6 +408,18 @@ export interface __UnhealthyNodesMethod__ {
     name: 'unhealthyNodes';
 }
 
+export interface __NodeStatsMethod__ {
+    name: 'nodeStats';
+}
+
+export interface __CacheMetricsMethod__ {
+    name: 'cacheMetrics';
+}
+
+export interface __ClearCacheMethod__ {
+    name: 'clearCache';
+}
+
 export interface __BuildBasicOutputMethod__ {
     name: 'buildBasicOutput';
     data: BasicOutputBuilderParams;
@@ -55,6 +55,9 @@ import type {
     __PromoteMethod__,
     __PromoteUncheckedMethod__,
     __UnhealthyNodesMethod__,
+    __NodeStatsMethod__,
+    __CacheMetricsMethod__,
+    __ClearCacheMethod__,
     __GetMilestoneByIdMethod__,
     __GetUtxoChangesByIdMethod__,
     __GetMilestoneByIndexMethod__,
@@ -129,6 +132,9 @@ export type __ClientMethods__ =
     | __PromoteMethod__
     | __PromoteUncheckedMethod__
     | __UnhealthyNodesMethod__
+    | __NodeStatsMethod__
+    | __CacheMetricsMethod__
+    | __ClearCacheMethod__
     | __BuildBasicOutputMethod__
     | __BuildAliasOutputMethod__
     | __BuildFoundryOutputMethod__
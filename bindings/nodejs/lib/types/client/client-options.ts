@@ -38,6 +38,69 @@ export interface IClientOptions {
     localPow?: boolean;
     /** The maximum parallel API requests. */
     maxParallelApiRequests?: number;
+    /** Policy to retry GET requests that failed with transient errors, not retried if unset */
+    retryPolicy?: IRetryPolicy;
+    /** Policy to retry POST requests, like the submission of blocks, that failed with transient errors, not retried if unset */
+    submitRetryPolicy?: IRetryPolicy;
+    /** Options to cache the responses of routes that return immutable resources */
+    cacheOptions?: ICacheOptions;
+}
+
+/** Classes of node errors that can be retried */
+export type RetryableError =
+    | 'connection'
+    | 'timeout'
+    | 'tooManyRequests'
+    | 'serverError';
+
+/** Policy to retry node requests that failed with transient errors, unset fields keep their default */
+export interface IRetryPolicy {
+    /** The maximum number of times the nodes are tried, 1 to not retry */
+    maxAttempts?: number;
+    /** The delay before the first retry */
+    initialBackoff?: IDuration;
+    /** The maximum delay before a retry */
+    maxBackoff?: IDuration;
+    /** The factor by which the delay grows after each retry */
+    backoffMultiplier?: number;
+    /** Whether the delay is randomly shortened by up to half, so clients don't retry at the same time */
+    jitter?: boolean;
+    /** Whether to wait as long as a node asks for with a `Retry-After` header, up to `maxBackoff` */
+    respectRetryAfter?: boolean;
+    /** The errors after which requests are retried */
+    retryOn?: RetryableError[];
+}
+
+/** Options of the cache of immutable node responses, unset fields keep their default */
+export interface ICacheOptions {
+    /** The maximum number of responses kept in memory */
+    maxEntries?: number;
+    /** The maximum total size of the responses kept in memory, in bytes */
+    maxSize?: number;
+    /** How long responses are kept, forever if not set */
+    ttl?: IDuration;
+    /** A directory in which responses are also stored, to keep them across restarts */
+    directory?: string;
+    /** The maximum total size of the responses stored in the directory, in bytes */
+    maxDirectorySize?: number;
+}
+
+/** Metrics of the cache of immutable node responses */
+export interface ICacheMetrics {
+    /** The number of requests answered from the cache */
+    hits: number;
+    /** The number of hits that were loaded from the directory */
+    directoryHits: number;
+    /** The number of requests of cacheable routes that were sent to a node */
+    misses: number;
+    /** The number of responses added to the cache */
+    insertions: number;
+    /** The number of responses removed from memory because of the limits or their TTL */
+    evictions: number;
+    /** The number of responses in memory */
+    entries: number;
+    /** The total size of the responses in memory, in bytes */
+    size: number;
 }
 
 /** Time duration */
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { INodeInfoProtocol } from '../models/info';
+import type { IDuration } from './client-options';
 
 /**
  * Network types.
@@ -51,6 +52,40 @@ export interface INode {
     disabled?: boolean;
 }
 
+/**
+ * The state of the circuit breaker of a node.
+ */
+export enum CircuitState {
+    /** The node is used normally. */
+    Closed = 'closed',
+    /** The node failed too often and is only used if no other node answers. */
+    Open = 'open',
+    /** The node can be probed with a single request to close the circuit again. */
+    HalfOpen = 'halfOpen',
+}
+
+/**
+ * Statistics of the requests sent to a node.
+ */
+export interface INodeStats {
+    /** The URL of the node. */
+    url: string;
+    /** The number of requests sent to the node. */
+    requests: number;
+    /** The number of requests the node failed to answer. */
+    failures: number;
+    /** The average latency of the recent successful requests. */
+    latency?: IDuration;
+    /** The share of the recent requests the node failed to answer. */
+    errorRate: number;
+    /** The number of milestones the node was behind the other nodes at the last sync. */
+    milestoneLag?: number;
+    /** The number of failed requests since the last successful one. */
+    consecutiveFailures: number;
+    /** The state of the circuit breaker. */
+    circuitState: CircuitState;
+}
+
 /**
  * Struct containing network and PoW related information
  */
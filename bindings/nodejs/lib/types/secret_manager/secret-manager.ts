@@ -15,6 +15,16 @@ export interface MnemonicSecretManager {
     mnemonic: string;
 }
 
+/** Secret manager that uses a mnemonic and a BIP-39 passphrase. */
+export interface MnemonicWithPassphraseSecretManager {
+    mnemonicWithPassphrase: {
+        /** The underlying mnemonic. */
+        mnemonic: string;
+        /** The BIP-39 passphrase, also known as the 25th word. */
+        passphrase: string;
+    };
+}
+
 /** Secret manager that uses a seed. */
 export interface SeedSecretManager {
     /** The underlying seed. */
@@ -38,13 +48,42 @@ export interface PrivateKeySecretManager {
     privateKey: HexEncodedString;
 }
 
+/** Secret manager that forwards address generation and signing to a remote signer over JSON-RPC. */
+export interface RemoteSecretManager {
+    remote: {
+        /** The HTTP URL or the Unix socket path of the signer. */
+        endpoint: { http: string } | { unixSocket: string };
+        /** The timeout of a request in seconds. */
+        timeout?: number;
+    };
+}
+
+/** Secret manager that keeps Ed25519 keys in a PKCS#11 token, like a hardware security module. */
+export interface Pkcs11SecretManager {
+    pkcs11: {
+        /** The path of the PKCS#11 module. */
+        modulePath: string;
+        /** The label of the token. */
+        tokenLabel: string;
+        /** The user PIN of the token. */
+        pin?: string;
+        /** The prefix of the key labels. */
+        keyLabelPrefix?: string;
+        /** Whether missing key pairs are generated in the token, defaults to `false`. */
+        generateKeys?: boolean;
+    };
+}
+
 /** Supported secret managers */
 export type SecretManagerType =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
+    | MnemonicWithPassphraseSecretManager
     | SeedSecretManager
     | StrongholdSecretManager
     | PrivateKeySecretManager
+    | RemoteSecretManager
+    | Pkcs11SecretManager
     | PlaceholderSecretManager;
 
 export interface Secp256k1EcdsaSignature {
@@ -69,3 +108,11 @@ export interface Bip44 {
     /** The address index segment. */
     addressIndex?: number;
 }
+
+/** A group of SLIP-39 mnemonic shares. */
+export interface Slip39Group {
+    /** The number of member shares required to recover the group secret. */
+    threshold: number;
+    /** The number of member shares in the group. */
+    count: number;
+}
@@ -1,6 +1,8 @@
 import type {
     __GenerateMnemonicMethod__,
     __MnemonicToHexSeedMethod__,
+    __MnemonicToSlip39SharesMethod__,
+    __Slip39SharesToMnemonicMethod__,
     __ComputeAliasIdMethod__,
     __ComputeOutputIdMethod__,
     __ComputeTokenIdMethod__,
@@ -25,11 +27,15 @@ import type {
     __FaucetMethod__,
     __OutputIdToUtxoInput__,
     __OutputHexBytes__,
+    __EncodeFountainParts__,
+    __DecodeFountainParts__,
 } from './utils';
 
 export type __UtilsMethods__ =
     | __GenerateMnemonicMethod__
     | __MnemonicToHexSeedMethod__
+    | __MnemonicToSlip39SharesMethod__
+    | __Slip39SharesToMnemonicMethod__
     | __ComputeAliasIdMethod__
     | __ComputeNftIdMethod__
     | __ComputeFoundryIdMethod__
@@ -53,4 +59,6 @@ export type __UtilsMethods__ =
     | __VerifyMnemonicMethod__
     | __FaucetMethod__
     | __OutputIdToUtxoInput__
-    | __OutputHexBytes__;
+    | __OutputHexBytes__
+    | __EncodeFountainParts__
+    | __DecodeFountainParts__;
@@ -12,6 +12,7 @@ import {
     OutputId,
     NftId,
     Bech32Address,
+    Slip39Group,
 } from '../../';
 import { AliasId } from '../../block/id';
 
@@ -23,6 +24,25 @@ export interface __MnemonicToHexSeedMethod__ {
     name: 'mnemonicToHexSeed';
     data: {
         mnemonic: string;
+        passphrase?: string;
+    };
+}
+
+export interface __MnemonicToSlip39SharesMethod__ {
+    name: 'mnemonicToSlip39Shares';
+    data: {
+        mnemonic: string;
+        passphrase?: string;
+        groupThreshold: number;
+        groups: Slip39Group[];
+    };
+}
+
+export interface __Slip39SharesToMnemonicMethod__ {
+    name: 'slip39SharesToMnemonic';
+    data: {
+        shares: string[];
+        passphrase?: string;
     };
 }
 
@@ -205,3 +225,20 @@ export interface __OutputHexBytes__ {
         output: Output;
     };
 }
+
+export interface __EncodeFountainParts__ {
+    name: 'encodeFountainParts';
+    data: {
+        bytes: HexEncodedString;
+        maxFragmentLength: number;
+        urType: string;
+        count?: number;
+    };
+}
+
+export interface __DecodeFountainParts__ {
+    name: 'decodeFountainParts';
+    data: {
+        parts: string[];
+    };
+}
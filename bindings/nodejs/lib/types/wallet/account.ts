@@ -190,6 +190,8 @@ export interface CreateAccountPayload {
     bech32Hrp?: string;
     /** Account addresses to use. */
     addresses?: AccountAddress[];
+    /** Whether the account only watches the given addresses, without generating addresses or signing. */
+    watchOnly?: boolean;
 }
 
 /** Options to filter outputs */
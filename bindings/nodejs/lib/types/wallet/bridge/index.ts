@@ -73,10 +73,12 @@ import type {
     __RestoreBackupMethod__,
     __SetClientOptionsMethod__,
     __SetStrongholdPasswordClearIntervalMethod__,
+    __SetPkcs11PinMethod__,
     __SetStrongholdPasswordMethod__,
     __StartBackgroundSyncMethod__,
     __StopBackgroundSyncMethod__,
     __StoreMnemonicMethod__,
+    __StoreSlip39SharesMethod__,
     __UpdateNodeAuthMethod__,
 } from './wallet';
 
@@ -163,8 +165,10 @@ export type __Method__ =
     | __RestoreBackupMethod__
     | __SetClientOptionsMethod__
     | __SetStrongholdPasswordClearIntervalMethod__
+    | __SetPkcs11PinMethod__
     | __SetStrongholdPasswordMethod__
     | __StartBackgroundSyncMethod__
     | __StopBackgroundSyncMethod__
     | __StoreMnemonicMethod__
+    | __StoreSlip39SharesMethod__
     | __UpdateNodeAuthMethod__;
@@ -106,6 +106,11 @@ export type __SetClientOptionsMethod__ = {
     data: { clientOptions: IClientOptions };
 };
 
+export type __SetPkcs11PinMethod__ = {
+    name: 'setPkcs11Pin';
+    data: { pin: string };
+};
+
 export type __SetStrongholdPasswordMethod__ = {
     name: 'setStrongholdPassword';
     data: { password: string };
@@ -130,7 +135,12 @@ export type __StopBackgroundSyncMethod__ = {
 
 export type __StoreMnemonicMethod__ = {
     name: 'storeMnemonic';
-    data: { mnemonic: string };
+    data: { mnemonic: string; passphrase?: string };
+};
+
+export type __StoreSlip39SharesMethod__ = {
+    name: 'storeSlip39Shares';
+    data: { shares: string[]; passphrase?: string };
 };
 
 export type __UpdateNodeAuthMethod__ = {
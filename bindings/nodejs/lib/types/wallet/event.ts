@@ -51,6 +51,12 @@ enum WalletEventType {
     TransactionInclusion = 4,
     /** A progress update while submitting a transaction. */
     TransactionProgress = 5,
+    /** A scheduled payment was sent. */
+    ScheduledPaymentExecuted = 6,
+    /** A scheduled payment was skipped because of insufficient funds. */
+    ScheduledPaymentSkipped = 7,
+    /** Sending a scheduled payment failed. */
+    ScheduledPaymentFailed = 8,
 }
 
 /**
@@ -149,6 +155,73 @@ class TransactionInclusionWalletEvent extends WalletEvent {
     }
 }
 
+/**
+ * A 'scheduled payment executed' wallet event.
+ */
+class ScheduledPaymentExecutedWalletEvent extends WalletEvent {
+    scheduledPaymentId: string;
+    transactionId: TransactionId;
+    nextRun?: number;
+
+    /**
+     * @param scheduledPaymentId The ID of the scheduled payment.
+     * @param transactionId The ID of the transaction that sent the payment.
+     * @param nextRun The time the payment is sent next, if the schedule didn't end.
+     */
+    constructor(
+        scheduledPaymentId: string,
+        transactionId: TransactionId,
+        nextRun?: number,
+    ) {
+        super(WalletEventType.ScheduledPaymentExecuted);
+        this.scheduledPaymentId = scheduledPaymentId;
+        this.transactionId = transactionId;
+        this.nextRun = nextRun;
+    }
+}
+
+/**
+ * A 'scheduled payment skipped' wallet event.
+ */
+class ScheduledPaymentSkippedWalletEvent extends WalletEvent {
+    scheduledPaymentId: string;
+    error: string;
+    nextRun?: number;
+
+    /**
+     * @param scheduledPaymentId The ID of the scheduled payment.
+     * @param error Why the account didn't have enough funds.
+     * @param nextRun The time the payment is sent next, if the schedule didn't end.
+     */
+    constructor(scheduledPaymentId: string, error: string, nextRun?: number) {
+        super(WalletEventType.ScheduledPaymentSkipped);
+        this.scheduledPaymentId = scheduledPaymentId;
+        this.error = error;
+        this.nextRun = nextRun;
+    }
+}
+
+/**
+ * A 'scheduled payment failed' wallet event.
+ */
+class ScheduledPaymentFailedWalletEvent extends WalletEvent {
+    scheduledPaymentId: string;
+    error: string;
+    nextRun?: number;
+
+    /**
+     * @param scheduledPaymentId The ID of the scheduled payment.
+     * @param error The error sending the payment.
+     * @param nextRun The time the payment is tried again or sent next, if the schedule didn't end.
+     */
+    constructor(scheduledPaymentId: string, error: string, nextRun?: number) {
+        super(WalletEventType.ScheduledPaymentFailed);
+        this.scheduledPaymentId = scheduledPaymentId;
+        this.error = error;
+        this.nextRun = nextRun;
+    }
+}
+
 /**
  * All of the transaction progress types.
  */
@@ -299,6 +372,9 @@ export {
     SpentOutputWalletEvent,
     TransactionInclusionWalletEvent,
     TransactionProgressWalletEvent,
+    ScheduledPaymentExecutedWalletEvent,
+    ScheduledPaymentSkippedWalletEvent,
+    ScheduledPaymentFailedWalletEvent,
     TransactionProgress,
     SelectingInputsProgress,
     GeneratingRemainderDepositAddressProgress,
@@ -27,8 +27,23 @@ export interface TransactionOptions {
     note?: string;
     /** Whether to allow sending a micro amount. */
     allowMicroAmount?: boolean;
+    /** The strategy deciding which inputs are used to cover the amount of the outputs, `smallestFirst` by default. */
+    coinSelectionStrategy?: CoinSelectionStrategy;
 }
 
+/**
+ * The possible coin selection strategies.
+ * - `largestFirst`: select the inputs with the highest amounts first.
+ * - `smallestFirst`: select the inputs with the lowest amounts first, to consume many small outputs.
+ * - `branchAndBound`: look for inputs with exactly the required amount, to avoid a remainder.
+ * - `privacyPreserving`: select inputs of as few addresses as possible.
+ */
+export type CoinSelectionStrategy =
+    | 'largestFirst'
+    | 'smallestFirst'
+    | 'branchAndBound'
+    | 'privacyPreserving';
+
 /** The possible remainder value strategies. */
 export type RemainderValueStrategy =
     | ChangeAddress
@@ -17,6 +17,7 @@ import {
     IRent,
     OutputId,
     Bech32Address,
+    Slip39Group,
 } from '../types';
 import { AliasId, BlockId, FoundryId, NftId, TokenId } from '../types/block/id';
 
@@ -35,13 +36,64 @@ export class Utils {
      * Convert a mnemonic to a hex encoded seed.
      *
      * @param mnemonic A mnemonic string.
+     * @param passphrase An optional BIP-39 passphrase.
      * @returns The seed as hex-encoded string.
      */
-    static mnemonicToHexSeed(mnemonic: string): HexEncodedString {
+    static mnemonicToHexSeed(
+        mnemonic: string,
+        passphrase?: string,
+    ): HexEncodedString {
         return callUtilsMethod({
             name: 'mnemonicToHexSeed',
             data: {
                 mnemonic,
+                passphrase,
+            },
+        });
+    }
+
+    /**
+     * Split a mnemonic into groups of SLIP-39 mnemonic shares.
+     *
+     * @param mnemonic A mnemonic string.
+     * @param groupThreshold The number of groups required to recover the mnemonic.
+     * @param groups The member threshold and member count of each group.
+     * @param passphrase An optional passphrase to encrypt the shares with.
+     * @returns The mnemonic shares of each group.
+     */
+    static mnemonicToSlip39Shares(
+        mnemonic: string,
+        groupThreshold: number,
+        groups: Slip39Group[],
+        passphrase?: string,
+    ): string[][] {
+        return callUtilsMethod({
+            name: 'mnemonicToSlip39Shares',
+            data: {
+                mnemonic,
+                passphrase,
+                groupThreshold,
+                groups,
+            },
+        });
+    }
+
+    /**
+     * Recover a mnemonic from SLIP-39 mnemonic shares.
+     *
+     * @param shares The SLIP-39 mnemonic shares.
+     * @param passphrase The optional passphrase the shares were encrypted with.
+     * @returns The mnemonic.
+     */
+    static slip39SharesToMnemonic(
+        shares: string[],
+        passphrase?: string,
+    ): string {
+        return callUtilsMethod({
+            name: 'slip39SharesToMnemonic',
+            data: {
+                shares,
+                passphrase,
             },
         });
     }
@@ -420,4 +472,46 @@ export class Utils {
         });
         return hexBytes;
     }
+
+    /**
+     * Encode packed bytes into fountain-coded UR parts, e.g. for animated QR codes.
+     *
+     * @param bytes The hex encoded packed bytes.
+     * @param maxFragmentLength The maximum length of the data of a single part.
+     * @param urType The UR type of the parts.
+     * @param count The number of parts to generate, defaults to the number of fragments and can be at most 10 times
+     * the number of fragments.
+     * @returns The UR parts.
+     */
+    static encodeFountainParts(
+        bytes: HexEncodedString,
+        maxFragmentLength: number,
+        urType: string,
+        count?: number,
+    ): string[] {
+        return callUtilsMethod({
+            name: 'encodeFountainParts',
+            data: {
+                bytes,
+                maxFragmentLength,
+                urType,
+                count,
+            },
+        });
+    }
+
+    /**
+     * Decode fountain-coded UR parts, received in any order, into the packed bytes.
+     *
+     * @param parts The UR parts.
+     * @returns The hex encoded packed bytes.
+     */
+    static decodeFountainParts(parts: string[]): HexEncodedString {
+        return callUtilsMethod({
+            name: 'decodeFountainParts',
+            data: {
+                parts,
+            },
+        });
+    }
 }
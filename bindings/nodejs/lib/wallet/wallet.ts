@@ -300,6 +300,16 @@ export class Wallet {
         });
     }
 
+    /**
+     * Log into the PKCS#11 token with the user PIN.
+     */
+    async setPkcs11Pin(pin: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'setPkcs11Pin',
+            data: { pin },
+        });
+    }
+
     /**
      * Set the Stronghold password.
      */
@@ -348,12 +358,25 @@ export class Wallet {
     }
 
     /**
-     * Store a mnemonic in the Stronghold snapshot.
+     * Store a mnemonic in the Stronghold snapshot, with an optional BIP-39 passphrase which isn't stored.
      */
-    async storeMnemonic(mnemonic: string): Promise<void> {
+    async storeMnemonic(mnemonic: string, passphrase?: string): Promise<void> {
         await this.methodHandler.callMethod({
             name: 'storeMnemonic',
-            data: { mnemonic },
+            data: { mnemonic, passphrase },
+        });
+    }
+
+    /**
+     * Recover a mnemonic from SLIP-39 mnemonic shares and store it in the Stronghold snapshot.
+     */
+    async storeSlip39Shares(
+        shares: string[],
+        passphrase?: string,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'storeSlip39Shares',
+            data: { shares, passphrase },
         });
     }
 
@@ -13,6 +13,7 @@ from .prefix_hex import *
 from .types.address import *
 from .types.balance import *
 from .types.block import *
+from .types.cache import *
 from .types.block_builder_options import *
 from .types.burn import *
 from .types.client_options import *
@@ -26,12 +27,15 @@ from .types.input import *
 from .types.native_token import *
 from .types.network_info import *
 from .types.node_info import *
+from .types.node_stats import *
 from .types.output import *
 from .types.output_data import *
 from .types.output_id import *
 from .types.output_params import *
 from .types.payload import *
+from .types.retry_policy import *
 from .types.send_params import *
+from .types.slip39 import *
 from .types.token_scheme import *
 from .types.transaction import *
 from .types.transaction_data import *
@@ -9,12 +9,15 @@ from iota_sdk.client._high_level_api import HighLevelAPI
 from iota_sdk.client._utils import ClientUtils
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager
 from iota_sdk.types.block import Block
+from iota_sdk.types.cache import CacheMetrics, CacheOptions
 from iota_sdk.types.common import HexStr, Node, AddressAndAmount
 from iota_sdk.types.feature import Feature
 from iota_sdk.types.native_token import NativeToken
 from iota_sdk.types.network_info import NetworkInfo
+from iota_sdk.types.node_stats import CircuitState, NodeStats
 from iota_sdk.types.output import AliasOutput, BasicOutput, FoundryOutput, NftOutput, output_from_dict
 from iota_sdk.types.payload import Payload, TransactionPayload
+from iota_sdk.types.retry_policy import RetryPolicy
 from iota_sdk.types.token_scheme import SimpleTokenScheme
 from iota_sdk.types.unlock_condition import UnlockCondition
 from iota_sdk.types.transaction_data import PreparedTransactionData
@@ -22,7 +25,7 @@ from json import dumps, loads
 import humps
 from datetime import timedelta
 from typing import Any, Dict, List, Optional, Union
-from dacite import from_dict
+from dacite import Config, from_dict
 
 
 class ClientError(Exception):
@@ -55,6 +58,9 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         local_pow: Optional[bool] = None,
         fallback_to_local_pow: Optional[bool] = None,
         pow_worker_count: Optional[int] = None,
+        retry_policy: Optional[RetryPolicy] = None,
+        submit_retry_policy: Optional[RetryPolicy] = None,
+        cache_options: Optional[CacheOptions] = None,
         client_handle=None
     ):
         """Initialize the IOTA Client.
@@ -92,6 +98,12 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Fallback to local proof of work if the node doesn't support remote PoW.
         pow_worker_count :
             The amount of threads to be used for proof of work.
+        retry_policy :
+            Policy to retry GET requests that failed with transient errors, not retried if unset.
+        submit_retry_policy :
+            Policy to retry POST requests, like the submission of blocks, that failed with transient errors, not retried if unset.
+        cache_options :
+            Options to cache the responses of routes that return immutable resources.
         client_handle :
             An instance of a node client.
         """
@@ -129,6 +141,12 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         if 'remote_pow_timeout' in client_config:
             client_config['remote_pow_timeout'] = {'secs': int(client_config['remote_pow_timeout'].total_seconds(
             )), 'nanos': get_remaining_nano_seconds(client_config['remote_pow_timeout'])}
+        if 'retry_policy' in client_config:
+            client_config['retry_policy'] = client_config['retry_policy'].as_dict()
+        if 'submit_retry_policy' in client_config:
+            client_config['submit_retry_policy'] = client_config['submit_retry_policy'].as_dict()
+        if 'cache_options' in client_config:
+            client_config['cache_options'] = client_config['cache_options'].as_dict()
 
         client_config = humps.camelize(client_config)
         client_config_str = dumps(client_config)
@@ -470,6 +488,23 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         """
         return self._call_method('unhealthyNodes')
 
+    def node_stats(self) -> List[NodeStats]:
+        """Returns the request statistics and circuit breaker state of each node.
+        """
+        return [from_dict(NodeStats, stats, Config(cast=[CircuitState]))
+                for stats in self._call_method('nodeStats')]
+
+    def cache_metrics(self) -> Optional[CacheMetrics]:
+        """Returns the hit and miss metrics of the cache of immutable node responses, if it's enabled.
+        """
+        metrics = self._call_method('cacheMetrics')
+        return from_dict(CacheMetrics, metrics) if metrics is not None else None
+
+    def clear_cache(self):
+        """Removes all responses from the cache of immutable node responses.
+        """
+        return self._call_method('clearCache')
+
     def prepare_transaction(self,
                             secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager,
                                                      SeedSecretManager, StrongholdSecretManager]] = None,
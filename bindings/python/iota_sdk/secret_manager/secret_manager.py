@@ -41,6 +41,23 @@ class MnemonicSecretManager(dict):
         dict.__init__(self, mnemonic=mnemonic)
 
 
+class MnemonicWithPassphraseSecretManager(dict):
+    """Secret manager that uses a mnemonic and a BIP-39 passphrase held in memory.
+    This is not recommended in production. Use LedgerNano or Stronghold instead.
+    """
+
+    def __init__(self, mnemonic, passphrase):
+        """Initialize a mnemonic secret manager with a passphrase.
+
+        Args:
+            mnemonic: The root secret of this type of secret manager.
+            passphrase: The BIP-39 passphrase, also known as the 25th word.
+        """
+
+        dict.__init__(self, mnemonicWithPassphrase={
+            'mnemonic': mnemonic, 'passphrase': passphrase})
+
+
 class SeedSecretManager(dict):
     """Secret manager that uses a seed.
     """
@@ -75,6 +92,48 @@ class StrongholdSecretManager(dict):
             dict.__init__(self, password=password, snapshotPath=snapshot_path)
 
 
+class RemoteSecretManager(dict):
+    """Secret manager that forwards address generation and signing to a remote signer over JSON-RPC.
+    """
+
+    def __init__(self, http_url: Optional[str] = None, unix_socket_path: Optional[str] = None,
+                 timeout: Optional[int] = None):
+        """Initialize a remote secret manager, with either an HTTP URL or a Unix socket path.
+
+        Args:
+            http_url: The URL of a signer accepting JSON-RPC requests over HTTP.
+            unix_socket_path: The path of a Unix socket of a signer accepting JSON-RPC requests.
+            timeout: The timeout of a request in seconds.
+        """
+
+        if (http_url is None) == (unix_socket_path is None):
+            raise ValueError(
+                'Exactly one of http_url and unix_socket_path must be provided')
+        endpoint = {'http': http_url} if http_url is not None else {
+            'unixSocket': unix_socket_path}
+        dict.__init__(self, remote={'endpoint': endpoint, 'timeout': timeout})
+
+
+class Pkcs11SecretManager(dict):
+    """Secret manager that keeps Ed25519 keys in a PKCS#11 token, like a hardware security module.
+    """
+
+    def __init__(self, module_path: str, token_label: str, pin: Optional[str] = None,
+                 key_label_prefix: Optional[str] = None, generate_keys: Optional[bool] = None):
+        """Initialize a PKCS#11 secret manager.
+
+        Args:
+            module_path: The path of the PKCS#11 module.
+            token_label: The label of the token.
+            pin: The user PIN of the token.
+            key_label_prefix: The prefix of the key labels.
+            generate_keys: Whether missing key pairs are generated in the token, defaults to `False`.
+        """
+
+        dict.__init__(self, pkcs11={'modulePath': module_path, 'tokenLabel': token_label, 'pin': pin,
+                                    'keyLabelPrefix': key_label_prefix, 'generateKeys': generate_keys})
+
+
 class SecretManagerError(Exception):
     """Secret manager error.
     """
@@ -83,7 +142,9 @@ class SecretManagerError(Exception):
 
 class SecretManager():
     def __init__(self, secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager,
-                 SeedSecretManager, StrongholdSecretManager]] = None, secret_manager_handle=None):
+                 MnemonicWithPassphraseSecretManager, SeedSecretManager, StrongholdSecretManager,
+                 RemoteSecretManager, Pkcs11SecretManager]] = None,
+                 secret_manager_handle=None):
         """Initialize a secret manager.
 
         Args:
@@ -223,14 +284,16 @@ class SecretManager():
         """
         return self._call_method('getLedgerNanoStatus')
 
-    def store_mnemonic(self, mnemonic: str):
+    def store_mnemonic(self, mnemonic: str, passphrase: Optional[str] = None):
         """Store a mnemonic.
 
         Args:
             mnemonic: A mnemonic to store in the secret manager.
+            passphrase: An optional BIP-39 passphrase, which isn't stored.
         """
         return self._call_method('storeMnemonic', {
-            'mnemonic': mnemonic
+            'mnemonic': mnemonic,
+            'passphrase': passphrase
         })
 
     def sign_ed25519(self, message: HexStr, chain: Bip44) -> Ed25519Signature:
@@ -0,0 +1,56 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from datetime import timedelta
+from typing import Optional
+
+
+@dataclass
+class CacheOptions:
+    """Options of the cache of immutable node responses. Unset fields keep their default.
+
+    Attributes:
+        max_entries: The maximum number of responses kept in memory.
+        max_size: The maximum total size of the responses kept in memory, in bytes.
+        ttl: How long responses are kept, forever if not set.
+        directory: A directory in which responses are also stored, to keep them across restarts.
+        max_directory_size: The maximum total size of the responses stored in the directory, in bytes.
+    """
+    max_entries: Optional[int] = None
+    max_size: Optional[int] = None
+    ttl: Optional[timedelta] = None
+    directory: Optional[str] = None
+    max_directory_size: Optional[int] = None
+
+    def as_dict(self):
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+
+        if 'ttl' in config:
+            config['ttl'] = {'secs': int(self.ttl.total_seconds()),
+                             'nanos': self.ttl.microseconds * 1_000}
+
+        return config
+
+
+@dataclass
+class CacheMetrics:
+    """Metrics of the cache of immutable node responses.
+
+    Attributes:
+        hits: The number of requests answered from the cache.
+        directoryHits: The number of hits that were loaded from the directory.
+        misses: The number of requests of cacheable routes that were sent to a node.
+        insertions: The number of responses added to the cache.
+        evictions: The number of responses removed from memory because of the limits or their TTL.
+        entries: The number of responses in memory.
+        size: The total size of the responses in memory, in bytes.
+    """
+    hits: int
+    directoryHits: int
+    misses: int
+    insertions: int
+    evictions: int
+    entries: int
+    size: int
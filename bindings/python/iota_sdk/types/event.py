@@ -14,6 +14,9 @@ class WalletEventType(IntEnum):
         SpentOutput (3): An output was spent.
         TransactionInclusion (4): A transaction was included into the ledger.
         TransactionProgress (5): A progress update while submitting a transaction.
+        ScheduledPaymentExecuted (6): A scheduled payment was sent.
+        ScheduledPaymentSkipped (7): A scheduled payment was skipped because of insufficient funds.
+        ScheduledPaymentFailed (8): Sending a scheduled payment failed.
     """
     ConsolidationRequired = 0,
     LedgerAddressGeneration = 1,
@@ -21,3 +24,6 @@ class WalletEventType(IntEnum):
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    ScheduledPaymentExecuted = 6,
+    ScheduledPaymentSkipped = 7,
+    ScheduledPaymentFailed = 8,
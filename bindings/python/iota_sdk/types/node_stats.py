@@ -0,0 +1,44 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from enum import Enum
+from typing import Dict, Optional
+
+
+class CircuitState(str, Enum):
+    """States of the circuit breaker of a node.
+
+    Attributes:
+        Closed: The node is used normally.
+        Open: The node failed too often and is only used if no other node answers.
+        HalfOpen: The node can be probed with a single request to close the circuit again.
+    """
+    Closed = 'closed'
+    Open = 'open'
+    HalfOpen = 'halfOpen'
+
+
+@dataclass
+class NodeStats:
+    """Statistics of the requests sent to a node.
+
+    Attributes:
+        url: The node URL.
+        requests: The number of requests sent to the node.
+        failures: The number of requests the node failed to answer.
+        errorRate: The share of the recent requests the node failed to answer.
+        consecutiveFailures: The number of failed requests since the last successful one.
+        circuitState: The state of the circuit breaker.
+        latency: The average latency of the recent successful requests, as `secs` and `nanos`.
+        milestoneLag: The number of milestones the node was behind the other nodes at the last sync.
+    """
+    url: str
+    requests: int
+    failures: int
+    errorRate: float
+    consecutiveFailures: int
+    circuitState: CircuitState
+    latency: Optional[Dict[str, int]] = None
+    milestoneLag: Optional[int] = None
@@ -0,0 +1,58 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from datetime import timedelta
+from enum import Enum
+from typing import List, Optional
+
+
+class RetryableError(str, Enum):
+    """Classes of node errors that can be retried.
+
+    Attributes:
+        Connection: The connection to the node couldn't be established or was reset.
+        Timeout: The node didn't answer before the request timed out.
+        TooManyRequests: The node rate limited the request, with status code 429.
+        ServerError: The node failed with a server error, with a 5xx status code.
+    """
+    Connection = 'connection'
+    Timeout = 'timeout'
+    TooManyRequests = 'tooManyRequests'
+    ServerError = 'serverError'
+
+
+@dataclass
+class RetryPolicy:
+    """Policy to retry node requests that failed with transient errors. Unset fields keep their default.
+
+    Attributes:
+        max_attempts: The maximum number of times the nodes are tried, 1 to not retry.
+        initial_backoff: The delay before the first retry.
+        max_backoff: The maximum delay before a retry.
+        backoff_multiplier: The factor by which the delay grows after each retry.
+        jitter: Whether the delay is randomly shortened by up to half.
+        respect_retry_after: Whether to wait as long as a node asks for with a `Retry-After` header, up to `max_backoff`.
+        retry_on: The errors after which requests are retried.
+    """
+    max_attempts: Optional[int] = None
+    initial_backoff: Optional[timedelta] = None
+    max_backoff: Optional[timedelta] = None
+    backoff_multiplier: Optional[int] = None
+    jitter: Optional[bool] = None
+    respect_retry_after: Optional[bool] = None
+    retry_on: Optional[List[RetryableError]] = None
+
+    def as_dict(self):
+        config = {}
+
+        for k, v in self.__dict__.items():
+            if v is None:
+                continue
+            if isinstance(v, timedelta):
+                v = {'secs': int(v.total_seconds()),
+                     'nanos': v.microseconds * 1_000}
+            config[k] = v
+
+        return config
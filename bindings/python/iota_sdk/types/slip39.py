@@ -0,0 +1,16 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from dataclasses import dataclass
+
+
+@dataclass
+class Slip39Group():
+    """A group of SLIP-39 mnemonic shares.
+
+    Attributes:
+        threshold: The number of member shares required to recover the group secret.
+        count: The number of member shares in the group.
+    """
+    threshold: int
+    count: int
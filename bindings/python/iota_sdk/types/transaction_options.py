@@ -46,6 +46,21 @@ class RemainderValueStrategy(Enum):
         return dict({"strategy": self.name, "value": self.value[0]})
 
 
+class CoinSelectionStrategy(str, Enum):
+    """Coin selection strategy variants.
+
+    Attributes:
+        LargestFirst: Select the inputs with the highest amounts first.
+        SmallestFirst: Select the inputs with the lowest amounts first, to consume many small outputs.
+        BranchAndBound: Look for inputs with exactly the required amount, to avoid a remainder.
+        PrivacyPreserving: Select inputs of as few addresses as possible.
+    """
+    LargestFirst = 'largestFirst'
+    SmallestFirst = 'smallestFirst'
+    BranchAndBound = 'branchAndBound'
+    PrivacyPreserving = 'privacyPreserving'
+
+
 class TransactionOptions():
     """Transaction options.
 
@@ -57,6 +72,7 @@ class TransactionOptions():
         burn: Specifies what needs to be burned during input selection.
         note: A string attached to the transaction.
         allow_micro_amount: Whether to allow sending a micro amount.
+        coin_selection_strategy: The strategy deciding which inputs are used to cover the amount of the outputs.
     """
 
     def __init__(self, remainder_value_strategy: Optional[Union[RemainderValueStrategy, RemainderValueStrategyCustomAddress]] = None,
@@ -65,7 +81,8 @@ class TransactionOptions():
                  mandatory_inputs: Optional[List[OutputId]] = None,
                  burn: Optional[Burn] = None,
                  note: Optional[str] = None,
-                 allow_micro_amount: Optional[bool] = None):
+                 allow_micro_amount: Optional[bool] = None,
+                 coin_selection_strategy: Optional[CoinSelectionStrategy] = None):
         """Initialize transaction options.
         """
         self.remainder_value_strategy = remainder_value_strategy
@@ -75,6 +92,7 @@ class TransactionOptions():
         self.burn = burn
         self.note = note
         self.allow_micro_amount = allow_micro_amount
+        self.coin_selection_strategy = coin_selection_strategy
 
     def as_dict(self):
         return dict(self.__dict__)
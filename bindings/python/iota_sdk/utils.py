@@ -8,8 +8,9 @@ from iota_sdk.types.address import Address, AddressType, Ed25519Address, AliasAd
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import Output
+from iota_sdk.types.slip39 import Slip39Group
 from json import dumps, loads
-from typing import TYPE_CHECKING, List
+from typing import TYPE_CHECKING, List, Optional
 from dacite import from_dict
 
 # Required to prevent circular import
@@ -97,11 +98,33 @@ class Utils():
         return _call_method('generateMnemonic')
 
     @staticmethod
-    def mnemonic_to_hex_seed(mnemonic: str) -> HexStr:
-        """Convert a mnemonic to a hex encoded seed.
+    def mnemonic_to_hex_seed(mnemonic: str, passphrase: Optional[str] = None) -> HexStr:
+        """Convert a mnemonic and an optional BIP-39 passphrase to a hex encoded seed.
         """
         return _call_method('mnemonicToHexSeed', {
-            'mnemonic': mnemonic
+            'mnemonic': mnemonic,
+            'passphrase': passphrase
+        })
+
+    @staticmethod
+    def mnemonic_to_slip39_shares(mnemonic: str, group_threshold: int, groups: List[Slip39Group],
+                                  passphrase: Optional[str] = None) -> List[List[str]]:
+        """Split a mnemonic into groups of SLIP-39 mnemonic shares, optionally encrypted with a passphrase.
+        """
+        return _call_method('mnemonicToSlip39Shares', {
+            'mnemonic': mnemonic,
+            'passphrase': passphrase,
+            'groupThreshold': group_threshold,
+            'groups': [group.__dict__ for group in groups]
+        })
+
+    @staticmethod
+    def slip39_shares_to_mnemonic(shares: List[str], passphrase: Optional[str] = None) -> str:
+        """Recover a mnemonic from SLIP-39 mnemonic shares and the optional passphrase they were encrypted with.
+        """
+        return _call_method('slip39SharesToMnemonic', {
+            'shares': shares,
+            'passphrase': passphrase
         })
 
     @staticmethod
@@ -205,6 +228,26 @@ class Utils():
             'message': message,
         })
 
+    @staticmethod
+    def encode_fountain_parts(bytes: HexStr, max_fragment_length: int,
+                              ur_type: str, count: Optional[int] = None) -> List[str]:
+        """Encode packed bytes into fountain-coded UR parts, e.g. for animated QR codes.
+        """
+        return _call_method('encodeFountainParts', {
+            'bytes': bytes,
+            'maxFragmentLength': max_fragment_length,
+            'urType': ur_type,
+            'count': count,
+        })
+
+    @staticmethod
+    def decode_fountain_parts(parts: List[str]) -> HexStr:
+        """Decode fountain-coded UR parts, received in any order, into the packed bytes.
+        """
+        return _call_method('decodeFountainParts', {
+            'parts': parts,
+        })
+
 
 class UtilsError(Exception):
     """A utils error."""
@@ -43,12 +43,13 @@ class Wallet():
         return self.handle
 
     def create_account(self, alias: Optional[str] = None, bech32_hrp: Optional[str]
-                       = None, addresses: Optional[AccountAddress] = None) -> Account:
+                       = None, addresses: Optional[AccountAddress] = None, watch_only: bool = False) -> Account:
         """Create a new account.
 
         Args:
             alias: The alias of the newaccount.
             bech32_hrp: The Bech32 HRP of the new account.
+            watch_only: Whether the account only watches the given addresses, without generating addresses or signing.
 
         Returns:
             An account object.
@@ -58,6 +59,7 @@ class Wallet():
                 'alias': self.__return_str_or_none(alias),
                 'bech32Hrp': self.__return_str_or_none(bech32_hrp),
                 'addresses': addresses,
+                'watchOnly': watch_only,
             }
         )
         return Account(account_data, self.handle)
@@ -202,6 +204,15 @@ class Wallet():
             }
         )
 
+    def set_pkcs11_pin(self, pin: str):
+        """Log into the PKCS#11 token with the user PIN.
+        """
+        return self._call_method(
+            'setPkcs11Pin', {
+                'pin': pin
+            }
+        )
+
     def set_stronghold_password(self, password: str):
         """Set stronghold password.
         """
@@ -222,16 +233,27 @@ class Wallet():
             }
         )
 
-    def store_mnemonic(self, mnemonic: str):
-        """Store mnemonic.
+    def store_mnemonic(self, mnemonic: str, passphrase: Optional[str] = None):
+        """Store mnemonic, with an optional BIP-39 passphrase which isn't stored.
         """
         return self._call_method(
             'storeMnemonic', {
-                'mnemonic': mnemonic
+                'mnemonic': mnemonic,
+                'passphrase': passphrase
             }
 
         )
 
+    def store_slip39_shares(self, shares: List[str], passphrase: Optional[str] = None):
+        """Recover a mnemonic from SLIP-39 mnemonic shares and store it.
+        """
+        return self._call_method(
+            'storeSlip39Shares', {
+                'shares': shares,
+                'passphrase': passphrase
+            }
+        )
+
     def start_background_sync(
             self, options: Optional[SyncOptions] = None, interval_in_milliseconds: Optional[int] = None):
         """Start background syncing.
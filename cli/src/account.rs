@@ -15,8 +15,8 @@ use crate::{
             increase_voting_power_command, melt_native_token_command, mint_native_token, mint_nft_command,
             new_address_command, node_info_command, output_command, outputs_command, participation_overview_command,
             send_command, send_native_token_command, send_nft_command, stop_participating_command, sync_command,
-            transaction_command, transactions_command, unspent_outputs_command, vote_command, voting_output_command,
-            voting_power_command, AccountCli, AccountCommand,
+            transaction_command, transaction_history_command, transactions_command, unspent_outputs_command,
+            vote_command, voting_output_command, voting_power_command, AccountCli, AccountCommand,
         },
         account_completion::AccountPromptHelper,
     },
@@ -179,8 +179,9 @@ pub async fn account_prompt_internal(
                                 allow_micro_amount
                             };
                             send_command(
+                                wallet,
                                 account,
-                                address,
+                                &address,
                                 amount,
                                 return_address,
                                 expiration.map(|e| e.as_secs() as u32),
@@ -203,6 +204,9 @@ pub async fn account_prompt_internal(
                         AccountCommand::Transactions { show_details } => {
                             transactions_command(account, show_details).await
                         }
+                        AccountCommand::TransactionHistory { json, file, from, to } => {
+                            transaction_history_command(account, json, file, from, to).await
+                        }
                         AccountCommand::UnspentOutputs => unspent_outputs_command(account).await,
                         AccountCommand::Vote { event_id, answers } => vote_command(account, event_id, answers).await,
                         AccountCommand::StopParticipating { event_id } => {
@@ -21,9 +21,10 @@ use iota_sdk::{
     wallet::{
         account::{
             types::{AccountAddress, AccountIdentifier},
-            Account, ConsolidationParams, OutputsToClaim, SyncOptions, TransactionOptions,
+            Account, ConsolidationParams, OutputsToClaim, SyncOptions, TransactionHistoryFilter,
+            TransactionHistoryFormat, TransactionOptions,
         },
-        CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams,
+        CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams, Wallet,
     },
     U256,
 };
@@ -164,8 +165,9 @@ pub enum AccountCommand {
     Outputs,
     /// Send an amount.
     Send {
-        /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
-        address: Bech32Address,
+        /// Address or address book contact to send funds to, e.g.
+        /// rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3 or alice.
+        address: String,
         /// Amount to send, e.g. 1000000.
         amount: u64,
         /// Bech32 encoded return address, to which the storage deposit will be returned if one is necessary
@@ -224,6 +226,21 @@ pub enum AccountCommand {
         #[arg(long, default_value_t = false)]
         show_details: bool,
     },
+    /// Export the account transfers as CSV, or as JSON with `--json`.
+    TransactionHistory {
+        /// Export as JSON instead of CSV.
+        #[arg(long, default_value_t = false)]
+        json: bool,
+        /// File to write the export to, e.g. history.csv. It's printed if no file is provided.
+        #[arg(long)]
+        file: Option<String>,
+        /// Only export transfers confirmed at or after this UNIX timestamp in seconds, e.g. 1696118400.
+        #[arg(long)]
+        from: Option<u32>,
+        /// Only export transfers confirmed at or before this UNIX timestamp in seconds, e.g. 1698796799.
+        #[arg(long)]
+        to: Option<u32>,
+    },
     /// List the account unspent outputs.
     UnspentOutputs,
     /// Cast votes for an event.
@@ -673,13 +690,15 @@ pub async fn outputs_command(account: &Account) -> Result<(), Error> {
 
 // `send` command
 pub async fn send_command(
+    wallet: &Wallet,
     account: &Account,
-    address: impl ConvertTo<Bech32Address>,
+    recipient: &str,
     amount: u64,
     return_address: Option<impl ConvertTo<Bech32Address>>,
     expiration: Option<u32>,
     allow_micro_amount: bool,
 ) -> Result<(), Error> {
+    let address = recipient_address(wallet, recipient).await?;
     let params = [SendParams::new(amount, address)?
         .with_return_address(return_address.map(ConvertTo::convert).transpose()?)
         .with_expiration(expiration)];
@@ -823,6 +842,37 @@ pub async fn transactions_command(account: &Account, show_details: bool) -> Resu
     Ok(())
 }
 
+/// `transaction-history` command
+pub async fn transaction_history_command(
+    account: &Account,
+    json: bool,
+    file: Option<String>,
+    from: Option<u32>,
+    to: Option<u32>,
+) -> Result<(), Error> {
+    let filter = TransactionHistoryFilter {
+        lower_bound_milestone_timestamp: from,
+        upper_bound_milestone_timestamp: to,
+        ..Default::default()
+    };
+    let format = if json {
+        TransactionHistoryFormat::Json
+    } else {
+        TransactionHistoryFormat::Csv
+    };
+    let export = account.export_transaction_history(filter, format).await?;
+
+    match file {
+        Some(file) => {
+            tokio::fs::write(&file, export).await?;
+            println_log_info!("Transaction history written to {file}");
+        }
+        None => println_log_info!("{export}"),
+    }
+
+    Ok(())
+}
+
 /// `unspent-outputs` command
 pub async fn unspent_outputs_command(account: &Account) -> Result<(), Error> {
     let outputs = account.unspent_outputs(None).await?;
@@ -992,3 +1042,15 @@ async fn print_address(account: &Account, address: &AccountAddress) -> Result<()
 
     Ok(())
 }
+
+// Resolves a recipient given either as Bech32 address or as the label of an address book contact.
+async fn recipient_address(wallet: &Wallet, recipient: &str) -> Result<Bech32Address, Error> {
+    if let Ok(address) = Bech32Address::try_from_str(recipient) {
+        return Ok(address);
+    }
+    wallet
+        .get_contact(recipient)
+        .await?
+        .map(|contact| *contact.address())
+        .ok_or_else(|| Error::Miscellaneous(format!("\"{recipient}\" is neither an address nor a contact")))
+}
@@ -40,6 +40,7 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "switch",
     "sync",
     "transaction",
+    "transaction-history",
     "transactions",
     "tx",
     "txs",
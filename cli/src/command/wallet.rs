@@ -11,7 +11,8 @@ use iota_sdk::{
         stronghold::StrongholdAdapter,
         utils::Password,
     },
-    wallet::{account::types::AccountIdentifier, ClientOptions, Wallet},
+    types::block::address::Bech32Address,
+    wallet::{account::types::AccountIdentifier, ClientOptions, Contact, Wallet},
 };
 use log::LevelFilter;
 
@@ -55,6 +56,16 @@ impl WalletCli {
 pub enum WalletCommand {
     /// List all accounts.
     Accounts,
+    /// Add a contact to the address book.
+    AddContact {
+        /// Unique label of the contact, can be used instead of the address when sending.
+        label: String,
+        /// Address of the contact, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+        /// Tags of the contact, e.g. --tag exchange --tag team.
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+    },
     /// Create a stronghold backup file.
     Backup {
         /// Path of the created stronghold backup file.
@@ -62,6 +73,12 @@ pub enum WalletCommand {
     },
     /// Change the stronghold password.
     ChangePassword,
+    /// List the contacts of the address book.
+    Contacts {
+        /// Only list the contacts with this tag.
+        #[arg(long)]
+        tag: Option<String>,
+    },
     /// Initialize the wallet.
     Init(InitParameters),
     /// Migrate a stronghold snapshot v2 to v3.
@@ -85,6 +102,11 @@ pub enum WalletCommand {
     },
     /// Get information about currently set node.
     NodeInfo,
+    /// Remove a contact from the address book.
+    RemoveContact {
+        /// Label of the contact to remove.
+        label: String,
+    },
     /// Restore a stronghold backup file.
     Restore {
         /// Path of the to be restored stronghold backup file.
@@ -146,6 +168,24 @@ pub async fn accounts_command(storage_path: &Path, snapshot_path: &Path) -> Resu
     Ok(())
 }
 
+pub async fn add_contact_command(
+    storage_path: &Path,
+    snapshot_path: &Path,
+    label: String,
+    address: Bech32Address,
+    tags: Vec<String>,
+) -> Result<(), Error> {
+    let password = get_password("Stronghold password", false)?;
+    let wallet = unlock_wallet(storage_path, snapshot_path, password).await?;
+    wallet
+        .add_contact(Contact::new(label.clone(), address).with_tags(tags))
+        .await?;
+
+    println_log_info!("Contact \"{label}\" has been added.");
+
+    Ok(())
+}
+
 pub async fn backup_command(storage_path: &Path, snapshot_path: &Path, backup_path: &Path) -> Result<(), Error> {
     let password = get_password("Stronghold password", !snapshot_path.exists())?;
     let wallet = unlock_wallet(storage_path, snapshot_path, password.clone()).await?;
@@ -167,6 +207,32 @@ pub async fn change_password_command(storage_path: &Path, snapshot_path: &Path)
     Ok(wallet)
 }
 
+pub async fn contacts_command(storage_path: &Path, snapshot_path: &Path, tag: Option<String>) -> Result<(), Error> {
+    let password = get_password("Stronghold password", false)?;
+    let wallet = unlock_wallet(storage_path, snapshot_path, password).await?;
+    let contacts = match tag {
+        Some(tag) => wallet.contacts_with_tag(&tag).await?,
+        None => wallet.contacts().await?,
+    };
+
+    if contacts.is_empty() {
+        println_log_info!("No contacts found");
+        return Ok(());
+    }
+
+    println!("LABEL\tADDRESS\tTAGS");
+    for contact in contacts {
+        println!(
+            "{}\t{}\t{}",
+            contact.label(),
+            contact.address(),
+            contact.tags().join(",")
+        );
+    }
+
+    Ok(())
+}
+
 pub async fn init_command(
     storage_path: &Path,
     snapshot_path: &Path,
@@ -244,6 +310,16 @@ pub async fn node_info_command(storage_path: &Path) -> Result<Wallet, Error> {
     Ok(wallet)
 }
 
+pub async fn remove_contact_command(storage_path: &Path, snapshot_path: &Path, label: String) -> Result<(), Error> {
+    let password = get_password("Stronghold password", false)?;
+    let wallet = unlock_wallet(storage_path, snapshot_path, password).await?;
+    wallet.remove_contact(&label).await?;
+
+    println_log_info!("Contact \"{label}\" has been removed.");
+
+    Ok(())
+}
+
 pub async fn restore_command(storage_path: &Path, snapshot_path: &Path, backup_path: &Path) -> Result<Wallet, Error> {
     check_file_exists(backup_path).await?;
 
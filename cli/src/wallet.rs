@@ -7,10 +7,10 @@ use iota_sdk::wallet::{account::types::AccountIdentifier, Wallet};
 
 use crate::{
     command::wallet::{
-        accounts_command, add_account, backup_command, change_password_command, init_command,
-        migrate_stronghold_snapshot_v2_to_v3_command, mnemonic_command, new_account_command, node_info_command,
-        restore_command, set_node_url_command, set_pow_command, sync_command, unlock_wallet, InitParameters, WalletCli,
-        WalletCommand,
+        accounts_command, add_account, add_contact_command, backup_command, change_password_command,
+        contacts_command, init_command, migrate_stronghold_snapshot_v2_to_v3_command, mnemonic_command,
+        new_account_command, node_info_command, remove_contact_command, restore_command, set_node_url_command,
+        set_pow_command, sync_command, unlock_wallet, InitParameters, WalletCli, WalletCommand,
     },
     error::Error,
     helper::{get_account_alias, get_decision, get_password, pick_account},
@@ -27,6 +27,18 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<Accoun
                 accounts_command(storage_path, snapshot_path).await?;
                 return Ok((None, None));
             }
+            WalletCommand::AddContact { label, address, tags } => {
+                add_contact_command(storage_path, snapshot_path, label, address, tags).await?;
+                return Ok((None, None));
+            }
+            WalletCommand::Contacts { tag } => {
+                contacts_command(storage_path, snapshot_path, tag).await?;
+                return Ok((None, None));
+            }
+            WalletCommand::RemoveContact { label } => {
+                remove_contact_command(storage_path, snapshot_path, label).await?;
+                return Ok((None, None));
+            }
             WalletCommand::Init(init_parameters) => {
                 let wallet = init_command(storage_path, snapshot_path, init_parameters).await?;
                 (Some(wallet), None)
@@ -10,16 +10,17 @@
 
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        api::{PreparedTransactionData, SigningEnvelope},
         constants::SHIMMER_COIN_TYPE,
         secret::SecretManager,
     },
+    types::block::protocol::ProtocolParameters,
     wallet::{account::types::AccountAddress, ClientOptions, Result, SendParams, Wallet},
 };
 
 const ONLINE_WALLET_DB_PATH: &str = "./examples/wallet/offline_signing/example-online-walletdb";
 const ADDRESSES_FILE_PATH: &str = "./examples/wallet/offline_signing/example.addresses.json";
-const PREPARED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.prepared_transaction.json";
+const PREPARED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.prepared_transaction.envelope";
 // Address to which we want to send the amount.
 const RECV_ADDRESS: &str = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
 // The amount to send.
@@ -61,7 +62,7 @@ async fn main() -> Result<()> {
 
     println!("Prepared transaction sending {params:?}");
 
-    write_transaction_to_file(prepared_transaction).await?;
+    write_transaction_to_file(prepared_transaction, &account.client().get_protocol_parameters().await?).await?;
 
     Ok(())
 }
@@ -76,13 +77,17 @@ async fn read_addresses_from_file() -> Result<Vec<AccountAddress>> {
     Ok(serde_json::from_str(&json)?)
 }
 
-async fn write_transaction_to_file(prepared_transaction: PreparedTransactionData) -> Result<()> {
+async fn write_transaction_to_file(
+    prepared_transaction: PreparedTransactionData,
+    protocol_parameters: &ProtocolParameters,
+) -> Result<()> {
     use tokio::io::AsyncWriteExt;
 
-    let json = serde_json::to_string_pretty(&PreparedTransactionDataDto::from(&prepared_transaction))?;
+    // The envelope binds the transaction to the network and protects it against corruption during the transfer
+    let envelope = SigningEnvelope::request(prepared_transaction, protocol_parameters)?.to_text()?;
     let mut file = tokio::io::BufWriter::new(tokio::fs::File::create(PREPARED_TRANSACTION_FILE_PATH).await?);
-    println!("example.prepared_transaction.json:\n{json}");
-    file.write_all(json.as_bytes()).await?;
+    println!("example.prepared_transaction.envelope:\n{envelope}");
+    file.write_all(envelope.as_bytes()).await?;
     file.flush().await?;
     Ok(())
 }
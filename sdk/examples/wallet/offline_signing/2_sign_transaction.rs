@@ -11,18 +11,19 @@
 use iota_sdk::{
     client::{
         api::{
-            transaction::validate_transaction_payload_length, PreparedTransactionData, PreparedTransactionDataDto,
-            SignedTransactionData, SignedTransactionDataDto,
+            transaction::validate_transaction_payload_length, SignedTransactionData, SigningEnvelope,
+            SigningEnvelopeContent,
         },
         secret::{stronghold::StrongholdSecretManager, SecretManage, SecretManager},
+        Error as ClientError,
     },
-    types::{block::payload::TransactionPayload, TryFromDto},
+    types::block::payload::TransactionPayload,
     wallet::Result,
 };
 
 const STRONGHOLD_SNAPSHOT_PATH: &str = "./examples/wallet/offline_signing/example.stronghold";
-const PREPARED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.prepared_transaction.json";
-const SIGNED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.signed_transaction.json";
+const PREPARED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.prepared_transaction.envelope";
+const SIGNED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.signed_transaction.envelope";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -34,11 +35,14 @@ async fn main() -> Result<()> {
         .password(std::env::var("STRONGHOLD_PASSWORD").unwrap())
         .build(STRONGHOLD_SNAPSHOT_PATH)?;
 
-    let prepared_transaction_data = read_prepared_transaction_from_file().await?;
+    let request = read_signing_request_from_file().await?;
+    let SigningEnvelopeContent::Request(prepared_transaction_data) = request.content() else {
+        return Err(ClientError::InvalidSigningEnvelope("expected a signing request".to_string()).into());
+    };
 
     // Signs prepared transaction offline.
     let unlocks = SecretManager::Stronghold(secret_manager)
-        .sign_transaction_essence(&prepared_transaction_data, None)
+        .sign_transaction_essence(prepared_transaction_data, None)
         .await?;
 
     let signed_transaction = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
@@ -47,36 +51,34 @@ async fn main() -> Result<()> {
 
     let signed_transaction_data = SignedTransactionData {
         transaction_payload: signed_transaction,
-        inputs_data: prepared_transaction_data.inputs_data,
+        inputs_data: prepared_transaction_data.inputs_data.clone(),
     };
 
     println!("Signed transaction.");
 
-    write_signed_transaction_to_file(&signed_transaction_data).await?;
+    write_signing_response_to_file(&request.respond(signed_transaction_data)?).await?;
 
     Ok(())
 }
 
-async fn read_prepared_transaction_from_file() -> Result<PreparedTransactionData> {
+async fn read_signing_request_from_file() -> Result<SigningEnvelope> {
     use tokio::io::AsyncReadExt;
 
     let mut file = tokio::io::BufReader::new(tokio::fs::File::open(PREPARED_TRANSACTION_FILE_PATH).await?);
-    let mut json = String::new();
-    file.read_to_string(&mut json).await?;
+    let mut text = String::new();
+    file.read_to_string(&mut text).await?;
 
-    Ok(PreparedTransactionData::try_from_dto(serde_json::from_str::<
-        PreparedTransactionDataDto,
-    >(&json)?)?)
+    // Fails if the envelope was corrupted
+    Ok(SigningEnvelope::try_from_text(&text)?)
 }
 
-async fn write_signed_transaction_to_file(signed_transaction_data: &SignedTransactionData) -> Result<()> {
+async fn write_signing_response_to_file(response: &SigningEnvelope) -> Result<()> {
     use tokio::io::AsyncWriteExt;
 
-    let dto = SignedTransactionDataDto::from(signed_transaction_data);
-    let json = serde_json::to_string_pretty(&dto)?;
+    let envelope = response.to_text()?;
     let mut file = tokio::io::BufWriter::new(tokio::fs::File::create(SIGNED_TRANSACTION_FILE_PATH).await?);
-    println!("example.signed_transaction.json:\n{json}");
-    file.write_all(json.as_bytes()).await?;
+    println!("example.signed_transaction.envelope:\n{envelope}");
+    file.write_all(envelope.as_bytes()).await?;
     file.flush().await?;
     Ok(())
 }
@@ -10,17 +10,17 @@
 
 use iota_sdk::{
     client::{
-        api::{SignedTransactionData, SignedTransactionDataDto},
+        api::{SignedTransactionData, SigningEnvelope, SigningEnvelopeContent},
         secret::SecretManager,
-        Client,
+        Client, Error as ClientError,
     },
-    types::{block::payload::transaction::TransactionId, TryFromDto},
+    types::block::payload::transaction::TransactionId,
     wallet::{Account, Result},
     Wallet,
 };
 
 const ONLINE_WALLET_DB_PATH: &str = "./examples/wallet/offline_signing/example-online-walletdb";
-const SIGNED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.signed_transaction.json";
+const SIGNED_TRANSACTION_FILE_PATH: &str = "./examples/wallet/offline_signing/example.signed_transaction.envelope";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -52,15 +52,19 @@ async fn read_signed_transaction_from_file(client: &Client) -> Result<SignedTran
     use tokio::io::AsyncReadExt;
 
     let mut file = tokio::io::BufReader::new(tokio::fs::File::open(SIGNED_TRANSACTION_FILE_PATH).await?);
-    let mut json = String::new();
-    file.read_to_string(&mut json).await?;
+    let mut text = String::new();
+    file.read_to_string(&mut text).await?;
 
-    let dto = serde_json::from_str::<SignedTransactionDataDto>(&json)?;
+    let envelope = SigningEnvelope::try_from_text(&text)?;
+    // Make sure the transaction was signed for the network the client is connected to
+    envelope.verify_protocol_parameters(&client.get_protocol_parameters().await?)?;
 
-    Ok(SignedTransactionData::try_from_dto_with_params(
-        dto,
-        client.get_protocol_parameters().await?,
-    )?)
+    match envelope.into_content() {
+        SigningEnvelopeContent::Response(signed_transaction_data) => Ok(signed_transaction_data),
+        SigningEnvelopeContent::Request(_) => {
+            Err(ClientError::InvalidSigningEnvelope("expected a signing response".to_string()).into())
+        }
+    }
 }
 
 async fn wait_for_inclusion(transaction_id: &TransactionId, account: &Account) -> Result<()> {
@@ -0,0 +1,208 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::types::InputSigningData,
+    types::block::{
+        address::Address,
+        input::INPUT_COUNT_MAX,
+        output::{unlock_condition::UnlockConditions, Output, OutputId},
+    },
+};
+
+/// A strategy deciding which of the available inputs are used to fulfil the amount requirement of an
+/// [`InputSelection`](super::InputSelection).
+///
+/// Inputs are selected in the order returned by the strategy until the required amount is reached, but inputs
+/// without native tokens and storage deposit return unlock conditions are still preferred over the other ones.
+pub trait CoinSelectionStrategy: Debug + Send + Sync {
+    /// Orders the available inputs by the preference to select them to cover the `required_amount`.
+    fn order_inputs(&self, inputs: &mut [InputSigningData], required_amount: u64);
+}
+
+/// Selects the inputs with the highest amounts first, resulting in fewer inputs.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LargestFirst;
+
+impl CoinSelectionStrategy for LargestFirst {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _required_amount: u64) {
+        inputs.sort_by_key(|input| Reverse(input.output.amount()));
+    }
+}
+
+/// Selects the inputs with the lowest amounts first, consuming as many small outputs as possible.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SmallestFirst;
+
+impl CoinSelectionStrategy for SmallestFirst {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _required_amount: u64) {
+        inputs.sort_by_key(|input| input.output.amount());
+    }
+}
+
+/// Searches for a set of inputs with exactly the required amount, so that no remainder output is needed.
+///
+/// Only basic outputs with just an Ed25519 address unlock condition and without native tokens are considered for it.
+/// If no such set is found within the maximum number of tries, inputs are selected [`SmallestFirst`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BranchAndBound {
+    /// The maximum number of search steps.
+    pub max_tries: usize,
+}
+
+impl BranchAndBound {
+    /// The default maximum number of search steps.
+    pub const DEFAULT_MAX_TRIES: usize = 100_000;
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        Self {
+            max_tries: Self::DEFAULT_MAX_TRIES,
+        }
+    }
+}
+
+impl CoinSelectionStrategy for BranchAndBound {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], required_amount: u64) {
+        let mut candidates = inputs
+            .iter()
+            .filter(|input| {
+                if let Output::Basic(output) = &input.output {
+                    output.simple_deposit_address().map_or(false, Address::is_ed25519)
+                } else {
+                    false
+                }
+            })
+            .map(|input| (*input.output_id(), input.output.amount()))
+            .collect::<Vec<_>>();
+        // Trying the highest amounts first finds a set with fewer inputs faster.
+        candidates.sort_by_key(|(_, amount)| Reverse(*amount));
+
+        let amounts = candidates.iter().map(|(_, amount)| *amount).collect::<Vec<_>>();
+        let mut selected = Vec::new();
+        let exact_match = search_exact_match(&amounts, required_amount, &mut selected, self.max_tries);
+
+        let selected = if exact_match {
+            selected
+                .into_iter()
+                .map(|index| candidates[index].0)
+                .collect::<HashSet<OutputId>>()
+        } else {
+            log::debug!("No set of inputs without remainder found");
+            HashSet::new()
+        };
+
+        inputs.sort_by_key(|input| (!selected.contains(input.output_id()), input.output.amount()));
+    }
+}
+
+// Depth first search for amounts summing up exactly to the target, skipping branches that can't reach it anymore.
+// Uses an explicit stack instead of recursion, since the search can be as deep as the number of amounts.
+fn search_exact_match(amounts: &[u64], target: u64, selected: &mut Vec<usize>, mut tries: usize) -> bool {
+    // Index of the next amount, sum of the remaining amounts, remaining target and number of selected amounts.
+    let mut stack = vec![(0, amounts.iter().sum::<u64>(), target, 0)];
+
+    while let Some((index, remaining_sum, target, selected_len)) = stack.pop() {
+        selected.truncate(selected_len);
+        if target == 0 {
+            return true;
+        }
+        if tries == 0 {
+            break;
+        }
+        if index == amounts.len() || remaining_sum < target {
+            continue;
+        }
+        tries -= 1;
+
+        let amount = amounts[index];
+        // Without the amount, searched after the branch with it.
+        stack.push((index + 1, remaining_sum - amount, target, selected_len));
+        if amount <= target && selected_len < INPUT_COUNT_MAX as usize {
+            selected.push(index);
+            stack.push((index + 1, remaining_sum - amount, target - amount, selected_len + 1));
+        }
+    }
+
+    selected.clear();
+    false
+}
+
+/// Avoids linking addresses by selecting inputs of as few addresses as possible.
+///
+/// If the inputs of a single address can cover the required amount, the address with the lowest sufficient amount is
+/// used, otherwise the addresses with the highest amounts are combined.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PrivacyPreserving;
+
+impl CoinSelectionStrategy for PrivacyPreserving {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], required_amount: u64) {
+        let mut address_amounts = HashMap::<Option<Address>, u64>::new();
+        for input in inputs.iter() {
+            *address_amounts.entry(input_address(input)).or_default() += input.output.amount();
+        }
+
+        let mut addresses = address_amounts.into_iter().collect::<Vec<_>>();
+        // Sufficient addresses with the lowest amount first, then the remaining ones with the highest amount first.
+        addresses.sort_by_key(|(_, amount)| {
+            if *amount >= required_amount {
+                (false, *amount)
+            } else {
+                (true, u64::MAX - *amount)
+            }
+        });
+        let address_ranks = addresses
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (address, _))| (address, rank))
+            .collect::<HashMap<_, _>>();
+
+        inputs.sort_by_key(|input| (address_ranks[&input_address(input)], Reverse(input.output.amount())));
+    }
+}
+
+// The address that can unlock the input
+fn input_address(input: &InputSigningData) -> Option<Address> {
+    match &input.output {
+        Output::Alias(output) => Some(*output.state_controller_address()),
+        output => output
+            .unlock_conditions()
+            .and_then(UnlockConditions::address)
+            .map(|unlock_condition| *unlock_condition.address()),
+    }
+}
+
+/// The built-in [`CoinSelectionStrategy`]s, to choose one in serialized options.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CoinSelectionStrategyDto {
+    /// [`LargestFirst`]
+    LargestFirst,
+    /// [`SmallestFirst`]
+    SmallestFirst,
+    /// [`BranchAndBound`] with the default maximum number of tries.
+    BranchAndBound,
+    /// [`PrivacyPreserving`]
+    PrivacyPreserving,
+}
+
+impl From<CoinSelectionStrategyDto> for Arc<dyn CoinSelectionStrategy> {
+    fn from(value: CoinSelectionStrategyDto) -> Self {
+        match value {
+            CoinSelectionStrategyDto::LargestFirst => Arc::new(LargestFirst),
+            CoinSelectionStrategyDto::SmallestFirst => Arc::new(SmallestFirst),
+            CoinSelectionStrategyDto::BranchAndBound => Arc::new(BranchAndBound::default()),
+            CoinSelectionStrategyDto::PrivacyPreserving => Arc::new(PrivacyPreserving),
+        }
+    }
+}
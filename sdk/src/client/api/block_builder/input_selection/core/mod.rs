@@ -2,19 +2,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod burn;
+pub(crate) mod coin_selection;
 pub(crate) mod error;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
 pub(crate) mod transition;
 
 use core::ops::Deref;
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use packable::PackableExt;
 pub(crate) use requirement::is_alias_transition;
 
 pub use self::{
     burn::{Burn, BurnDto},
+    coin_selection::{
+        BranchAndBound, CoinSelectionStrategy, CoinSelectionStrategyDto, LargestFirst, PrivacyPreserving, SmallestFirst,
+    },
     error::Error,
     requirement::Requirement,
 };
@@ -42,6 +49,7 @@ pub struct InputSelection {
     addresses: HashSet<Address>,
     burn: Option<Burn>,
     remainder_address: Option<Address>,
+    coin_selection_strategy: Arc<dyn CoinSelectionStrategy>,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
     requirements: Vec<Requirement>,
@@ -187,6 +195,7 @@ impl InputSelection {
             addresses,
             burn: None,
             remainder_address: None,
+            coin_selection_strategy: Arc::new(SmallestFirst),
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
@@ -218,6 +227,12 @@ impl InputSelection {
         self
     }
 
+    /// Sets the [`CoinSelectionStrategy`] of an [`InputSelection`], [`SmallestFirst`] by default.
+    pub fn coin_selection_strategy(mut self, strategy: Arc<dyn CoinSelectionStrategy>) -> Self {
+        self.coin_selection_strategy = strategy;
+        self
+    }
+
     /// Sets the timestamp of an [`InputSelection`].
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
@@ -5,7 +5,10 @@ use std::collections::HashMap;
 
 use super::{Error, InputSelection, Requirement};
 use crate::{
-    client::secret::types::InputSigningData,
+    client::{
+        api::input_selection::{CoinSelectionStrategy, LargestFirst},
+        secret::types::InputSigningData,
+    },
     types::block::{
         address::Address,
         input::INPUT_COUNT_MAX,
@@ -294,15 +297,13 @@ impl InputSelection {
             );
         }
 
-        // TODO if consolidate strategy: sum all the lowest amount until diff is covered.
-        // TODO this would be lowest amount of input strategy.
-
-        // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
+        // Try to select outputs first with the ordering of the coin selection strategy, if that results in too many
+        // inputs, try from high to low amount.
 
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        log::debug!("Ordering inputs with {:?}", self.coin_selection_strategy);
+        let required_amount = amount_selection.missing_amount();
+        self.coin_selection_strategy
+            .order_inputs(&mut self.available_inputs, required_amount);
 
         if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
             return Ok(r);
@@ -314,9 +315,7 @@ impl InputSelection {
             amount_selection = AmountSelection::new(self)?;
 
             log::debug!("Ordering inputs from high to low amount");
-            // Sort inputs per amount, high to low.
-            self.available_inputs
-                .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+            LargestFirst.order_inputs(&mut self.available_inputs, required_amount);
 
             if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
                 return Ok(r);
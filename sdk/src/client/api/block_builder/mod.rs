@@ -11,7 +11,7 @@ use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
 use self::input_selection::BurnDto;
-pub use self::transaction::verify_semantic;
+pub use self::transaction::{verify_semantic, verify_semantic_unsigned};
 use crate::{
     client::{
         api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
@@ -3,12 +3,13 @@
 
 //! Transaction preparation and signing
 
+use crypto::signatures::ed25519::SecretKey;
 use packable::PackableExt;
 
 use crate::{
     client::{
         api::{types::PreparedTransactionData, ClientBlockBuilder},
-        secret::{types::InputSigningData, SecretManage},
+        secret::{reference_unlocks, types::InputSigningData, SecretManage},
         Error, Result,
     },
     types::block::{
@@ -19,7 +20,8 @@ use crate::{
             TaggedDataPayload,
         },
         semantic::{semantic_validation, ConflictReason, ValidationContext},
-        signature::Ed25519Signature,
+        signature::{Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
         Block, BlockId,
     },
 };
@@ -138,6 +140,53 @@ pub fn verify_semantic(
     Ok(semantic_validation(context, inputs.as_slice(), transaction.unlocks())?)
 }
 
+/// Verifies the semantic of a prepared transaction without signing it.
+///
+/// Signature unlocks are replaced by placeholders which aren't verified, so the result is the one of the signed
+/// transaction if the inputs are signed by their owners.
+pub fn verify_semantic_unsigned(
+    prepared_transaction_data: &PreparedTransactionData,
+    current_time: u32,
+) -> Result<ConflictReason> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+    let essence_hash = prepared_transaction_data.essence.hash();
+    // Each placeholder is signed by a key derived from its index, as identical signature unlocks are rejected.
+    let placeholder_unlock = |index: usize| {
+        let mut key_bytes = [0; SecretKey::LENGTH];
+        key_bytes[..core::mem::size_of::<usize>()].copy_from_slice(&index.to_le_bytes());
+        let placeholder_key = SecretKey::from_bytes(&key_bytes);
+        Unlock::Signature(SignatureUnlock::new(Signature::from(Ed25519Signature::new(
+            placeholder_key.public_key(),
+            placeholder_key.sign(&essence_hash),
+        ))))
+    };
+    let unlocks = Unlocks::new(
+        reference_unlocks(prepared_transaction_data, Some(current_time))?
+            .into_iter()
+            .enumerate()
+            .map(|(index, unlock)| unlock.unwrap_or_else(|| placeholder_unlock(index)))
+            .collect::<Vec<_>>(),
+    )?;
+
+    let transaction_id = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks.clone())?.id();
+    let inputs = prepared_transaction_data
+        .inputs_data
+        .iter()
+        .map(|input| (input.output_id(), &input.output))
+        .collect::<Vec<(&OutputId, &Output)>>();
+
+    let context = ValidationContext::new(
+        &transaction_id,
+        essence,
+        inputs.iter().map(|(id, input)| (*id, *input)),
+        &unlocks,
+        current_time,
+    )
+    .without_signature_verification();
+
+    Ok(semantic_validation(context, inputs.as_slice(), &unlocks)?)
+}
+
 /// Verifies that the transaction payload doesn't exceed the block size limit with 8 parents.
 pub fn validate_transaction_payload_length(transaction_payload: &TransactionPayload) -> Result<()> {
     let transaction_payload_bytes = transaction_payload.pack_to_vec();
@@ -7,8 +7,9 @@ mod address;
 mod block_builder;
 mod consolidation;
 mod high_level;
+mod signing_envelope;
 mod types;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{address::*, block_builder::*, signing_envelope::*, types::*};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
@@ -0,0 +1,352 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+};
+
+use crate::{
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
+        secret::types::InputSigningData,
+        Error, Result,
+    },
+    types::{
+        block::{
+            input::Input,
+            output::InputsCommitment,
+            payload::transaction::{RegularTransactionEssence, TransactionEssence},
+            protocol::ProtocolParameters,
+        },
+        TryFromDto,
+    },
+};
+
+/// The content of a [`SigningEnvelope`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SigningEnvelopeContent {
+    /// A transaction to be signed by the offline signer.
+    Request(Box<PreparedTransactionData>),
+    /// The transaction signed by the offline signer.
+    Response(SignedTransactionData),
+}
+
+impl SigningEnvelopeContent {
+    const REQUEST_KIND: u8 = 0;
+    const RESPONSE_KIND: u8 = 1;
+
+    fn kind(&self) -> u8 {
+        match self {
+            Self::Request(_) => Self::REQUEST_KIND,
+            Self::Response(_) => Self::RESPONSE_KIND,
+        }
+    }
+
+    fn essence(&self) -> &RegularTransactionEssence {
+        let essence = match self {
+            Self::Request(prepared_transaction_data) => &prepared_transaction_data.essence,
+            Self::Response(signed_transaction_data) => signed_transaction_data.transaction_payload.essence(),
+        };
+        let TransactionEssence::Regular(essence) = essence;
+        essence
+    }
+
+    fn inputs_data(&self) -> &[InputSigningData] {
+        match self {
+            Self::Request(prepared_transaction_data) => &prepared_transaction_data.inputs_data,
+            Self::Response(signed_transaction_data) => &signed_transaction_data.inputs_data,
+        }
+    }
+}
+
+/// A versioned envelope to move signing requests and responses to and from an offline signer.
+///
+/// The envelope binds its content to a network and its protocol parameters and carries the inputs commitment, all of
+/// which are validated against the content on import. The binary form ends with a checksum, so data corrupted in
+/// transfer is rejected. It can also be encoded as hex text and split into chunks small enough for QR codes.
+///
+/// Binary layout: magic (4 bytes), version (1), kind (1), network id (8, little endian), protocol parameters hash
+/// (32), inputs commitment (32), content length (4, little endian), content as JSON DTO, checksum (4, the start of
+/// the Blake2b-256 hash of all previous bytes).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SigningEnvelope {
+    network_id: u64,
+    protocol_parameters_hash: [u8; 32],
+    inputs_commitment: InputsCommitment,
+    content: SigningEnvelopeContent,
+}
+
+impl SigningEnvelope {
+    /// The magic bytes every binary [`SigningEnvelope`] starts with.
+    pub const MAGIC: [u8; 4] = *b"ISGE";
+    /// The version of the envelope format.
+    pub const VERSION: u8 = 1;
+    const HEADER_LENGTH: usize = 4 + 1 + 1 + 8 + 32 + InputsCommitment::LENGTH + 4;
+    const CHECKSUM_LENGTH: usize = 4;
+
+    /// Creates an envelope for a signing request, bound to the given protocol parameters.
+    pub fn request(
+        prepared_transaction_data: PreparedTransactionData,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self> {
+        let content = SigningEnvelopeContent::Request(Box::new(prepared_transaction_data));
+        let envelope = Self {
+            network_id: protocol_parameters.network_id(),
+            protocol_parameters_hash: protocol_parameters.hash(),
+            inputs_commitment: *content.essence().inputs_commitment(),
+            content,
+        };
+        envelope.validate_content()?;
+
+        Ok(envelope)
+    }
+
+    /// Creates the response envelope for this signing request, the signed transaction has to sign the requested
+    /// essence.
+    pub fn respond(&self, signed_transaction_data: SignedTransactionData) -> Result<Self> {
+        let SigningEnvelopeContent::Request(prepared_transaction_data) = &self.content else {
+            return Err(Error::InvalidSigningEnvelope(
+                "can only respond to a request".to_string(),
+            ));
+        };
+        if signed_transaction_data.transaction_payload.essence() != &prepared_transaction_data.essence {
+            return Err(Error::InvalidSigningEnvelope(
+                "signed essence doesn't match the requested one".to_string(),
+            ));
+        }
+
+        let envelope = Self {
+            content: SigningEnvelopeContent::Response(signed_transaction_data),
+            ..self.clone()
+        };
+        envelope.validate_content()?;
+
+        Ok(envelope)
+    }
+
+    /// Returns the id of the network the envelope is bound to.
+    pub fn network_id(&self) -> u64 {
+        self.network_id
+    }
+
+    /// Returns the hash of the protocol parameters the envelope is bound to.
+    pub fn protocol_parameters_hash(&self) -> &[u8; 32] {
+        &self.protocol_parameters_hash
+    }
+
+    /// Returns the commitment to the inputs of the transaction.
+    pub fn inputs_commitment(&self) -> &InputsCommitment {
+        &self.inputs_commitment
+    }
+
+    /// Returns the BIP44 chains to sign the inputs with, in the order of the inputs.
+    pub fn chains(&self) -> Vec<Option<Bip44>> {
+        self.content.inputs_data().iter().map(|input| input.chain).collect()
+    }
+
+    /// Returns the content of the envelope.
+    pub fn content(&self) -> &SigningEnvelopeContent {
+        &self.content
+    }
+
+    /// Consumes the envelope, returning its content.
+    pub fn into_content(self) -> SigningEnvelopeContent {
+        self.content
+    }
+
+    /// Checks that the envelope was created for the given protocol parameters.
+    pub fn verify_protocol_parameters(&self, protocol_parameters: &ProtocolParameters) -> Result<()> {
+        if self.network_id != protocol_parameters.network_id() {
+            return Err(Error::InvalidSigningEnvelope(format!(
+                "network id {} doesn't match the expected one {}",
+                self.network_id,
+                protocol_parameters.network_id()
+            )));
+        }
+        if self.protocol_parameters_hash != protocol_parameters.hash() {
+            return Err(Error::InvalidSigningEnvelope(
+                "protocol parameters hash doesn't match".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the envelope in its binary form.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let content = match &self.content {
+            SigningEnvelopeContent::Request(prepared_transaction_data) => {
+                serde_json::to_vec(&PreparedTransactionDataDto::from(prepared_transaction_data.as_ref()))?
+            }
+            SigningEnvelopeContent::Response(signed_transaction_data) => {
+                serde_json::to_vec(&SignedTransactionDataDto::from(signed_transaction_data))?
+            }
+        };
+
+        let mut bytes = Vec::with_capacity(Self::HEADER_LENGTH + content.len() + Self::CHECKSUM_LENGTH);
+        bytes.extend_from_slice(&Self::MAGIC);
+        bytes.push(Self::VERSION);
+        bytes.push(self.content.kind());
+        bytes.extend_from_slice(&self.network_id.to_le_bytes());
+        bytes.extend_from_slice(&self.protocol_parameters_hash);
+        bytes.extend_from_slice(self.inputs_commitment.as_ref());
+        bytes.extend_from_slice(&(content.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&content);
+        let checksum = Blake2b256::digest(&bytes);
+        bytes.extend_from_slice(&checksum[..Self::CHECKSUM_LENGTH]);
+
+        Ok(bytes)
+    }
+
+    /// Decodes and validates an envelope from its binary form.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < Self::HEADER_LENGTH + Self::CHECKSUM_LENGTH {
+            return Err(Error::InvalidSigningEnvelope("too short".to_string()));
+        }
+        let (bytes, checksum) = bytes.split_at(bytes.len() - Self::CHECKSUM_LENGTH);
+        if Blake2b256::digest(bytes)[..Self::CHECKSUM_LENGTH] != *checksum {
+            return Err(Error::InvalidSigningEnvelope("checksum mismatch".to_string()));
+        }
+        if bytes[..4] != Self::MAGIC {
+            return Err(Error::InvalidSigningEnvelope("not a signing envelope".to_string()));
+        }
+        if bytes[4] != Self::VERSION {
+            return Err(Error::InvalidSigningEnvelope(format!(
+                "unsupported version {}",
+                bytes[4]
+            )));
+        }
+
+        let kind = bytes[5];
+        // Lengths are checked above, so the conversions can't fail
+        let network_id = u64::from_le_bytes(bytes[6..14].try_into().unwrap());
+        let protocol_parameters_hash = bytes[14..46].try_into().unwrap();
+        let inputs_commitment = InputsCommitment::from(<[u8; 32]>::try_from(&bytes[46..78]).unwrap());
+        let content_length = u32::from_le_bytes(bytes[78..82].try_into().unwrap()) as usize;
+        let content = &bytes[Self::HEADER_LENGTH..];
+        if content.len() != content_length {
+            return Err(Error::InvalidSigningEnvelope(format!(
+                "content length {} doesn't match the expected one {content_length}",
+                content.len()
+            )));
+        }
+
+        let content = match kind {
+            SigningEnvelopeContent::REQUEST_KIND => SigningEnvelopeContent::Request(Box::new(
+                PreparedTransactionData::try_from_dto(serde_json::from_slice(content)?)?,
+            )),
+            SigningEnvelopeContent::RESPONSE_KIND => {
+                SigningEnvelopeContent::Response(SignedTransactionData::try_from_dto(serde_json::from_slice(content)?)?)
+            }
+            _ => return Err(Error::InvalidSigningEnvelope(format!("unknown kind {kind}"))),
+        };
+
+        let envelope = Self {
+            network_id,
+            protocol_parameters_hash,
+            inputs_commitment,
+            content,
+        };
+        envelope.validate_content()?;
+
+        Ok(envelope)
+    }
+
+    /// Encodes the envelope as hex text.
+    pub fn to_text(&self) -> Result<String> {
+        Ok(prefix_hex::encode(self.to_bytes()?))
+    }
+
+    /// Decodes and validates an envelope from its hex text form.
+    pub fn try_from_text(text: &str) -> Result<Self> {
+        Self::try_from_bytes(&prefix_hex::decode::<Vec<u8>>(text.trim())?)
+    }
+
+    /// Splits the text form of the envelope into chunks of the form `<index>/<count>:<hex>`, with at most
+    /// `max_chunk_length` characters of hex data each.
+    pub fn to_chunks(&self, max_chunk_length: usize) -> Result<Vec<String>> {
+        if max_chunk_length == 0 {
+            return Err(Error::InvalidSigningEnvelope(
+                "chunk length must be greater than zero".to_string(),
+            ));
+        }
+        let text = self.to_text()?;
+        let hex = text.trim_start_matches("0x");
+        let count = hex.len().div_ceil(max_chunk_length);
+
+        // Hex is ASCII, so every chunk boundary is a char boundary
+        Ok((0..count)
+            .map(|index| {
+                let start = index * max_chunk_length;
+                let end = hex.len().min(start + max_chunk_length);
+                format!("{}/{count}:{}", index + 1, &hex[start..end])
+            })
+            .collect())
+    }
+
+    /// Reassembles and validates an envelope from the chunks created by [`SigningEnvelope::to_chunks()`], the chunks
+    /// can be passed in any order and duplicates are ignored.
+    pub fn try_from_chunks(chunks: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self> {
+        // Fragments by index, the count isn't used to allocate since it's untrusted input
+        let mut fragments = BTreeMap::<usize, String>::new();
+        let mut expected_count = None;
+
+        for chunk in chunks {
+            let invalid_chunk = || Error::InvalidSigningEnvelope(format!("invalid chunk {}", chunk.as_ref()));
+            let (position, fragment) = chunk.as_ref().trim().split_once(':').ok_or_else(invalid_chunk)?;
+            let (index, count) = position.split_once('/').ok_or_else(invalid_chunk)?;
+            let index = index.parse::<usize>().map_err(|_| invalid_chunk())?;
+            let count = count.parse::<usize>().map_err(|_| invalid_chunk())?;
+
+            if *expected_count.get_or_insert(count) != count || index == 0 || index > count {
+                return Err(invalid_chunk());
+            }
+            fragments.insert(index, fragment.to_string());
+        }
+
+        let count = expected_count.ok_or_else(|| Error::InvalidSigningEnvelope("no chunks".to_string()))?;
+        let missing = count - fragments.len();
+        if missing > 0 {
+            return Err(Error::InvalidSigningEnvelope(format!("{missing}/{count} chunks are missing")));
+        }
+
+        Self::try_from_text(&format!("0x{}", fragments.into_values().collect::<String>()))
+    }
+
+    // Checks that the header matches the content.
+    fn validate_content(&self) -> Result<()> {
+        let essence = self.content.essence();
+        let inputs_data = self.content.inputs_data();
+
+        if essence.network_id() != self.network_id {
+            return Err(Error::InvalidSigningEnvelope(format!(
+                "network id {} of the transaction doesn't match the envelope",
+                essence.network_id()
+            )));
+        }
+        if essence.inputs().len() != inputs_data.len()
+            || essence
+                .inputs()
+                .iter()
+                .zip(inputs_data)
+                .any(|(input, input_data)| match input {
+                    Input::Utxo(input) => input.output_id() != input_data.output_id(),
+                    Input::Treasury(_) => true,
+                })
+        {
+            return Err(Error::InvalidSigningEnvelope(
+                "inputs data don't match the inputs of the transaction".to_string(),
+            ));
+        }
+        if essence.inputs_commitment() != &self.inputs_commitment
+            || InputsCommitment::new(inputs_data.iter().map(|input| &input.output)) != self.inputs_commitment
+        {
+            return Err(Error::InvalidSigningEnvelope("inputs commitment mismatch".to_string()));
+        }
+
+        Ok(())
+    }
+}
@@ -2,10 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crypto::keys::bip44::Bip44;
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::secret::types::{InputSigningData, InputSigningDataDto},
+    client::secret::types::{pack_chain, unpack_chain, InputSigningData, InputSigningDataDto},
     types::{
         block::{
             address::{dto::AddressDto, Address},
@@ -17,6 +23,7 @@ use crate::{
                 },
                 TransactionPayload,
             },
+            protocol::ProtocolParameters,
             Error,
         },
         TryFromDto, ValidationParams,
@@ -35,6 +42,48 @@ pub struct PreparedTransactionData {
     pub remainder: Option<RemainderData>,
 }
 
+impl Packable for PreparedTransactionData {
+    type UnpackError = Error;
+    type UnpackVisitor = ProtocolParameters;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.essence.pack(packer)?;
+        pack_inputs_data(&self.inputs_data, packer)?;
+        self.remainder.is_some().pack(packer)?;
+
+        if let Some(remainder) = &self.remainder {
+            remainder.output.pack(packer)?;
+            pack_chain(remainder.chain.as_ref(), packer)?;
+            remainder.address.pack(packer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let essence = TransactionEssence::unpack::<_, VERIFY>(unpacker, visitor)?;
+        let inputs_data = unpack_inputs_data::<_, VERIFY>(unpacker, visitor)?;
+        let remainder = if bool::unpack::<_, VERIFY>(unpacker, &()).coerce()? {
+            Some(RemainderData {
+                output: Output::unpack::<_, VERIFY>(unpacker, visitor)?,
+                chain: unpack_chain::<_, VERIFY>(unpacker)?,
+                address: Address::unpack::<_, VERIFY>(unpacker, &())?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            essence,
+            inputs_data,
+            remainder,
+        })
+    }
+}
+
 /// PreparedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -91,6 +140,50 @@ pub struct SignedTransactionData {
     pub inputs_data: Vec<InputSigningData>,
 }
 
+impl Packable for SignedTransactionData {
+    type UnpackError = Error;
+    type UnpackVisitor = ProtocolParameters;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.transaction_payload.pack(packer)?;
+        pack_inputs_data(&self.inputs_data, packer)?;
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Self {
+            transaction_payload: TransactionPayload::unpack::<_, VERIFY>(unpacker, visitor)?,
+            inputs_data: unpack_inputs_data::<_, VERIFY>(unpacker, visitor)?,
+        })
+    }
+}
+
+fn pack_inputs_data<P: Packer>(inputs_data: &[InputSigningData], packer: &mut P) -> Result<(), P::Error> {
+    // The number of inputs is bounded by the input count of a transaction essence.
+    (inputs_data.len() as u16).pack(packer)?;
+
+    for input in inputs_data {
+        input.pack(packer)?;
+    }
+
+    Ok(())
+}
+
+fn unpack_inputs_data<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+    visitor: &ProtocolParameters,
+) -> Result<Vec<InputSigningData>, UnpackError<Error, U::Error>> {
+    let count = u16::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+
+    (0..count)
+        .map(|_| InputSigningData::unpack::<_, VERIFY>(unpacker, visitor))
+        .collect()
+}
+
 /// SignedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
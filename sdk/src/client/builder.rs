@@ -11,12 +11,15 @@ use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
+        cache::{CacheOptions, ResponseCache},
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
         error::Result,
         node_manager::{
             builder::validate_url,
             node::{Node, NodeAuth},
+            retry::RetryPolicy,
         },
+        verification::{VerificationOptions, Verifier},
         Client,
     },
     types::block::protocol::ProtocolParameters,
@@ -52,6 +55,12 @@ pub struct ClientBuilder {
     #[cfg(not(target_family = "wasm"))]
     #[serde(default = "default_max_parallel_api_requests")]
     pub max_parallel_api_requests: usize,
+    /// Options to verify node responses against trusted milestones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_options: Option<VerificationOptions>,
+    /// Options to cache the responses of routes that return immutable resources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_options: Option<CacheOptions>,
 }
 
 fn default_api_timeout() -> Duration {
@@ -93,6 +102,8 @@ impl Default for ClientBuilder {
             pow_worker_count: None,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: super::constants::MAX_PARALLEL_API_REQUESTS,
+            verification_options: None,
+            cache_options: None,
         }
     }
 }
@@ -241,6 +252,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the policy to retry GET requests that failed with transient errors, they aren't retried by default.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Sets the policy to retry POST requests, like the submission of blocks, that failed with transient errors, they
+    /// aren't retried by default.
+    pub fn with_submit_retry_policy(mut self, submit_retry_policy: RetryPolicy) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_submit_retry_policy(submit_retry_policy);
+        self
+    }
+
     /// Set User-Agent header for requests
     /// Default is "iota-client/{version}"
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
@@ -255,6 +279,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Only accepts the outputs, block metadata and included blocks returned by nodes if they can be verified against
+    /// milestones signed by the trusted public keys.
+    pub fn with_verification_options(mut self, options: impl Into<Option<VerificationOptions>>) -> Self {
+        self.verification_options = options.into();
+        self
+    }
+
+    /// Caches the responses of routes that return blocks, milestones and other resources that can't change anymore.
+    pub fn with_cache_options(mut self, options: impl Into<Option<CacheOptions>>) -> Self {
+        self.cache_options = options.into();
+        self
+    }
+
     /// Build the Client instance.
     #[cfg(not(target_family = "wasm"))]
     pub async fn finish(self) -> Result<Client> {
@@ -288,6 +325,11 @@ impl ClientBuilder {
                 receiver: RwLock::new(mqtt_event_rx),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
+            verifier: RwLock::new(
+                self.verification_options
+                    .map(|options| Arc::new(Verifier::new(options))),
+            ),
+            response_cache: RwLock::new(self.cache_options.map(|options| Arc::new(ResponseCache::new(options)))),
         });
 
         client_inner.sync_nodes(&nodes, ignore_node_health).await?;
@@ -330,6 +372,11 @@ impl ClientBuilder {
                     receiver: RwLock::new(mqtt_event_rx),
                 },
                 last_sync: tokio::sync::Mutex::new(None),
+                verifier: RwLock::new(
+                    self.verification_options
+                        .map(|options| Arc::new(Verifier::new(options))),
+                ),
+                response_cache: RwLock::new(self.cache_options.map(|options| Arc::new(ResponseCache::new(options)))),
             }),
         };
 
@@ -348,6 +395,8 @@ impl ClientBuilder {
             pow_worker_count: *client.pow_worker_count.read().await,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: client.request_pool.size().await,
+            verification_options: client.verification_options().await,
+            cache_options: client.cache_options().await,
         }
     }
 }
@@ -0,0 +1,574 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Client-side cache of node responses that can't change anymore.
+//!
+//! When a [`Client`](crate::client::Client) is built with [`CacheOptions`], the responses of routes that return
+//! immutable resources are kept in a least recently used memory cache and, optionally, in a directory:
+//! - blocks, by block ID and by the transaction they include;
+//! - milestones and their UTXO changes, by milestone ID and index;
+//! - block metadata once the block was referenced by a milestone;
+//! - outputs and their metadata once they were spent in a confirmed milestone.
+//!
+//! Blocks and milestones are only cached after they were checked against the requested ID or index, so a wrong response
+//! isn't served from the cache. Responses are cached by network ID. The cache is cleared when the nodes change and its
+//! memory when the network changes. The files of the directory are checked against a checksum before they are used. The
+//! `ledger_index` of the metadata of cached outputs is the one of the time they were cached. Verified responses are
+//! only cached after they passed the verification and are verified again when they are served from the cache.
+
+#[cfg(not(target_family = "wasm"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+#[cfg(not(target_family = "wasm"))]
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use instant::Instant;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::client::{ClientInner, Result};
+
+/// Options of the cache of immutable node responses.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CacheOptions {
+    /// The maximum number of responses kept in memory.
+    pub max_entries: usize,
+    /// The maximum total size of the responses kept in memory, in bytes.
+    pub max_size: usize,
+    /// How long responses are kept, forever if `None`.
+    pub ttl: Option<Duration>,
+    /// A directory in which responses are also stored, to keep them across restarts.
+    #[cfg(not(target_family = "wasm"))]
+    pub directory: Option<std::path::PathBuf>,
+    /// The maximum total size of the responses stored in the directory, in bytes.
+    #[cfg(not(target_family = "wasm"))]
+    pub max_directory_size: u64,
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            max_size: 32 * 1024 * 1024,
+            ttl: None,
+            #[cfg(not(target_family = "wasm"))]
+            directory: None,
+            #[cfg(not(target_family = "wasm"))]
+            max_directory_size: 256 * 1024 * 1024,
+        }
+    }
+}
+
+impl CacheOptions {
+    /// Sets the maximum number and total size of the responses kept in memory.
+    pub fn with_memory_limits(mut self, max_entries: usize, max_size: usize) -> Self {
+        self.max_entries = max_entries;
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets how long responses are kept.
+    pub fn with_ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    /// Sets a directory in which responses are also stored and the maximum total size of its responses.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_directory(mut self, directory: impl Into<std::path::PathBuf>, max_directory_size: u64) -> Self {
+        self.directory.replace(directory.into());
+        self.max_directory_size = max_directory_size;
+        self
+    }
+}
+
+/// Metrics of the cache of immutable node responses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheMetrics {
+    /// The number of requests answered from the cache.
+    pub hits: u64,
+    /// The number of hits that were loaded from the directory.
+    pub directory_hits: u64,
+    /// The number of requests of cacheable routes that were sent to a node.
+    pub misses: u64,
+    /// The number of responses added to the cache.
+    pub insertions: u64,
+    /// The number of responses removed from memory because of the limits or their TTL.
+    pub evictions: u64,
+    /// The number of responses in memory.
+    pub entries: usize,
+    /// The total size of the responses in memory, in bytes.
+    pub size: usize,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Arc<[u8]>,
+    inserted: Instant,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Memory {
+    entries: HashMap<String, Entry>,
+    // The keys of the entries by when they were last used.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    metrics: CacheMetrics,
+}
+
+impl Memory {
+    fn remove(&mut self, key: &str) -> Option<Entry> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.last_used);
+        self.metrics.entries -= 1;
+        self.metrics.size -= entry.value.len();
+
+        Some(entry)
+    }
+
+    fn get(&mut self, key: &str, ttl: Option<Duration>) -> Option<Arc<[u8]>> {
+        let entry = self.entries.get_mut(key)?;
+
+        if ttl.is_some_and(|ttl| entry.inserted.elapsed() >= ttl) {
+            self.remove(key);
+            self.metrics.evictions += 1;
+            return None;
+        }
+
+        self.tick += 1;
+        self.order.remove(&entry.last_used);
+        self.order.insert(self.tick, key.to_string());
+        entry.last_used = self.tick;
+
+        Some(entry.value.clone())
+    }
+
+    fn insert(&mut self, key: &str, value: Arc<[u8]>, options: &CacheOptions) {
+        self.remove(key);
+
+        if value.len() > options.max_size || options.max_entries == 0 {
+            return;
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.to_string());
+        self.metrics.entries += 1;
+        self.metrics.size += value.len();
+        self.entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                inserted: Instant::now(),
+                last_used: self.tick,
+            },
+        );
+
+        while self.metrics.entries > options.max_entries || self.metrics.size > options.max_size {
+            let Some((_, key)) = self.order.pop_first() else {
+                break;
+            };
+            self.remove(&key);
+            self.metrics.evictions += 1;
+        }
+    }
+}
+
+/// Stores responses as files named after their network and route, prefixed with the time they were stored and a
+/// checksum of the route and response.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+struct Directory {
+    path: std::path::PathBuf,
+    // The total size of the stored files, computed when the directory is first used.
+    size: std::sync::OnceLock<AtomicU64>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Directory {
+    /// The length of the stored time and checksum that precede a response in its file.
+    const HEADER_LENGTH: usize = 8 + 32;
+
+    fn new(path: std::path::PathBuf) -> Self {
+        Self {
+            path,
+            size: Default::default(),
+        }
+    }
+
+    // Runs a file system operation on the blocking thread pool, `None` if it panicked.
+    async fn run<T: Send + 'static>(self: &Arc<Self>, f: impl FnOnce(&Self) -> T + Send + 'static) -> Option<T> {
+        let directory = self.clone();
+
+        tokio::task::spawn_blocking(move || f(&directory))
+            .await
+            .map_err(|error| log::warn!("cache directory operation failed: {error}"))
+            .ok()
+    }
+
+    fn size(&self) -> &AtomicU64 {
+        self.size.get_or_init(|| {
+            if let Err(error) = std::fs::create_dir_all(&self.path) {
+                log::warn!("couldn't create the cache directory {}: {error}", self.path.display());
+            }
+            AtomicU64::new(Self::files(&self.path).iter().map(|(_, _, len)| len).sum())
+        })
+    }
+
+    // Returns the path, modification time and size of the stored files, other files of the directory are left alone.
+    fn files(path: &std::path::Path) -> Vec<(std::path::PathBuf, std::time::SystemTime, u64)> {
+        std::fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .split_once('_')
+                    .map_or(false, |(network_id, route)| {
+                        network_id.parse::<u64>().is_ok() && route.starts_with("api_")
+                    })
+            })
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+
+                if metadata.is_file() {
+                    Some((entry.path(), metadata.modified().ok()?, metadata.len()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn file(&self, key: &str) -> std::path::PathBuf {
+        self.path.join(key.replace('/', "_"))
+    }
+
+    fn now() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    // The checksum covers the key, so a file that was renamed or copied isn't served for another route.
+    fn checksum(key: &str, value: &[u8]) -> [u8; 32] {
+        Blake2b256::new()
+            .chain_update(key)
+            .chain_update(value)
+            .finalize()
+            .into()
+    }
+
+    fn remove(&self, file: &std::path::Path) {
+        if let Ok(metadata) = std::fs::metadata(file) {
+            if std::fs::remove_file(file).is_ok() {
+                // The closure always returns `Some`.
+                self.size()
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |size| {
+                        Some(size.saturating_sub(metadata.len()))
+                    })
+                    .ok();
+            }
+        }
+    }
+
+    fn get(&self, key: &str, ttl: Option<Duration>) -> Option<Arc<[u8]>> {
+        let file = self.file(key);
+        let bytes = std::fs::read(&file).ok()?;
+
+        if bytes.len() >= Self::HEADER_LENGTH {
+            let (header, value) = bytes.split_at(Self::HEADER_LENGTH);
+            let (stored, checksum) = header.split_at(8);
+            // PANIC: the header is split at the length of a u64.
+            let stored = u64::from_be_bytes(stored.try_into().unwrap());
+
+            if checksum != Self::checksum(key, value) {
+                log::warn!("removing the corrupted response of {key} from the cache directory");
+            } else if ttl.map_or(true, |ttl| Self::now().saturating_sub(stored) < ttl.as_secs()) {
+                return Some(value.into());
+            }
+        }
+
+        self.remove(&file);
+        None
+    }
+
+    fn insert(&self, key: &str, value: &[u8], max_size: u64) {
+        // Creates the directory on first use.
+        let size = self.size();
+        let file = self.file(key);
+        let mut bytes = Self::now().to_be_bytes().to_vec();
+        bytes.extend_from_slice(&Self::checksum(key, value));
+        bytes.extend_from_slice(value);
+
+        self.remove(&file);
+        if let Err(error) = std::fs::write(&file, &bytes) {
+            log::warn!("couldn't store {key} in the cache directory: {error}");
+            return;
+        }
+
+        if size.fetch_add(bytes.len() as u64, Ordering::Relaxed) + bytes.len() as u64 > max_size {
+            // Removes the oldest files until a quarter of the space is free, to not scan the directory every time
+            let mut files = Self::files(&self.path);
+            files.sort_by_key(|(_, modified, _)| *modified);
+            let mut total = files.iter().map(|(_, _, len)| len).sum::<u64>();
+
+            for (file, _, len) in files {
+                if total <= max_size / 4 * 3 {
+                    break;
+                }
+                if std::fs::remove_file(file).is_ok() {
+                    total -= len;
+                }
+            }
+            size.store(total, Ordering::Relaxed);
+        }
+    }
+
+    fn clear(&self) {
+        for (file, _, _) in Self::files(&self.path) {
+            std::fs::remove_file(file).ok();
+        }
+        self.size().store(
+            Self::files(&self.path).iter().map(|(_, _, len)| len).sum(),
+            Ordering::Relaxed,
+        );
+    }
+}
+
+/// Cache of immutable node responses.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    options: CacheOptions,
+    memory: Mutex<Memory>,
+    #[cfg(not(target_family = "wasm"))]
+    directory: Option<Arc<Directory>>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(options: CacheOptions) -> Self {
+        Self {
+            #[cfg(not(target_family = "wasm"))]
+            directory: options.directory.clone().map(|path| Arc::new(Directory::new(path))),
+            options,
+            memory: Default::default(),
+        }
+    }
+
+    // The cache stays usable if a thread panicked while holding the lock, it only holds copies of node responses.
+    fn memory(&self) -> MutexGuard<'_, Memory> {
+        self.memory.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get_from_memory(&self, key: &str) -> Option<Arc<[u8]>> {
+        let mut memory = self.memory();
+        let value = memory.get(key, self.options.ttl)?;
+        memory.metrics.hits += 1;
+
+        Some(value)
+    }
+
+    // The memory lock isn't held while the directory is read, only to add the loaded response afterwards.
+    async fn get(&self, key: &str) -> Option<Arc<[u8]>> {
+        if let Some(value) = self.get_from_memory(key) {
+            return Some(value);
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(directory) = &self.directory {
+            let (owned_key, ttl) = (key.to_owned(), self.options.ttl);
+
+            if let Some(value) = directory
+                .run(move |directory| directory.get(&owned_key, ttl))
+                .await
+                .flatten()
+            {
+                let mut memory = self.memory();
+                memory.insert(key, value.clone(), &self.options);
+                memory.metrics.hits += 1;
+                memory.metrics.directory_hits += 1;
+                return Some(value);
+            }
+        }
+
+        self.memory().metrics.misses += 1;
+        None
+    }
+
+    async fn insert(&self, key: &str, value: Arc<[u8]>) {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(directory) = &self.directory {
+            let (key, value, max_size) = (key.to_owned(), value.clone(), self.options.max_directory_size);
+            directory
+                .run(move |directory| directory.insert(&key, &value, max_size))
+                .await;
+        }
+
+        let mut memory = self.memory();
+        memory.insert(key, value, &self.options);
+        memory.metrics.insertions += 1;
+    }
+
+    fn metrics(&self) -> CacheMetrics {
+        self.memory().metrics
+    }
+
+    fn clear_memory(&self) {
+        let mut memory = self.memory();
+        let metrics = memory.metrics;
+
+        *memory = Memory {
+            metrics: CacheMetrics {
+                entries: 0,
+                size: 0,
+                ..metrics
+            },
+            ..Default::default()
+        };
+    }
+
+    async fn clear(&self) {
+        self.clear_memory();
+
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(directory) = &self.directory {
+            directory.run(Directory::clear).await;
+        }
+    }
+}
+
+impl ClientInner {
+    /// Returns the options of the cache of immutable node responses, `None` if it's disabled.
+    pub async fn cache_options(&self) -> Option<CacheOptions> {
+        self.response_cache
+            .read()
+            .await
+            .as_ref()
+            .map(|cache| cache.options.clone())
+    }
+
+    /// Sets the options of the cache of immutable node responses, the cached responses are kept if they don't change.
+    pub async fn set_cache_options(&self, options: Option<CacheOptions>) {
+        let mut cache = self.response_cache.write().await;
+
+        if cache.as_ref().map(|cache| &cache.options) != options.as_ref() {
+            *cache = options.map(|options| Arc::new(ResponseCache::new(options)));
+        }
+    }
+
+    /// Returns the hit and miss metrics of the cache of immutable node responses, `None` if it's disabled.
+    pub async fn cache_metrics(&self) -> Option<CacheMetrics> {
+        self.response_cache.read().await.as_ref().map(|cache| cache.metrics())
+    }
+
+    /// Removes all responses from the cache, in memory and in its directory.
+    pub async fn clear_cache(&self) {
+        if let Some(cache) = self.response_cache().await {
+            cache.clear().await;
+        }
+    }
+
+    // The directory is kept when the network changes, its responses are stored by network and aren't served for others.
+    pub(crate) async fn clear_memory_cache(&self) {
+        if let Some(cache) = self.response_cache().await {
+            cache.clear_memory();
+        }
+    }
+
+    async fn response_cache(&self) -> Option<Arc<ResponseCache>> {
+        self.response_cache.read().await.clone()
+    }
+
+    // Responses are cached by network, the same routes return other resources on other networks.
+    async fn cache_key(&self, path: &str) -> String {
+        let network_id = self.network_info.read().await.protocol_parameters.network_id();
+
+        format!("{network_id}/{path}")
+    }
+
+    /// Returns the cached response of a route, if it's cached and can be deserialized.
+    pub(crate) async fn cached_response<T: DeserializeOwned>(&self, path: &str) -> Option<T> {
+        let cache = self.response_cache().await?;
+        let value = cache.get(&self.cache_key(path).await).await?;
+
+        serde_json::from_slice(&value).ok()
+    }
+
+    /// Caches the response of a route.
+    pub(crate) async fn cache_response<T: Serialize + Sync>(&self, path: &str, response: &T) {
+        if let Some(cache) = self.response_cache().await {
+            match serde_json::to_vec(response) {
+                Ok(value) => cache.insert(&self.cache_key(path).await, value.into()).await,
+                Err(error) => log::warn!("couldn't cache the response of {path}: {error}"),
+            }
+        }
+    }
+
+    /// Sends a GET request to a route returning an immutable resource, unless its response is cached.
+    ///
+    /// `check` converts the response and returns an error if it isn't the requested resource, the response is only
+    /// cached if it passes.
+    pub(crate) async fn get_immutable_request<T, U>(
+        &self,
+        path: &str,
+        prefer_permanode: bool,
+        check: impl FnOnce(T) -> Result<U> + Send,
+    ) -> Result<U>
+    where
+        T: DeserializeOwned + Debug + Serialize + Clone + Send + Sync,
+    {
+        if let Some(response) = self.cached_response(path).await {
+            return check(response);
+        }
+
+        let response: T = self.get_request(path, None, false, prefer_permanode).await?;
+        let checked = check(response.clone())?;
+        self.cache_response(path, &response).await;
+
+        Ok(checked)
+    }
+
+    /// Sends a GET request for the raw bytes of an immutable resource, unless its response is cached.
+    ///
+    /// `check` returns an error if the bytes aren't the requested resource, they're only cached if they pass.
+    pub(crate) async fn get_immutable_request_bytes(
+        &self,
+        path: &str,
+        check: impl FnOnce(&[u8]) -> Result<()> + Send,
+    ) -> Result<Vec<u8>> {
+        if let Some(response) = self.cached_response_bytes(path).await {
+            check(&response)?;
+            return Ok(response);
+        }
+
+        let response = self.get_request_bytes(path, None).await?;
+        check(&response)?;
+        self.cache_response_bytes(path, &response).await;
+
+        Ok(response)
+    }
+
+    async fn cached_response_bytes(&self, path: &str) -> Option<Vec<u8>> {
+        let cache = self.response_cache().await?;
+        let value = cache.get(&self.cache_key(&format!("{path}/raw")).await).await?;
+
+        Some(value.to_vec())
+    }
+
+    async fn cache_response_bytes(&self, path: &str, response: &[u8]) {
+        if let Some(cache) = self.response_cache().await {
+            cache
+                .insert(&self.cache_key(&format!("{path}/raw")).await, response.into())
+                .await;
+        }
+    }
+}
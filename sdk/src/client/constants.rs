@@ -22,6 +22,12 @@ pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 #[cfg(not(target_family = "wasm"))]
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
+/// Number of recent requests per node used to compute its latency and error rate
+pub(crate) const NODE_STATS_WINDOW: usize = 20;
+/// Number of consecutive failed requests after which the circuit breaker of a node opens
+pub(crate) const CIRCUIT_BREAKER_FAILURE_THRESHOLD: u32 = 3;
+/// Time after which a node with an open circuit breaker can be probed again
+pub(crate) const CIRCUIT_BREAKER_OPEN_DURATION: Duration = Duration::from_secs(30);
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
 pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
 /// Delay for caching a node info response in WASM runtime
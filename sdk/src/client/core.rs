@@ -20,8 +20,10 @@ use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
         builder::{ClientBuilder, NetworkInfo},
+        cache::ResponseCache,
         error::Result,
         node_manager::NodeManager,
+        verification::Verifier,
         Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
@@ -60,6 +62,10 @@ pub struct ClientInner {
     pub(crate) last_sync: tokio::sync::Mutex<Option<u32>>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) request_pool: RequestPool,
+    /// Verifies node responses against trusted milestones.
+    pub(crate) verifier: RwLock<Option<Arc<Verifier>>>,
+    /// Caches the responses of routes that return immutable resources.
+    pub(crate) response_cache: RwLock<Option<Arc<ResponseCache>>>,
 }
 
 #[derive(Default)]
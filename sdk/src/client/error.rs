@@ -67,6 +67,9 @@ pub enum Error {
         /// The max supported length.
         max_length: usize,
     },
+    /// A signing envelope is corrupted or doesn't match its content
+    #[error("invalid signing envelope: {0}")]
+    InvalidSigningEnvelope(String),
     /// The transaction payload is too large
     #[error("the transaction payload is too large. Its length is {length}, max length is {max_length}")]
     InvalidTransactionPayloadLength {
@@ -115,6 +118,17 @@ pub enum Error {
         /// The minimum quorum threshold.
         minimum_threshold: usize,
     },
+    /// The node returned another resource than the requested one.
+    #[error("the node returned {found} instead of the requested {expected}")]
+    ResponseMismatch {
+        /// The requested resource.
+        expected: String,
+        /// The returned resource.
+        found: String,
+    },
+    /// SLIP-39 errors
+    #[error("SLIP-39 error: {0}")]
+    Slip39(String),
     /// Specifically used for `TryInfo` implementations for `SecretManager`.
     #[error("cannot unwrap a SecretManager: type mismatch!")]
     SecretManagerMismatch,
@@ -159,6 +173,9 @@ pub enum Error {
     /// URL validation error
     #[error("{0}")]
     UrlValidation(String),
+    /// Verification error
+    #[error("{0}")]
+    Verification(#[from] crate::client::verification::Error),
     /// Input selection error.
     #[error("{0}")]
     InputSelection(#[from] InputSelectionError),
@@ -178,12 +195,30 @@ pub enum Error {
     #[error("{0}")]
     Ledger(#[from] crate::client::secret::ledger_nano::Error),
 
+    /// Mock node error
+    #[cfg(all(feature = "test-utils", not(target_family = "wasm")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+    #[error("{0}")]
+    MockNode(#[from] crate::client::mock_node::Error),
+
     /// MQTT error
     #[cfg(feature = "mqtt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
     #[error("{0}")]
     Mqtt(#[from] crate::client::node_api::mqtt::Error),
 
+    /// PKCS#11 error
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    #[error("{0}")]
+    Pkcs11(#[from] crate::client::secret::pkcs11::Error),
+
+    /// Remote signer error
+    #[cfg(feature = "remote_secret_manager")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "remote_secret_manager")))]
+    #[error("{0}")]
+    RemoteSigner(#[from] crate::client::secret::remote::Error),
+
     /// Stronghold error
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -0,0 +1,32 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::block::{BlockId, Error as BlockError};
+
+/// Mock node related errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Block error.
+    #[error("{0}")]
+    Block(#[from] BlockError),
+    /// The block doesn't reach the minimum PoW score of the mock node.
+    #[error("block {0} doesn't reach the minimum PoW score")]
+    InsufficientPowScore(BlockId),
+    /// Invalid pruning index.
+    #[error("invalid pruning index {index}, the confirmed milestone index is {confirmed_milestone_index}")]
+    InvalidPruningIndex {
+        /// The requested pruning index.
+        index: u32,
+        /// The confirmed milestone index of the mock node.
+        confirmed_milestone_index: u32,
+    },
+    /// The mock node needs at least one key to sign milestones with.
+    #[error("no milestone keys")]
+    NoMilestoneKeys,
+    /// Server error.
+    #[error("server error {0}")]
+    Server(#[from] hyper::Error),
+    /// The payload kind is not accepted by the mock node.
+    #[error("unsupported payload kind {0}")]
+    UnsupportedPayloadKind(u32),
+}
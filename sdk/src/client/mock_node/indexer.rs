@@ -0,0 +1,165 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use crate::types::block::{
+    address::{Address, Bech32Address},
+    output::{Output, OutputWithMetadata},
+};
+
+/// A filter of the indexer output routes.
+pub(crate) enum OutputFilter {
+    Address(Address),
+    AliasAddress(Address),
+    CreatedAfter(u32),
+    CreatedBefore(u32),
+    ExpirationReturnAddress(Address),
+    ExpiresAfter(u32),
+    ExpiresBefore(u32),
+    Governor(Address),
+    HasExpiration(bool),
+    HasNativeTokens(bool),
+    HasStorageDepositReturn(bool),
+    HasTimelock(bool),
+    Issuer(Address),
+    MaxNativeTokenCount(usize),
+    MinNativeTokenCount(usize),
+    Sender(Address),
+    StateController(Address),
+    StorageDepositReturnAddress(Address),
+    Tag(Vec<u8>),
+    TimelockedAfter(u32),
+    TimelockedBefore(u32),
+    UnlockableByAddress(Address),
+}
+
+impl OutputFilter {
+    /// Parses a query parameter of the indexer, returns an error message if it's unknown or has an invalid value.
+    pub(crate) fn parse(key: &str, value: &str) -> Result<Self, String> {
+        fn address(value: &str) -> Result<Address, String> {
+            Bech32Address::try_from_str(value)
+                .map(|address| *address.inner())
+                .map_err(|e| e.to_string())
+        }
+        fn parse<T: FromStr>(value: &str) -> Result<T, String> {
+            value.parse().map_err(|_| format!("invalid value {value}"))
+        }
+
+        Ok(match key {
+            "address" => Self::Address(address(value)?),
+            "aliasAddress" => Self::AliasAddress(address(value)?),
+            "createdAfter" => Self::CreatedAfter(parse(value)?),
+            "createdBefore" => Self::CreatedBefore(parse(value)?),
+            "expirationReturnAddress" => Self::ExpirationReturnAddress(address(value)?),
+            "expiresAfter" => Self::ExpiresAfter(parse(value)?),
+            "expiresBefore" => Self::ExpiresBefore(parse(value)?),
+            "governor" => Self::Governor(address(value)?),
+            "hasExpiration" => Self::HasExpiration(parse(value)?),
+            "hasNativeTokens" => Self::HasNativeTokens(parse(value)?),
+            "hasStorageDepositReturn" => Self::HasStorageDepositReturn(parse(value)?),
+            "hasTimelock" => Self::HasTimelock(parse(value)?),
+            "issuer" => Self::Issuer(address(value)?),
+            "maxNativeTokenCount" => Self::MaxNativeTokenCount(parse(value)?),
+            "minNativeTokenCount" => Self::MinNativeTokenCount(parse(value)?),
+            "sender" => Self::Sender(address(value)?),
+            "stateController" => Self::StateController(address(value)?),
+            "storageDepositReturnAddress" => Self::StorageDepositReturnAddress(address(value)?),
+            "tag" => Self::Tag(prefix_hex::decode(value).map_err(|e| e.to_string())?),
+            "timelockedAfter" => Self::TimelockedAfter(parse(value)?),
+            "timelockedBefore" => Self::TimelockedBefore(parse(value)?),
+            "unlockableByAddress" => Self::UnlockableByAddress(address(value)?),
+            _ => return Err(format!("unsupported query parameter {key}")),
+        })
+    }
+
+    /// Returns whether the output matches the filter.
+    pub(crate) fn matches(&self, output_with_metadata: &OutputWithMetadata) -> bool {
+        let output = output_with_metadata.output();
+        let unlock_conditions = output.unlock_conditions();
+        let features = output.features();
+        let native_token_count = output.native_tokens().map_or(0, |native_tokens| native_tokens.len());
+        let created = output_with_metadata.metadata().milestone_timestamp_booked();
+
+        let address_unlock = unlock_conditions
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map(|unlock_condition| unlock_condition.address());
+        let expiration = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.expiration());
+        let timelock = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.timelock());
+        let storage_deposit_return =
+            unlock_conditions.and_then(|unlock_conditions| unlock_conditions.storage_deposit_return());
+        let state_controller = unlock_conditions
+            .and_then(|unlock_conditions| unlock_conditions.state_controller_address())
+            .map(|unlock_condition| unlock_condition.address());
+        let governor = unlock_conditions
+            .and_then(|unlock_conditions| unlock_conditions.governor_address())
+            .map(|unlock_condition| unlock_condition.address());
+        let alias_address = unlock_conditions
+            .and_then(|unlock_conditions| unlock_conditions.immutable_alias_address())
+            .map(|unlock_condition| unlock_condition.address());
+
+        match self {
+            Self::Address(address) => address_unlock == Some(address),
+            Self::AliasAddress(address) => alias_address == Some(address),
+            Self::CreatedAfter(timestamp) => created > *timestamp,
+            Self::CreatedBefore(timestamp) => created < *timestamp,
+            Self::ExpirationReturnAddress(address) => {
+                expiration.map(|expiration| expiration.return_address()) == Some(address)
+            }
+            Self::ExpiresAfter(timestamp) => expiration.map_or(false, |expiration| expiration.timestamp() > *timestamp),
+            Self::ExpiresBefore(timestamp) => {
+                expiration.map_or(false, |expiration| expiration.timestamp() < *timestamp)
+            }
+            Self::Governor(address) => governor == Some(address),
+            Self::HasExpiration(has) => expiration.is_some() == *has,
+            Self::HasNativeTokens(has) => (native_token_count > 0) == *has,
+            Self::HasStorageDepositReturn(has) => storage_deposit_return.is_some() == *has,
+            Self::HasTimelock(has) => timelock.is_some() == *has,
+            Self::Issuer(address) => {
+                output
+                    .immutable_features()
+                    .and_then(|features| features.issuer())
+                    .map(|issuer| issuer.address())
+                    == Some(address)
+            }
+            Self::MaxNativeTokenCount(count) => native_token_count <= *count,
+            Self::MinNativeTokenCount(count) => native_token_count >= *count,
+            Self::Sender(address) => {
+                features
+                    .and_then(|features| features.sender())
+                    .map(|sender| sender.address())
+                    == Some(address)
+            }
+            Self::StateController(address) => state_controller == Some(address),
+            Self::StorageDepositReturnAddress(address) => {
+                storage_deposit_return.map(|storage_deposit_return| storage_deposit_return.return_address())
+                    == Some(address)
+            }
+            Self::Tag(tag) => features
+                .and_then(|features| features.tag())
+                .map_or(false, |feature| feature.tag() == tag.as_slice()),
+            Self::TimelockedAfter(timestamp) => timelock.map_or(false, |timelock| timelock.timestamp() > *timestamp),
+            Self::TimelockedBefore(timestamp) => timelock.map_or(false, |timelock| timelock.timestamp() < *timestamp),
+            Self::UnlockableByAddress(address) => [
+                address_unlock,
+                expiration.map(|expiration| expiration.return_address()),
+                state_controller,
+                governor,
+                alias_address,
+            ]
+            .contains(&Some(address)),
+        }
+    }
+}
+
+/// Returns whether the output is of the kind of an indexer route, `None` matches all outputs.
+pub(crate) fn matches_kind(output: &Output, kind: Option<&str>) -> bool {
+    match kind {
+        None => true,
+        Some("basic") => output.is_basic(),
+        Some("alias") => output.is_alias(),
+        Some("foundry") => output.is_foundry(),
+        Some("nft") => output.is_nft(),
+        Some(_) => false,
+    }
+}
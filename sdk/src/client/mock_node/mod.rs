@@ -0,0 +1,344 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-process mock of a Hornet node to test clients and wallets deterministically without a network.
+//!
+//! The mock node serves the core, indexer and participation routes from an in-memory UTXO ledger. Submitted blocks
+//! are only referenced when a milestone is issued, either manually with [`MockNode::issue_milestone()`] or
+//! periodically with [`MockNodeBuilder::with_milestone_interval()`]. Transactions are semantically validated against
+//! the ledger at that point and end up either included or conflicting, like they would on a real node.
+//!
+//! Milestones are signed with the keys of [`MockNodeBuilder::with_milestone_keys()`] and proofs of inclusion are
+//! served like by the `poi` plugin, so clients can verify the responses with [`MockNode::verification_options()`].
+//! [`MockNode::forge_response()`] replaces responses to test how clients handle a malicious node.
+//!
+//! ```no_run
+//! # use iota_sdk::{
+//! #     client::{mock_node::MockNode, Client, Result},
+//! #     types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+//! # };
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! # let address = iota_sdk::types::block::address::Ed25519Address::new([0; 32]);
+//! let node = MockNode::builder().finish().await?;
+//! let client = Client::builder().with_node(&node.url())?.finish().await?;
+//!
+//! // Fund an address without a transaction.
+//! let output = BasicOutputBuilder::new_with_amount(1_000_000)
+//!     .add_unlock_condition(AddressUnlockCondition::new(address))
+//!     .finish_output(node.protocol_parameters().token_supply())?;
+//! node.add_output(output).await;
+//!
+//! // Submit blocks with the client, then confirm them.
+//! node.issue_milestone().await;
+//! # Ok(())}
+//! ```
+
+mod error;
+mod indexer;
+#[cfg(feature = "participation")]
+mod participation;
+mod routes;
+mod state;
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use crypto::signatures::ed25519::SecretKey;
+use hyper::service::{make_service_fn, service_fn};
+use serde::Serialize;
+use tokio::{
+    sync::{oneshot, RwLock},
+    task::JoinHandle,
+};
+
+pub use self::error::Error;
+use self::state::NodeState;
+use super::verification::{MilestoneKeyRange, VerificationOptions};
+#[cfg(feature = "participation")]
+use crate::types::api::plugins::participation::types::ParticipationEvent;
+use crate::types::{
+    api::core::response::BlockMetadataResponse,
+    block::{
+        output::{Output, OutputId, OutputWithMetadata, RentStructure},
+        protocol::ProtocolParameters,
+        BlockId, PROTOCOL_VERSION,
+    },
+};
+
+/// The number of keys the mock node signs milestones with, if none are given.
+const DEFAULT_MILESTONE_KEY_COUNT: usize = 3;
+
+/// Builder to configure and start a [`MockNode`].
+#[derive(Clone)]
+#[must_use]
+pub struct MockNodeBuilder {
+    address: SocketAddr,
+    protocol_parameters: ProtocolParameters,
+    milestone_interval: Option<Duration>,
+    milestone_keys: Option<Vec<SecretKey>>,
+}
+
+impl core::fmt::Debug for MockNodeBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MockNodeBuilder")
+            .field("address", &self.address)
+            .field("protocol_parameters", &self.protocol_parameters)
+            .field("milestone_interval", &self.milestone_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for MockNodeBuilder {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::from(([127, 0, 0, 1], 0)),
+            // PANIC: These values are known to be correct.
+            protocol_parameters: ProtocolParameters::new(
+                PROTOCOL_VERSION,
+                String::from("mock"),
+                "rms",
+                0,
+                15,
+                RentStructure::default(),
+                1_813_620_509_061_365,
+            )
+            .unwrap(),
+            milestone_interval: None,
+            milestone_keys: None,
+        }
+    }
+}
+
+impl MockNodeBuilder {
+    /// Creates a new [`MockNodeBuilder`] that binds to a random local port and doesn't require any PoW.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the address the mock node listens on.
+    pub fn with_address(mut self, address: SocketAddr) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Sets the protocol parameters of the mock node.
+    pub fn with_protocol_parameters(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.protocol_parameters = protocol_parameters;
+        self
+    }
+
+    /// Issues a milestone periodically, instead of only when [`MockNode::issue_milestone()`] is called.
+    pub fn with_milestone_interval(mut self, milestone_interval: Duration) -> Self {
+        self.milestone_interval.replace(milestone_interval);
+        self
+    }
+
+    /// Sets the keys milestones are signed with, instead of random ones.
+    pub fn with_milestone_keys(mut self, milestone_keys: impl Into<Vec<SecretKey>>) -> Self {
+        self.milestone_keys.replace(milestone_keys.into());
+        self
+    }
+
+    /// Starts the mock node.
+    pub async fn finish(self) -> crate::client::Result<MockNode> {
+        let milestone_keys = match self.milestone_keys {
+            Some(milestone_keys) if milestone_keys.is_empty() => return Err(Error::NoMilestoneKeys)?,
+            Some(milestone_keys) => milestone_keys,
+            None => (0..DEFAULT_MILESTONE_KEY_COUNT)
+                .map(|_| SecretKey::generate())
+                .collect::<Result<_, _>>()?,
+        };
+        let milestone_public_keys = milestone_keys
+            .iter()
+            .map(|key| hex::encode(key.public_key().to_bytes()))
+            .collect::<std::collections::BTreeSet<_>>();
+        let state = Arc::new(RwLock::new(NodeState::new(
+            self.protocol_parameters.clone(),
+            milestone_keys,
+        )));
+
+        let service_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, core::convert::Infallible>(service_fn(move |request| {
+                    routes::handle_request(state.clone(), request)
+                }))
+            }
+        });
+        let server = hyper::Server::try_bind(&self.address)
+            .map_err(Error::from)?
+            .serve(make_service);
+        let address = server.local_addr();
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let server_handle = tokio::spawn(async move {
+            server
+                .with_graceful_shutdown(async {
+                    shutdown_receiver.await.ok();
+                })
+                .await
+                .map_err(Error::from)
+        });
+
+        let milestone_handle = self.milestone_interval.map(|milestone_interval| {
+            let state = state.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(milestone_interval);
+                // The first tick completes immediately.
+                interval.tick().await;
+                loop {
+                    interval.tick().await;
+                    state.write().await.issue_milestone();
+                }
+            })
+        });
+
+        Ok(MockNode {
+            address,
+            protocol_parameters: self.protocol_parameters,
+            milestone_public_keys: milestone_public_keys.into_iter().collect(),
+            state,
+            shutdown_sender: Some(shutdown_sender),
+            server_handle: Some(server_handle),
+            milestone_handle,
+        })
+    }
+}
+
+/// An in-process mock of a Hornet node, stopped when dropped.
+pub struct MockNode {
+    address: SocketAddr,
+    protocol_parameters: ProtocolParameters,
+    milestone_public_keys: Vec<String>,
+    state: Arc<RwLock<NodeState>>,
+    shutdown_sender: Option<oneshot::Sender<()>>,
+    server_handle: Option<JoinHandle<Result<(), Error>>>,
+    milestone_handle: Option<JoinHandle<()>>,
+}
+
+impl core::fmt::Debug for MockNode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MockNode").field("address", &self.address).finish()
+    }
+}
+
+impl MockNode {
+    /// Creates a [`MockNodeBuilder`] to configure and start a mock node.
+    pub fn builder() -> MockNodeBuilder {
+        MockNodeBuilder::new()
+    }
+
+    /// Returns the address the mock node listens on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the url of the mock node, to be used as node of a client.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Returns the protocol parameters of the mock node.
+    pub fn protocol_parameters(&self) -> &ProtocolParameters {
+        &self.protocol_parameters
+    }
+
+    /// Returns the options to verify the responses of the mock node, trusting the keys it signs milestones with.
+    pub fn verification_options(&self) -> VerificationOptions {
+        VerificationOptions::new(
+            self.milestone_public_keys.len(),
+            self.milestone_public_keys
+                .iter()
+                .map(|public_key| MilestoneKeyRange::new(public_key.as_str(), 0, 0))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Serves the given JSON instead of the real response to GET requests of the path, like a malicious node would.
+    pub async fn forge_response(&self, path: &str, response: &(impl Serialize + Sync)) {
+        let response = serde_json::to_string(response).expect("response is serializable");
+
+        self.state
+            .write()
+            .await
+            .forged_responses
+            .insert(path.trim_matches('/').to_owned(), response);
+    }
+
+    /// Books an output in the confirmed milestone, without a transaction. Used to fund addresses.
+    pub async fn add_output(&self, output: Output) -> OutputId {
+        self.state.write().await.add_output(output)
+    }
+
+    /// Issues a milestone that confirms all blocks submitted since the previous one and returns its index.
+    pub async fn issue_milestone(&self) -> u32 {
+        self.state.write().await.issue_milestone()
+    }
+
+    /// Returns the index of the latest confirmed milestone.
+    pub async fn confirmed_milestone_index(&self) -> u32 {
+        self.state.read().await.milestone_index
+    }
+
+    /// Prunes all blocks, spent outputs and milestone data up to and including the given milestone index.
+    pub async fn prune(&self, index: u32) -> crate::client::Result<()> {
+        Ok(self.state.write().await.prune(index)?)
+    }
+
+    /// Returns the metadata of a block, `None` if it's unknown or pruned.
+    pub async fn block_metadata(&self, block_id: &BlockId) -> Option<BlockMetadataResponse> {
+        self.state
+            .read()
+            .await
+            .blocks
+            .get(block_id)
+            .map(|entry| entry.metadata.clone())
+    }
+
+    /// Returns an output with its metadata, `None` if it's unknown or pruned.
+    pub async fn output(&self, output_id: &OutputId) -> Option<OutputWithMetadata> {
+        self.state.read().await.output(output_id)
+    }
+
+    /// Adds a participation event which participations get tracked from the next milestone on.
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    pub async fn add_participation_event(&self, event: ParticipationEvent) {
+        self.state
+            .write()
+            .await
+            .participation
+            .events
+            .insert(event.id, event.data);
+    }
+
+    /// Stops the mock node and waits until the server shut down.
+    pub async fn shutdown(mut self) -> crate::client::Result<()> {
+        self.stop_milestones();
+        if let Some(shutdown_sender) = self.shutdown_sender.take() {
+            shutdown_sender.send(()).ok();
+        }
+        if let Some(server_handle) = self.server_handle.take() {
+            if let Ok(result) = server_handle.await {
+                result?;
+            }
+        }
+        Ok(())
+    }
+
+    fn stop_milestones(&mut self) {
+        if let Some(milestone_handle) = self.milestone_handle.take() {
+            milestone_handle.abort();
+        }
+    }
+}
+
+impl Drop for MockNode {
+    fn drop(&mut self) {
+        self.stop_milestones();
+        if let Some(server_handle) = self.server_handle.take() {
+            server_handle.abort();
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde_json::{json, Value};
+
+use crate::types::{
+    api::plugins::participation::{
+        responses::{OutputStatusResponse, TrackedParticipation},
+        types::{
+            AddressStakingStatus, ParticipationEventData, ParticipationEventId, ParticipationEventPayload,
+            Participations, StakingStatus, PARTICIPATION_TAG,
+        },
+    },
+    block::{
+        output::OutputId,
+        payload::{transaction::TransactionEssence, Payload, TransactionPayload},
+        BlockId,
+    },
+};
+
+/// The participations tracked by the participation plugin of a mock node.
+#[derive(Default)]
+pub(crate) struct ParticipationState {
+    pub(crate) events: BTreeMap<ParticipationEventId, ParticipationEventData>,
+    pub(crate) participations: HashMap<OutputId, HashMap<ParticipationEventId, TrackedParticipation>>,
+}
+
+impl ParticipationState {
+    /// Tracks the participations of a transaction, which are stored in its tagged data payload and made with its
+    /// first output.
+    pub(crate) fn track_participations(
+        &mut self,
+        block_id: BlockId,
+        transaction: &TransactionPayload,
+        milestone_index: u32,
+    ) {
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        let Some(Payload::TaggedData(tagged_data)) = essence.payload() else {
+            return;
+        };
+        if tagged_data.tag() != PARTICIPATION_TAG.as_bytes() {
+            return;
+        }
+        let (Ok(participations), Some(output)) = (
+            Participations::from_bytes(&mut tagged_data.data()),
+            essence.outputs().first(),
+        ) else {
+            return;
+        };
+        // PANIC: 0 is a valid output index.
+        let output_id = OutputId::new(transaction.id(), 0).unwrap();
+
+        for participation in participations.participations {
+            let Some(event) = self.events.get(&participation.event_id) else {
+                continue;
+            };
+            if !(*event.milestone_index_commence()..*event.milestone_index_end()).contains(&milestone_index) {
+                continue;
+            }
+            let answers = match event.payload() {
+                ParticipationEventPayload::VotingEventPayload(_) => Some(participation.answers),
+                ParticipationEventPayload::StakingEventPayload(_) => None,
+            };
+            self.participations.entry(output_id).or_default().insert(
+                participation.event_id,
+                TrackedParticipation {
+                    block_id,
+                    amount: output.amount(),
+                    start_milestone_index: milestone_index,
+                    end_milestone_index: 0,
+                    answers,
+                },
+            );
+        }
+    }
+
+    /// Ends the active participations of a spent output.
+    pub(crate) fn end_participations(&mut self, output_id: &OutputId, milestone_index: u32) {
+        if let Some(participations) = self.participations.get_mut(output_id) {
+            for participation in participations.values_mut() {
+                if participation.end_milestone_index == 0 {
+                    participation.end_milestone_index = milestone_index;
+                }
+            }
+        }
+    }
+
+    /// Returns the participations made with an output.
+    pub(crate) fn output_status(&self, output_id: &OutputId) -> Option<OutputStatusResponse> {
+        self.participations
+            .get(output_id)
+            .map(|participations| OutputStatusResponse {
+                participations: participations.iter().map(|(id, p)| (*id, p.clone())).collect(),
+            })
+    }
+
+    /// Returns the status of an event at the given milestone index.
+    pub(crate) fn event_status(&self, event_id: &ParticipationEventId, milestone_index: u32) -> Option<Value> {
+        let event = self.events.get(event_id)?;
+
+        let status = if milestone_index < *event.milestone_index_commence() {
+            "upcoming"
+        } else if milestone_index < *event.milestone_index_start() {
+            "commencing"
+        } else if milestone_index < *event.milestone_index_end() {
+            "holding"
+        } else {
+            "ended"
+        };
+
+        let mut response = json!({
+            "milestoneIndex": milestone_index,
+            "status": status,
+        });
+
+        if let ParticipationEventPayload::VotingEventPayload(payload) = event.payload() {
+            let questions = payload
+                .questions()
+                .iter()
+                .enumerate()
+                .map(|(question_index, question)| {
+                    let answers = question
+                        .answers()
+                        .iter()
+                        .map(|answer| {
+                            let (current, accumulated) = self
+                                .event_participations(event_id)
+                                .filter(|p| {
+                                    p.answers
+                                        .as_ref()
+                                        .and_then(|answers| answers.get(question_index))
+                                        .map_or(false, |value| value == answer.value())
+                                })
+                                .fold((0, 0), |(current, accumulated), p| {
+                                    (
+                                        current + if p.end_milestone_index == 0 { p.amount } else { 0 },
+                                        accumulated + p.amount * held_milestones(p, event, milestone_index),
+                                    )
+                                });
+                            json!({ "value": answer.value(), "current": current, "accumulated": accumulated })
+                        })
+                        .collect::<Vec<_>>();
+                    json!({ "answers": answers })
+                })
+                .collect::<Vec<_>>();
+            response["questions"] = Value::from(questions);
+        }
+
+        response["checksum"] = Value::from(prefix_hex::encode(
+            Blake2b256::digest(response.to_string().as_bytes()).as_slice(),
+        ));
+
+        Some(response)
+    }
+
+    /// Returns the staking rewards of the participations made with the given outputs.
+    pub(crate) fn staking_status<'a>(
+        &self,
+        output_ids: impl Iterator<Item = &'a OutputId>,
+        milestone_index: u32,
+    ) -> AddressStakingStatus {
+        let mut rewards = hashbrown::HashMap::new();
+
+        for output_id in output_ids {
+            let Some(participations) = self.participations.get(output_id) else {
+                continue;
+            };
+            for (event_id, participation) in participations {
+                let Some(event) = self.events.get(event_id) else {
+                    continue;
+                };
+                let ParticipationEventPayload::StakingEventPayload(payload) = event.payload() else {
+                    continue;
+                };
+                let status = rewards.entry(event_id.to_string()).or_insert_with(|| StakingStatus {
+                    amount: 0,
+                    symbol: payload.symbol().clone(),
+                    minimum_reached: false,
+                });
+                status.amount +=
+                    participation.amount * held_milestones(participation, event, milestone_index) * payload.numerator()
+                        / payload.denominator();
+                status.minimum_reached = status.amount >= *payload.required_minimum_rewards();
+            }
+        }
+
+        AddressStakingStatus {
+            rewards,
+            milestone_index,
+        }
+    }
+
+    fn event_participations<'a>(
+        &'a self,
+        event_id: &'a ParticipationEventId,
+    ) -> impl Iterator<Item = &'a TrackedParticipation> + 'a {
+        self.participations
+            .values()
+            .filter_map(move |participations| participations.get(event_id))
+    }
+}
+
+// The number of milestones a participation was held during the holding phase of its event.
+fn held_milestones(participation: &TrackedParticipation, event: &ParticipationEventData, milestone_index: u32) -> u64 {
+    let from = participation.start_milestone_index.max(*event.milestone_index_start());
+    let to = match participation.end_milestone_index {
+        0 => milestone_index,
+        end_milestone_index => end_milestone_index,
+    }
+    .min(*event.milestone_index_end());
+
+    to.saturating_sub(from) as u64
+}
@@ -0,0 +1,457 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, convert::Infallible, str::FromStr, sync::Arc};
+
+use hyper::{
+    header::{ACCEPT, CONTENT_TYPE},
+    Body, Method, Request, Response, StatusCode,
+};
+use packable::PackableExt;
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+use super::{
+    indexer::{matches_kind, OutputFilter},
+    state::NodeState,
+};
+use crate::types::{
+    api::{
+        core::response::{
+            BaseTokenResponse, ConfirmedMilestoneResponse, InfoResponse, LatestMilestoneResponse, MetricsResponse,
+            OutputWithMetadataResponse, RoutesResponse, StatusResponse, SubmitBlockResponse, TipsResponse,
+        },
+        plugins::indexer::OutputIdsResponse,
+    },
+    block::{
+        output::{AliasId, FoundryId, NftId, Output, OutputId},
+        payload::transaction::TransactionId,
+        Block, BlockDto, BlockId,
+    },
+    TryFromDto,
+};
+
+/// The content type of packed blocks and outputs.
+const SERIALIZER_V1: &str = "application/vnd.iota.serializer-v1";
+/// The maximum number of output ids the indexer returns in one page, if no page size is requested.
+const DEFAULT_PAGE_SIZE: usize = 1000;
+
+/// An error response of a route.
+enum RouteError {
+    BadRequest(String),
+    NotFound(String),
+}
+
+impl RouteError {
+    fn into_response(self) -> Response<Body> {
+        let (status, message) = match self {
+            Self::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            Self::NotFound(message) => (StatusCode::NOT_FOUND, message),
+        };
+        let body = serde_json::json!({
+            "error": {
+                "code": status.as_u16().to_string(),
+                "message": message,
+            }
+        });
+
+        response(status, "application/json", body.to_string())
+    }
+}
+
+type RouteResult = Result<Response<Body>, RouteError>;
+
+/// Handles a request to the mock node.
+pub(crate) async fn handle_request(
+    state: Arc<RwLock<NodeState>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let path = request.uri().path().trim_matches('/').to_owned();
+    let query = request
+        .uri()
+        .query()
+        .map(|query| url::form_urlencoded::parse(query.as_bytes()).into_owned().collect())
+        .unwrap_or_default();
+    let raw = request
+        .headers()
+        .get(ACCEPT)
+        .map_or(false, |accept| accept.as_bytes() == SERIALIZER_V1.as_bytes());
+    let segments = path.split('/').collect::<Vec<_>>();
+
+    let forged_response = state.read().await.forged_responses.get(&path).cloned();
+
+    let result = match *request.method() {
+        Method::GET => match forged_response {
+            Some(body) => Ok(response(StatusCode::OK, "application/json", body)),
+            None => get(&*state.read().await, &segments, &query, raw),
+        },
+        Method::POST if segments == ["api", "core", "v2", "blocks"] => {
+            let packed = request.headers().get(CONTENT_TYPE).map_or(false, |content_type| {
+                content_type.as_bytes() == SERIALIZER_V1.as_bytes()
+            });
+            match hyper::body::to_bytes(request.into_body()).await {
+                Ok(body) => post_block(&mut *state.write().await, &body, packed),
+                Err(e) => Err(RouteError::BadRequest(e.to_string())),
+            }
+        }
+        _ => Err(RouteError::NotFound(format!("unknown route {path}"))),
+    };
+
+    Ok(result.unwrap_or_else(RouteError::into_response))
+}
+
+fn get(state: &NodeState, segments: &[&str], query: &HashMap<String, String>, raw: bool) -> RouteResult {
+    match segments {
+        ["health"] => Ok(response(StatusCode::OK, "text/plain", "")),
+        ["api", "routes"] => json(&RoutesResponse {
+            routes: vec![
+                "core/v2".to_owned(),
+                "indexer/v1".to_owned(),
+                "poi/v1".to_owned(),
+                #[cfg(feature = "participation")]
+                "participation/v1".to_owned(),
+            ],
+        }),
+        ["api", "core", "v2", "info"] => json(&info(state)),
+        ["api", "core", "v2", "tips"] => json(&TipsResponse { tips: state.tips() }),
+        ["api", "core", "v2", "blocks", block_id] => block(state, &parse(block_id)?, raw),
+        ["api", "core", "v2", "blocks", block_id, "metadata"] => block_metadata(state, &parse(block_id)?),
+        ["api", "core", "v2", "outputs", output_id] => {
+            let output = state
+                .output(&parse(output_id)?)
+                .ok_or_else(|| RouteError::NotFound(format!("output {output_id} not found")))?;
+            if raw {
+                Ok(response(StatusCode::OK, SERIALIZER_V1, output.output().pack_to_vec()))
+            } else {
+                json(&OutputWithMetadataResponse::from(&output))
+            }
+        }
+        ["api", "core", "v2", "outputs", output_id, "metadata"] => {
+            let output = state
+                .output(&parse(output_id)?)
+                .ok_or_else(|| RouteError::NotFound(format!("output {output_id} not found")))?;
+            json(output.metadata())
+        }
+        ["api", "core", "v2", "transactions", transaction_id, "included-block"] => {
+            block(state, &included_block_id(state, transaction_id)?, raw)
+        }
+        [
+            "api",
+            "core",
+            "v2",
+            "transactions",
+            transaction_id,
+            "included-block",
+            "metadata",
+        ] => block_metadata(state, &included_block_id(state, transaction_id)?),
+        ["api", "core", "v2", "milestones", "by-index", index, "utxo-changes"] => {
+            let utxo_changes = state
+                .utxo_changes
+                .get(&parse(index)?)
+                .ok_or_else(|| RouteError::NotFound(format!("milestone {index} not found")))?;
+            json(utxo_changes)
+        }
+        ["api", "indexer", "v1", "outputs"] => output_ids(state, None, query),
+        [
+            "api",
+            "indexer",
+            "v1",
+            "outputs",
+            kind @ ("basic" | "alias" | "foundry" | "nft"),
+        ] => output_ids(state, Some(kind), query),
+        ["api", "indexer", "v1", "outputs", "alias", alias_id] => {
+            let alias_id = parse::<AliasId>(alias_id)?;
+            chain_output_id(
+                state,
+                |output_id, output| matches!(output, Output::Alias(alias) if alias.alias_id_non_null(output_id) == alias_id),
+            )
+        }
+        ["api", "indexer", "v1", "outputs", "foundry", foundry_id] => {
+            let foundry_id = parse::<FoundryId>(foundry_id)?;
+            chain_output_id(
+                state,
+                |_, output| matches!(output, Output::Foundry(foundry) if foundry.id() == foundry_id),
+            )
+        }
+        ["api", "indexer", "v1", "outputs", "nft", nft_id] => {
+            let nft_id = parse::<NftId>(nft_id)?;
+            chain_output_id(
+                state,
+                |output_id, output| matches!(output, Output::Nft(nft) if nft.nft_id_non_null(output_id) == nft_id),
+            )
+        }
+        ["api", "poi", "v1", "create", block_id] => state
+            .inclusion_proof(&parse(block_id)?)
+            .ok_or_else(|| RouteError::NotFound(format!("no proof of inclusion for block {block_id}")))
+            .and_then(|proof| json(&proof)),
+        #[cfg(feature = "participation")]
+        ["api", "participation", "v1", segments @ ..] => participation::get(state, segments, query),
+        _ => Err(RouteError::NotFound(format!("unknown route {}", segments.join("/")))),
+    }
+}
+
+fn post_block(state: &mut NodeState, body: &[u8], packed: bool) -> RouteResult {
+    let block = if packed {
+        Block::unpack_verified(body, &state.protocol_parameters).map_err(|e| RouteError::BadRequest(e.to_string()))?
+    } else {
+        let dto = serde_json::from_slice::<BlockDto>(body).map_err(|e| RouteError::BadRequest(e.to_string()))?;
+        Block::try_from_dto_with_params(dto, &state.protocol_parameters)
+            .map_err(|e| RouteError::BadRequest(e.to_string()))?
+    };
+    let block_id = state
+        .submit_block(block)
+        .map_err(|e| RouteError::BadRequest(e.to_string()))?;
+
+    Ok(response(
+        StatusCode::CREATED,
+        "application/json",
+        serde_json::to_string(&SubmitBlockResponse { block_id }).expect("response is serializable"),
+    ))
+}
+
+fn info(state: &NodeState) -> InfoResponse {
+    InfoResponse {
+        name: "mock-node".to_owned(),
+        version: env!("CARGO_PKG_VERSION").to_owned(),
+        status: StatusResponse {
+            is_healthy: true,
+            latest_milestone: LatestMilestoneResponse {
+                index: state.milestone_index,
+                timestamp: Some(state.milestone_timestamp),
+                milestone_id: state.milestone_id(),
+            },
+            confirmed_milestone: ConfirmedMilestoneResponse {
+                index: state.milestone_index,
+                timestamp: Some(state.milestone_timestamp),
+                milestone_id: state.milestone_id(),
+            },
+            pruning_index: state.pruning_index,
+        },
+        supported_protocol_versions: vec![state.protocol_parameters.protocol_version()],
+        protocol: state.protocol_parameters.clone(),
+        pending_protocol_parameters: Vec::new(),
+        base_token: BaseTokenResponse {
+            name: "Shimmer".to_owned(),
+            ticker_symbol: "SMR".to_owned(),
+            unit: "SMR".to_owned(),
+            subunit: Some("glow".to_owned()),
+            decimals: 6,
+            use_metric_prefix: false,
+        },
+        metrics: MetricsResponse {
+            blocks_per_second: 0.0,
+            referenced_blocks_per_second: 0.0,
+            referenced_rate: 0.0,
+        },
+        features: Vec::new(),
+    }
+}
+
+fn block(state: &NodeState, block_id: &BlockId, raw: bool) -> RouteResult {
+    let entry = state
+        .blocks
+        .get(block_id)
+        .ok_or_else(|| RouteError::NotFound(format!("block {block_id} not found")))?;
+
+    if raw {
+        Ok(response(StatusCode::OK, SERIALIZER_V1, entry.block.pack_to_vec()))
+    } else {
+        json(&BlockDto::from(&entry.block))
+    }
+}
+
+fn block_metadata(state: &NodeState, block_id: &BlockId) -> RouteResult {
+    state
+        .blocks
+        .get(block_id)
+        .ok_or_else(|| RouteError::NotFound(format!("block {block_id} not found")))
+        .and_then(|entry| json(&entry.metadata))
+}
+
+fn included_block_id(state: &NodeState, transaction_id: &str) -> Result<BlockId, RouteError> {
+    state
+        .included_transactions
+        .get(&parse::<TransactionId>(transaction_id)?)
+        .copied()
+        .ok_or_else(|| RouteError::NotFound(format!("transaction {transaction_id} not included")))
+}
+
+fn output_ids(state: &NodeState, kind: Option<&str>, query: &HashMap<String, String>) -> RouteResult {
+    let mut page_size = DEFAULT_PAGE_SIZE;
+    let mut offset = 0;
+    let mut filters = Vec::new();
+
+    for (key, value) in query {
+        match key.as_str() {
+            "pageSize" => page_size = parse(value)?,
+            // The cursor is the offset of the next page followed by the page size.
+            "cursor" => {
+                let (cursor_offset, cursor_page_size) = value
+                    .split_once('.')
+                    .ok_or_else(|| RouteError::BadRequest(format!("invalid cursor {value}")))?;
+                offset = parse(cursor_offset)?;
+                page_size = parse(cursor_page_size)?;
+            }
+            _ => filters.push(OutputFilter::parse(key, value).map_err(RouteError::BadRequest)?),
+        }
+    }
+    let page_size = page_size.max(1);
+
+    let output_ids = state
+        .unspent_outputs()
+        .into_iter()
+        .filter(|output| matches_kind(output.output(), kind) && filters.iter().all(|filter| filter.matches(output)))
+        .map(|output| *output.metadata().output_id())
+        .collect::<Vec<_>>();
+    let next_offset = offset + page_size;
+
+    json(&OutputIdsResponse {
+        ledger_index: state.milestone_index,
+        cursor: (next_offset < output_ids.len()).then(|| format!("{next_offset}.{page_size}")),
+        items: output_ids.into_iter().skip(offset).take(page_size).collect(),
+    })
+}
+
+fn chain_output_id(state: &NodeState, predicate: impl Fn(&OutputId, &Output) -> bool) -> RouteResult {
+    let output_id = state
+        .unspent_outputs()
+        .into_iter()
+        .find(|output| predicate(output.metadata().output_id(), output.output()))
+        .map(|output| *output.metadata().output_id())
+        .ok_or_else(|| RouteError::NotFound("output not found".to_owned()))?;
+
+    json(&OutputIdsResponse {
+        ledger_index: state.milestone_index,
+        cursor: None,
+        items: vec![output_id],
+    })
+}
+
+#[cfg(feature = "participation")]
+mod participation {
+    use std::collections::HashMap;
+
+    use super::{json, parse, RouteError, RouteResult};
+    use crate::{
+        client::mock_node::state::NodeState,
+        types::{
+            api::plugins::participation::{
+                responses::{AddressOutputsResponse, EventsResponse},
+                types::{ParticipationEventId, ParticipationEventPayload},
+            },
+            block::{
+                address::{Address, Bech32Address},
+                output::OutputId,
+            },
+        },
+    };
+
+    pub(super) fn get(state: &NodeState, segments: &[&str], query: &HashMap<String, String>) -> RouteResult {
+        let participation = &state.participation;
+
+        match segments {
+            ["events"] => {
+                let event_type = query
+                    .get("type")
+                    .map(|event_type| parse::<u8>(event_type))
+                    .transpose()?;
+                json(&EventsResponse {
+                    event_ids: participation
+                        .events
+                        .iter()
+                        .filter(|(_, event)| {
+                            event_type.map_or(true, |event_type| match event.payload() {
+                                ParticipationEventPayload::VotingEventPayload(_) => event_type == 0,
+                                ParticipationEventPayload::StakingEventPayload(_) => event_type == 1,
+                            })
+                        })
+                        .map(|(event_id, _)| *event_id)
+                        .collect(),
+                })
+            }
+            ["events", event_id] => participation
+                .events
+                .get(&parse::<ParticipationEventId>(event_id)?)
+                .ok_or_else(|| RouteError::NotFound(format!("event {event_id} not found")))
+                .and_then(json),
+            ["events", event_id, "status"] => {
+                let milestone_index = query
+                    .get("milestoneIndex")
+                    .map(|index| parse(index))
+                    .transpose()?
+                    .unwrap_or(state.milestone_index);
+                participation
+                    .event_status(&parse(event_id)?, milestone_index)
+                    .ok_or_else(|| RouteError::NotFound(format!("event {event_id} not found")))
+                    .and_then(|status| json(&status))
+            }
+            ["outputs", output_id] => participation
+                .output_status(&parse(output_id)?)
+                .ok_or_else(|| RouteError::NotFound(format!("output {output_id} not found")))
+                .and_then(|status| json(&status)),
+            ["addresses", address] => {
+                let output_ids = address_output_ids(state, address)?;
+                json(&participation.staking_status(output_ids.iter(), state.milestone_index))
+            }
+            ["addresses", address, "outputs"] => json(&AddressOutputsResponse {
+                outputs: address_output_ids(state, address)?
+                    .into_iter()
+                    .filter_map(|output_id| {
+                        participation
+                            .output_status(&output_id)
+                            .map(|status| (output_id, status))
+                    })
+                    .collect(),
+            }),
+            _ => Err(RouteError::NotFound(format!(
+                "unknown route api/participation/v1/{}",
+                segments.join("/")
+            ))),
+        }
+    }
+
+    // The ids of all known outputs that are owned by an address.
+    fn address_output_ids(state: &NodeState, address: &str) -> Result<Vec<OutputId>, RouteError> {
+        let address = Bech32Address::try_from_str(address).map_err(|e| RouteError::BadRequest(e.to_string()))?;
+        let address: &Address = address.inner();
+
+        Ok(state
+            .ledger
+            .unspent_outputs()
+            .chain(
+                state
+                    .ledger
+                    .spent_outputs()
+                    .map(|(output_id, spent_output)| (output_id, spent_output.output())),
+            )
+            .filter(|(_, output)| {
+                output
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.address())
+                    .map_or(false, |unlock_condition| unlock_condition.address() == address)
+            })
+            .map(|(output_id, _)| *output_id)
+            .collect())
+    }
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, RouteError> {
+    value
+        .parse()
+        .map_err(|_| RouteError::BadRequest(format!("invalid parameter {value}")))
+}
+
+fn json<T: Serialize>(value: &T) -> RouteResult {
+    serde_json::to_string(value)
+        .map(|body| response(StatusCode::OK, "application/json", body))
+        .map_err(|e| RouteError::BadRequest(e.to_string()))
+}
+
+fn response(status: StatusCode, content_type: &str, body: impl Into<Body>) -> Response<Body> {
+    let mut response = Response::new(body.into());
+    *response.status_mut() = status;
+    if let Ok(content_type) = content_type.parse() {
+        response.headers_mut().insert(CONTENT_TYPE, content_type);
+    }
+    response
+}
@@ -0,0 +1,435 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+use packable::PackableExt;
+
+use super::Error;
+use crate::{
+    pow::score::PowScorer,
+    types::{
+        api::{
+            core::response::{BlockMetadataResponse, LedgerInclusionState, UtxoChangesResponse},
+            plugins::poi::ProofResponse,
+        },
+        block::{
+            input::Input,
+            output::{Output, OutputId, OutputMetadata, OutputWithMetadata},
+            parent::Parents,
+            payload::{
+                milestone::{
+                    MerkleHasher, MilestoneEssence, MilestoneId, MilestoneIndex, MilestoneOptions, MilestonePayload,
+                },
+                transaction::{TransactionEssence, TransactionId},
+                Payload, TransactionPayload,
+            },
+            protocol::ProtocolParameters,
+            semantic::ConflictReason,
+            signature::{Ed25519Signature, Signature},
+            Block, BlockId,
+        },
+        ledger::{SpentOutput, UtxoLedger},
+    },
+    utils::unix_timestamp_now,
+};
+
+/// The maximum number of tips returned to attach new blocks to.
+const MAX_TIPS: usize = 8;
+
+/// A block known to the mock node together with its metadata.
+pub(crate) struct BlockEntry {
+    pub(crate) block: Block,
+    pub(crate) metadata: BlockMetadataResponse,
+}
+
+/// A milestone issued by the mock node together with the blocks it referenced, in white flag order.
+pub(crate) struct MilestoneEntry {
+    pub(crate) payload: MilestonePayload,
+    referenced_blocks: Vec<BlockId>,
+}
+
+/// The block and milestone that booked an output.
+struct BookedOutput {
+    block_id: BlockId,
+    milestone_index: u32,
+    milestone_timestamp: u32,
+}
+
+/// The in-memory state of a mock node: the tangle, the UTXO ledger and the milestones that confirmed them.
+pub(crate) struct NodeState {
+    pub(crate) protocol_parameters: ProtocolParameters,
+    pub(crate) milestone_index: u32,
+    pub(crate) milestone_timestamp: u32,
+    pub(crate) pruning_index: u32,
+    pub(crate) blocks: HashMap<BlockId, BlockEntry>,
+    // The keys milestones are signed with, sorted by public key.
+    milestone_keys: Vec<SecretKey>,
+    pub(crate) milestones: BTreeMap<u32, MilestoneEntry>,
+    // Blocks that are not yet referenced by a milestone, in the order they got submitted.
+    pending_blocks: Vec<BlockId>,
+    tips: Vec<BlockId>,
+    pub(crate) ledger: UtxoLedger,
+    // The block and milestone that booked each output known to the ledger.
+    booked_outputs: HashMap<OutputId, BookedOutput>,
+    pub(crate) included_transactions: HashMap<TransactionId, BlockId>,
+    pub(crate) utxo_changes: BTreeMap<u32, UtxoChangesResponse>,
+    // Counter to derive unique transaction ids for outputs that are added without a transaction.
+    genesis_counter: u64,
+    // JSON bodies served instead of the real responses to GET requests, by path.
+    pub(crate) forged_responses: HashMap<String, String>,
+    #[cfg(feature = "participation")]
+    pub(crate) participation: super::participation::ParticipationState,
+}
+
+impl NodeState {
+    pub(crate) fn new(protocol_parameters: ProtocolParameters, mut milestone_keys: Vec<SecretKey>) -> Self {
+        let milestone_index = 1;
+        let milestone_timestamp = unix_timestamp_now().as_secs() as u32;
+        milestone_keys.sort_by_key(|key| key.public_key().to_bytes());
+        milestone_keys.dedup_by_key(|key| key.public_key().to_bytes());
+
+        let mut state = Self {
+            protocol_parameters,
+            milestone_index,
+            milestone_timestamp,
+            pruning_index: 0,
+            blocks: HashMap::new(),
+            milestone_keys,
+            milestones: BTreeMap::new(),
+            pending_blocks: Vec::new(),
+            tips: Vec::new(),
+            ledger: UtxoLedger::new(milestone_index, milestone_timestamp),
+            booked_outputs: HashMap::new(),
+            included_transactions: HashMap::new(),
+            utxo_changes: BTreeMap::from([(milestone_index, empty_utxo_changes(milestone_index))]),
+            genesis_counter: 0,
+            forged_responses: HashMap::new(),
+            #[cfg(feature = "participation")]
+            participation: Default::default(),
+        };
+        state.sign_milestone(Vec::new(), &[]);
+
+        state
+    }
+
+    /// Books an output in the confirmed milestone without a transaction consuming any inputs.
+    pub(crate) fn add_output(&mut self, output: Output) -> OutputId {
+        self.genesis_counter += 1;
+        let transaction_id = TransactionId::new(
+            Blake2b256::digest([b"mock-node".as_slice(), &self.genesis_counter.to_le_bytes()].concat()).into(),
+        );
+        // PANIC: 0 is a valid output index.
+        let output_id = OutputId::new(transaction_id, 0).unwrap();
+
+        self.ledger.add_output(output_id, output);
+        self.book_output(BlockId::null(), output_id);
+
+        output_id
+    }
+
+    /// Returns the tips new blocks should be attached to.
+    pub(crate) fn tips(&self) -> Vec<BlockId> {
+        if self.tips.is_empty() {
+            vec![BlockId::null()]
+        } else {
+            self.tips.clone()
+        }
+    }
+
+    /// Adds a block to the tangle, it gets processed by the next milestone.
+    pub(crate) fn submit_block(&mut self, block: Block) -> Result<BlockId, Error> {
+        let block_id = block.id();
+
+        let min_pow_score = self.protocol_parameters.min_pow_score();
+        if min_pow_score > 0 && PowScorer::new().score(&block.pack_to_vec()) < min_pow_score as f64 {
+            return Err(Error::InsufficientPowScore(block_id));
+        }
+
+        match block.payload() {
+            None | Some(Payload::Transaction(_)) | Some(Payload::TaggedData(_)) => {}
+            Some(payload) => return Err(Error::UnsupportedPayloadKind(payload.kind())),
+        }
+
+        if self.blocks.contains_key(&block_id) {
+            return Ok(block_id);
+        }
+
+        self.tips.retain(|tip| !block.parents().contains(tip));
+        self.tips.push(block_id);
+        if self.tips.len() > MAX_TIPS {
+            self.tips.remove(0);
+        }
+
+        self.pending_blocks.push(block_id);
+        self.blocks.insert(
+            block_id,
+            BlockEntry {
+                metadata: BlockMetadataResponse {
+                    block_id,
+                    parents: block.parents().to_vec(),
+                    is_solid: true,
+                    referenced_by_milestone_index: None,
+                    milestone_index: None,
+                    ledger_inclusion_state: None,
+                    conflict_reason: None,
+                    white_flag_index: None,
+                    should_promote: Some(false),
+                    should_reattach: Some(false),
+                },
+                block,
+            },
+        );
+
+        Ok(block_id)
+    }
+
+    /// Issues a new milestone that references all pending blocks and applies their transactions to the ledger.
+    pub(crate) fn issue_milestone(&mut self) -> u32 {
+        self.milestone_index += 1;
+        self.milestone_timestamp = self.milestone_timestamp.max(unix_timestamp_now().as_secs() as u32);
+        self.ledger.next_milestone(self.milestone_timestamp);
+        self.utxo_changes
+            .insert(self.milestone_index, empty_utxo_changes(self.milestone_index));
+
+        let referenced_blocks = core::mem::take(&mut self.pending_blocks);
+        let mut applied_blocks = Vec::new();
+
+        for (white_flag_index, block_id) in referenced_blocks.iter().copied().enumerate() {
+            let transaction = match self.blocks.get(&block_id).and_then(|entry| entry.block.payload()) {
+                Some(Payload::Transaction(transaction)) => Some(transaction.clone()),
+                _ => None,
+            };
+
+            let (ledger_inclusion_state, conflict_reason) = transaction.map_or(
+                (LedgerInclusionState::NoTransaction, ConflictReason::None),
+                |transaction| match self.ledger.apply_transaction(&transaction) {
+                    Ok(()) => {
+                        self.book_transaction(block_id, &transaction);
+                        applied_blocks.push(block_id);
+                        (LedgerInclusionState::Included, ConflictReason::None)
+                    }
+                    Err(conflict_reason) => (LedgerInclusionState::Conflicting, conflict_reason),
+                },
+            );
+
+            if let Some(entry) = self.blocks.get_mut(&block_id) {
+                let metadata = &mut entry.metadata;
+                metadata.referenced_by_milestone_index = Some(self.milestone_index);
+                metadata.ledger_inclusion_state = Some(ledger_inclusion_state);
+                metadata.conflict_reason = Some(conflict_reason as u8);
+                metadata.white_flag_index = Some(white_flag_index as u32);
+                metadata.should_promote = None;
+                metadata.should_reattach = None;
+            }
+        }
+
+        self.sign_milestone(referenced_blocks, &applied_blocks);
+
+        self.milestone_index
+    }
+
+    // Signs the milestone at the current index and links it to the previous one.
+    fn sign_milestone(&mut self, referenced_blocks: Vec<BlockId>, applied_blocks: &[BlockId]) {
+        let previous_milestone_id = self
+            .milestones
+            .last_key_value()
+            .map_or_else(MilestoneId::null, |(_, entry)| entry.payload.id());
+        // PANIC: the tips are within the parents count range and there are no milestone options.
+        let essence = MilestoneEssence::new(
+            MilestoneIndex::new(self.milestone_index),
+            self.milestone_timestamp,
+            self.protocol_parameters.protocol_version(),
+            previous_milestone_id,
+            Parents::from_vec(self.tips()).unwrap(),
+            MerkleHasher::digest(&referenced_blocks),
+            MerkleHasher::digest(applied_blocks),
+            Vec::new(),
+            MilestoneOptions::from_vec(Vec::new()).unwrap(),
+        )
+        .unwrap();
+        let essence_hash = essence.hash();
+        let signatures = self
+            .milestone_keys
+            .iter()
+            .map(|key| Signature::from(Ed25519Signature::new(key.public_key(), key.sign(&essence_hash))))
+            .collect::<Vec<_>>();
+        // PANIC: the mock node is only started with milestone keys.
+        let payload = MilestonePayload::new(essence, signatures).unwrap();
+
+        self.milestones.insert(
+            self.milestone_index,
+            MilestoneEntry {
+                payload,
+                referenced_blocks,
+            },
+        );
+    }
+
+    /// Returns the proof that a block was referenced by a milestone, `None` if it isn't referenced yet.
+    pub(crate) fn inclusion_proof(&self, block_id: &BlockId) -> Option<ProofResponse> {
+        let entry = self.blocks.get(block_id)?;
+        let milestone = self.milestones.get(&entry.metadata.referenced_by_milestone_index?)?;
+        let proof = MerkleHasher::proof(&milestone.referenced_blocks, entry.metadata.white_flag_index? as usize)?;
+
+        Some(ProofResponse {
+            milestone: (&milestone.payload).into(),
+            block: (&entry.block).into(),
+            proof,
+        })
+    }
+
+    /// Returns the ID of the latest milestone.
+    pub(crate) fn milestone_id(&self) -> Option<MilestoneId> {
+        self.milestones.last_key_value().map(|(_, entry)| entry.payload.id())
+    }
+
+    /// Removes all data that was confirmed by milestones up to and including the given index.
+    pub(crate) fn prune(&mut self, index: u32) -> Result<(), Error> {
+        if index >= self.milestone_index {
+            return Err(Error::InvalidPruningIndex {
+                index,
+                confirmed_milestone_index: self.milestone_index,
+            });
+        }
+
+        self.blocks.retain(|_, entry| {
+            entry
+                .metadata
+                .referenced_by_milestone_index
+                .map_or(true, |referenced_index| referenced_index > index)
+        });
+        self.tips.retain(|tip| self.blocks.contains_key(tip));
+        let blocks = &self.blocks;
+        self.included_transactions
+            .retain(|_, block_id| blocks.contains_key(block_id));
+        self.ledger.prune(index);
+        let ledger = &self.ledger;
+        self.booked_outputs
+            .retain(|output_id, _| ledger.is_unspent(output_id) || ledger.spent_output(output_id).is_some());
+        self.utxo_changes.retain(|milestone_index, _| *milestone_index > index);
+        self.milestones.retain(|milestone_index, _| *milestone_index > index);
+        self.pruning_index = self.pruning_index.max(index);
+
+        Ok(())
+    }
+
+    /// Returns an output with its metadata at the current ledger index.
+    pub(crate) fn output(&self, output_id: &OutputId) -> Option<OutputWithMetadata> {
+        let booked = self.booked_outputs.get(output_id)?;
+
+        if let Some(output) = self.ledger.output(output_id) {
+            return Some(self.with_metadata(output_id, output.clone(), booked, None));
+        }
+        self.ledger.spent_output(output_id).map(|spent_output| {
+            self.with_metadata(output_id, spent_output.output().clone(), booked, Some(spent_output))
+        })
+    }
+
+    /// Returns the unspent outputs, ordered by the milestone that booked them.
+    pub(crate) fn unspent_outputs(&self) -> Vec<OutputWithMetadata> {
+        let mut outputs = self
+            .ledger
+            .unspent_outputs()
+            .filter_map(|(output_id, output)| {
+                self.booked_outputs
+                    .get(output_id)
+                    .map(|booked| self.with_metadata(output_id, output.clone(), booked, None))
+            })
+            .collect::<Vec<_>>();
+        outputs.sort_by_key(|output| {
+            (
+                output.metadata().milestone_index_booked(),
+                *output.metadata().output_id(),
+            )
+        });
+        outputs
+    }
+
+    fn with_metadata(
+        &self,
+        output_id: &OutputId,
+        output: Output,
+        booked: &BookedOutput,
+        spent_output: Option<&SpentOutput>,
+    ) -> OutputWithMetadata {
+        OutputWithMetadata::new(
+            output,
+            OutputMetadata::new(
+                booked.block_id,
+                *output_id,
+                spent_output.is_some(),
+                spent_output.map(|spent_output| spent_output.milestone_index()),
+                spent_output.map(|spent_output| spent_output.milestone_timestamp()),
+                spent_output.map(|spent_output| *spent_output.transaction_id()),
+                booked.milestone_index,
+                booked.milestone_timestamp,
+                self.milestone_index,
+            ),
+        )
+    }
+
+    // Records the effects of a transaction that was applied to the ledger.
+    fn book_transaction(&mut self, block_id: BlockId, transaction: &TransactionPayload) {
+        let transaction_id = transaction.id();
+
+        for output_id in input_ids(transaction) {
+            #[cfg(feature = "participation")]
+            self.participation.end_participations(&output_id, self.milestone_index);
+            if let Some(utxo_changes) = self.utxo_changes.get_mut(&self.milestone_index) {
+                utxo_changes.consumed_outputs.push(output_id);
+            }
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        for index in 0..essence.outputs().len() {
+            // PANIC: the output count is bounded by the transaction essence.
+            let output_id = OutputId::new(transaction_id, index as u16).unwrap();
+            self.book_output(block_id, output_id);
+        }
+
+        #[cfg(feature = "participation")]
+        self.participation
+            .track_participations(block_id, transaction, self.milestone_index);
+
+        self.included_transactions.insert(transaction_id, block_id);
+    }
+
+    fn book_output(&mut self, block_id: BlockId, output_id: OutputId) {
+        self.booked_outputs.insert(
+            output_id,
+            BookedOutput {
+                block_id,
+                milestone_index: self.milestone_index,
+                milestone_timestamp: self.milestone_timestamp,
+            },
+        );
+        if let Some(utxo_changes) = self.utxo_changes.get_mut(&self.milestone_index) {
+            utxo_changes.created_outputs.push(output_id);
+        }
+    }
+}
+
+fn input_ids(transaction: &TransactionPayload) -> Vec<OutputId> {
+    let TransactionEssence::Regular(essence) = transaction.essence();
+    essence
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::Utxo(input) => Some(*input.output_id()),
+            Input::Treasury(_) => None,
+        })
+        .collect()
+}
+
+fn empty_utxo_changes(index: u32) -> UtxoChangesResponse {
+    UtxoChangesResponse {
+        index,
+        created_outputs: Vec::new(),
+        consumed_outputs: Vec::new(),
+    }
+}
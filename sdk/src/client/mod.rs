@@ -37,9 +37,13 @@ macro_rules! lazy_static {
 
 pub mod api;
 pub mod builder;
+pub mod cache;
 pub mod constants;
 pub mod core;
 pub mod error;
+#[cfg(all(feature = "test-utils", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod mock_node;
 pub mod node_api;
 pub mod node_manager;
 #[cfg(not(target_family = "wasm"))]
@@ -50,6 +54,7 @@ pub mod storage;
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
 pub mod stronghold;
 pub mod utils;
+pub mod verification;
 
 #[cfg(feature = "mqtt")]
 pub use self::node_api::mqtt;
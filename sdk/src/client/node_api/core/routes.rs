@@ -23,6 +23,7 @@ use crate::{
             payload::{
                 milestone::{dto::MilestonePayloadDto, MilestoneId, MilestonePayload},
                 transaction::TransactionId,
+                Payload,
             },
             Block, BlockDto, BlockId,
         },
@@ -30,6 +31,55 @@ use crate::{
     },
 };
 
+// Outputs spent in a confirmed milestone don't change anymore, apart from the ledger index of their metadata.
+fn is_spent_output(metadata: &OutputMetadata) -> bool {
+    metadata.is_spent() && metadata.milestone_index_spent().is_some()
+}
+
+// Responses of immutable resources are checked against the request before they're cached, so a wrong response isn't
+// served from the cache afterwards.
+fn check_block_id(block: &Block, block_id: &BlockId) -> Result<()> {
+    let found = block.id();
+    if found != *block_id {
+        return Err(Error::ResponseMismatch {
+            expected: format!("block {block_id}"),
+            found: format!("block {found}"),
+        });
+    }
+    Ok(())
+}
+
+fn check_included_transaction(block: &Block, transaction_id: &TransactionId) -> Result<()> {
+    match block.payload() {
+        Some(Payload::Transaction(transaction)) if transaction.id() == *transaction_id => Ok(()),
+        _ => Err(Error::ResponseMismatch {
+            expected: format!("block including transaction {transaction_id}"),
+            found: format!("block {}", block.id()),
+        }),
+    }
+}
+
+fn check_milestone_id(milestone: &MilestonePayload, milestone_id: &MilestoneId) -> Result<()> {
+    let found = milestone.id();
+    if found != *milestone_id {
+        return Err(Error::ResponseMismatch {
+            expected: format!("milestone {milestone_id}"),
+            found: format!("milestone {found}"),
+        });
+    }
+    Ok(())
+}
+
+fn check_milestone_index(found: u32, index: u32) -> Result<()> {
+    if found != index {
+        return Err(Error::ResponseMismatch {
+            expected: format!("milestone index {index}"),
+            found: format!("milestone index {found}"),
+        });
+    }
+    Ok(())
+}
+
 /// Info path is the exact path extension for node APIs to request their info.
 pub(crate) static INFO_PATH: &str = "api/core/v2/info";
 
@@ -168,9 +218,6 @@ impl ClientInner {
 
         // fallback to local Pow if remote Pow fails
         let response = match self
-            .node_manager
-            .read()
-            .await
             .post_request_bytes::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec(), local_pow)
             .await
         {
@@ -195,10 +242,7 @@ impl ClientInner {
                         return Err(e);
                     }
                 };
-                self.node_manager
-                    .read()
-                    .await
-                    .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
+                self.post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
                     .await?
             }
             Err(e) => return Err(e),
@@ -211,21 +255,26 @@ impl ClientInner {
     /// GET /api/core/v2/blocks/{BlockId}
     pub async fn get_block(&self, block_id: &BlockId) -> Result<Block> {
         let path = &format!("api/core/v2/blocks/{block_id}");
-
-        let dto = self.get_request::<BlockDto>(path, None, false, true).await?;
-
-        Ok(Block::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        self.get_immutable_request(path, true, |dto: BlockDto| {
+            let block = Block::try_from_dto_with_params(dto, protocol_parameters)?;
+            check_block_id(&block, block_id)?;
+            Ok(block)
+        })
+        .await
     }
 
     /// Finds a block by its BlockId. This method returns the given block raw data.
     /// GET /api/core/v2/blocks/{BlockId}
     pub async fn get_block_raw(&self, block_id: &BlockId) -> Result<Vec<u8>> {
         let path = &format!("api/core/v2/blocks/{block_id}");
+        let protocol_parameters = self.get_protocol_parameters().await?;
 
-        self.get_request_bytes(path, None).await
+        self.get_immutable_request_bytes(path, |bytes| {
+            check_block_id(&Block::unpack_verified(bytes, &protocol_parameters)?, block_id)
+        })
+        .await
     }
 
     /// Returns the metadata of a block.
@@ -233,7 +282,18 @@ impl ClientInner {
     pub async fn get_block_metadata(&self, block_id: &BlockId) -> Result<BlockMetadataResponse> {
         let path = &format!("api/core/v2/blocks/{block_id}/metadata");
 
-        self.get_request(path, None, true, true).await
+        let (metadata, cached) = match self.cached_response::<BlockMetadataResponse>(path).await {
+            Some(metadata) => (metadata, true),
+            None => (self.get_request(path, None, true, true).await?, false),
+        };
+
+        self.verify_block_metadata(block_id, &metadata).await?;
+        // The metadata doesn't change anymore once the block was referenced
+        if !cached && metadata.referenced_by_milestone_index.is_some() {
+            self.cache_response(path, &metadata).await;
+        }
+
+        Ok(metadata)
     }
 
     // UTXO routes.
@@ -243,12 +303,21 @@ impl ClientInner {
     pub async fn get_output(&self, output_id: &OutputId) -> Result<OutputWithMetadata> {
         let path = &format!("api/core/v2/outputs/{output_id}");
 
-        let response: OutputWithMetadataResponse = self.get_request(path, None, false, true).await?;
+        let (response, cached) = match self.cached_response::<OutputWithMetadataResponse>(path).await {
+            Some(response) => (response, true),
+            None => (self.get_request(path, None, false, true).await?, false),
+        };
 
         let token_supply = self.get_token_supply().await?;
-        let output = Output::try_from_dto_with_params(response.output, token_supply)?;
+        let output = Output::try_from_dto_with_params(response.output.clone(), token_supply)?;
+        let output = OutputWithMetadata::new(output, response.metadata);
+
+        self.verify_output(output_id, &output).await?;
+        if !cached && is_spent_output(&response.metadata) {
+            self.cache_response(path, &response).await;
+        }
 
-        Ok(OutputWithMetadata::new(output, response.metadata))
+        Ok(output)
     }
 
     /// Finds an output, as raw bytes, by its OutputId (TransactionId + output_index).
@@ -264,7 +333,16 @@ impl ClientInner {
     pub async fn get_output_metadata(&self, output_id: &OutputId) -> Result<OutputMetadata> {
         let path = &format!("api/core/v2/outputs/{output_id}/metadata");
 
-        self.get_request::<OutputMetadata>(path, None, false, true).await
+        if let Some(metadata) = self.cached_response(path).await {
+            return Ok(metadata);
+        }
+
+        let metadata = self.get_request::<OutputMetadata>(path, None, false, true).await?;
+        if is_spent_output(&metadata) {
+            self.cache_response(path, &metadata).await;
+        }
+
+        Ok(metadata)
     }
 
     /// Gets all stored receipts.
@@ -301,20 +379,31 @@ impl ClientInner {
     pub async fn get_included_block(&self, transaction_id: &TransactionId) -> Result<Block> {
         let path = &format!("api/core/v2/transactions/{transaction_id}/included-block");
 
-        let dto = self.get_request::<BlockDto>(path, None, true, true).await?;
+        let (dto, cached) = match self.cached_response::<BlockDto>(path).await {
+            Some(dto) => (dto, true),
+            None => (self.get_request::<BlockDto>(path, None, true, true).await?, false),
+        };
+
+        let block = Block::try_from_dto_with_params(dto.clone(), self.get_protocol_parameters().await?)?;
+        check_included_transaction(&block, transaction_id)?;
+        self.verify_included_block(transaction_id, &block).await?;
+        if !cached {
+            self.cache_response(path, &dto).await;
+        }
 
-        Ok(Block::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        Ok(block)
     }
 
     /// Returns the block, as raw bytes, that was included in the ledger for a given TransactionId.
     /// GET /api/core/v2/transactions/{transactionId}/included-block
     pub async fn get_included_block_raw(&self, transaction_id: &TransactionId) -> Result<Vec<u8>> {
         let path = &format!("api/core/v2/transactions/{transaction_id}/included-block");
+        let protocol_parameters = self.get_protocol_parameters().await?;
 
-        self.get_request_bytes(path, None).await
+        self.get_immutable_request_bytes(path, |bytes| {
+            check_included_transaction(&Block::unpack_verified(bytes, &protocol_parameters)?, transaction_id)
+        })
+        .await
     }
 
     /// Returns the metadata of the block that was included in the ledger for a given TransactionId.
@@ -322,7 +411,16 @@ impl ClientInner {
     pub async fn get_included_block_metadata(&self, transaction_id: &TransactionId) -> Result<BlockMetadataResponse> {
         let path = &format!("api/core/v2/transactions/{transaction_id}/included-block/metadata");
 
-        self.get_request(path, None, true, true).await
+        if let Some(metadata) = self.cached_response(path).await {
+            return Ok(metadata);
+        }
+
+        let metadata: BlockMetadataResponse = self.get_request(path, None, true, true).await?;
+        if metadata.referenced_by_milestone_index.is_some() {
+            self.cache_response(path, &metadata).await;
+        }
+
+        Ok(metadata)
     }
 
     // Milestones routes.
@@ -331,21 +429,29 @@ impl ClientInner {
     /// GET /api/core/v2/milestones/{milestoneId}
     pub async fn get_milestone_by_id(&self, milestone_id: &MilestoneId) -> Result<MilestonePayload> {
         let path = &format!("api/core/v2/milestones/{milestone_id}");
-
-        let dto = self.get_request::<MilestonePayloadDto>(path, None, false, true).await?;
-
-        Ok(MilestonePayload::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        self.get_immutable_request(path, true, |dto: MilestonePayloadDto| {
+            let milestone = MilestonePayload::try_from_dto_with_params(dto, protocol_parameters)?;
+            check_milestone_id(&milestone, milestone_id)?;
+            Ok(milestone)
+        })
+        .await
     }
 
     /// Gets the milestone by the given milestone id.
     /// GET /api/core/v2/milestones/{milestoneId}
     pub async fn get_milestone_by_id_raw(&self, milestone_id: &MilestoneId) -> Result<Vec<u8>> {
         let path = &format!("api/core/v2/milestones/{milestone_id}");
+        let protocol_parameters = self.get_protocol_parameters().await?;
 
-        self.get_request_bytes(path, None).await
+        self.get_immutable_request_bytes(path, |bytes| {
+            check_milestone_id(
+                &MilestonePayload::unpack_verified(bytes, &protocol_parameters)?,
+                milestone_id,
+            )
+        })
+        .await
     }
 
     /// Gets all UTXO changes of a milestone by its milestone id.
@@ -353,28 +459,35 @@ impl ClientInner {
     pub async fn get_utxo_changes_by_id(&self, milestone_id: &MilestoneId) -> Result<UtxoChangesResponse> {
         let path = &format!("api/core/v2/milestones/{milestone_id}/utxo-changes");
 
-        self.get_request(path, None, false, false).await
+        // The response only contains the milestone index, which can't be checked against the milestone id
+        self.get_immutable_request(path, false, Ok).await
     }
 
     /// Gets the milestone by the given milestone index.
     /// GET /api/core/v2/milestones/{index}
     pub async fn get_milestone_by_index(&self, index: u32) -> Result<MilestonePayload> {
         let path = &format!("api/core/v2/milestones/by-index/{index}");
-
-        let dto = self.get_request::<MilestonePayloadDto>(path, None, false, true).await?;
-
-        Ok(MilestonePayload::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        self.get_immutable_request(path, true, |dto: MilestonePayloadDto| {
+            let milestone = MilestonePayload::try_from_dto_with_params(dto, protocol_parameters)?;
+            check_milestone_index(*milestone.essence().index(), index)?;
+            Ok(milestone)
+        })
+        .await
     }
 
     /// Gets the milestone by the given milestone index.
     /// GET /api/core/v2/milestones/{index}
     pub async fn get_milestone_by_index_raw(&self, index: u32) -> Result<Vec<u8>> {
         let path = &format!("api/core/v2/milestones/by-index/{index}");
+        let protocol_parameters = self.get_protocol_parameters().await?;
 
-        self.get_request_bytes(path, None).await
+        self.get_immutable_request_bytes(path, |bytes| {
+            let milestone = MilestonePayload::unpack_verified(bytes, &protocol_parameters)?;
+            check_milestone_index(*milestone.essence().index(), index)
+        })
+        .await
     }
 
     /// Gets all UTXO changes of a milestone by its milestone index.
@@ -382,7 +495,11 @@ impl ClientInner {
     pub async fn get_utxo_changes_by_index(&self, index: u32) -> Result<UtxoChangesResponse> {
         let path = &format!("api/core/v2/milestones/by-index/{index}/utxo-changes");
 
-        self.get_request(path, None, false, false).await
+        self.get_immutable_request(path, false, |response: UtxoChangesResponse| {
+            check_milestone_index(response.index, index)?;
+            Ok(response)
+        })
+        .await
     }
 
     // Peers routes.
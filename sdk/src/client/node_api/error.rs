@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 /// Type alias of `Result` in Node errors
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -22,6 +24,8 @@ pub enum Error {
         text: String,
         /// The url of the API.
         url: String,
+        /// The delay the node asked to wait for before retrying, from a `Retry-After` header in seconds.
+        retry_after: Option<Duration>,
     },
     /// None of our nodes have remote Pow enabled
     #[error("No node available for remote Pow")]
@@ -30,3 +34,21 @@ pub enum Error {
     #[error("Call to {0} is not supported on this node")]
     NotSupported(String),
 }
+
+impl Error {
+    /// Returns the status code of an unexpected status code response.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::ResponseError { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Returns the delay a node asked to wait for before retrying with a `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::ResponseError { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
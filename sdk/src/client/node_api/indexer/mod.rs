@@ -6,54 +6,116 @@
 pub mod query_parameters;
 pub mod routes;
 
+use futures::{Stream, TryStreamExt};
+
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
     client::{ClientInner, Result},
-    types::api::plugins::indexer::OutputIdsResponse,
+    types::{api::plugins::indexer::OutputIdsResponse, block::output::OutputWithMetadata},
 };
 
+/// A page of outputs returned by an indexer query.
+#[derive(Clone, Debug)]
+pub struct OutputsPage {
+    /// The ledger index at which the outputs were collected.
+    pub ledger_index: u32,
+    /// The cursor to resume the query after this page, `None` if it's the last page.
+    pub cursor: Option<String>,
+    /// The outputs of the page.
+    pub items: Vec<OutputWithMetadata>,
+}
+
 impl ClientInner {
     /// Get all output ids for a provided URL route and query parameters.
     /// If a `QueryParameter::Cursor(_)` is provided, only a single page will be queried.
     pub async fn get_output_ids(
         &self,
         route: &str,
-        mut query_parameters: QueryParameters,
+        query_parameters: QueryParameters,
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<OutputIdsResponse> {
-        let mut merged_output_ids_response = OutputIdsResponse {
-            ledger_index: 0,
-            cursor: None,
-            items: Vec::new(),
-        };
+        let pages = self.get_output_ids_stream(
+            route.to_string(),
+            query_parameters.clone(),
+            need_quorum,
+            prefer_permanode,
+        );
 
         // Return early with only a single page if a `QueryParameter::Cursor(_)` is provided.
-        let return_early = query_parameters.contains(QueryParameter::Cursor(String::new()).kind());
-
-        while let Some(cursor) = {
-            let output_ids_response = self
-                .get_request::<OutputIdsResponse>(
-                    route,
-                    query_parameters.to_query_string().as_deref(),
-                    need_quorum,
-                    prefer_permanode,
-                )
-                .await?;
-
-            if return_early {
-                return Ok(output_ids_response);
-            }
+        if query_parameters.contains(QueryParameter::Cursor(String::new()).kind()) {
+            let mut pages = core::pin::pin!(pages);
+            // PANIC: the stream yields at least one page or an error.
+            return Ok(pages.try_next().await?.unwrap());
+        }
 
-            merged_output_ids_response.ledger_index = output_ids_response.ledger_index;
-            merged_output_ids_response.cursor = output_ids_response.cursor;
-            merged_output_ids_response.items.extend(output_ids_response.items);
+        pages
+            .try_fold(
+                OutputIdsResponse {
+                    ledger_index: 0,
+                    cursor: None,
+                    items: Vec::new(),
+                },
+                |mut merged_output_ids_response, output_ids_response| async move {
+                    merged_output_ids_response.ledger_index = output_ids_response.ledger_index;
+                    merged_output_ids_response.cursor = output_ids_response.cursor;
+                    merged_output_ids_response.items.extend(output_ids_response.items);
+                    Ok(merged_output_ids_response)
+                },
+            )
+            .await
+    }
 
-            &merged_output_ids_response.cursor
-        } {
-            query_parameters.replace(QueryParameter::Cursor(cursor.to_string()));
-        }
+    /// Get the output ids for a provided URL route and query parameters page by page, the next page is only requested
+    /// once the previous one was consumed.
+    /// A `QueryParameter::Cursor(_)` resumes the query after the page it was returned with and a
+    /// `QueryParameter::PageSize(_)` sets the number of output ids per page.
+    pub fn get_output_ids_stream(
+        &self,
+        route: String,
+        query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> impl Stream<Item = Result<OutputIdsResponse>> + Send + '_ {
+        futures::stream::try_unfold(Some(query_parameters), move |query_parameters| {
+            let route = route.clone();
+
+            async move {
+                let Some(mut query_parameters) = query_parameters else {
+                    return Ok(None);
+                };
+                let output_ids_response = self
+                    .get_request::<OutputIdsResponse>(
+                        &route,
+                        query_parameters.to_query_string().as_deref(),
+                        need_quorum,
+                        prefer_permanode,
+                    )
+                    .await?;
+                let next_query_parameters = output_ids_response.cursor.clone().map(|cursor| {
+                    query_parameters.replace(QueryParameter::Cursor(cursor));
+                    query_parameters
+                });
+
+                Ok(Some((output_ids_response, next_query_parameters)))
+            }
+        })
+    }
 
-        Ok(merged_output_ids_response)
+    /// Requests the outputs of pages of output ids, like the ones of the `*_output_ids_stream` methods. The outputs of
+    /// a page are requested in parallel, within the limit of parallel API requests, and only one page is held in
+    /// memory at a time.
+    pub fn get_outputs_stream<'a>(
+        &'a self,
+        output_ids: impl Stream<Item = Result<OutputIdsResponse>> + Send + 'a,
+    ) -> impl Stream<Item = Result<OutputsPage>> + Send + 'a {
+        output_ids.and_then(move |output_ids_response| async move {
+            Ok(OutputsPage {
+                ledger_index: output_ids_response.ledger_index,
+                items: futures::future::try_join_all(output_ids_response.items.iter().map(|id| self.get_output(id)))
+                    .await?,
+                cursor: output_ids_response.cursor,
+            })
+        })
     }
 }
@@ -3,6 +3,8 @@
 
 //! IOTA node indexer routes
 
+use futures::Stream;
+
 use crate::{
     client::{
         node_api::indexer::{
@@ -41,6 +43,20 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get basic, alias, nft and foundry outputs filtered by the given parameters page by page, with the query
+    /// parameters of [`output_ids()`](Self::output_ids). See
+    /// [`get_output_ids_stream()`](Self::get_output_ids_stream).
+    pub fn output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let route = "api/indexer/v1/outputs";
+
+        let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream(route.to_string(), query_parameters, true, false))
+    }
+
     /// Get basic outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "address", "hasStorageDepositReturn", "storageDepositReturnAddress",
@@ -59,6 +75,19 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get basic outputs filtered by the given parameters page by page, with the query parameters of
+    /// [`basic_output_ids()`](Self::basic_output_ids). See [`get_output_ids_stream()`](Self::get_output_ids_stream).
+    pub fn basic_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let route = "api/indexer/v1/outputs/basic";
+
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream(route.to_string(), query_parameters, true, false))
+    }
+
     /// Get alias outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "stateController", "governor", "issuer", "sender", "createdBefore", "createdAfter"
@@ -75,6 +104,19 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get alias outputs filtered by the given parameters page by page, with the query parameters of
+    /// [`alias_output_ids()`](Self::alias_output_ids). See [`get_output_ids_stream()`](Self::get_output_ids_stream).
+    pub fn alias_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let route = "api/indexer/v1/outputs/alias";
+
+        let query_parameters = verify_query_parameters_alias_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream(route.to_string(), query_parameters, true, false))
+    }
+
     /// Get alias output by its aliasID.
     /// api/indexer/v1/outputs/alias/:{AliasId}
     pub async fn alias_output_id(&self, alias_id: AliasId) -> Result<OutputId> {
@@ -103,6 +145,20 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get foundry outputs filtered by the given parameters page by page, with the query parameters of
+    /// [`foundry_output_ids()`](Self::foundry_output_ids). See
+    /// [`get_output_ids_stream()`](Self::get_output_ids_stream).
+    pub fn foundry_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let route = "api/indexer/v1/outputs/foundry";
+
+        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream(route.to_string(), query_parameters, true, false))
+    }
+
     /// Get foundry output by its foundryID.
     /// api/indexer/v1/outputs/foundry/:{FoundryID}
     pub async fn foundry_output_id(&self, foundry_id: FoundryId) -> Result<OutputId> {
@@ -132,6 +188,19 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get NFT outputs filtered by the given parameters page by page, with the query parameters of
+    /// [`nft_output_ids()`](Self::nft_output_ids). See [`get_output_ids_stream()`](Self::get_output_ids_stream).
+    pub fn nft_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let route = "api/indexer/v1/outputs/nft";
+
+        let query_parameters = verify_query_parameters_nft_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream(route.to_string(), query_parameters, true, false))
+    }
+
     /// Get NFT output by its nftID.
     /// api/indexer/v1/outputs/nft/:{NftId}
     pub async fn nft_output_id(&self, nft_id: NftId) -> Result<OutputId> {
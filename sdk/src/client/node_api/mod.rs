@@ -13,3 +13,4 @@ pub mod mqtt;
 #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
 pub mod participation;
 pub mod plugin;
+pub mod poi;
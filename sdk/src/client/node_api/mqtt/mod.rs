@@ -44,6 +44,40 @@ impl Client {
     }
 }
 
+// Adds the handler to the topics, unless it was already added for a topic.
+pub(crate) fn add_topic_handler(
+    topic_handlers: &mut TopicHandlerMap,
+    topics: impl IntoIterator<Item = Topic>,
+    handler: &Arc<TopicHandler>,
+) {
+    for topic in topics {
+        let handlers = topic_handlers.entry(topic).or_default();
+        if !handlers.iter().any(|h| Arc::ptr_eq(h, handler)) {
+            handlers.push(handler.clone());
+        }
+    }
+}
+
+// Removes the handler from the topics, keeping the handlers of other subscribers. Returns the topics which have no
+// handlers left.
+pub(crate) fn remove_topic_handler(
+    topic_handlers: &mut TopicHandlerMap,
+    topics: impl IntoIterator<Item = Topic>,
+    handler: &Arc<TopicHandler>,
+) -> Vec<Topic> {
+    let mut empty_topics = Vec::new();
+    for topic in topics {
+        if let Some(handlers) = topic_handlers.get_mut(&topic) {
+            handlers.retain(|h| !Arc::ptr_eq(h, handler));
+            if handlers.is_empty() {
+                topic_handlers.remove(&topic);
+                empty_topics.push(topic);
+            }
+        }
+    }
+    empty_topics
+}
+
 impl ClientInner {
     /// Returns the mqtt event receiver.
     pub async fn mqtt_event_receiver(&self) -> WatchReceiver<MqttEvent> {
@@ -352,11 +386,11 @@ impl<'a> MqttTopicManager<'a> {
         self,
         callback: C,
     ) -> Result<(), Error> {
-        let cb =
-            Arc::new(Box::new(callback)
-                as Box<
-                    dyn Fn(&crate::client::node_api::mqtt::TopicEvent) + Send + Sync + 'static,
-                >);
+        self.subscribe_handler(&Arc::new(Box::new(callback))).await
+    }
+
+    /// Subscribe to the given topics with a handler, which is only added once per topic.
+    pub(crate) async fn subscribe_handler(self, handler: &Arc<TopicHandler>) -> Result<(), Error> {
         set_mqtt_client(self.client).await?;
         self.client
             .inner
@@ -372,12 +406,33 @@ impl<'a> MqttTopicManager<'a> {
                     .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce)),
             )
             .await?;
-        {
+        add_topic_handler(
+            &mut *self.client.mqtt.topic_handlers.write().await,
+            self.topics,
+            handler,
+        );
+        Ok(())
+    }
+
+    /// Remove the handler from the given topics. Only the topics without handlers of other subscribers are
+    /// unsubscribed.
+    pub(crate) async fn unsubscribe_handler(self, handler: &Arc<TopicHandler>) -> Result<(), Error> {
+        let (empty_topics, empty_topic_handlers) = {
             let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
-            for topic in self.topics {
-                mqtt_topic_handlers.entry(topic).or_default().push(cb.clone());
+            let empty_topics = remove_topic_handler(&mut mqtt_topic_handlers, self.topics, handler);
+            (empty_topics, mqtt_topic_handlers.is_empty())
+        };
+
+        if let Some(client) = &*self.client.mqtt.client.write().await {
+            for topic in &empty_topics {
+                client.unsubscribe(topic.as_str()).await?;
             }
         }
+
+        if self.client.mqtt.broker_options.read().await.automatic_disconnect && empty_topic_handlers {
+            MqttManager::new(self.client).disconnect().await?;
+        }
+
         Ok(())
     }
 
@@ -414,3 +469,40 @@ impl<'a> MqttTopicManager<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handler() -> Arc<TopicHandler> {
+        Arc::new(Box::new(|_: &TopicEvent| {}))
+    }
+
+    #[test]
+    fn topic_handlers_of_other_subscribers_are_kept() {
+        let topic = Topic::new("milestone-info/latest").unwrap();
+        let other_topic = Topic::new("milestone-info/confirmed").unwrap();
+        let own_handler = handler();
+        let other_handler = handler();
+        let mut topic_handlers = TopicHandlerMap::new();
+
+        add_topic_handler(&mut topic_handlers, [topic.clone()], &other_handler);
+        // Subscribing again, e.g. after a reconnection, doesn't add the handler twice
+        add_topic_handler(&mut topic_handlers, [topic.clone(), other_topic.clone()], &own_handler);
+        add_topic_handler(&mut topic_handlers, [topic.clone(), other_topic.clone()], &own_handler);
+        assert_eq!(topic_handlers[&topic].len(), 2);
+        assert_eq!(topic_handlers[&other_topic].len(), 1);
+
+        let empty_topics =
+            remove_topic_handler(&mut topic_handlers, [topic.clone(), other_topic.clone()], &own_handler);
+        assert_eq!(empty_topics, [other_topic]);
+        assert_eq!(topic_handlers.len(), 1);
+        assert!(Arc::ptr_eq(&topic_handlers[&topic][0], &other_handler));
+
+        assert_eq!(
+            remove_topic_handler(&mut topic_handlers, [topic.clone()], &other_handler),
+            [topic]
+        );
+        assert!(topic_handlers.is_empty());
+    }
+}
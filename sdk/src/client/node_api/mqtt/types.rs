@@ -15,7 +15,7 @@ use crate::types::block::{
     BlockDto,
 };
 
-type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
+pub(crate) type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
 
 pub(crate) type TopicHandlerMap = HashMap<Topic, Vec<Arc<TopicHandler>>>;
 
@@ -0,0 +1,20 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! IOTA node proof-of-inclusion routes.
+//! <https://github.com/iotaledger/inx-poi/blob/develop/components/poi/routes.go>
+
+use crate::{
+    client::{ClientInner, Result},
+    types::{api::plugins::poi::ProofResponse, block::BlockId},
+};
+
+impl ClientInner {
+    /// Creates the proof that a block was referenced by a milestone.
+    /// GET /api/poi/v1/create/{blockId}
+    pub async fn get_inclusion_proof(&self, block_id: &BlockId) -> Result<ProofResponse> {
+        let path = &format!("api/poi/v1/create/{block_id}");
+
+        self.get_request(path, None, false, true).await
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
         node_manager::{
             http_client::HttpClient,
             node::{Node, NodeAuth, NodeDto},
+            retry::RetryPolicy,
             NodeManager,
         },
     },
@@ -61,6 +62,12 @@ pub struct NodeManagerBuilder {
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// Policy to retry GET requests
+    #[serde(default = "RetryPolicy::disabled")]
+    pub retry_policy: RetryPolicy,
+    /// Policy to retry POST requests, like the submission of blocks
+    #[serde(default = "RetryPolicy::disabled")]
+    pub submit_retry_policy: RetryPolicy,
 }
 
 fn default_user_agent() -> String {
@@ -211,6 +218,16 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub(crate) fn with_submit_retry_policy(mut self, submit_retry_policy: RetryPolicy) -> Self {
+        self.submit_retry_policy = submit_retry_policy;
+        self
+    }
+
     pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
         NodeManager {
             primary_node: self.primary_node.map(Into::into),
@@ -223,7 +240,10 @@ impl NodeManagerBuilder {
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
+            retry_policy: self.retry_policy,
+            submit_retry_policy: self.submit_retry_policy,
             http_client: HttpClient::new(self.user_agent),
+            node_stats: Default::default(),
         }
     }
 }
@@ -241,6 +261,8 @@ impl Default for NodeManagerBuilder {
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            retry_policy: RetryPolicy::disabled(),
+            submit_retry_policy: RetryPolicy::disabled(),
         }
     }
 }
@@ -266,6 +288,8 @@ impl From<&NodeManager> for NodeManagerBuilder {
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
             user_agent: value.http_client.user_agent.clone(),
+            retry_policy: value.retry_policy.clone(),
+            submit_retry_policy: value.submit_retry_policy.clone(),
         }
     }
 }
@@ -53,6 +53,12 @@ impl HttpClient {
         if status.is_success() {
             Ok(Response(response))
         } else {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|retry_after| retry_after.to_str().ok())
+                .and_then(|retry_after| retry_after.trim().parse().ok())
+                .map(Duration::from_secs);
             let text = response.text().await?;
             // Different urls, nodes and versions give different replies
             if text == *"no available nodes with remote Pow"
@@ -68,6 +74,7 @@ impl HttpClient {
                     code: status.as_u16(),
                     text,
                     url: url.to_string(),
+                    retry_after,
                 })
             }
         }
@@ -7,19 +7,21 @@ pub mod builder;
 pub(crate) mod http_client;
 /// Structs for nodes
 pub mod node;
+pub mod retry;
+pub mod stats;
 pub(crate) mod syncing;
 
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    sync::RwLock,
+    sync::{Arc, RwLock},
     time::Duration,
 };
 
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{http_client::HttpClient, node::Node, retry::RetryPolicy, stats::NodeStatsTracker};
 use super::ClientInner;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::request_pool::RateLimitExt;
@@ -44,7 +46,10 @@ pub struct NodeManager {
     quorum: bool,
     min_quorum_size: usize,
     quorum_threshold: usize,
+    retry_policy: RetryPolicy,
+    submit_retry_policy: RetryPolicy,
     pub(crate) http_client: HttpClient,
+    pub(crate) node_stats: Arc<NodeStatsTracker>,
 }
 
 impl Debug for NodeManager {
@@ -59,11 +64,16 @@ impl Debug for NodeManager {
         d.field("healthy_nodes", &self.healthy_nodes);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
-        d.field("quorum_threshold", &self.quorum_threshold).finish()
+        d.field("quorum_threshold", &self.quorum_threshold);
+        d.field("retry_policy", &self.retry_policy);
+        d.field("submit_retry_policy", &self.submit_retry_policy);
+        d.field("node_stats", &self.node_stats).finish()
     }
 }
 
 impl ClientInner {
+    // Requests are retried without holding the node manager lock or a request permit, so other requests and updates of
+    // the nodes aren't blocked during the backoff.
     pub(crate) async fn get_request<T: DeserializeOwned + Debug + Serialize>(
         &self,
         path: &str,
@@ -71,19 +81,53 @@ impl ClientInner {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
-        let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request(path, query, self.get_timeout().await, need_quorum, prefer_permanode);
-        #[cfg(not(target_family = "wasm"))]
-        let request = request.rate_limit(&self.request_pool);
-        request.await
+        let retry_policy = self.node_manager.read().await.retry_policy.clone();
+        let timeout = self.get_timeout().await;
+
+        retry_policy
+            .retry(|| async {
+                let node_manager = self.node_manager.read().await;
+                let request = node_manager.get_request(path, query, timeout, need_quorum, prefer_permanode);
+                #[cfg(not(target_family = "wasm"))]
+                let request = request.rate_limit(&self.request_pool);
+                request.await
+            })
+            .await
     }
 
     pub(crate) async fn get_request_bytes(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
-        let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request_bytes(path, query, self.get_timeout().await);
-        #[cfg(not(target_family = "wasm"))]
-        let request = request.rate_limit(&self.request_pool);
-        request.await
+        let retry_policy = self.node_manager.read().await.retry_policy.clone();
+        let timeout = self.get_timeout().await;
+
+        retry_policy
+            .retry(|| async {
+                let node_manager = self.node_manager.read().await;
+                let request = node_manager.get_request_bytes(path, query, timeout);
+                #[cfg(not(target_family = "wasm"))]
+                let request = request.rate_limit(&self.request_pool);
+                request.await
+            })
+            .await
+    }
+
+    pub(crate) async fn post_request_bytes<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+        body: &[u8],
+        local_pow: bool,
+    ) -> Result<T> {
+        let submit_retry_policy = self.node_manager.read().await.submit_retry_policy.clone();
+
+        submit_retry_policy
+            .retry(|| async {
+                self.node_manager
+                    .read()
+                    .await
+                    .post_request_bytes(path, timeout, body, local_pow)
+                    .await
+            })
+            .await
     }
 
     pub(crate) async fn post_request_json<T: DeserializeOwned>(
@@ -92,11 +136,18 @@ impl ClientInner {
         json: Value,
         local_pow: bool,
     ) -> Result<T> {
-        let node_manager = self.node_manager.read().await;
-        let request = node_manager.post_request_json(path, self.get_timeout().await, json, local_pow);
-        #[cfg(not(target_family = "wasm"))]
-        let request = request.rate_limit(&self.request_pool);
-        request.await
+        let submit_retry_policy = self.node_manager.read().await.submit_retry_policy.clone();
+        let timeout = self.get_timeout().await;
+
+        submit_retry_policy
+            .retry(|| async {
+                let node_manager = self.node_manager.read().await;
+                let request = node_manager.post_request_json(path, timeout, json.clone(), local_pow);
+                #[cfg(not(target_family = "wasm"))]
+                let request = request.rate_limit(&self.request_pool);
+                request.await
+            })
+            .await
     }
 }
 
@@ -136,8 +187,8 @@ impl NodeManager {
             }
         }
 
-        // Add other nodes in random order, so they are not always used in the same order
-        let nodes_random_order = if !self.ignore_node_health {
+        // Add other nodes in an order weighted by their statistics, so faster and more reliable nodes are preferred
+        let nodes_weighted_order = if !self.ignore_node_health {
             #[cfg(not(target_family = "wasm"))]
             {
                 self.healthy_nodes
@@ -168,8 +219,8 @@ impl NodeManager {
             self.nodes.clone()
         };
 
-        // Add remaining nodes in random order
-        for node in nodes_random_order {
+        // Add remaining nodes in weighted order
+        for node in self.node_stats.weighted_order(nodes_weighted_order) {
             if !nodes_with_modified_url.iter().any(|n| n.url == node.url) {
                 nodes_with_modified_url.push(node);
             }
@@ -177,6 +228,8 @@ impl NodeManager {
 
         // remove disabled nodes
         nodes_with_modified_url.retain(|n| !n.disabled);
+        // Only use nodes with an open circuit breaker if no other node answers
+        let mut nodes_with_modified_url = self.node_stats.eject_open_circuits(nodes_with_modified_url);
 
         if nodes_with_modified_url.is_empty() {
             if use_pow_nodes {
@@ -240,7 +293,13 @@ impl NodeManager {
                 for (index, node) in nodes.into_iter().enumerate() {
                     if index < self.min_quorum_size {
                         let client_ = self.http_client.clone();
-                        tasks.push(async move { tokio::spawn(async move { client_.get(node, timeout).await }).await });
+                        let node_stats = self.node_stats.clone();
+                        tasks.push(async move {
+                            tokio::spawn(
+                                async move { node_stats.track(&node, client_.get(node.clone(), timeout)).await },
+                            )
+                            .await
+                        });
                     }
                 }
                 for res in futures::future::try_join_all(tasks).await? {
@@ -264,7 +323,11 @@ impl NodeManager {
         } else {
             // Send requests
             for node in nodes {
-                match self.http_client.get(node.clone(), timeout).await {
+                match self
+                    .node_stats
+                    .track(&node, self.http_client.get(node.clone(), timeout))
+                    .await
+                {
                     Ok(res) => {
                         // Handle node_info extra because we also want to return the url
                         if path == crate::client::node_api::core::routes::INFO_PATH {
@@ -337,7 +400,11 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.get_bytes(node, timeout).await {
+            match self
+                .node_stats
+                .track(&node, self.http_client.get_bytes(node.clone(), timeout))
+                .await
+            {
                 Ok(res) => {
                     match res.into_bytes().await {
                         Ok(res_text) => return Ok(res_text),
@@ -366,7 +433,11 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_bytes(node, timeout, body).await {
+            match self
+                .node_stats
+                .track(&node, self.http_client.post_bytes(node.clone(), timeout, body))
+                .await
+            {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -395,7 +466,11 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_json(node, timeout, json.clone()).await {
+            match self
+                .node_stats
+                .track(&node, self.http_client.post_json(node.clone(), timeout, json.clone()))
+                .await
+            {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -0,0 +1,187 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Policies to retry node requests that failed with transient errors.
+//!
+//! A request is first sent to the candidate nodes one after another. If all of them failed and the last error is one
+//! of the [`RetryableError`]s of the policy, the nodes are tried again after an exponential backoff, until
+//! [`RetryPolicy::max_attempts`] is reached. Requests aren't retried unless a policy is set with
+//! [`ClientBuilder::with_retry_policy()`](crate::client::ClientBuilder::with_retry_policy) or
+//! [`ClientBuilder::with_submit_retry_policy()`](crate::client::ClientBuilder::with_submit_retry_policy).
+
+use std::{collections::BTreeSet, time::Duration};
+
+use futures::Future;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{node_api::error::Error as NodeApiError, node_manager::stats::random_unit, Error, Result};
+
+/// The classes of node errors that can be retried.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RetryableError {
+    /// The connection to the node couldn't be established or was reset.
+    Connection,
+    /// The node didn't answer before the request timed out.
+    Timeout,
+    /// The node rate limited the request, with status code 429.
+    TooManyRequests,
+    /// The node failed with a server error, with a 5xx status code.
+    ServerError,
+}
+
+impl RetryableError {
+    /// Returns the class of a node error, if it's one that can be retried.
+    pub fn from_node_error(error: &NodeApiError) -> Option<Self> {
+        match error {
+            NodeApiError::Reqwest(error) if error.is_timeout() => Some(Self::Timeout),
+            NodeApiError::Reqwest(error) if error.is_connect() || error.is_request() || error.is_body() => {
+                Some(Self::Connection)
+            }
+            _ => match error.status_code()? {
+                429 => Some(Self::TooManyRequests),
+                code if code >= 500 => Some(Self::ServerError),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Policy to retry node requests that failed with transient errors.
+///
+/// The default policy tries the nodes up to 3 times after any [`RetryableError`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RetryPolicy {
+    /// The maximum number of times the nodes are tried, 1 to not retry.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum delay before a retry.
+    pub max_backoff: Duration,
+    /// The factor by which the delay grows after each retry.
+    pub backoff_multiplier: u32,
+    /// Whether the delay is randomly shortened by up to half, so clients don't retry at the same time.
+    pub jitter: bool,
+    /// Whether to wait as long as a node asks for with a `Retry-After` header in seconds, up to `max_backoff`.
+    pub respect_retry_after: bool,
+    /// The errors after which requests are retried.
+    pub retry_on: BTreeSet<RetryableError>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            backoff_multiplier: 2,
+            jitter: true,
+            respect_retry_after: true,
+            retry_on: BTreeSet::from([
+                RetryableError::Connection,
+                RetryableError::Timeout,
+                RetryableError::TooManyRequests,
+                RetryableError::ServerError,
+            ]),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that doesn't retry requests, the default for all node requests.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the maximum number of times the nodes are tried.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry and the maximum delay before a retry.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor by which the delay grows after each retry.
+    pub fn with_backoff_multiplier(mut self, backoff_multiplier: u32) -> Self {
+        self.backoff_multiplier = backoff_multiplier;
+        self
+    }
+
+    /// Sets whether the delay is randomly shortened.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether `Retry-After` headers are respected.
+    pub fn with_respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Sets the errors after which requests are retried.
+    pub fn with_retry_on(mut self, retry_on: impl IntoIterator<Item = RetryableError>) -> Self {
+        self.retry_on = retry_on.into_iter().collect();
+        self
+    }
+
+    /// Returns the delay before the given retry, starting at 1, after a request failed with the given error.
+    pub fn backoff(&self, retry: u32, error: &NodeApiError) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(self.backoff_multiplier.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_backoff);
+        let backoff = if self.jitter {
+            backoff.mul_f64(1.0 - random_unit() / 2.0)
+        } else {
+            backoff
+        };
+
+        match error.retry_after() {
+            Some(retry_after) if self.respect_retry_after => backoff.max(retry_after.min(self.max_backoff)),
+            _ => backoff,
+        }
+    }
+
+    fn retryable_error<'a>(&self, error: &'a Error) -> Option<&'a NodeApiError> {
+        match error {
+            Error::Node(error) => {
+                RetryableError::from_node_error(error).and_then(|class| self.retry_on.contains(&class).then_some(error))
+            }
+            _ => None,
+        }
+    }
+
+    /// Sends a request until it succeeds, fails with an error that can't be retried or the attempts are exhausted.
+    pub(crate) async fn retry<T, F: Future<Output = Result<T>>>(&self, mut request: impl FnMut() -> F) -> Result<T> {
+        let mut attempt = 1;
+
+        loop {
+            let error = match request().await {
+                Err(error) if attempt < self.max_attempts => error,
+                result => return result,
+            };
+            let Some(node_error) = self.retryable_error(&error) else {
+                return Err(error);
+            };
+            let backoff = self.backoff(attempt, node_error);
+            log::debug!("retrying request in {backoff:?} after: {error}");
+
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(backoff.as_millis() as u32).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(backoff).await;
+
+            attempt += 1;
+        }
+    }
+}
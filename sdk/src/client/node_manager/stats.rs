@@ -0,0 +1,325 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Statistics of the requests sent to each node, used to prefer fast and reliable nodes.
+//!
+//! Every request records its latency and whether the node failed to answer it. Nodes are then selected in a weighted
+//! random order favoring low latency, low error rate and a small lag behind the latest milestone of the other nodes.
+//! After [`CIRCUIT_BREAKER_FAILURE_THRESHOLD`] consecutive failures or timeouts the circuit breaker of a node opens
+//! and it's only tried after all other nodes, until a single probe request succeeds once
+//! [`CIRCUIT_BREAKER_OPEN_DURATION`] has passed.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use futures::Future;
+use instant::Instant;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::client::{
+    constants::{CIRCUIT_BREAKER_FAILURE_THRESHOLD, CIRCUIT_BREAKER_OPEN_DURATION, NODE_STATS_WINDOW},
+    node_api::error::{Error as NodeApiError, Result as NodeApiResult},
+    node_manager::{http_client::Response, node::Node},
+    ClientInner,
+};
+
+/// The state of the circuit breaker of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CircuitState {
+    /// The node is used normally.
+    Closed,
+    /// The node failed too often and is only used if no other node answers.
+    Open,
+    /// The node can be probed with a single request to close the circuit again.
+    HalfOpen,
+}
+
+/// Statistics of the requests sent to a node.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStats {
+    /// The node URL.
+    pub url: Url,
+    /// The number of requests sent to the node.
+    pub requests: u64,
+    /// The number of requests the node failed to answer.
+    pub failures: u64,
+    /// The average latency of the recent successful requests.
+    pub latency: Option<Duration>,
+    /// The share of the recent requests the node failed to answer.
+    pub error_rate: f64,
+    /// The number of milestones the node was behind the latest milestone of the other nodes at the last sync.
+    pub milestone_lag: Option<u32>,
+    /// The number of failed requests since the last successful one.
+    pub consecutive_failures: u32,
+    /// The state of the circuit breaker.
+    pub circuit_state: CircuitState,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Circuit {
+    Closed,
+    Open { since: Instant },
+    HalfOpen { probe_since: Option<Instant> },
+}
+
+#[derive(Debug)]
+struct Statistics {
+    // Latency of the recent requests, `None` for the failed ones.
+    recent: VecDeque<Option<Duration>>,
+    requests: u64,
+    failures: u64,
+    consecutive_failures: u32,
+    milestone_lag: Option<u32>,
+    circuit: Circuit,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self {
+            recent: VecDeque::with_capacity(NODE_STATS_WINDOW),
+            requests: 0,
+            failures: 0,
+            consecutive_failures: 0,
+            milestone_lag: None,
+            circuit: Circuit::Closed,
+        }
+    }
+}
+
+impl Statistics {
+    fn latency(&self) -> Option<Duration> {
+        let latencies = self.recent.iter().flatten().collect::<Vec<_>>();
+
+        (!latencies.is_empty()).then(|| latencies.iter().copied().sum::<Duration>() / latencies.len() as u32)
+    }
+
+    fn error_rate(&self) -> f64 {
+        if self.recent.is_empty() {
+            0.0
+        } else {
+            self.recent.iter().filter(|latency| latency.is_none()).count() as f64 / self.recent.len() as f64
+        }
+    }
+
+    // Moves an open circuit to half-open once it was open long enough.
+    fn update_circuit(&mut self) {
+        if let Circuit::Open { since } = self.circuit {
+            if since.elapsed() >= CIRCUIT_BREAKER_OPEN_DURATION {
+                self.circuit = Circuit::HalfOpen { probe_since: None };
+            }
+        }
+    }
+
+    fn circuit_state(&self) -> CircuitState {
+        match self.circuit {
+            Circuit::Closed => CircuitState::Closed,
+            Circuit::Open { .. } => CircuitState::Open,
+            Circuit::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn allows_request(&mut self) -> bool {
+        self.update_circuit();
+
+        match self.circuit {
+            Circuit::Closed => true,
+            Circuit::Open { .. } => false,
+            // A probe whose request was dropped shouldn't keep the circuit half-open forever
+            Circuit::HalfOpen { probe_since } => {
+                probe_since.map_or(true, |since| since.elapsed() >= CIRCUIT_BREAKER_OPEN_DURATION)
+            }
+        }
+    }
+
+    fn weight(&self) -> f64 {
+        // Nodes without requests yet get the highest weight, so they are tried and get statistics
+        let latency = self.latency().unwrap_or_default().as_secs_f64();
+        let lag = self.milestone_lag.unwrap_or_default() as f64;
+
+        ((1.0 - self.error_rate()) / (latency + 0.05) / (1.0 + lag)).max(f64::EPSILON)
+    }
+
+    fn record(&mut self, latency: Option<Duration>) {
+        if self.recent.len() == NODE_STATS_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(latency);
+        self.requests += 1;
+
+        if latency.is_some() {
+            self.consecutive_failures = 0;
+            self.circuit = Circuit::Closed;
+        } else {
+            self.failures += 1;
+            self.consecutive_failures += 1;
+
+            if matches!(self.circuit, Circuit::HalfOpen { .. })
+                || self.consecutive_failures >= CIRCUIT_BREAKER_FAILURE_THRESHOLD
+            {
+                self.circuit = Circuit::Open { since: Instant::now() };
+            }
+        }
+    }
+}
+
+// Statistics are kept per origin, the path and credentials of node URLs are replaced for each request.
+fn key(url: &Url) -> String {
+    url.origin().ascii_serialization()
+}
+
+// Only errors that show that the node can't serve requests count as failures, not the ones about the request.
+fn is_node_failure(error: &NodeApiError) -> bool {
+    match error {
+        NodeApiError::Reqwest(_) => true,
+        NodeApiError::ResponseError { code, .. } => *code >= 500,
+        NodeApiError::NotFound(_) | NodeApiError::UnavailablePow | NodeApiError::NotSupported(_) => false,
+    }
+}
+
+// A random number in (0, 1].
+pub(crate) fn random_unit() -> f64 {
+    let mut bytes = [0u8; 8];
+
+    match crypto::utils::rand::fill(&mut bytes) {
+        Ok(()) => (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64 + f64::EPSILON,
+        Err(_) => 0.5,
+    }
+}
+
+/// Keeps track of the statistics of the nodes.
+#[derive(Debug, Default)]
+pub(crate) struct NodeStatsTracker {
+    stats: Mutex<HashMap<String, Statistics>>,
+}
+
+impl NodeStatsTracker {
+    // The statistics stay usable if a thread panicked while holding the lock, they only influence the node selection.
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Statistics>> {
+        self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn with_stats<T>(&self, url: &Url, f: impl FnOnce(&mut Statistics) -> T) -> T {
+        let mut stats = self.lock();
+
+        f(stats.entry(key(url)).or_default())
+    }
+
+    /// Sends a request and records its outcome.
+    pub(crate) async fn track(
+        &self,
+        node: &Node,
+        request: impl Future<Output = NodeApiResult<Response>>,
+    ) -> NodeApiResult<Response> {
+        self.with_stats(&node.url, |stats| {
+            if stats.allows_request() {
+                if let Circuit::HalfOpen { probe_since } = &mut stats.circuit {
+                    *probe_since = Some(Instant::now());
+                }
+            }
+        });
+
+        let start_time = Instant::now();
+        let response = request.await;
+        let latency = match &response {
+            Err(error) if is_node_failure(error) => None,
+            _ => Some(start_time.elapsed()),
+        };
+
+        self.with_stats(&node.url, |stats| stats.record(latency));
+
+        response
+    }
+
+    /// Orders the nodes randomly, weighted by their statistics.
+    pub(crate) fn weighted_order(&self, nodes: impl IntoIterator<Item = Node>) -> Vec<Node> {
+        let mut stats = self.lock();
+        // Weighted random sampling without replacement, as described by Efraimidis and Spirakis
+        let mut keyed = nodes
+            .into_iter()
+            .map(|node| {
+                let weight = stats.entry(key(&node.url)).or_default().weight();
+                (random_unit().powf(1.0 / weight), node)
+            })
+            .collect::<Vec<_>>();
+
+        keyed.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        keyed.into_iter().map(|(_, node)| node).collect()
+    }
+
+    /// Moves the nodes with an open circuit breaker after the other nodes, keeping the order otherwise.
+    pub(crate) fn eject_open_circuits(&self, nodes: Vec<Node>) -> Vec<Node> {
+        let mut stats = self.lock();
+        let (mut allowed, ejected): (Vec<_>, Vec<_>) = nodes
+            .into_iter()
+            .partition(|node| stats.entry(key(&node.url)).or_default().allows_request());
+
+        allowed.extend(ejected);
+        allowed
+    }
+
+    /// Sets how many milestones each node is behind the latest milestone of the other nodes.
+    pub(crate) fn set_latest_milestones<'a>(&self, latest_milestones: impl IntoIterator<Item = (&'a Node, u32)>) {
+        let latest_milestones = latest_milestones.into_iter().collect::<Vec<_>>();
+        let latest = latest_milestones
+            .iter()
+            .map(|(_, index)| *index)
+            .max()
+            .unwrap_or_default();
+        let mut stats = self.lock();
+
+        for (node, index) in latest_milestones {
+            stats.entry(key(&node.url)).or_default().milestone_lag = Some(latest - index);
+        }
+    }
+
+    pub(crate) fn stats<'a>(&self, nodes: impl IntoIterator<Item = &'a Node>) -> Vec<NodeStats> {
+        let mut stats = self.lock();
+        let mut node_stats = Vec::<NodeStats>::new();
+
+        for node in nodes {
+            if node_stats
+                .iter()
+                .any(|node_stats| key(&node_stats.url) == key(&node.url))
+            {
+                continue;
+            }
+
+            let stats = stats.entry(key(&node.url)).or_default();
+            stats.update_circuit();
+
+            node_stats.push(NodeStats {
+                url: node.url.clone(),
+                requests: stats.requests,
+                failures: stats.failures,
+                latency: stats.latency(),
+                error_rate: stats.error_rate(),
+                milestone_lag: stats.milestone_lag,
+                consecutive_failures: stats.consecutive_failures,
+                circuit_state: stats.circuit_state(),
+            });
+        }
+
+        node_stats
+    }
+}
+
+impl ClientInner {
+    /// Returns the request statistics and circuit breaker state of each node.
+    pub async fn node_stats(&self) -> Vec<NodeStats> {
+        let node_manager = self.node_manager.read().await;
+        let nodes = node_manager
+            .primary_node
+            .iter()
+            .chain(node_manager.primary_pow_node.iter())
+            .chain(node_manager.nodes.iter())
+            .chain(node_manager.permanodes.iter());
+
+        node_manager.node_stats.stats(nodes)
+    }
+}
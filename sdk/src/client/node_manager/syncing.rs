@@ -8,7 +8,7 @@ use {
     tokio::time::sleep,
 };
 
-use super::{Node, NodeManager};
+use super::{builder::NodeManagerBuilder, Node, NodeManager};
 use crate::client::{Client, ClientInner, Error, Result};
 
 impl ClientInner {
@@ -104,9 +104,15 @@ impl ClientInner {
         if let Some(nodes) = network_nodes.get(most_nodes.0) {
             if let Some((info, _node_url)) = nodes.first() {
                 let mut network_info = self.network_info.write().await;
+                let network_changed = network_info.protocol_parameters.network_id() != info.protocol.network_id();
 
                 network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
                 network_info.protocol_parameters = info.protocol.clone();
+                drop(network_info);
+
+                if network_changed {
+                    self.clear_memory_cache().await;
+                }
             }
 
             for (info, node_url) in nodes {
@@ -114,11 +120,16 @@ impl ClientInner {
             }
         }
 
+        let node_manager = self.node_manager.read().await;
+
+        node_manager.node_stats.set_latest_milestones(
+            healthy_nodes
+                .iter()
+                .map(|(node, info)| (node, info.status.latest_milestone.index)),
+        );
+
         // Update the sync list.
-        *self
-            .node_manager
-            .read()
-            .await
+        *node_manager
             .healthy_nodes
             .write()
             .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
@@ -129,7 +140,7 @@ impl ClientInner {
 
 impl Client {
     #[cfg(not(target_family = "wasm"))]
-    pub async fn update_node_manager(&self, node_manager: NodeManager) -> Result<()> {
+    pub async fn update_node_manager(&self, mut node_manager: NodeManager) -> Result<()> {
         let node_sync_interval = node_manager.node_sync_interval;
         let ignore_node_health = node_manager.ignore_node_health;
         let nodes = node_manager
@@ -139,7 +150,17 @@ impl Client {
             .cloned()
             .collect();
 
-        *self.node_manager.write().await = node_manager;
+        let mut current_node_manager = self.node_manager.write().await;
+        let nodes_changed = NodeManagerBuilder::from(&*current_node_manager) != NodeManagerBuilder::from(&node_manager);
+        // Keep the statistics of the nodes that are still used
+        node_manager.node_stats = current_node_manager.node_stats.clone();
+        *current_node_manager = node_manager;
+        drop(current_node_manager);
+
+        // The cached responses were returned by the previous nodes
+        if nodes_changed {
+            self.clear_cache().await;
+        }
 
         self.sync_nodes(&nodes, ignore_node_health).await?;
         let client = self.clone();
@@ -155,8 +176,18 @@ impl Client {
     }
 
     #[cfg(target_family = "wasm")]
-    pub async fn update_node_manager(&self, node_manager: NodeManager) -> Result<()> {
-        *self.node_manager.write().await = node_manager;
+    pub async fn update_node_manager(&self, mut node_manager: NodeManager) -> Result<()> {
+        let mut current_node_manager = self.node_manager.write().await;
+        let nodes_changed = NodeManagerBuilder::from(&*current_node_manager) != NodeManagerBuilder::from(&node_manager);
+        // Keep the statistics of the nodes that are still used
+        node_manager.node_stats = current_node_manager.node_stats.clone();
+        *current_node_manager = node_manager;
+        drop(current_node_manager);
+
+        // The cached responses were returned by the previous nodes
+        if nodes_changed {
+            self.clear_cache().await;
+        }
         Ok(())
     }
 }
@@ -8,7 +8,11 @@ use std::ops::Range;
 use async_trait::async_trait;
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::{bip39::Mnemonic, bip44::Bip44, slip10::Seed},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+        slip10::Seed,
+    },
     signatures::{
         ed25519,
         secp256k1_ecdsa::{self, EvmAddress},
@@ -145,6 +149,23 @@ impl MnemonicSecretManager {
         Ok(Self(Client::mnemonic_to_seed(mnemonic.into())?.into()))
     }
 
+    /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English and a BIP-39 passphrase, also known as
+    /// the 25th word. The passphrase is zeroized once the seed is derived.
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+    ) -> Result<Self, Error> {
+        Ok(Self(
+            Client::mnemonic_to_seed_with_passphrase(mnemonic.into(), passphrase.into())?.into(),
+        ))
+    }
+
+    /// Create a new [`MnemonicSecretManager`] from the SLIP-39 shares of a BIP-39 mnemonic and their SLIP-39
+    /// passphrase.
+    pub fn try_from_slip39_shares(shares: &[impl AsRef<str>], passphrase: &str) -> Result<Self, Error> {
+        Self::try_from_mnemonic(Client::slip39_shares_to_mnemonic(shares, passphrase)?)
+    }
+
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
     pub fn try_from_hex_seed(hex: impl Into<Zeroizing<String>>) -> Result<Self, Error> {
         let hex = hex.into();
@@ -177,6 +198,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn passphrase_address() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        // BIP-39 test vector seed of the mnemonic with the passphrase "TREZOR"
+        let seed = "0xc55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+
+        let with_passphrase =
+            MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic.to_owned(), "TREZOR").unwrap();
+        let from_seed = MnemonicSecretManager::try_from_hex_seed(seed.to_owned()).unwrap();
+        let without_passphrase = MnemonicSecretManager::try_from_mnemonic(mnemonic.to_owned()).unwrap();
+
+        let address = with_passphrase
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            address,
+            from_seed
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap()
+        );
+        assert_ne!(
+            address,
+            without_passphrase
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn seed_address() {
         use crate::client::constants::IOTA_COIN_TYPE;
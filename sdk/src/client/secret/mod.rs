@@ -9,10 +9,18 @@
 pub mod ledger_nano;
 /// Module for mnemonic based secret management.
 pub mod mnemonic;
+/// Module for PKCS#11 token based secret management.
+#[cfg(feature = "pkcs11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+pub mod pkcs11;
 /// Module for single private key based secret management.
 #[cfg(feature = "private_key_secret_manager")]
 #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
 pub mod private_key;
+/// Module for remote signer based secret management.
+#[cfg(feature = "remote_secret_manager")]
+#[cfg_attr(docsrs, doc(cfg(feature = "remote_secret_manager")))]
+pub mod remote;
 /// Module for stronghold based secret management.
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -26,7 +34,10 @@ use std::{collections::HashMap, fmt::Debug, ops::Range, str::FromStr};
 
 use async_trait::async_trait;
 use crypto::{
-    keys::{bip39::Mnemonic, bip44::Bip44},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+    },
     signatures::secp256k1_ecdsa::{self, EvmAddress},
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -35,11 +46,15 @@ use zeroize::Zeroizing;
 #[cfg(feature = "ledger_nano")]
 use self::ledger_nano::LedgerSecretManager;
 use self::mnemonic::MnemonicSecretManager;
+#[cfg(feature = "pkcs11")]
+use self::pkcs11::{Pkcs11SecretManager, Pkcs11SecretManagerDto};
 #[cfg(feature = "private_key_secret_manager")]
 use self::private_key::PrivateKeySecretManager;
+#[cfg(feature = "remote_secret_manager")]
+use self::remote::{RemoteSecretManager, RemoteSecretManagerDto};
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
-pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
+pub use self::types::{GenerateAddressOptions, LedgerNanoStatus, MnemonicWithPassphraseDto};
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
 use crate::{
@@ -144,11 +159,21 @@ pub enum SecretManager {
     /// LedgerNano or Stronghold instead.
     Mnemonic(MnemonicSecretManager),
 
+    /// Secret manager that keeps Ed25519 keys in a PKCS#11 token, like a hardware security module.
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    Pkcs11(Pkcs11SecretManager),
+
     /// Secret manager that uses a single private key.
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
     PrivateKey(Box<PrivateKeySecretManager>),
 
+    /// Secret manager that forwards address generation and signing to a remote signer over JSON-RPC.
+    #[cfg(feature = "remote_secret_manager")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "remote_secret_manager")))]
+    Remote(RemoteSecretManager),
+
     /// Secret manager that's just a placeholder, so it can be provided to an online wallet, but can't be used for
     /// signing.
     Placeholder,
@@ -174,6 +199,13 @@ impl From<MnemonicSecretManager> for SecretManager {
     }
 }
 
+#[cfg(feature = "pkcs11")]
+impl From<Pkcs11SecretManager> for SecretManager {
+    fn from(secret_manager: Pkcs11SecretManager) -> Self {
+        Self::Pkcs11(secret_manager)
+    }
+}
+
 #[cfg(feature = "private_key_secret_manager")]
 impl From<PrivateKeySecretManager> for SecretManager {
     fn from(secret_manager: PrivateKeySecretManager) -> Self {
@@ -181,6 +213,13 @@ impl From<PrivateKeySecretManager> for SecretManager {
     }
 }
 
+#[cfg(feature = "remote_secret_manager")]
+impl From<RemoteSecretManager> for SecretManager {
+    fn from(secret_manager: RemoteSecretManager) -> Self {
+        Self::Remote(secret_manager)
+    }
+}
+
 impl Debug for SecretManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -189,8 +228,12 @@ impl Debug for SecretManager {
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(_) => f.debug_tuple("LedgerNano").field(&"...").finish(),
             Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => f.debug_tuple("Pkcs11").field(secret_manager).finish(),
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => f.debug_tuple("PrivateKey").field(&"...").finish(),
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => f.debug_tuple("Remote").field(secret_manager).finish(),
             Self::Placeholder => f.debug_struct("Placeholder").finish(),
         }
     }
@@ -221,11 +264,24 @@ pub enum SecretManagerDto {
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(Zeroizing<String>),
+    /// Mnemonic with a BIP-39 passphrase
+    #[serde(alias = "mnemonicWithPassphrase")]
+    MnemonicWithPassphrase(MnemonicWithPassphraseDto),
+    /// PKCS#11 token
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    #[serde(alias = "pkcs11")]
+    Pkcs11(Pkcs11SecretManagerDto),
     /// Private Key
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
     #[serde(alias = "privateKey")]
     PrivateKey(Zeroizing<String>),
+    /// Remote signer
+    #[cfg(feature = "remote_secret_manager")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "remote_secret_manager")))]
+    #[serde(alias = "remote")]
+    Remote(RemoteSecretManagerDto),
     /// Hex seed
     #[serde(alias = "hexSeed")]
     HexSeed(Zeroizing<String>),
@@ -261,11 +317,25 @@ impl TryFrom<SecretManagerDto> for SecretManager {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
 
+            SecretManagerDto::MnemonicWithPassphrase(dto) => {
+                // `Zeroizing` will take care of zeroizing the mnemonic and passphrase of the DTO.
+                Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic_with_passphrase(
+                    dto.mnemonic.as_str().to_owned(),
+                    dto.passphrase.as_str(),
+                )?)
+            }
+
+            #[cfg(feature = "pkcs11")]
+            SecretManagerDto::Pkcs11(dto) => Self::Pkcs11(Pkcs11SecretManager::from_config(&dto)?),
+
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key)?))
             }
 
+            #[cfg(feature = "remote_secret_manager")]
+            SecretManagerDto::Remote(dto) => Self::Remote(RemoteSecretManager::from_config(&dto)?),
+
             SecretManagerDto::HexSeed(hex_seed) => {
                 // `SecretManagerDto` is `ZeroizeOnDrop` so it will take care of zeroizing the original.
                 Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(hex_seed)?)
@@ -299,9 +369,18 @@ impl From<&SecretManager> for SecretManagerDto {
             // to know the type
             SecretManager::Mnemonic(_mnemonic) => Self::Mnemonic("...".to_string().into()),
 
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => Self::Pkcs11(pkcs11.config()),
+
             #[cfg(feature = "private_key_secret_manager")]
             SecretManager::PrivateKey(_private_key) => Self::PrivateKey("...".to_string().into()),
 
+            #[cfg(feature = "remote_secret_manager")]
+            SecretManager::Remote(remote) => Self::Remote(RemoteSecretManagerDto {
+                endpoint: remote.endpoint().clone(),
+                timeout: Some(remote.timeout().as_secs()),
+            }),
+
             SecretManager::Placeholder => Self::Placeholder,
         }
     }
@@ -332,12 +411,24 @@ impl SecretManage for SecretManager {
                     .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => {
                 secret_manager
                     .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -363,12 +454,24 @@ impl SecretManage for SecretManager {
                     .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => {
                 secret_manager
                     .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -380,8 +483,12 @@ impl SecretManage for SecretManager {
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -397,8 +504,12 @@ impl SecretManage for SecretManager {
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_secp256k1_ecdsa(msg, chain).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -422,12 +533,24 @@ impl SecretManage for SecretManager {
                     .sign_transaction_essence(prepared_transaction_data, time)
                     .await
             }
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => {
                 secret_manager
                     .sign_transaction_essence(prepared_transaction_data, time)
                     .await
             }
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -442,8 +565,12 @@ impl SecretManage for SecretManager {
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_transaction(prepared_transaction_data).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -469,8 +596,12 @@ impl SecretManagerConfig for SecretManager {
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(s) => s.to_config().map(Self::Config::LedgerNano),
             Self::Mnemonic(_) => None,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(s) => s.to_config().map(Self::Config::Pkcs11),
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => None,
+            #[cfg(feature = "remote_secret_manager")]
+            Self::Remote(s) => s.to_config().map(Self::Config::Remote),
             Self::Placeholder => None,
         }
     }
@@ -487,10 +618,20 @@ impl SecretManagerConfig for SecretManager {
             SecretManagerDto::Mnemonic(mnemonic) => {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
+            SecretManagerDto::MnemonicWithPassphrase(dto) => {
+                Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic_with_passphrase(
+                    dto.mnemonic.as_str().to_owned(),
+                    dto.passphrase.as_str(),
+                )?)
+            }
+            #[cfg(feature = "pkcs11")]
+            SecretManagerDto::Pkcs11(config) => Self::Pkcs11(Pkcs11SecretManager::from_config(config)?),
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key.to_owned())?))
             }
+            #[cfg(feature = "remote_secret_manager")]
+            SecretManagerDto::Remote(config) => Self::Remote(RemoteSecretManager::from_config(config)?),
             SecretManagerDto::Placeholder => Self::Placeholder,
         })
     }
@@ -502,6 +643,16 @@ impl SecretManager {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?))
     }
 
+    /// Tries to create a [`SecretManager`] from a mnemonic string and a BIP-39 passphrase.
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+    ) -> crate::client::Result<Self> {
+        Ok(Self::Mnemonic(
+            MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, passphrase)?,
+        ))
+    }
+
     /// Tries to create a [`SecretManager`] from a seed hex string.
     pub fn try_from_hex_seed(seed: impl Into<Zeroizing<String>>) -> crate::client::Result<Self> {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
@@ -519,6 +670,31 @@ where
     // The hashed_essence gets signed
     let hashed_essence = prepared_transaction_data.essence.hash();
     let mut blocks = Vec::new();
+
+    for (input, unlock) in prepared_transaction_data
+        .inputs_data
+        .iter()
+        .zip(reference_unlocks(prepared_transaction_data, time)?)
+    {
+        match unlock {
+            Some(unlock) => blocks.push(unlock),
+            None => {
+                let chain = input.chain.ok_or(Error::MissingBip32Chain)?;
+                blocks.push(secret_manager.signature_unlock(&hashed_essence, chain).await?);
+            }
+        }
+    }
+
+    Ok(Unlocks::new(blocks)?)
+}
+
+/// Returns the [`Unlock`]s of the inputs that reference the unlock of an earlier input, `None` for the inputs that need
+/// a signature unlock.
+pub(crate) fn reference_unlocks(
+    prepared_transaction_data: &PreparedTransactionData,
+    time: Option<u32>,
+) -> crate::client::Result<Vec<Option<Unlock>>> {
+    let mut blocks = Vec::new();
     let mut block_indexes = HashMap::<Address, usize>::new();
 
     // Assuming inputs_data is ordered by address type
@@ -536,11 +712,11 @@ where
         match block_indexes.get(&input_address) {
             // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
             Some(block_index) => match input_address {
-                Address::Alias(_alias) => blocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
+                Address::Alias(_alias) => blocks.push(Some(Unlock::Alias(AliasUnlock::new(*block_index as u16)?))),
                 Address::Ed25519(_ed25519) => {
-                    blocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
+                    blocks.push(Some(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?)));
                 }
-                Address::Nft(_nft) => blocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
+                Address::Nft(_nft) => blocks.push(Some(Unlock::Nft(NftUnlock::new(*block_index as u16)?))),
             },
             None => {
                 // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
@@ -550,10 +726,7 @@ where
                     Err(InputSelectionError::MissingInputWithEd25519Address)?;
                 }
 
-                let chain = input.chain.ok_or(Error::MissingBip32Chain)?;
-
-                let block = secret_manager.signature_unlock(&hashed_essence, chain).await?;
-                blocks.push(block);
+                blocks.push(None);
 
                 // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
                 // the same address in their unlock condition
@@ -577,7 +750,7 @@ where
         };
     }
 
-    Ok(Unlocks::new(blocks)?)
+    Ok(blocks)
 }
 
 pub(crate) async fn default_sign_transaction<M: SecretManage>(
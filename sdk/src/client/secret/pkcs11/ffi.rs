@@ -0,0 +1,191 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Minimal bindings to the parts of the PKCS#11 v2.40 C API (Cryptoki) used by the secret manager.
+//!
+//! Structures follow the layout of `pkcs11.h`, which packs them on Windows.
+
+use std::os::raw::{c_uchar, c_ulong, c_void};
+
+pub(super) type CkUlong = c_ulong;
+pub(super) type CkRv = CkUlong;
+pub(super) type CkBbool = c_uchar;
+pub(super) type CkSlotId = CkUlong;
+pub(super) type CkSessionHandle = CkUlong;
+pub(super) type CkObjectHandle = CkUlong;
+
+pub(super) const CK_TRUE: CkBbool = 1;
+pub(super) const CK_FALSE: CkBbool = 0;
+
+pub(super) const CKR_OK: CkRv = 0x000;
+pub(super) const CKR_USER_ALREADY_LOGGED_IN: CkRv = 0x100;
+pub(super) const CKR_CRYPTOKI_ALREADY_INITIALIZED: CkRv = 0x191;
+
+pub(super) const CKF_RW_SESSION: CkUlong = 0x2;
+pub(super) const CKF_SERIAL_SESSION: CkUlong = 0x4;
+pub(super) const CKF_OS_LOCKING_OK: CkUlong = 0x2;
+
+pub(super) const CKU_USER: CkUlong = 1;
+
+pub(super) const CKO_PUBLIC_KEY: CkUlong = 2;
+pub(super) const CKO_PRIVATE_KEY: CkUlong = 3;
+
+pub(super) const CKA_CLASS: CkUlong = 0x000;
+pub(super) const CKA_TOKEN: CkUlong = 0x001;
+pub(super) const CKA_PRIVATE: CkUlong = 0x002;
+pub(super) const CKA_LABEL: CkUlong = 0x003;
+pub(super) const CKA_SENSITIVE: CkUlong = 0x103;
+pub(super) const CKA_SIGN: CkUlong = 0x108;
+pub(super) const CKA_VERIFY: CkUlong = 0x10A;
+pub(super) const CKA_EXTRACTABLE: CkUlong = 0x162;
+pub(super) const CKA_EC_PARAMS: CkUlong = 0x180;
+pub(super) const CKA_EC_POINT: CkUlong = 0x181;
+
+pub(super) const CKM_EC_EDWARDS_KEY_PAIR_GEN: CkUlong = 0x1055;
+pub(super) const CKM_EDDSA: CkUlong = 0x1057;
+
+/// DER encoding of the `id-Ed25519` object identifier (1.3.101.112), used as `CKA_EC_PARAMS`.
+pub(super) const ED25519_EC_PARAMS: [u8; 5] = [0x06, 0x03, 0x2B, 0x65, 0x70];
+
+type Unused = Option<unsafe extern "C" fn()>;
+
+#[derive(Clone, Copy)]
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub(super) struct CkVersion {
+    pub(super) major: c_uchar,
+    pub(super) minor: c_uchar,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub(super) struct CkAttribute {
+    pub(super) kind: CkUlong,
+    pub(super) value: *mut c_void,
+    pub(super) value_len: CkUlong,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub(super) struct CkMechanism {
+    pub(super) mechanism: CkUlong,
+    pub(super) parameter: *mut c_void,
+    pub(super) parameter_len: CkUlong,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub(super) struct CkCInitializeArgs {
+    pub(super) create_mutex: Unused,
+    pub(super) destroy_mutex: Unused,
+    pub(super) lock_mutex: Unused,
+    pub(super) unlock_mutex: Unused,
+    pub(super) flags: CkUlong,
+    pub(super) reserved: *mut c_void,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub(super) struct CkTokenInfo {
+    pub(super) label: [c_uchar; 32],
+    pub(super) manufacturer_id: [c_uchar; 32],
+    pub(super) model: [c_uchar; 16],
+    pub(super) serial_number: [c_uchar; 16],
+    pub(super) flags: CkUlong,
+    /// Session counts, PIN lengths and memory sizes.
+    pub(super) counters: [CkUlong; 10],
+    pub(super) hardware_version: CkVersion,
+    pub(super) firmware_version: CkVersion,
+    pub(super) utc_time: [c_uchar; 16],
+}
+
+/// The `CK_FUNCTION_LIST` returned by `C_GetFunctionList`, up to `C_GenerateKeyPair`.
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub(super) struct CkFunctionList {
+    pub(super) version: CkVersion,
+    pub(super) c_initialize: Option<unsafe extern "C" fn(init_args: *mut c_void) -> CkRv>,
+    /// `C_Finalize`, `C_GetInfo`, `C_GetFunctionList`
+    _unused_0: [Unused; 3],
+    pub(super) c_get_slot_list:
+        Option<unsafe extern "C" fn(token_present: CkBbool, slot_list: *mut CkSlotId, count: *mut CkUlong) -> CkRv>,
+    /// `C_GetSlotInfo`
+    _unused_1: [Unused; 1],
+    pub(super) c_get_token_info: Option<unsafe extern "C" fn(slot_id: CkSlotId, info: *mut CkTokenInfo) -> CkRv>,
+    /// `C_GetMechanismList`, `C_GetMechanismInfo`, `C_InitToken`, `C_InitPIN`, `C_SetPIN`
+    _unused_2: [Unused; 5],
+    pub(super) c_open_session: Option<
+        unsafe extern "C" fn(
+            slot_id: CkSlotId,
+            flags: CkUlong,
+            application: *mut c_void,
+            notify: Unused,
+            session: *mut CkSessionHandle,
+        ) -> CkRv,
+    >,
+    pub(super) c_close_session: Option<unsafe extern "C" fn(session: CkSessionHandle) -> CkRv>,
+    /// `C_CloseAllSessions`, `C_GetSessionInfo`, `C_GetOperationState`, `C_SetOperationState`
+    _unused_3: [Unused; 4],
+    pub(super) c_login: Option<
+        unsafe extern "C" fn(
+            session: CkSessionHandle,
+            user_type: CkUlong,
+            pin: *const c_uchar,
+            pin_len: CkUlong,
+        ) -> CkRv,
+    >,
+    /// `C_Logout`, `C_CreateObject`, `C_CopyObject`, `C_DestroyObject`, `C_GetObjectSize`
+    _unused_4: [Unused; 5],
+    pub(super) c_get_attribute_value: Option<
+        unsafe extern "C" fn(
+            session: CkSessionHandle,
+            object: CkObjectHandle,
+            template: *mut CkAttribute,
+            count: CkUlong,
+        ) -> CkRv,
+    >,
+    /// `C_SetAttributeValue`
+    _unused_5: [Unused; 1],
+    pub(super) c_find_objects_init:
+        Option<unsafe extern "C" fn(session: CkSessionHandle, template: *const CkAttribute, count: CkUlong) -> CkRv>,
+    pub(super) c_find_objects: Option<
+        unsafe extern "C" fn(
+            session: CkSessionHandle,
+            objects: *mut CkObjectHandle,
+            max_count: CkUlong,
+            count: *mut CkUlong,
+        ) -> CkRv,
+    >,
+    pub(super) c_find_objects_final: Option<unsafe extern "C" fn(session: CkSessionHandle) -> CkRv>,
+    /// `C_Encrypt*`, `C_Decrypt*` and `C_Digest*`
+    _unused_6: [Unused; 13],
+    pub(super) c_sign_init: Option<
+        unsafe extern "C" fn(session: CkSessionHandle, mechanism: *const CkMechanism, key: CkObjectHandle) -> CkRv,
+    >,
+    pub(super) c_sign: Option<
+        unsafe extern "C" fn(
+            session: CkSessionHandle,
+            data: *const c_uchar,
+            data_len: CkUlong,
+            signature: *mut c_uchar,
+            signature_len: *mut CkUlong,
+        ) -> CkRv,
+    >,
+    /// `C_SignUpdate`, `C_SignFinal`, `C_SignRecover*`, `C_Verify*`, the dual-function operations and `C_GenerateKey`
+    _unused_7: [Unused; 15],
+    pub(super) c_generate_key_pair: Option<
+        unsafe extern "C" fn(
+            session: CkSessionHandle,
+            mechanism: *const CkMechanism,
+            public_key_template: *const CkAttribute,
+            public_key_count: CkUlong,
+            private_key_template: *const CkAttribute,
+            private_key_count: CkUlong,
+            public_key: *mut CkObjectHandle,
+            private_key: *mut CkObjectHandle,
+        ) -> CkRv,
+    >,
+}
+
+/// The `C_GetFunctionList` entry point of a PKCS#11 module.
+pub(super) type CGetFunctionList = unsafe extern "C" fn(function_list: *mut *const CkFunctionList) -> CkRv;
@@ -0,0 +1,628 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`Pkcs11SecretManager`], which keeps Ed25519 keys in a PKCS#11 token, like a hardware security
+//! module, and signs inside of it.
+//!
+//! A key pair is located by its `CKA_LABEL`, which is the BIP-44 path of the key, like `m/44'/4218'/0'/0'/0'`, with an
+//! optional prefix. Missing key pairs are only generated in the token if enabled, and their private keys are sensitive
+//! and not extractable.
+//!
+//! Calls into the module block until the token answers, so they run on the blocking threads of the tokio runtime.
+//!
+//! PKCS#11 has no hierarchical derivation, so the keys of a token are independent of each other and can't be recovered
+//! from a mnemonic: the token must be backed up with the tools of its vendor. Only Ed25519 is supported, EVM addresses
+//! and secp256k1 signatures are not.
+
+mod ffi;
+
+use std::{
+    ffi::c_void,
+    ops::Range,
+    os::raw::c_ulong,
+    path::{Path, PathBuf},
+    ptr,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+
+use self::ffi::*;
+use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig};
+use crate::{
+    client::{api::PreparedTransactionData, Password},
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+
+/// PKCS#11 errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The PKCS#11 module couldn't be loaded
+    #[error("couldn't load the PKCS#11 module: {0}")]
+    Library(#[from] libloading::Error),
+    /// The PKCS#11 module doesn't provide a function
+    #[error("the PKCS#11 module doesn't provide {0}")]
+    MissingFunction(&'static str),
+    /// A PKCS#11 function returned an error
+    #[error("{function} failed with CKR {code:#x}")]
+    Function {
+        /// The name of the function.
+        function: &'static str,
+        /// The `CK_RV` return value.
+        code: c_ulong,
+    },
+    /// No token with the label is present
+    #[error("PKCS#11 token {0} not found")]
+    TokenNotFound(String),
+    /// No key pair with the label exists in the token and key generation is disabled
+    #[error("PKCS#11 key {0} not found")]
+    KeyNotFound(String),
+    /// The token returned a public key which isn't an Ed25519 public key
+    #[error("invalid PKCS#11 public key")]
+    InvalidPublicKey,
+    /// The token returned a signature which isn't an Ed25519 signature
+    #[error("invalid PKCS#11 signature")]
+    InvalidSignature,
+    /// Unsupported operation
+    #[error("unsupported operation")]
+    UnsupportedOperation,
+    /// The blocking task of a PKCS#11 call failed
+    #[error("{0}")]
+    TaskJoin(#[from] tokio::task::JoinError),
+}
+
+/// The config of a [`Pkcs11SecretManager`].
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pkcs11SecretManagerDto {
+    /// The path of the PKCS#11 module, like `/usr/lib/softhsm/libsofthsm2.so`.
+    pub module_path: String,
+    /// The label of the token.
+    pub token_label: String,
+    /// The user PIN of the token.
+    #[serde(default)]
+    pub pin: Option<Password>,
+    /// The prefix of the key labels.
+    #[serde(default)]
+    pub key_label_prefix: Option<String>,
+    /// Whether missing key pairs are generated in the token, defaults to `false`.
+    #[serde(default)]
+    pub generate_keys: Option<bool>,
+}
+
+impl core::fmt::Debug for Pkcs11SecretManagerDto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pkcs11SecretManagerDto")
+            .field("module_path", &self.module_path)
+            .field("token_label", &self.token_label)
+            .field("key_label_prefix", &self.key_label_prefix)
+            .field("generate_keys", &self.generate_keys)
+            .finish()
+    }
+}
+
+/// Calls a function of a [`CkFunctionList`] and returns its `CK_RV`.
+macro_rules! call {
+    ($functions:expr, $function:ident($($arg:expr),* $(,)?)) => {{
+        let function = { $functions.$function }.ok_or(Error::MissingFunction(stringify!($function)))?;
+        // SAFETY: the arguments follow the PKCS#11 specification of the function.
+        unsafe { function($($arg),*) }
+    }};
+}
+
+fn check(function: &'static str, code: CkRv) -> Result<(), Error> {
+    if code == CKR_OK {
+        Ok(())
+    } else {
+        Err(Error::Function { function, code })
+    }
+}
+
+fn attribute<T>(kind: CkUlong, value: &T) -> CkAttribute {
+    CkAttribute {
+        kind,
+        value: (value as *const T).cast_mut().cast(),
+        value_len: std::mem::size_of::<T>() as CkUlong,
+    }
+}
+
+fn bytes_attribute(kind: CkUlong, value: &[u8]) -> CkAttribute {
+    CkAttribute {
+        kind,
+        value: value.as_ptr().cast_mut().cast(),
+        value_len: value.len() as CkUlong,
+    }
+}
+
+/// An open session with a token.
+struct Token {
+    functions: *const CkFunctionList,
+    session: CkSessionHandle,
+    // Keeps the module loaded while the session is open.
+    _library: Library,
+}
+
+// SAFETY: the module is initialized with `CKF_OS_LOCKING_OK` and the session is only used behind a mutex.
+unsafe impl Send for Token {}
+
+impl Token {
+    fn open(module_path: &Path, token_label: &str) -> Result<Self, Error> {
+        // SAFETY: loading a PKCS#11 module runs its initialization routines, which is what the user asked for.
+        let library = unsafe { Library::new(module_path) }?;
+        let mut functions = ptr::null();
+        // SAFETY: `C_GetFunctionList` is the entry point every PKCS#11 module exports with this signature.
+        check("C_GetFunctionList", unsafe {
+            library.get::<CGetFunctionList>(b"C_GetFunctionList\0")?(&mut functions)
+        })?;
+        if functions.is_null() {
+            return Err(Error::MissingFunction("C_GetFunctionList"));
+        }
+        // SAFETY: the function list is owned by the module, which is kept loaded by `library`.
+        let function_list = unsafe { &*functions };
+
+        let mut init_args = CkCInitializeArgs {
+            create_mutex: None,
+            destroy_mutex: None,
+            lock_mutex: None,
+            unlock_mutex: None,
+            flags: CKF_OS_LOCKING_OK,
+            reserved: ptr::null_mut(),
+        };
+        // Another secret manager of the process may already have initialized the module.
+        match call!(function_list, c_initialize(ptr::addr_of_mut!(init_args).cast())) {
+            CKR_OK | CKR_CRYPTOKI_ALREADY_INITIALIZED => {}
+            code => {
+                return Err(Error::Function {
+                    function: "C_Initialize",
+                    code,
+                });
+            }
+        }
+
+        let slot = Self::find_slot(function_list, token_label)?;
+        let mut session = 0;
+        check(
+            "C_OpenSession",
+            call!(
+                function_list,
+                c_open_session(
+                    slot,
+                    CKF_SERIAL_SESSION | CKF_RW_SESSION,
+                    ptr::null_mut(),
+                    None,
+                    &mut session
+                )
+            ),
+        )?;
+
+        Ok(Self {
+            functions,
+            session,
+            _library: library,
+        })
+    }
+
+    fn find_slot(functions: &CkFunctionList, token_label: &str) -> Result<CkSlotId, Error> {
+        let mut count = 0;
+        check(
+            "C_GetSlotList",
+            call!(functions, c_get_slot_list(CK_TRUE, ptr::null_mut(), &mut count)),
+        )?;
+        let mut slots = vec![0; count as usize];
+        check(
+            "C_GetSlotList",
+            call!(functions, c_get_slot_list(CK_TRUE, slots.as_mut_ptr(), &mut count)),
+        )?;
+        slots.truncate(count as usize);
+
+        for slot in slots {
+            // SAFETY: `CK_TOKEN_INFO` only consists of integers and byte arrays.
+            let mut info: CkTokenInfo = unsafe { std::mem::zeroed() };
+            check("C_GetTokenInfo", call!(functions, c_get_token_info(slot, &mut info)))?;
+            // Token labels are padded with blanks.
+            let label = { info.label };
+            if std::str::from_utf8(&label).map(|label| label.trim_end_matches([' ', '\0'])) == Ok(token_label) {
+                return Ok(slot);
+            }
+        }
+
+        Err(Error::TokenNotFound(token_label.to_owned()))
+    }
+
+    fn functions(&self) -> &CkFunctionList {
+        // SAFETY: the function list is owned by the module, which is kept loaded by `_library`.
+        unsafe { &*self.functions }
+    }
+
+    fn login(&self, pin: &[u8]) -> Result<(), Error> {
+        match call!(
+            self.functions(),
+            c_login(self.session, CKU_USER, pin.as_ptr(), pin.len() as CkUlong)
+        ) {
+            CKR_OK | CKR_USER_ALREADY_LOGGED_IN => Ok(()),
+            code => Err(Error::Function {
+                function: "C_Login",
+                code,
+            }),
+        }
+    }
+
+    fn find_object(&self, class: CkUlong, label: &str) -> Result<Option<CkObjectHandle>, Error> {
+        let template = [
+            attribute(CKA_CLASS, &class),
+            bytes_attribute(CKA_LABEL, label.as_bytes()),
+        ];
+        check(
+            "C_FindObjectsInit",
+            call!(
+                self.functions(),
+                c_find_objects_init(self.session, template.as_ptr(), template.len() as CkUlong)
+            ),
+        )?;
+
+        let mut object = 0;
+        let mut count = 0;
+        let found = check(
+            "C_FindObjects",
+            call!(
+                self.functions(),
+                c_find_objects(self.session, &mut object, 1, &mut count)
+            ),
+        );
+        check(
+            "C_FindObjectsFinal",
+            call!(self.functions(), c_find_objects_final(self.session)),
+        )?;
+        found?;
+
+        Ok((count > 0).then_some(object))
+    }
+
+    fn generate_key_pair(&self, label: &str) -> Result<CkObjectHandle, Error> {
+        let mechanism = CkMechanism {
+            mechanism: CKM_EC_EDWARDS_KEY_PAIR_GEN,
+            parameter: ptr::null_mut(),
+            parameter_len: 0,
+        };
+        let public_key_template = [
+            bytes_attribute(CKA_EC_PARAMS, &ED25519_EC_PARAMS),
+            bytes_attribute(CKA_LABEL, label.as_bytes()),
+            attribute(CKA_TOKEN, &CK_TRUE),
+            attribute(CKA_VERIFY, &CK_TRUE),
+        ];
+        let private_key_template = [
+            bytes_attribute(CKA_LABEL, label.as_bytes()),
+            attribute(CKA_TOKEN, &CK_TRUE),
+            attribute(CKA_PRIVATE, &CK_TRUE),
+            attribute(CKA_SENSITIVE, &CK_TRUE),
+            attribute(CKA_EXTRACTABLE, &CK_FALSE),
+            attribute(CKA_SIGN, &CK_TRUE),
+        ];
+
+        let mut public_key = 0;
+        let mut private_key = 0;
+        check(
+            "C_GenerateKeyPair",
+            call!(
+                self.functions(),
+                c_generate_key_pair(
+                    self.session,
+                    &mechanism,
+                    public_key_template.as_ptr(),
+                    public_key_template.len() as CkUlong,
+                    private_key_template.as_ptr(),
+                    private_key_template.len() as CkUlong,
+                    &mut public_key,
+                    &mut private_key,
+                )
+            ),
+        )?;
+
+        Ok(public_key)
+    }
+
+    fn public_key(&self, object: CkObjectHandle) -> Result<[u8; 32], Error> {
+        let mut template = [CkAttribute {
+            kind: CKA_EC_POINT,
+            value: ptr::null_mut(),
+            value_len: 0,
+        }];
+        check(
+            "C_GetAttributeValue",
+            call!(
+                self.functions(),
+                c_get_attribute_value(self.session, object, template.as_mut_ptr(), 1)
+            ),
+        )?;
+        let mut point = vec![0u8; template[0].value_len as usize];
+        template[0].value = point.as_mut_ptr().cast::<c_void>();
+        check(
+            "C_GetAttributeValue",
+            call!(
+                self.functions(),
+                c_get_attribute_value(self.session, object, template.as_mut_ptr(), 1)
+            ),
+        )?;
+        point.truncate(template[0].value_len as usize);
+
+        // The point is either a DER octet string, as required by the specification, or the raw key.
+        let key = match point.as_slice() {
+            [0x04, 0x20, key @ ..] if key.len() == 32 => key,
+            key => key,
+        };
+        key.try_into().map_err(|_| Error::InvalidPublicKey)
+    }
+
+    fn sign(&self, key: CkObjectHandle, msg: &[u8]) -> Result<[u8; 64], Error> {
+        let mechanism = CkMechanism {
+            mechanism: CKM_EDDSA,
+            parameter: ptr::null_mut(),
+            parameter_len: 0,
+        };
+        check(
+            "C_SignInit",
+            call!(self.functions(), c_sign_init(self.session, &mechanism, key)),
+        )?;
+
+        let mut signature = [0u8; 64];
+        let mut signature_len = signature.len() as CkUlong;
+        check(
+            "C_Sign",
+            call!(
+                self.functions(),
+                c_sign(
+                    self.session,
+                    msg.as_ptr(),
+                    msg.len() as CkUlong,
+                    signature.as_mut_ptr(),
+                    &mut signature_len,
+                )
+            ),
+        )?;
+        if signature_len as usize != signature.len() {
+            return Err(Error::InvalidSignature);
+        }
+
+        Ok(signature)
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        // The module stays initialized, as other sessions of the process may still use it.
+        let close_session = { self.functions().c_close_session };
+        if let Some(close_session) = close_session {
+            // SAFETY: the session was opened by this token and isn't used afterwards.
+            unsafe { close_session(self.session) };
+        }
+    }
+}
+
+/// Secret manager that keeps Ed25519 keys in a PKCS#11 token.
+pub struct Pkcs11SecretManager {
+    module_path: PathBuf,
+    token_label: String,
+    key_label_prefix: String,
+    generate_keys: bool,
+    token: Arc<Mutex<Token>>,
+}
+
+impl std::fmt::Debug for Pkcs11SecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pkcs11SecretManager")
+            .field("module_path", &self.module_path)
+            .field("token_label", &self.token_label)
+            .field("key_label_prefix", &self.key_label_prefix)
+            .field("generate_keys", &self.generate_keys)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Pkcs11SecretManager {
+    /// Loads the PKCS#11 module and opens a session with the token with the given label. Missing key pairs aren't
+    /// generated, unless enabled with [`Pkcs11SecretManager::with_key_generation()`].
+    pub fn open(module_path: impl Into<PathBuf>, token_label: impl Into<String>) -> Result<Self, Error> {
+        let module_path = module_path.into();
+        let token_label = token_label.into();
+        let token = Token::open(&module_path, &token_label)?;
+
+        Ok(Self {
+            module_path,
+            token_label,
+            key_label_prefix: String::new(),
+            generate_keys: false,
+            token: Arc::new(Mutex::new(token)),
+        })
+    }
+
+    /// Sets the prefix of the key labels.
+    pub fn with_key_label_prefix(mut self, key_label_prefix: impl Into<String>) -> Self {
+        self.key_label_prefix = key_label_prefix.into();
+        self
+    }
+
+    /// Sets whether missing key pairs are generated in the token.
+    pub fn with_key_generation(mut self, generate_keys: bool) -> Self {
+        self.generate_keys = generate_keys;
+        self
+    }
+
+    /// Logs the user into the token, which is required to use private keys.
+    pub async fn login(&self, pin: impl Into<Password> + Send) -> Result<(), Error> {
+        let pin = pin.into();
+        self.with_token(move |token| token.login(pin.as_bytes())).await
+    }
+
+    /// Runs calls into the module on a blocking thread, holding the session for their duration.
+    async fn with_token<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&Token) -> Result<T, Error> + Send + 'static,
+    ) -> Result<T, Error> {
+        let token = self.token.clone();
+        tokio::task::spawn_blocking(move || f(&token.lock().unwrap_or_else(PoisonError::into_inner))).await?
+    }
+
+    /// Returns the path of the PKCS#11 module.
+    pub fn module_path(&self) -> &Path {
+        &self.module_path
+    }
+
+    /// Returns the label of the token.
+    pub fn token_label(&self) -> &str {
+        &self.token_label
+    }
+
+    /// Returns the label of the key pair of a BIP-44 chain.
+    pub fn key_label(&self, chain: Bip44) -> String {
+        format!(
+            "{}m/44'/{}'/{}'/{}'/{}'",
+            self.key_label_prefix, chain.coin_type, chain.account, chain.change, chain.address_index
+        )
+    }
+
+    /// Returns the public key of a chain, generating the key pair if it's missing and key generation is enabled.
+    async fn public_key(&self, chain: Bip44) -> Result<[u8; 32], Error> {
+        let label = self.key_label(chain);
+        let generate_keys = self.generate_keys;
+
+        self.with_token(move |token| {
+            let public_key = match token.find_object(CKO_PUBLIC_KEY, &label)? {
+                Some(public_key) => public_key,
+                None if generate_keys => token.generate_key_pair(&label)?,
+                None => return Err(Error::KeyNotFound(label)),
+            };
+
+            token.public_key(public_key)
+        })
+        .await
+    }
+
+    pub(crate) fn config(&self) -> Pkcs11SecretManagerDto {
+        Pkcs11SecretManagerDto {
+            module_path: self.module_path.to_string_lossy().into(),
+            token_label: self.token_label.clone(),
+            pin: None,
+            key_label_prefix: (!self.key_label_prefix.is_empty()).then(|| self.key_label_prefix.clone()),
+            generate_keys: Some(self.generate_keys),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretManage for Pkcs11SecretManager {
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        let internal = options.into().map(|o| o.internal).unwrap_or_default();
+        let mut addresses = Vec::with_capacity(address_indexes.len());
+
+        for address_index in address_indexes {
+            let chain = Bip44::new(coin_type)
+                .with_account(account_index)
+                .with_change(internal as _)
+                .with_address_index(address_index);
+            let public_key = self.public_key(chain).await?;
+
+            // Hash the public key to get the address
+            addresses.push(Ed25519Address::new(Blake2b256::digest(public_key).into()));
+        }
+
+        Ok(addresses)
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        let label = self.key_label(chain);
+        let msg = msg.to_vec();
+
+        let (public_key, signature) = self
+            .with_token(move |token| {
+                let (Some(public_key), Some(private_key)) = (
+                    token.find_object(CKO_PUBLIC_KEY, &label)?,
+                    token.find_object(CKO_PRIVATE_KEY, &label)?,
+                ) else {
+                    return Err(Error::KeyNotFound(label));
+                };
+
+                Ok((token.public_key(public_key)?, token.sign(private_key, &msg)?))
+            })
+            .await?;
+
+        Ok(Ed25519Signature::try_from_bytes(public_key, signature)?)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        super::default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+impl SecretManagerConfig for Pkcs11SecretManager {
+    type Config = Pkcs11SecretManagerDto;
+
+    fn to_config(&self) -> Option<Self::Config> {
+        Some(self.config())
+    }
+
+    fn from_config(config: &Self::Config) -> Result<Self, Self::Error> {
+        let mut secret_manager = Self::open(&config.module_path, &config.token_label)?;
+
+        if let Some(key_label_prefix) = &config.key_label_prefix {
+            secret_manager = secret_manager.with_key_label_prefix(key_label_prefix);
+        }
+        if let Some(generate_keys) = config.generate_keys {
+            secret_manager = secret_manager.with_key_generation(generate_keys);
+        }
+        if let Some(pin) = &config.pin {
+            secret_manager
+                .token
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .login(pin.as_bytes())?;
+        }
+
+        Ok(secret_manager)
+    }
+}
@@ -0,0 +1,437 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`RemoteSecretManager`], which forwards address generation and signing to an external signer,
+//! like a hardware security module daemon.
+//!
+//! The signer is reached with [JSON-RPC 2.0](https://www.jsonrpc.org/specification) requests over HTTP or a Unix
+//! socket. Over HTTP, every request is the body of a `POST` request and the response is the response body. Over a Unix
+//! socket, a new connection is opened for every request, which is written as a single line of JSON, and the response
+//! is read as a single line of JSON.
+//!
+//! Binary values are prefix hex encoded, BIP-44 chains are objects with `coinType`, `account`, `change` and
+//! `addressIndex` fields. The methods are:
+//! - `generateEd25519Addresses` with the params `{ coinType, accountIndex, addressIndexes: { start, end }, options: {
+//!   internal, ledgerNanoPrompt } }`, returning an array of Ed25519 addresses;
+//! - `generateEvmAddresses` with the same params, returning an array of EVM addresses;
+//! - `signEd25519` with the params `{ message, chain }`, returning `{ type: 0, publicKey, signature }`;
+//! - `signSecp256k1Ecdsa` with the params `{ message, chain }`, returning `{ publicKey, signature }` with a compressed
+//!   public key and a recoverable signature;
+//! - `signTransactionEssence` with the params `{ preparedTransactionData, time }`, returning an array of unlocks.
+//!
+//! A signer which doesn't implement `signTransactionEssence` can return the error code `-32601` (method not found) for
+//! it, the unlocks are then created with `signEd25519` requests.
+
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::{
+    ops::Range,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use crypto::{
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+#[cfg(unix)]
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::UnixStream,
+};
+use url::Url;
+
+use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig};
+use crate::{
+    client::api::{PreparedTransactionData, PreparedTransactionDataDto},
+    types::block::{
+        address::Ed25519Address,
+        payload::transaction::TransactionPayload,
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature},
+        unlock::{dto::UnlockDto, Unlock, Unlocks},
+    },
+    utils::serde::bip44::Bip44Def,
+};
+
+/// The JSON-RPC error code of a method which isn't implemented by the signer.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The default timeout of a request to the signer.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Remote signer errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The signer returned a JSON-RPC error
+    #[error("remote signer error {code}: {message}")]
+    Rpc {
+        /// The JSON-RPC error code.
+        code: i64,
+        /// The error message.
+        message: String,
+    },
+    /// The response of the signer is not valid
+    #[error("invalid remote signer response: {0}")]
+    InvalidResponse(String),
+    /// IO error
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    /// JSON error
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    /// Reqwest error
+    #[error("{0}")]
+    Reqwest(#[from] reqwest::Error),
+    /// The signer didn't respond in time
+    #[error("remote signer request timed out")]
+    Timeout,
+}
+
+/// The endpoint of a remote signer.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RemoteSignerEndpoint {
+    /// JSON-RPC over HTTP `POST` requests.
+    Http(Url),
+    /// JSON-RPC over a Unix socket, with one line of JSON per request and response.
+    #[cfg(unix)]
+    UnixSocket(PathBuf),
+}
+
+/// The config of a [`RemoteSecretManager`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSecretManagerDto {
+    /// The endpoint of the signer.
+    pub endpoint: RemoteSignerEndpoint,
+    /// The timeout of a request in seconds.
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+/// Secret manager which forwards address generation and signing to a remote signer.
+pub struct RemoteSecretManager {
+    endpoint: RemoteSignerEndpoint,
+    timeout: Duration,
+    http_client: reqwest::Client,
+    request_id: AtomicU64,
+}
+
+impl std::fmt::Debug for RemoteSecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RemoteSecretManager")
+            .field("endpoint", &self.endpoint)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Serialize)]
+struct Request<'a, P> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    params: P,
+}
+
+#[derive(Deserialize)]
+struct Response<R> {
+    id: Option<u64>,
+    result: Option<R>,
+    error: Option<ResponseError>,
+}
+
+#[derive(Deserialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateAddressesParams {
+    coin_type: u32,
+    account_index: u32,
+    address_indexes: Range<u32>,
+    options: GenerateAddressOptions,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SignParams {
+    message: String,
+    #[serde(with = "Bip44Def")]
+    chain: Bip44,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SignTransactionEssenceParams {
+    prepared_transaction_data: PreparedTransactionDataDto,
+    time: Option<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Secp256k1EcdsaSignatureDto {
+    public_key: String,
+    signature: String,
+}
+
+impl RemoteSecretManager {
+    /// Creates a new [`RemoteSecretManager`] sending requests to the given endpoint.
+    pub fn new(endpoint: RemoteSignerEndpoint) -> Self {
+        Self {
+            endpoint,
+            timeout: DEFAULT_TIMEOUT,
+            http_client: reqwest::Client::new(),
+            request_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets the timeout of a request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the endpoint of the signer.
+    pub fn endpoint(&self) -> &RemoteSignerEndpoint {
+        &self.endpoint
+    }
+
+    /// Returns the timeout of a request.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sends a JSON-RPC request to the signer and returns the result.
+    async fn call<P: Serialize + Send, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<R, Error> {
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        let request = Request {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        };
+
+        let response: Response<R> = match &self.endpoint {
+            RemoteSignerEndpoint::Http(url) => {
+                self.http_client
+                    .post(url.clone())
+                    .timeout(self.timeout)
+                    .json(&request)
+                    .send()
+                    .await?
+                    .json()
+                    .await?
+            }
+            #[cfg(unix)]
+            RemoteSignerEndpoint::UnixSocket(path) => {
+                let mut line = serde_json::to_vec(&request)?;
+                line.push(b'\n');
+                let exchange = async {
+                    let mut stream = UnixStream::connect(path).await?;
+                    stream.write_all(&line).await?;
+                    let mut response = String::new();
+                    BufReader::new(stream).read_line(&mut response).await?;
+                    Ok::<_, Error>(serde_json::from_str(&response)?)
+                };
+                tokio::time::timeout(self.timeout, exchange)
+                    .await
+                    .map_err(|_| Error::Timeout)??
+            }
+        };
+
+        if response.id.is_some_and(|response_id| response_id != id) {
+            return Err(Error::InvalidResponse(format!("unexpected request id {:?}", response.id)));
+        }
+        if let Some(error) = response.error {
+            return Err(Error::Rpc {
+                code: error.code,
+                message: error.message,
+            });
+        }
+        response
+            .result
+            .ok_or_else(|| Error::InvalidResponse(format!("missing result of {method}")))
+    }
+
+    async fn generate_addresses(
+        &self,
+        method: &str,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<String>, Error> {
+        let count = address_indexes.len();
+        let addresses: Vec<String> = self
+            .call(
+                method,
+                GenerateAddressesParams {
+                    coin_type,
+                    account_index,
+                    address_indexes,
+                    options: options.unwrap_or_default(),
+                },
+            )
+            .await?;
+
+        if addresses.len() != count {
+            return Err(Error::InvalidResponse(format!(
+                "expected {count} addresses, got {}",
+                addresses.len()
+            )));
+        }
+        Ok(addresses)
+    }
+}
+
+#[async_trait]
+impl SecretManage for RemoteSecretManager {
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        self.generate_addresses(
+            "generateEd25519Addresses",
+            coin_type,
+            account_index,
+            address_indexes,
+            options.into(),
+        )
+        .await?
+        .iter()
+        .map(|address| {
+            address
+                .parse()
+                .map_err(|_| Error::InvalidResponse(format!("invalid Ed25519 address {address}")).into())
+        })
+        .collect()
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        self.generate_addresses(
+            "generateEvmAddresses",
+            coin_type,
+            account_index,
+            address_indexes,
+            options.into(),
+        )
+        .await?
+        .iter()
+        .map(|address| {
+            prefix_hex::decode::<[u8; EvmAddress::LENGTH]>(address)
+                .map(EvmAddress::from)
+                .map_err(|_| Error::InvalidResponse(format!("invalid EVM address {address}")).into())
+        })
+        .collect()
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        let signature: Ed25519SignatureDto = self
+            .call(
+                "signEd25519",
+                SignParams {
+                    message: prefix_hex::encode(msg),
+                    chain,
+                },
+            )
+            .await?;
+
+        Ok(Ed25519Signature::try_from(signature)?)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        let signature: Secp256k1EcdsaSignatureDto = self
+            .call(
+                "signSecp256k1Ecdsa",
+                SignParams {
+                    message: prefix_hex::encode(msg),
+                    chain,
+                },
+            )
+            .await?;
+
+        Ok((
+            secp256k1_ecdsa::PublicKey::try_from_slice(&prefix_hex::decode::<Vec<u8>>(signature.public_key)?)?,
+            secp256k1_ecdsa::RecoverableSignature::try_from_slice(&prefix_hex::decode::<Vec<u8>>(
+                signature.signature,
+            )?)?,
+        ))
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        let result: Result<Vec<UnlockDto>, Error> = self
+            .call(
+                "signTransactionEssence",
+                SignTransactionEssenceParams {
+                    prepared_transaction_data: PreparedTransactionDataDto::from(prepared_transaction_data),
+                    time,
+                },
+            )
+            .await;
+
+        match result {
+            Ok(unlocks) => Ok(Unlocks::new(
+                unlocks
+                    .into_iter()
+                    .map(Unlock::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )?),
+            Err(Error::Rpc {
+                code: METHOD_NOT_FOUND, ..
+            }) => super::default_sign_transaction_essence(self, prepared_transaction_data, time).await,
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+impl SecretManagerConfig for RemoteSecretManager {
+    type Config = RemoteSecretManagerDto;
+
+    fn to_config(&self) -> Option<Self::Config> {
+        Some(RemoteSecretManagerDto {
+            endpoint: self.endpoint.clone(),
+            timeout: Some(self.timeout.as_secs()),
+        })
+    }
+
+    fn from_config(config: &Self::Config) -> Result<Self, Self::Error> {
+        let secret_manager = Self::new(config.endpoint.clone());
+
+        Ok(match config.timeout {
+            Some(timeout) => secret_manager.with_timeout(Duration::from_secs(timeout)),
+            None => secret_manager,
+        })
+    }
+}
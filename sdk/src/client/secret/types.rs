@@ -4,13 +4,22 @@
 //! Miscellaneous types for secret managers.
 
 use crypto::keys::bip44::Bip44;
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 use crate::{
     types::{
         block::{
             address::Address,
             output::{dto::OutputDto, Output, OutputId, OutputMetadata},
+            protocol::ProtocolParameters,
+            Error,
         },
         TryFromDto, ValidationParams,
     },
@@ -41,6 +50,21 @@ impl core::fmt::Debug for StrongholdDto {
     }
 }
 
+/// Mnemonic DTO to allow the creation of a mnemonic secret manager with a BIP-39 passphrase from bindings
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MnemonicWithPassphraseDto {
+    /// The mnemonic
+    pub mnemonic: Zeroizing<String>,
+    /// The BIP-39 passphrase, also known as the 25th word
+    pub passphrase: Zeroizing<String>,
+}
+
+impl core::fmt::Debug for MnemonicWithPassphraseDto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MnemonicWithPassphraseDto").finish_non_exhaustive()
+    }
+}
+
 /// An account address.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AccountAddress {
@@ -168,6 +192,51 @@ impl InputSigningData {
     }
 }
 
+impl Packable for InputSigningData {
+    type UnpackError = Error;
+    type UnpackVisitor = ProtocolParameters;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.output.pack(packer)?;
+        self.output_metadata.pack(packer)?;
+        pack_chain(self.chain.as_ref(), packer)?;
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Self {
+            output: Output::unpack::<_, VERIFY>(unpacker, visitor)?,
+            output_metadata: OutputMetadata::unpack::<_, VERIFY>(unpacker, &())?,
+            chain: unpack_chain::<_, VERIFY>(unpacker)?,
+        })
+    }
+}
+
+/// Packs an optional [`Bip44`] chain as its coin type, account, change and address index.
+pub(crate) fn pack_chain<P: Packer>(chain: Option<&Bip44>, packer: &mut P) -> Result<(), P::Error> {
+    chain
+        .map(|chain| (chain.coin_type, chain.account, chain.change, chain.address_index))
+        .pack(packer)
+}
+
+/// Unpacks an optional [`Bip44`] chain packed with [`pack_chain()`].
+pub(crate) fn unpack_chain<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+) -> Result<Option<Bip44>, UnpackError<Error, U::Error>> {
+    Ok(Option::<(u32, u32, u32, u32)>::unpack::<_, VERIFY>(unpacker, &())
+        .map_packable_err(|_| Error::InvalidField("chain"))?
+        .map(|(coin_type, account, change, address_index)| {
+            Bip44::new(coin_type)
+                .with_account(account)
+                .with_change(change)
+                .with_address_index(address_index)
+        }))
+}
+
 /// Dto for data for transaction inputs for signing and ordering of unlock blocks
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
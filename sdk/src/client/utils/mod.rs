@@ -3,17 +3,20 @@
 
 //! Utility functions for IOTA
 
+pub mod slip39;
+
 use core::borrow::Borrow;
 use std::collections::HashMap;
 
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, Seed},
+    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, PassphraseRef, Seed},
     utils,
 };
 use serde::{Deserialize, Serialize};
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
+use self::slip39::{Slip39Group, DEFAULT_ITERATION_EXPONENT};
 use super::{Client, ClientInner};
 use crate::{
     client::{Error, Result},
@@ -66,13 +69,31 @@ pub fn mnemonic_to_hex_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<String
     Ok(prefix_hex::encode(mnemonic_to_seed(mnemonic)?.as_ref()))
 }
 
+/// Returns a hex encoded seed for a mnemonic and a BIP-39 passphrase.
+pub fn mnemonic_to_hex_seed_with_passphrase(
+    mnemonic: impl Borrow<MnemonicRef>,
+    passphrase: impl Borrow<PassphraseRef>,
+) -> Result<String> {
+    Ok(prefix_hex::encode(
+        mnemonic_to_seed_with_passphrase(mnemonic, passphrase)?.as_ref(),
+    ))
+}
+
 /// Returns a seed for a mnemonic.
 pub fn mnemonic_to_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<Seed> {
+    mnemonic_to_seed_with_passphrase(mnemonic, Passphrase::default())
+}
+
+/// Returns a seed for a mnemonic and a BIP-39 passphrase, also known as the 25th word.
+pub fn mnemonic_to_seed_with_passphrase(
+    mnemonic: impl Borrow<MnemonicRef>,
+    passphrase: impl Borrow<PassphraseRef>,
+) -> Result<Seed> {
     // first we check if the mnemonic is valid to give meaningful errors
     verify_mnemonic(mnemonic.borrow())?;
     Ok(crypto::keys::bip39::mnemonic_to_seed(
         mnemonic.borrow(),
-        &Passphrase::default(),
+        passphrase.borrow(),
     ))
 }
 
@@ -83,6 +104,36 @@ pub fn verify_mnemonic(mnemonic: impl Borrow<MnemonicRef>) -> Result<()> {
     Ok(())
 }
 
+/// Splits the entropy of a mnemonic into groups of SLIP-39 mnemonic shares.
+///
+/// `group_threshold` groups are required to recover the mnemonic. The shares are encrypted with the SLIP-39
+/// passphrase, which can be empty.
+pub fn mnemonic_to_slip39_shares(
+    mnemonic: impl Borrow<MnemonicRef>,
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[Slip39Group],
+) -> Result<Vec<Vec<Zeroizing<String>>>> {
+    let entropy = wordlist::decode(mnemonic.borrow(), &wordlist::ENGLISH)
+        .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
+
+    slip39::split_master_secret(
+        &entropy,
+        passphrase,
+        group_threshold,
+        groups,
+        DEFAULT_ITERATION_EXPONENT,
+    )
+}
+
+/// Recovers a mnemonic from SLIP-39 mnemonic shares created by [`mnemonic_to_slip39_shares()`] and their SLIP-39
+/// passphrase.
+pub fn slip39_shares_to_mnemonic(shares: &[impl AsRef<str>], passphrase: &str) -> Result<Mnemonic> {
+    let entropy = slip39::combine_shares(shares, passphrase)?;
+
+    wordlist::encode(&entropy, &wordlist::ENGLISH).map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))
+}
+
 /// Requests funds from a faucet
 pub async fn request_funds_from_faucet(url: &str, bech32_address: &Bech32Address) -> Result<String> {
     let mut map = HashMap::new();
@@ -172,6 +223,37 @@ impl Client {
         mnemonic_to_hex_seed(mnemonic)
     }
 
+    /// Splits the entropy of a mnemonic into groups of SLIP-39 mnemonic shares.
+    pub fn mnemonic_to_slip39_shares(
+        mnemonic: impl Borrow<MnemonicRef>,
+        passphrase: &str,
+        group_threshold: u8,
+        groups: &[Slip39Group],
+    ) -> Result<Vec<Vec<Zeroizing<String>>>> {
+        mnemonic_to_slip39_shares(mnemonic, passphrase, group_threshold, groups)
+    }
+
+    /// Recovers a mnemonic from SLIP-39 mnemonic shares.
+    pub fn slip39_shares_to_mnemonic(shares: &[impl AsRef<str>], passphrase: &str) -> Result<Mnemonic> {
+        slip39_shares_to_mnemonic(shares, passphrase)
+    }
+
+    /// Returns a seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_to_seed_with_passphrase(
+        mnemonic: impl Borrow<MnemonicRef>,
+        passphrase: impl Borrow<PassphraseRef>,
+    ) -> Result<Seed> {
+        mnemonic_to_seed_with_passphrase(mnemonic, passphrase)
+    }
+
+    /// Returns a hex encoded seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_to_hex_seed_with_passphrase(
+        mnemonic: impl Borrow<MnemonicRef>,
+        passphrase: impl Borrow<PassphraseRef>,
+    ) -> Result<String> {
+        mnemonic_to_hex_seed_with_passphrase(mnemonic, passphrase)
+    }
+
     /// UTF-8 encodes the `tag` of a given TaggedDataPayload.
     pub fn tag_to_utf8(payload: &TaggedDataPayload) -> Result<String> {
         String::from_utf8(payload.tag().to_vec()).map_err(|_| Error::TaggedData("found invalid UTF-8".to_string()))
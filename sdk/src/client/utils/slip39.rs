@@ -0,0 +1,633 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! SLIP-39 Shamir secret sharing of master secrets into mnemonic shares.
+//!
+//! For more information, see <https://github.com/satoshilabs/slips/blob/master/slip-0039.md>.
+
+use core::num::NonZeroU32;
+use std::collections::BTreeMap;
+
+use crypto::{keys::pbkdf::PBKDF2_HMAC_SHA256, macs::hmac::HMAC_SHA256, utils::rand};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::client::{Error, Result};
+
+/// The iteration exponent used when splitting a master secret, as recommended by SLIP-39.
+pub const DEFAULT_ITERATION_EXPONENT: u8 = 1;
+
+const MAX_SHARE_COUNT: u8 = 16;
+const MIN_MASTER_SECRET_LENGTH: usize = 16;
+const RADIX_BITS: usize = 10;
+const ID_LENGTH_BITS: usize = 15;
+const ITERATION_EXPONENT_LENGTH_BITS: usize = 4;
+// Identifier, extendable flag, iteration exponent, group index, group threshold, group count, member index and
+// member threshold.
+const METADATA_LENGTH_WORDS: usize = 4;
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+const MIN_SHARE_LENGTH_WORDS: usize =
+    METADATA_LENGTH_WORDS + CHECKSUM_LENGTH_WORDS + (MIN_MASTER_SECRET_LENGTH * 8).div_ceil(RADIX_BITS);
+const CUSTOMIZATION_STRING: &[u8] = b"shamir";
+const CUSTOMIZATION_STRING_EXTENDABLE: &[u8] = b"shamir_extendable";
+const BASE_ITERATION_COUNT: u32 = 10000;
+const ROUND_COUNT: u8 = 4;
+const DIGEST_LENGTH: usize = 4;
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+
+/// A group of member shares, of which `threshold` shares are required to recover the group share.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Slip39Group {
+    /// The number of member shares required to recover the group share.
+    pub threshold: u8,
+    /// The number of member shares.
+    pub count: u8,
+}
+
+impl Slip39Group {
+    /// Creates a new [`Slip39Group`].
+    pub fn new(threshold: u8, count: u8) -> Self {
+        Self { threshold, count }
+    }
+}
+
+/// Splits a master secret into groups of SLIP-39 mnemonic shares.
+///
+/// `group_threshold` groups are required to recover the master secret. It's encrypted with the passphrase, which is
+/// needed again to recover it.
+pub fn split_master_secret(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[Slip39Group],
+    iteration_exponent: u8,
+) -> Result<Vec<Vec<Zeroizing<String>>>> {
+    if master_secret.len() < MIN_MASTER_SECRET_LENGTH || master_secret.len() & 1 == 1 {
+        return Err(Error::Slip39(format!(
+            "the master secret must be at least {MIN_MASTER_SECRET_LENGTH} bytes long and have an even length"
+        )));
+    }
+    if group_threshold == 0 || usize::from(group_threshold) > groups.len() || groups.len() > MAX_SHARE_COUNT.into() {
+        return Err(Error::Slip39(format!(
+            "invalid group threshold {group_threshold} for {} groups",
+            groups.len()
+        )));
+    }
+    for group in groups {
+        if group.threshold == 0 || group.threshold > group.count || group.count > MAX_SHARE_COUNT {
+            return Err(Error::Slip39(format!(
+                "invalid member threshold {} for {} members",
+                group.threshold, group.count
+            )));
+        }
+        if group.threshold == 1 && group.count > 1 {
+            return Err(Error::Slip39(
+                "multiple member shares with a member threshold of 1 aren't allowed".to_string(),
+            ));
+        }
+    }
+    if iteration_exponent >= 1 << ITERATION_EXPONENT_LENGTH_BITS {
+        return Err(Error::Slip39(format!("invalid iteration exponent {iteration_exponent}")));
+    }
+    validate_passphrase(passphrase)?;
+
+    let mut id_bytes = [0u8; 2];
+    rand::fill(&mut id_bytes)?;
+    let header = ShareHeader {
+        id: u16::from_be_bytes(id_bytes) & ((1 << ID_LENGTH_BITS) - 1),
+        extendable: true,
+        iteration_exponent,
+        group_threshold,
+        group_count: groups.len() as u8,
+    };
+
+    let encrypted_master_secret = header.encrypt(master_secret, passphrase);
+    let group_shares = split_secret(group_threshold, groups.len() as u8, &encrypted_master_secret)?;
+
+    groups
+        .iter()
+        .zip(group_shares)
+        .map(|(group, (group_index, group_share))| {
+            split_secret(group.threshold, group.count, &group_share)?
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Ok(Share {
+                        header,
+                        group_index,
+                        member_index,
+                        member_threshold: group.threshold,
+                        value,
+                    }
+                    .to_mnemonic())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Recovers a master secret from SLIP-39 mnemonic shares and the passphrase it was encrypted with.
+///
+/// The shares can be given in any order, shares of incomplete groups are ignored.
+pub fn combine_shares(shares: &[impl AsRef<str>], passphrase: &str) -> Result<Zeroizing<Vec<u8>>> {
+    validate_passphrase(passphrase)?;
+
+    let shares = shares
+        .iter()
+        .map(|share| Share::from_mnemonic(share.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let (header, value_length) = shares
+        .first()
+        .map(|share| (share.header, share.value.len()))
+        .ok_or_else(|| Error::Slip39("no shares were given".to_string()))?;
+
+    let mut groups = BTreeMap::<u8, (u8, BTreeMap<u8, Zeroizing<Vec<u8>>>)>::new();
+    for share in shares {
+        if share.header != header || share.value.len() != value_length {
+            return Err(Error::Slip39("the shares don't belong to the same master secret".to_string()));
+        }
+        let (member_threshold, members) = groups
+            .entry(share.group_index)
+            .or_insert_with(|| (share.member_threshold, BTreeMap::new()));
+        if *member_threshold != share.member_threshold {
+            return Err(Error::Slip39(format!(
+                "the shares of group {} have different member thresholds",
+                share.group_index
+            )));
+        }
+        match members.get(&share.member_index) {
+            Some(value) if value != &share.value => {
+                return Err(Error::Slip39(format!(
+                    "the shares of group {} have conflicting values for member {}",
+                    share.group_index, share.member_index
+                )));
+            }
+            Some(_) => {}
+            None => {
+                members.insert(share.member_index, share.value);
+            }
+        }
+    }
+
+    let group_shares = groups
+        .into_iter()
+        .filter(|(_, (member_threshold, members))| members.len() >= usize::from(*member_threshold))
+        .take(header.group_threshold.into())
+        .map(|(group_index, (member_threshold, members))| {
+            let members = members.into_iter().take(member_threshold.into()).collect::<Vec<_>>();
+            Ok((group_index, recover_secret(member_threshold, &members)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if group_shares.len() < usize::from(header.group_threshold) {
+        return Err(Error::Slip39(format!(
+            "insufficient shares, {} complete groups are required but only {} were given",
+            header.group_threshold,
+            group_shares.len()
+        )));
+    }
+
+    let encrypted_master_secret = recover_secret(header.group_threshold, &group_shares)?;
+
+    Ok(header.decrypt(&encrypted_master_secret, passphrase))
+}
+
+fn validate_passphrase(passphrase: &str) -> Result<()> {
+    if passphrase.bytes().all(|byte| (32..=126).contains(&byte)) {
+        Ok(())
+    } else {
+        Err(Error::Slip39(
+            "the passphrase must only contain printable ASCII characters".to_string(),
+        ))
+    }
+}
+
+// The metadata which all shares of a master secret have in common.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct ShareHeader {
+    id: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_threshold: u8,
+    group_count: u8,
+}
+
+impl ShareHeader {
+    fn customization_string(&self) -> &'static [u8] {
+        if self.extendable {
+            CUSTOMIZATION_STRING_EXTENDABLE
+        } else {
+            CUSTOMIZATION_STRING
+        }
+    }
+
+    // The round function of the Feistel network.
+    fn round_function(&self, round: u8, passphrase: &str, value: &[u8]) -> Zeroizing<Vec<u8>> {
+        let mut password = Zeroizing::new(Vec::with_capacity(passphrase.len() + 1));
+        password.push(round);
+        password.extend_from_slice(passphrase.as_bytes());
+
+        let mut salt = Vec::new();
+        if !self.extendable {
+            salt.extend_from_slice(CUSTOMIZATION_STRING);
+            salt.extend_from_slice(&self.id.to_be_bytes());
+        }
+        salt.extend_from_slice(value);
+
+        let iterations = (BASE_ITERATION_COUNT << self.iteration_exponent) / u32::from(ROUND_COUNT);
+        let mut output = Zeroizing::new(vec![0; value.len()]);
+        // The iteration count is at least 2500.
+        PBKDF2_HMAC_SHA256(&password, &salt, NonZeroU32::new(iterations).unwrap(), &mut output);
+
+        output
+    }
+
+    fn feistel(&self, value: &[u8], passphrase: &str, rounds: impl Iterator<Item = u8>) -> Zeroizing<Vec<u8>> {
+        let (left, right) = value.split_at(value.len() / 2);
+        let (mut left, mut right) = (Zeroizing::new(left.to_vec()), Zeroizing::new(right.to_vec()));
+
+        for round in rounds {
+            let mut next_right = self.round_function(round, passphrase, &right);
+            xor_into(&mut next_right, &left);
+            left = right;
+            right = next_right;
+        }
+
+        right.extend_from_slice(&left);
+        right
+    }
+
+    fn encrypt(&self, master_secret: &[u8], passphrase: &str) -> Zeroizing<Vec<u8>> {
+        self.feistel(master_secret, passphrase, 0..ROUND_COUNT)
+    }
+
+    fn decrypt(&self, encrypted_master_secret: &[u8], passphrase: &str) -> Zeroizing<Vec<u8>> {
+        self.feistel(encrypted_master_secret, passphrase, (0..ROUND_COUNT).rev())
+    }
+}
+
+// A single share of a master secret.
+struct Share {
+    header: ShareHeader,
+    group_index: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    fn to_mnemonic(&self) -> Zeroizing<String> {
+        let mut words = Zeroizing::new(Vec::new());
+
+        let metadata = (u64::from(self.header.id) << 25)
+            | (u64::from(self.header.extendable) << 24)
+            | (u64::from(self.header.iteration_exponent) << 20)
+            | (u64::from(self.group_index) << 16)
+            | (u64::from(self.header.group_threshold - 1) << 12)
+            | (u64::from(self.header.group_count - 1) << 8)
+            | (u64::from(self.member_index) << 4)
+            | u64::from(self.member_threshold - 1);
+        words.extend((0..METADATA_LENGTH_WORDS).rev().map(|i| word_at(metadata, i)));
+
+        // The value is left padded with zero bits to a multiple of the word size.
+        let value_words = (self.value.len() * 8).div_ceil(RADIX_BITS);
+        let mut accumulator = 0u32;
+        let mut accumulator_bits = value_words * RADIX_BITS - self.value.len() * 8;
+        for byte in self.value.iter() {
+            accumulator = (accumulator << 8) | u32::from(*byte);
+            accumulator_bits += 8;
+            while accumulator_bits >= RADIX_BITS {
+                accumulator_bits -= RADIX_BITS;
+                words.push(((accumulator >> accumulator_bits) & 1023) as u16);
+            }
+        }
+        accumulator.zeroize();
+
+        let checksum = create_checksum(self.header.customization_string(), &words);
+        words.extend(checksum);
+
+        Zeroizing::new(
+            words
+                .iter()
+                .map(|index| WORDLIST[usize::from(*index)])
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Result<Self> {
+        let words = Zeroizing::new(
+            mnemonic
+                .split_whitespace()
+                .map(|word| {
+                    WORDLIST
+                        .binary_search(&word.to_lowercase().as_str())
+                        .map(|index| index as u16)
+                        .map_err(|_| Error::Slip39(format!("invalid word {word}")))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        );
+        if words.len() < MIN_SHARE_LENGTH_WORDS {
+            return Err(Error::Slip39(format!(
+                "invalid share length, a share has at least {MIN_SHARE_LENGTH_WORDS} words"
+            )));
+        }
+
+        let metadata = words[..METADATA_LENGTH_WORDS]
+            .iter()
+            .fold(0u64, |metadata, word| (metadata << RADIX_BITS) | u64::from(*word));
+        let header = ShareHeader {
+            id: (metadata >> 25) as u16,
+            extendable: (metadata >> 24) & 1 == 1,
+            iteration_exponent: ((metadata >> 20) & 15) as u8,
+            group_threshold: ((metadata >> 12) & 15) as u8 + 1,
+            group_count: ((metadata >> 8) & 15) as u8 + 1,
+        };
+        if !verify_checksum(header.customization_string(), &words) {
+            return Err(Error::Slip39("invalid share checksum".to_string()));
+        }
+        if header.group_threshold > header.group_count {
+            return Err(Error::Slip39("the group threshold exceeds the group count".to_string()));
+        }
+
+        let value_words = &words[METADATA_LENGTH_WORDS..words.len() - CHECKSUM_LENGTH_WORDS];
+        let padding_bits = (value_words.len() * RADIX_BITS) % 16;
+        if padding_bits > 8 {
+            return Err(Error::Slip39("invalid share padding".to_string()));
+        }
+        // The value is left padded with zero bits to a multiple of the word size.
+        let mut value = Zeroizing::new(Vec::with_capacity(value_words.len() * RADIX_BITS / 8));
+        let mut byte = 0u8;
+        let bits = value_words
+            .iter()
+            .flat_map(|word| (0..RADIX_BITS).rev().map(move |i| (word >> i) & 1 == 1));
+        for (i, bit) in bits.enumerate() {
+            if i < padding_bits {
+                if bit {
+                    return Err(Error::Slip39("invalid share padding".to_string()));
+                }
+                continue;
+            }
+            byte = (byte << 1) | u8::from(bit);
+            if (i - padding_bits) % 8 == 7 {
+                value.push(byte);
+            }
+        }
+        byte.zeroize();
+
+        Ok(Self {
+            header,
+            group_index: ((metadata >> 16) & 15) as u8,
+            member_index: ((metadata >> 4) & 15) as u8,
+            member_threshold: (metadata & 15) as u8 + 1,
+            value,
+        })
+    }
+}
+
+fn word_at(value: u64, index: usize) -> u16 {
+    ((value >> (index * RADIX_BITS)) & 1023) as u16
+}
+
+// Splits a secret into `count` shares, `threshold` of which are required to recover it.
+fn split_secret(threshold: u8, count: u8, secret: &[u8]) -> Result<Vec<(u8, Zeroizing<Vec<u8>>)>> {
+    if threshold == 1 {
+        return Ok((0..count).map(|index| (index, Zeroizing::new(secret.to_vec()))).collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares = (0..random_share_count)
+        .map(|index| {
+            let mut value = Zeroizing::new(vec![0; secret.len()]);
+            rand::fill(&mut value)?;
+            Ok((index, value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut digest_share = Zeroizing::new(vec![0; secret.len()]);
+    rand::fill(&mut digest_share[DIGEST_LENGTH..])?;
+    let digest = create_digest(&digest_share[DIGEST_LENGTH..], secret);
+    digest_share[..DIGEST_LENGTH].copy_from_slice(&digest);
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, digest_share));
+    base_shares.push((SECRET_INDEX, Zeroizing::new(secret.to_vec())));
+
+    for index in random_share_count..count {
+        shares.push((index, interpolate(&base_shares, index)));
+    }
+
+    Ok(shares)
+}
+
+// Recovers a secret from `threshold` shares and verifies its digest.
+fn recover_secret(threshold: u8, shares: &[(u8, Zeroizing<Vec<u8>>)]) -> Result<Zeroizing<Vec<u8>>> {
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    if create_digest(&digest_share[DIGEST_LENGTH..], &secret) != digest_share[..DIGEST_LENGTH] {
+        return Err(Error::Slip39("invalid digest of the recovered secret".to_string()));
+    }
+
+    Ok(secret)
+}
+
+fn create_digest(random_data: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut mac = [0; 32];
+    HMAC_SHA256(secret, random_data, &mut mac);
+    let mut digest = [0; DIGEST_LENGTH];
+    digest.copy_from_slice(&mac[..DIGEST_LENGTH]);
+    digest
+}
+
+// Evaluates the polynomial through the shares at `x` with Lagrange interpolation over GF(256).
+fn interpolate(shares: &[(u8, Zeroizing<Vec<u8>>)], x: u8) -> Zeroizing<Vec<u8>> {
+    if let Some((_, value)) = shares.iter().find(|(index, _)| *index == x) {
+        return value.clone();
+    }
+
+    let (exp, log) = gf256_tables();
+    let log_product = shares
+        .iter()
+        .map(|(index, _)| u32::from(log[usize::from(index ^ x)]))
+        .sum::<u32>();
+    let mut result = Zeroizing::new(vec![0; shares[0].1.len()]);
+
+    for (index, value) in shares {
+        let log_basis = shares
+            .iter()
+            .filter(|(other, _)| other != index)
+            .map(|(other, _)| u32::from(log[usize::from(index ^ other)]))
+            .sum::<u32>();
+        // The basis polynomial of the share evaluated at `x`, as a logarithm.
+        let log_basis =
+            (log_product + 255 * shares.len() as u32 - u32::from(log[usize::from(index ^ x)]) - log_basis) % 255;
+
+        for (result, byte) in result.iter_mut().zip(value.iter()) {
+            if *byte != 0 {
+                *result ^= exp[((u32::from(log[usize::from(*byte)]) + log_basis) % 255) as usize];
+            }
+        }
+    }
+
+    result
+}
+
+// Exponent and logarithm tables of GF(256) with the Rijndael polynomial and generator 3.
+fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0; 255];
+    let mut log = [0; 256];
+    let mut poly = 1u16;
+
+    for (i, exp) in exp.iter_mut().enumerate() {
+        *exp = poly as u8;
+        log[usize::from(poly)] = i as u8;
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+    }
+
+    (exp, log)
+}
+
+fn xor_into(target: &mut [u8], value: &[u8]) {
+    for (target, value) in target.iter_mut().zip(value) {
+        *target ^= value;
+    }
+}
+
+// The Reed-Solomon code over GF(1024) protecting the shares.
+fn polymod(values: impl Iterator<Item = u32>) -> u32 {
+    const GENERATOR: [u32; 10] = [
+        0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48, 0x21B1F890, 0x3F3F120,
+    ];
+
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 20;
+        let checksum = ((checksum & 0xFFFFF) << 10) ^ value;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
+
+fn create_checksum(customization_string: &[u8], words: &[u16]) -> [u16; CHECKSUM_LENGTH_WORDS] {
+    let values = customization_string
+        .iter()
+        .map(|byte| u32::from(*byte))
+        .chain(words.iter().map(|word| u32::from(*word)))
+        .chain([0; CHECKSUM_LENGTH_WORDS]);
+    let checksum = u64::from(polymod(values) ^ 1);
+
+    [word_at(checksum, 2), word_at(checksum, 1), word_at(checksum, 0)]
+}
+
+fn verify_checksum(customization_string: &[u8], words: &[u16]) -> bool {
+    let values = customization_string
+        .iter()
+        .map(|byte| u32::from(*byte))
+        .chain(words.iter().map(|word| u32::from(*word)));
+
+    polymod(values) == 1
+}
+
+#[rustfmt::skip]
+const WORDLIST: [&str; 1024] = [
+    "academic", "acid", "acne", "acquire", "acrobat", "activity", "actress", "adapt", "adequate", "adjust", "admit",
+    "adorn", "adult", "advance", "advocate", "afraid", "again", "agency", "agree", "aide", "aircraft", "airline",
+    "airport", "ajar", "alarm", "album", "alcohol", "alien", "alive", "alpha", "already", "alto", "aluminum", "always",
+    "amazing", "ambition", "amount", "amuse", "analysis", "anatomy", "ancestor", "ancient", "angel", "angry", "animal",
+    "answer", "antenna", "anxiety", "apart", "aquatic", "arcade", "arena", "argue", "armed", "artist", "artwork",
+    "aspect", "auction", "august", "aunt", "average", "aviation", "avoid", "award", "away", "axis", "axle", "beam",
+    "beard", "beaver", "become", "bedroom", "behavior", "being", "believe", "belong", "benefit", "best", "beyond",
+    "bike", "biology", "birthday", "bishop", "black", "blanket", "blessing", "blimp", "blind", "blue", "body", "bolt",
+    "boring", "born", "both", "boundary", "bracelet", "branch", "brave", "breathe", "briefing", "broken", "brother",
+    "browser", "bucket", "budget", "building", "bulb", "bulge", "bumpy", "bundle", "burden", "burning", "busy", "buyer",
+    "cage", "calcium", "camera", "campus", "canyon", "capacity", "capital", "capture", "carbon", "cards", "careful",
+    "cargo", "carpet", "carve", "category", "cause", "ceiling", "center", "ceramic", "champion", "change", "charity",
+    "check", "chemical", "chest", "chew", "chubby", "cinema", "civil", "class", "clay", "cleanup", "client", "climate",
+    "clinic", "clock", "clogs", "closet", "clothes", "club", "cluster", "coal", "coastal", "coding", "column",
+    "company", "corner", "costume", "counter", "course", "cover", "cowboy", "cradle", "craft", "crazy", "credit",
+    "cricket", "criminal", "crisis", "critical", "crowd", "crucial", "crunch", "crush", "crystal", "cubic", "cultural",
+    "curious", "curly", "custody", "cylinder", "daisy", "damage", "dance", "darkness", "database", "daughter",
+    "deadline", "deal", "debris", "debut", "decent", "decision", "declare", "decorate", "decrease", "deliver", "demand",
+    "density", "deny", "depart", "depend", "depict", "deploy", "describe", "desert", "desire", "desktop", "destroy",
+    "detailed", "detect", "device", "devote", "diagnose", "dictate", "diet", "dilemma", "diminish", "dining", "diploma",
+    "disaster", "discuss", "disease", "dish", "dismiss", "display", "distance", "dive", "divorce", "document", "domain",
+    "domestic", "dominant", "dough", "downtown", "dragon", "dramatic", "dream", "dress", "drift", "drink", "drove",
+    "drug", "dryer", "duckling", "duke", "duration", "dwarf", "dynamic", "early", "earth", "easel", "easy", "echo",
+    "eclipse", "ecology", "edge", "editor", "educate", "either", "elbow", "elder", "election", "elegant", "element",
+    "elephant", "elevator", "elite", "else", "email", "emerald", "emission", "emperor", "emphasis", "employer", "empty",
+    "ending", "endless", "endorse", "enemy", "energy", "enforce", "engage", "enjoy", "enlarge", "entrance", "envelope",
+    "envy", "epidemic", "episode", "equation", "equip", "eraser", "erode", "escape", "estate", "estimate", "evaluate",
+    "evening", "evidence", "evil", "evoke", "exact", "example", "exceed", "exchange", "exclude", "excuse", "execute",
+    "exercise", "exhaust", "exotic", "expand", "expect", "explain", "express", "extend", "extra", "eyebrow", "facility",
+    "fact", "failure", "faint", "fake", "false", "family", "famous", "fancy", "fangs", "fantasy", "fatal", "fatigue",
+    "favorite", "fawn", "fiber", "fiction", "filter", "finance", "findings", "finger", "firefly", "firm", "fiscal",
+    "fishing", "fitness", "flame", "flash", "flavor", "flea", "flexible", "flip", "float", "floral", "fluff", "focus",
+    "forbid", "force", "forecast", "forget", "formal", "fortune", "forward", "founder", "fraction", "fragment",
+    "frequent", "freshman", "friar", "fridge", "friendly", "frost", "froth", "frozen", "fumes", "funding", "furl",
+    "fused", "galaxy", "game", "garbage", "garden", "garlic", "gasoline", "gather", "general", "genius", "genre",
+    "genuine", "geology", "gesture", "glad", "glance", "glasses", "glen", "glimpse", "goat", "golden", "graduate",
+    "grant", "grasp", "gravity", "gray", "greatest", "grief", "grill", "grin", "grocery", "gross", "group", "grownup",
+    "grumpy", "guard", "guest", "guilt", "guitar", "gums", "hairy", "hamster", "hand", "hanger", "harvest", "have",
+    "havoc", "hawk", "hazard", "headset", "health", "hearing", "heat", "helpful", "herald", "herd", "hesitate", "hobo",
+    "holiday", "holy", "home", "hormone", "hospital", "hour", "huge", "human", "humidity", "hunting", "husband", "hush",
+    "husky", "hybrid", "idea", "identify", "idle", "image", "impact", "imply", "improve", "impulse", "include",
+    "income", "increase", "index", "indicate", "industry", "infant", "inform", "inherit", "injury", "inmate", "insect",
+    "inside", "install", "intend", "intimate", "invasion", "involve", "iris", "island", "isolate", "item", "ivory",
+    "jacket", "jerky", "jewelry", "join", "judicial", "juice", "jump", "junction", "junior", "junk", "jury", "justice",
+    "kernel", "keyboard", "kidney", "kind", "kitchen", "knife", "knit", "laden", "ladle", "ladybug", "lair", "lamp",
+    "language", "large", "laser", "laundry", "lawsuit", "leader", "leaf", "learn", "leaves", "lecture", "legal",
+    "legend", "legs", "lend", "length", "level", "liberty", "library", "license", "lift", "likely", "lilac", "lily",
+    "lips", "liquid", "listen", "literary", "living", "lizard", "loan", "lobe", "location", "losing", "loud", "loyalty",
+    "luck", "lunar", "lunch", "lungs", "luxury", "lying", "lyrics", "machine", "magazine", "maiden", "mailman", "main",
+    "makeup", "making", "mama", "manager", "mandate", "mansion", "manual", "marathon", "march", "market", "marvel",
+    "mason", "material", "math", "maximum", "mayor", "meaning", "medal", "medical", "member", "memory", "mental",
+    "merchant", "merit", "method", "metric", "midst", "mild", "military", "mineral", "minister", "miracle", "mixed",
+    "mixture", "mobile", "modern", "modify", "moisture", "moment", "morning", "mortgage", "mother", "mountain", "mouse",
+    "move", "much", "mule", "multiple", "muscle", "museum", "music", "mustang", "nail", "national", "necklace",
+    "negative", "nervous", "network", "news", "nuclear", "numb", "numerous", "nylon", "oasis", "obesity", "object",
+    "observe", "obtain", "ocean", "often", "olympic", "omit", "oral", "orange", "orbit", "order", "ordinary",
+    "organize", "ounce", "oven", "overall", "owner", "paces", "pacific", "package", "paid", "painting", "pajamas",
+    "pancake", "pants", "papa", "paper", "parcel", "parking", "party", "patent", "patrol", "payment", "payroll",
+    "peaceful", "peanut", "peasant", "pecan", "penalty", "pencil", "percent", "perfect", "permit", "petition",
+    "phantom", "pharmacy", "photo", "phrase", "physics", "pickup", "picture", "piece", "pile", "pink", "pipeline",
+    "pistol", "pitch", "plains", "plan", "plastic", "platform", "playoff", "pleasure", "plot", "plunge", "practice",
+    "prayer", "preach", "predator", "pregnant", "premium", "prepare", "presence", "prevent", "priest", "primary",
+    "priority", "prisoner", "privacy", "prize", "problem", "process", "profile", "program", "promise", "prospect",
+    "provide", "prune", "public", "pulse", "pumps", "punish", "puny", "pupal", "purchase", "purple", "python",
+    "quantity", "quarter", "quick", "quiet", "race", "racism", "radar", "railroad", "rainbow", "raisin", "random",
+    "ranked", "rapids", "raspy", "reaction", "realize", "rebound", "rebuild", "recall", "receiver", "recover", "regret",
+    "regular", "reject", "relate", "remember", "remind", "remove", "render", "repair", "repeat", "replace", "require",
+    "rescue", "research", "resident", "response", "result", "retailer", "retreat", "reunion", "revenue", "review",
+    "reward", "rhyme", "rhythm", "rich", "rival", "river", "robin", "rocky", "romantic", "romp", "roster", "round",
+    "royal", "ruin", "ruler", "rumor", "sack", "safari", "salary", "salon", "salt", "satisfy", "satoshi", "saver",
+    "says", "scandal", "scared", "scatter", "scene", "scholar", "science", "scout", "scramble", "screw", "script",
+    "scroll", "seafood", "season", "secret", "security", "segment", "senior", "shadow", "shaft", "shame", "shaped",
+    "sharp", "shelter", "sheriff", "short", "should", "shrimp", "sidewalk", "silent", "silver", "similar", "simple",
+    "single", "sister", "skin", "skunk", "slap", "slavery", "sled", "slice", "slim", "slow", "slush", "smart", "smear",
+    "smell", "smirk", "smith", "smoking", "smug", "snake", "snapshot", "sniff", "society", "software", "soldier",
+    "solution", "soul", "source", "space", "spark", "speak", "species", "spelling", "spend", "spew", "spider", "spill",
+    "spine", "spirit", "spit", "spray", "sprinkle", "square", "squeeze", "stadium", "staff", "standard", "starting",
+    "station", "stay", "steady", "step", "stick", "stilt", "story", "strategy", "strike", "style", "subject", "submit",
+    "sugar", "suitable", "sunlight", "superior", "surface", "surprise", "survive", "sweater", "swimming", "swing",
+    "switch", "symbolic", "sympathy", "syndrome", "system", "tackle", "tactics", "tadpole", "talent", "task", "taste",
+    "taught", "taxi", "teacher", "teammate", "teaspoon", "temple", "tenant", "tendency", "tension", "terminal",
+    "testify", "texture", "thank", "that", "theater", "theory", "therapy", "thorn", "threaten", "thumb", "thunder",
+    "ticket", "tidy", "timber", "timely", "ting", "tofu", "together", "tolerate", "total", "toxic", "tracks", "traffic",
+    "training", "transfer", "trash", "traveler", "treat", "trend", "trial", "tricycle", "trip", "triumph", "trouble",
+    "true", "trust", "twice", "twin", "type", "typical", "ugly", "ultimate", "umbrella", "uncover", "undergo", "unfair",
+    "unfold", "unhappy", "union", "universe", "unkind", "unknown", "unusual", "unwrap", "upgrade", "upstairs",
+    "username", "usher", "usual", "valid", "valuable", "vampire", "vanish", "various", "vegan", "velvet", "venture",
+    "verdict", "verify", "very", "veteran", "vexed", "victim", "video", "view", "vintage", "violence", "viral",
+    "visitor", "visual", "vitamins", "vocal", "voice", "volume", "voter", "voting", "walnut", "warmth", "warn", "watch",
+    "wavy", "wealthy", "weapon", "webcam", "welcome", "welfare", "western", "width", "wildlife", "window", "wine",
+    "wireless", "wisdom", "withdraw", "wits", "wolf", "woman", "work", "worthy", "wrap", "wrist", "writing", "wrote",
+    "year", "yelp", "yield", "yoga", "zero",
+];
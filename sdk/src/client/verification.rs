@@ -0,0 +1,400 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verification of node responses against milestones signed by trusted public keys.
+//!
+//! When a [`Client`](crate::client::Client) is built with [`VerificationOptions`], the responses of
+//! `get_output`, `get_block_metadata` and `get_included_block` are only returned if the node can prove them. The
+//! block they relate to is requested with a proof of inclusion from the `poi` plugin of the node. The proof must lead
+//! to the inclusion Merkle root of the milestone that referenced the block, and that milestone must be signed by enough
+//! trusted public keys and link to the other milestones verified so far.
+//!
+//! A proof of inclusion shows that a block was referenced by a milestone, not that its transaction was applied to the
+//! ledger, and that an output is unspent can't be proven at all. Metadata of conflicting blocks and unspent outputs are
+//! therefore rejected, unless [`VerificationOptions::accept_unverifiable_state`] is set to trust the node for them;
+//! the quorum of the node manager can then be used to cross-check those answers.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{ClientInner, Result},
+    types::{
+        api::core::response::{BlockMetadataResponse, LedgerInclusionState},
+        block::{
+            input::Input,
+            output::{OutputId, OutputWithMetadata},
+            payload::{
+                milestone::{MilestoneId, MilestonePayload},
+                transaction::{TransactionEssence, TransactionId, TransactionPayload},
+                Payload,
+            },
+            Block, BlockId,
+        },
+        TryFromDto,
+    },
+};
+
+/// The number of verified milestones kept to check that new milestones link to them.
+const MAX_VERIFIED_MILESTONES: usize = 1000;
+
+/// Errors of the verification of node responses.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The node returned another block than the requested one.
+    #[error("block {found} doesn't match the requested block {expected}")]
+    BlockMismatch {
+        /// The requested block.
+        expected: BlockId,
+        /// The returned block.
+        found: BlockId,
+    },
+    /// The proof of inclusion doesn't lead to the inclusion Merkle root of the milestone.
+    #[error("invalid proof of inclusion for block {0}")]
+    InvalidProof(BlockId),
+    /// The milestone isn't signed by enough trusted public keys.
+    #[error("milestone {index} isn't signed by the trusted public keys: {reason}")]
+    InvalidMilestone {
+        /// The index of the milestone.
+        index: u32,
+        /// Why the signatures were rejected.
+        reason: String,
+    },
+    /// The milestone doesn't link to the milestones verified before.
+    #[error("milestone {0} doesn't link to the verified milestones")]
+    MilestoneChain(u32),
+    /// The block was referenced by another milestone than the one the node claimed.
+    #[error("block was referenced by milestone {found} instead of milestone {expected}")]
+    MilestoneIndexMismatch {
+        /// The milestone index claimed by the node.
+        expected: u32,
+        /// The milestone index of the proof.
+        found: u32,
+    },
+    /// The transaction that created the output contains another output.
+    #[error("output {0} doesn't match the transaction that created it")]
+    OutputMismatch(OutputId),
+    /// The transaction claimed to spend an output doesn't spend it.
+    #[error("transaction {transaction_id} doesn't spend output {output_id}")]
+    SpentOutputMismatch {
+        /// The spent output.
+        output_id: OutputId,
+        /// The spending transaction.
+        transaction_id: TransactionId,
+    },
+    /// The block doesn't contain the transaction.
+    #[error("block {block_id} doesn't contain transaction {transaction_id}")]
+    TransactionMismatch {
+        /// The block.
+        block_id: BlockId,
+        /// The transaction.
+        transaction_id: TransactionId,
+    },
+    /// The block is conflicting, which a proof of inclusion can't show.
+    #[error("block {0} is conflicting, which can't be verified")]
+    UnverifiableConflict(BlockId),
+    /// The output isn't part of a block and has no proof of inclusion, like the outputs of the genesis snapshot.
+    #[error("output {0} wasn't created by a block and can't be verified")]
+    UnverifiableOutput(OutputId),
+    /// The output is unspent, which a proof of inclusion can't show.
+    #[error("output {0} is unspent, which can't be verified")]
+    UnverifiableUnspentOutput(OutputId),
+}
+
+/// A trusted milestone public key and the range of milestone indexes it is applicable to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneKeyRange {
+    /// The hex encoded Ed25519 public key.
+    pub public_key: String,
+    /// The first milestone index the key is applicable to.
+    pub start: u32,
+    /// The last milestone index the key is applicable to, 0 if it has no end.
+    pub end: u32,
+}
+
+impl MilestoneKeyRange {
+    /// Creates a new [`MilestoneKeyRange`].
+    pub fn new(public_key: impl Into<String>, start: u32, end: u32) -> Self {
+        Self {
+            public_key: public_key.into(),
+            start,
+            end,
+        }
+    }
+
+    fn is_applicable(&self, index: u32) -> bool {
+        index >= self.start && (self.end == 0 || index <= self.end)
+    }
+}
+
+/// Options to verify node responses against milestones signed by trusted public keys.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationOptions {
+    /// The number of trusted public keys a milestone needs to be signed with.
+    pub public_key_count: usize,
+    /// The trusted public keys.
+    pub key_ranges: Vec<MilestoneKeyRange>,
+    /// Whether to trust the node for the state that can't be proven: that outputs are unspent and blocks conflicting.
+    #[serde(default)]
+    pub accept_unverifiable_state: bool,
+}
+
+impl VerificationOptions {
+    /// Creates new [`VerificationOptions`] that reject the state that can't be proven.
+    pub fn new(public_key_count: usize, key_ranges: impl Into<Vec<MilestoneKeyRange>>) -> Self {
+        Self {
+            public_key_count,
+            key_ranges: key_ranges.into(),
+            accept_unverifiable_state: false,
+        }
+    }
+
+    /// Sets whether to trust the node that outputs are unspent and blocks conflicting.
+    pub fn with_accept_unverifiable_state(mut self, accept_unverifiable_state: bool) -> Self {
+        self.accept_unverifiable_state = accept_unverifiable_state;
+        self
+    }
+}
+
+/// Verifies milestones with the trusted public keys and keeps track of the verified ones.
+#[derive(Debug)]
+pub(crate) struct Verifier {
+    pub(crate) options: VerificationOptions,
+    // Milestone ID and previous milestone ID of verified milestones, by index.
+    milestones: Mutex<BTreeMap<u32, (MilestoneId, MilestoneId)>>,
+}
+
+impl Verifier {
+    pub(crate) fn new(options: VerificationOptions) -> Self {
+        Self {
+            options,
+            milestones: Default::default(),
+        }
+    }
+
+    fn verify_milestone(&self, milestone: &MilestonePayload) -> std::result::Result<(), Error> {
+        let index = *milestone.essence().index();
+        let public_keys = self
+            .options
+            .key_ranges
+            .iter()
+            .filter(|range| range.is_applicable(index))
+            .map(|range| range.public_key.trim_start_matches("0x").to_ascii_lowercase())
+            .collect::<Vec<_>>();
+
+        milestone
+            .validate(&public_keys, self.options.public_key_count)
+            .map_err(|error| Error::InvalidMilestone {
+                index,
+                reason: format!("{error:?}"),
+            })?;
+
+        let milestone_id = milestone.id();
+        let previous_milestone_id = *milestone.essence().previous_milestone_id();
+        let mut milestones = self.milestones.lock().expect("verified milestones lock poisoned");
+
+        let links = milestones.get(&index).map_or(true, |(id, _)| *id == milestone_id)
+            && index
+                .checked_sub(1)
+                .and_then(|previous_index| milestones.get(&previous_index))
+                .map_or(true, |(id, _)| *id == previous_milestone_id)
+            && index
+                .checked_add(1)
+                .and_then(|next_index| milestones.get(&next_index))
+                .map_or(true, |(_, previous_id)| *previous_id == milestone_id);
+
+        if !links {
+            return Err(Error::MilestoneChain(index));
+        }
+
+        milestones.insert(index, (milestone_id, previous_milestone_id));
+
+        if milestones.len() > MAX_VERIFIED_MILESTONES {
+            milestones.pop_first();
+        }
+
+        Ok(())
+    }
+}
+
+fn transaction_payload(block: &Block) -> Option<&TransactionPayload> {
+    match block.payload() {
+        Some(Payload::Transaction(transaction)) => Some(transaction),
+        _ => None,
+    }
+}
+
+impl ClientInner {
+    /// Returns the options used to verify node responses, if the client verifies them.
+    pub async fn verification_options(&self) -> Option<VerificationOptions> {
+        self.verifier
+            .read()
+            .await
+            .as_ref()
+            .map(|verifier| verifier.options.clone())
+    }
+
+    /// Sets the options used to verify node responses, the milestones verified so far are kept if they don't change.
+    pub async fn set_verification_options(&self, options: Option<VerificationOptions>) {
+        let mut verifier = self.verifier.write().await;
+
+        if verifier.as_ref().map(|verifier| &verifier.options) != options.as_ref() {
+            *verifier = options.map(|options| Arc::new(Verifier::new(options)));
+        }
+    }
+
+    async fn verifier(&self) -> Option<Arc<Verifier>> {
+        self.verifier.read().await.clone()
+    }
+
+    pub(crate) async fn verify_block_metadata(
+        &self,
+        block_id: &BlockId,
+        metadata: &BlockMetadataResponse,
+    ) -> Result<()> {
+        let Some(verifier) = &self.verifier().await else {
+            return Ok(());
+        };
+
+        if metadata.block_id != *block_id {
+            return Err(Error::BlockMismatch {
+                expected: *block_id,
+                found: metadata.block_id,
+            })?;
+        }
+
+        // Blocks that aren't referenced yet have nothing to prove, their metadata is needed to promote and reattach
+        // them
+        if let Some(milestone_index) = metadata.referenced_by_milestone_index {
+            let (_, milestone) = self.verified_inclusion(verifier, block_id).await?;
+            check_milestone_index(milestone_index, &milestone)?;
+
+            if metadata.ledger_inclusion_state == Some(LedgerInclusionState::Conflicting)
+                && !verifier.options.accept_unverifiable_state
+            {
+                return Err(Error::UnverifiableConflict(*block_id))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn verify_included_block(&self, transaction_id: &TransactionId, block: &Block) -> Result<()> {
+        let Some(verifier) = &self.verifier().await else {
+            return Ok(());
+        };
+
+        self.verified_transaction(verifier, transaction_id, &block.id())
+            .await
+            .map(|_| ())
+    }
+
+    pub(crate) async fn verify_output(&self, output_id: &OutputId, output: &OutputWithMetadata) -> Result<()> {
+        let Some(verifier) = &self.verifier().await else {
+            return Ok(());
+        };
+        let metadata = output.metadata();
+
+        if metadata.output_id() != output_id {
+            return Err(Error::OutputMismatch(*output_id))?;
+        }
+        if metadata.block_id().is_null() {
+            return Err(Error::UnverifiableOutput(*output_id))?;
+        }
+
+        let (transaction, milestone) = self
+            .verified_transaction(verifier, output_id.transaction_id(), metadata.block_id())
+            .await?;
+        check_milestone_index(metadata.milestone_index_booked(), &milestone)?;
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        if essence.outputs().get(output_id.index() as usize) != Some(output.output()) {
+            return Err(Error::OutputMismatch(*output_id))?;
+        }
+
+        let Some(transaction_id_spent) = metadata.transaction_id_spent() else {
+            if verifier.options.accept_unverifiable_state {
+                return Ok(());
+            }
+            return Err(Error::UnverifiableUnspentOutput(*output_id))?;
+        };
+
+        let block_id = self.get_included_block_metadata(transaction_id_spent).await?.block_id;
+        let (transaction, milestone) = self
+            .verified_transaction(verifier, transaction_id_spent, &block_id)
+            .await?;
+        check_milestone_index(metadata.milestone_index_spent().unwrap_or_default(), &milestone)?;
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        if !essence
+            .inputs()
+            .iter()
+            .any(|input| matches!(input, Input::Utxo(input) if input.output_id() == output_id))
+        {
+            return Err(Error::SpentOutputMismatch {
+                output_id: *output_id,
+                transaction_id: *transaction_id_spent,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    // Verifies that the block contains the transaction and was referenced by a trusted milestone.
+    async fn verified_transaction(
+        &self,
+        verifier: &Verifier,
+        transaction_id: &TransactionId,
+        block_id: &BlockId,
+    ) -> Result<(TransactionPayload, MilestonePayload)> {
+        let (block, milestone) = self.verified_inclusion(verifier, block_id).await?;
+
+        match transaction_payload(&block) {
+            Some(transaction) if transaction.id() == *transaction_id => Ok((transaction.clone(), milestone)),
+            _ => Err(Error::TransactionMismatch {
+                block_id: *block_id,
+                transaction_id: *transaction_id,
+            })?,
+        }
+    }
+
+    // Verifies that the block was referenced by a trusted milestone.
+    async fn verified_inclusion(&self, verifier: &Verifier, block_id: &BlockId) -> Result<(Block, MilestonePayload)> {
+        let response = self.get_inclusion_proof(block_id).await?;
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let block = Block::try_from_dto_with_params(response.block, &protocol_parameters)?;
+        let milestone = MilestonePayload::try_from_dto_with_params(response.milestone, &protocol_parameters)?;
+
+        if block.id() != *block_id {
+            return Err(Error::BlockMismatch {
+                expected: *block_id,
+                found: block.id(),
+            })?;
+        }
+        if response.proof.value() != Some(block_id)
+            || response.proof.root() != *milestone.essence().inclusion_merkle_root()
+        {
+            return Err(Error::InvalidProof(*block_id))?;
+        }
+
+        verifier.verify_milestone(&milestone)?;
+
+        Ok((block, milestone))
+    }
+}
+
+fn check_milestone_index(expected: u32, milestone: &MilestonePayload) -> std::result::Result<(), Error> {
+    let found = *milestone.essence().index();
+
+    if expected == found {
+        Ok(())
+    } else {
+        Err(Error::MilestoneIndexMismatch { expected, found })
+    }
+}
@@ -5,3 +5,5 @@
 
 pub mod indexer;
 pub mod participation;
+#[cfg(feature = "serde")]
+pub mod poi;
@@ -0,0 +1,22 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Proof-of-inclusion plugin responses.
+//! Types from <https://github.com/iotaledger/inx-poi/blob/develop/pkg/poi/proof.go>
+
+use crate::types::block::{
+    payload::milestone::{dto::MilestonePayloadDto, MerkleProof},
+    BlockDto,
+};
+
+/// Response of GET /api/poi/v1/create/{block_id}.
+/// Returns the proof that a block was referenced by a milestone.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ProofResponse {
+    /// The milestone that referenced the block.
+    pub milestone: MilestonePayloadDto,
+    /// The proven block.
+    pub block: BlockDto,
+    /// The audit path from the block ID to the inclusion Merkle root of the milestone.
+    pub proof: MerkleProof,
+}
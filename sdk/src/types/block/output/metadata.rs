@@ -1,7 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::types::block::{output::OutputId, payload::transaction::TransactionId, BlockId};
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+use crate::types::block::{output::OutputId, payload::transaction::TransactionId, BlockId, Error};
 
 /// Metadata of an [`Output`](crate::types::block::output::Output).
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -114,6 +121,45 @@ impl OutputMetadata {
     }
 }
 
+impl Packable for OutputMetadata {
+    type UnpackError = Error;
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.block_id.pack(packer)?;
+        self.output_id.pack(packer)?;
+        self.is_spent.pack(packer)?;
+        self.milestone_index_spent.pack(packer)?;
+        self.milestone_timestamp_spent.pack(packer)?;
+        self.transaction_id_spent.pack(packer)?;
+        self.milestone_index_booked.pack(packer)?;
+        self.milestone_timestamp_booked.pack(packer)?;
+        self.ledger_index.pack(packer)?;
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Self {
+            block_id: BlockId::unpack::<_, VERIFY>(unpacker, visitor).coerce()?,
+            output_id: OutputId::unpack::<_, VERIFY>(unpacker, visitor)?,
+            is_spent: bool::unpack::<_, VERIFY>(unpacker, visitor).coerce()?,
+            milestone_index_spent: Option::<u32>::unpack::<_, VERIFY>(unpacker, visitor)
+                .map_packable_err(|_| Error::InvalidField("milestone_index_spent"))?,
+            milestone_timestamp_spent: Option::<u32>::unpack::<_, VERIFY>(unpacker, visitor)
+                .map_packable_err(|_| Error::InvalidField("milestone_timestamp_spent"))?,
+            transaction_id_spent: Option::<TransactionId>::unpack::<_, VERIFY>(unpacker, visitor)
+                .map_packable_err(|_| Error::InvalidField("transaction_id_spent"))?,
+            milestone_index_booked: u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?,
+            milestone_timestamp_booked: u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?,
+            ledger_index: u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?,
+        })
+    }
+}
+
 #[cfg(feature = "serde")]
 mod dto {
     use serde::{Deserialize, Serialize};
@@ -1,7 +1,11 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::types::block::Error;
+use alloc::{boxed::Box, vec::Vec};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+
+use crate::types::block::{BlockId, Error};
 
 /// A Merkle root of a list of hashes.
 #[derive(Clone, Copy, Eq, PartialEq, packable::Packable, derive_more::From, derive_more::AsRef)]
@@ -50,3 +54,135 @@ impl core::str::FromStr for MerkleRoot {
         Ok(Self::new(prefix_hex::decode(s).map_err(Error::Hex)?))
     }
 }
+
+const LEAF_HASH_PREFIX: u8 = 0;
+const NODE_HASH_PREFIX: u8 = 1;
+
+fn leaf_hash(block_id: &BlockId) -> [u8; MerkleRoot::LENGTH] {
+    Blake2b256::new()
+        .chain_update([LEAF_HASH_PREFIX])
+        .chain_update(block_id)
+        .finalize()
+        .into()
+}
+
+fn node_hash(left: &[u8], right: &[u8]) -> [u8; MerkleRoot::LENGTH] {
+    Blake2b256::new()
+        .chain_update([NODE_HASH_PREFIX])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+// The largest power of two strictly less than `len`, with `len > 1`.
+fn split_point(len: usize) -> usize {
+    1 << (usize::BITS - 1 - (len - 1).leading_zeros())
+}
+
+/// Computes the Merkle trees of milestone essences, as defined in TIP-0004.
+pub struct MerkleHasher;
+
+impl MerkleHasher {
+    /// Computes the Merkle root of a list of block IDs, in the order in which they were referenced.
+    pub fn digest(block_ids: &[BlockId]) -> MerkleRoot {
+        MerkleRoot::new(Self::hash(block_ids))
+    }
+
+    /// Computes the audit path proving that the block ID at `index` is part of the Merkle tree of `block_ids`.
+    pub fn proof(block_ids: &[BlockId], index: usize) -> Option<MerkleProof> {
+        (index < block_ids.len()).then(|| Self::proof_inner(block_ids, index))
+    }
+
+    fn hash(block_ids: &[BlockId]) -> [u8; MerkleRoot::LENGTH] {
+        match block_ids {
+            [] => Blake2b256::digest([]).into(),
+            [block_id] => leaf_hash(block_id),
+            _ => {
+                let (left, right) = block_ids.split_at(split_point(block_ids.len()));
+                node_hash(&Self::hash(left), &Self::hash(right))
+            }
+        }
+    }
+
+    fn proof_inner(block_ids: &[BlockId], index: usize) -> MerkleProof {
+        if let [block_id] = block_ids {
+            return MerkleProof::Value { value: *block_id };
+        }
+
+        let (left, right) = block_ids.split_at(split_point(block_ids.len()));
+
+        if index < left.len() {
+            MerkleProof::Node {
+                l: Box::new(Self::proof_inner(left, index)),
+                r: Box::new(MerkleProof::Hash { h: Self::hash(right) }),
+            }
+        } else {
+            MerkleProof::Node {
+                l: Box::new(MerkleProof::Hash { h: Self::hash(left) }),
+                r: Box::new(Self::proof_inner(right, index - left.len())),
+            }
+        }
+    }
+}
+
+/// An audit path proving that a block ID is part of a Merkle tree, as returned by the proof-of-inclusion plugin.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum MerkleProof {
+    /// An inner node of the tree.
+    Node {
+        /// The left subtree.
+        l: Box<Self>,
+        /// The right subtree.
+        r: Box<Self>,
+    },
+    /// The hash of a subtree that doesn't contain the proven value.
+    Hash {
+        /// The hash of the subtree.
+        #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde::prefix_hex_bytes"))]
+        h: [u8; MerkleRoot::LENGTH],
+    },
+    /// The proven value.
+    Value {
+        /// The proven block ID.
+        value: BlockId,
+    },
+}
+
+impl MerkleProof {
+    /// Computes the Merkle root the proof leads to.
+    pub fn root(&self) -> MerkleRoot {
+        MerkleRoot::new(self.hash())
+    }
+
+    /// Returns the proven block ID, if the proof contains exactly one.
+    pub fn value(&self) -> Option<&BlockId> {
+        let mut values = Vec::new();
+        self.collect_values(&mut values);
+
+        match values.as_slice() {
+            [value] => Some(value),
+            _ => None,
+        }
+    }
+
+    fn hash(&self) -> [u8; MerkleRoot::LENGTH] {
+        match self {
+            Self::Node { l, r } => node_hash(&l.hash(), &r.hash()),
+            Self::Hash { h } => *h,
+            Self::Value { value } => leaf_hash(value),
+        }
+    }
+
+    fn collect_values<'a>(&'a self, values: &mut Vec<&'a BlockId>) {
+        match self {
+            Self::Node { l, r } => {
+                l.collect_values(values);
+                r.collect_values(values);
+            }
+            Self::Hash { .. } => {}
+            Self::Value { value } => values.push(value),
+        }
+    }
+}
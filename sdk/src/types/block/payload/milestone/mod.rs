@@ -22,7 +22,7 @@ use packable::{bounded::BoundedU8, prefix::VecPrefix, Packable};
 pub use self::{
     essence::MilestoneEssence,
     index::MilestoneIndex,
-    merkle::MerkleRoot,
+    merkle::{MerkleHasher, MerkleProof, MerkleRoot},
     milestone_id::MilestoneId,
     option::{MilestoneOption, MilestoneOptions, ParametersMilestoneOption, ReceiptMilestoneOption},
 };
@@ -4,7 +4,8 @@
 use alloc::string::String;
 use core::borrow::Borrow;
 
-use packable::{prefix::StringPrefix, Packable};
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{prefix::StringPrefix, Packable, PackableExt};
 
 use super::address::Hrp;
 use crate::types::block::{helper::network_name_to_id, output::RentStructure, ConvertTo, Error, PROTOCOL_VERSION};
@@ -122,6 +123,11 @@ impl ProtocolParameters {
     pub fn token_supply(&self) -> u64 {
         self.token_supply
     }
+
+    /// Returns the hash of the packed [`ProtocolParameters`].
+    pub fn hash(&self) -> [u8; 32] {
+        Blake2b256::digest(self.pack_to_vec()).into()
+    }
 }
 
 /// Returns a [`ProtocolParameters`] for testing purposes.
@@ -168,6 +168,7 @@ pub struct ValidationContext<'a> {
     pub storage_deposit_returns: HashMap<Address, u64>,
     ///
     pub simple_deposits: HashMap<Address, u64>,
+    verify_signatures: bool,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -212,8 +213,20 @@ impl<'a> ValidationContext<'a> {
             unlocked_addresses: HashSet::new(),
             storage_deposit_returns: HashMap::new(),
             simple_deposits: HashMap::new(),
+            verify_signatures: true,
         }
     }
+
+    /// Skips the verification of signatures, to validate a transaction with placeholder signature unlocks.
+    pub(crate) fn without_signature_verification(mut self) -> Self {
+        self.verify_signatures = false;
+        self
+    }
+
+    /// Whether the signatures of signature unlocks are verified.
+    pub(crate) fn verify_signatures(&self) -> bool {
+        self.verify_signatures
+    }
 }
 
 ///
@@ -0,0 +1,252 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-memory UTXO ledger that applies transactions the way a node confirms them.
+//!
+//! The [`UtxoLedger`] holds a set of unspent outputs and the current state of all alias, foundry and NFT chains.
+//! Transactions are checked against it with [`semantic_validation`] and, if they don't conflict, consume their inputs
+//! and create their outputs. This allows dry-running sequences of dependent transactions, e.g. creating an alias,
+//! then a foundry and then minting native tokens, without submitting anything to a network.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::types::block::{
+    input::Input,
+    output::{AliasId, ChainId, FoundryId, NftId, Output, OutputId},
+    payload::{
+        transaction::{TransactionEssence, TransactionId},
+        TransactionPayload,
+    },
+    semantic::{semantic_validation, ConflictReason, ValidationContext},
+};
+
+/// An output that was consumed by a transaction applied to a [`UtxoLedger`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpentOutput {
+    output: Output,
+    transaction_id: TransactionId,
+    milestone_index: u32,
+    milestone_timestamp: u32,
+}
+
+impl SpentOutput {
+    /// Returns the spent output.
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    /// Returns the id of the transaction that consumed the output.
+    pub fn transaction_id(&self) -> &TransactionId {
+        &self.transaction_id
+    }
+
+    /// Returns the index of the milestone in which the output was consumed.
+    pub fn milestone_index(&self) -> u32 {
+        self.milestone_index
+    }
+
+    /// Returns the timestamp of the milestone in which the output was consumed.
+    pub fn milestone_timestamp(&self) -> u32 {
+        self.milestone_timestamp
+    }
+}
+
+/// An in-memory UTXO set that tracks the state of alias, foundry and NFT chains and applies transactions to it.
+///
+/// Transactions are applied in the context of a milestone, which provides the timestamp used to check timelocks and
+/// expirations. Inputs consumed twice within the same milestone conflict with
+/// [`ConflictReason::InputUtxoAlreadySpentInThisMilestone`], inputs consumed in a previous one with
+/// [`ConflictReason::InputUtxoAlreadySpent`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UtxoLedger {
+    milestone_index: u32,
+    milestone_timestamp: u32,
+    unspent_outputs: BTreeMap<OutputId, Output>,
+    // Spent outputs are kept until they get pruned.
+    spent_outputs: BTreeMap<OutputId, SpentOutput>,
+    // The output that currently holds the state of each chain.
+    chains: BTreeMap<ChainId, OutputId>,
+}
+
+impl UtxoLedger {
+    /// Creates an empty [`UtxoLedger`] at the given milestone.
+    pub fn new(milestone_index: u32, milestone_timestamp: u32) -> Self {
+        Self {
+            milestone_index,
+            milestone_timestamp,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the index of the current milestone.
+    pub fn milestone_index(&self) -> u32 {
+        self.milestone_index
+    }
+
+    /// Returns the timestamp of the current milestone.
+    pub fn milestone_timestamp(&self) -> u32 {
+        self.milestone_timestamp
+    }
+
+    /// Moves the ledger to the next milestone with the given timestamp and returns its index.
+    pub fn next_milestone(&mut self, milestone_timestamp: u32) -> u32 {
+        self.milestone_index += 1;
+        self.milestone_timestamp = milestone_timestamp;
+        self.milestone_index
+    }
+
+    /// Adds an unspent output without a transaction consuming any inputs, e.g. to seed the ledger with genesis
+    /// outputs. An output with the same id gets replaced.
+    pub fn add_output(&mut self, output_id: OutputId, output: Output) {
+        if let Some(chain_id) = output.chain_id() {
+            self.chains.insert(chain_id.or_from_output_id(&output_id), output_id);
+        }
+        self.spent_outputs.remove(&output_id);
+        self.unspent_outputs.insert(output_id, output);
+    }
+
+    /// Returns an unspent output.
+    pub fn output(&self, output_id: &OutputId) -> Option<&Output> {
+        self.unspent_outputs.get(output_id)
+    }
+
+    /// Returns a spent output, `None` if it's unspent, unknown or pruned.
+    pub fn spent_output(&self, output_id: &OutputId) -> Option<&SpentOutput> {
+        self.spent_outputs.get(output_id)
+    }
+
+    /// Returns whether an output is known and unspent.
+    pub fn is_unspent(&self, output_id: &OutputId) -> bool {
+        self.unspent_outputs.contains_key(output_id)
+    }
+
+    /// Returns all unspent outputs, ordered by their id.
+    pub fn unspent_outputs(&self) -> impl Iterator<Item = (&OutputId, &Output)> + '_ {
+        self.unspent_outputs.iter()
+    }
+
+    /// Returns all spent outputs that were not pruned yet, ordered by their id.
+    pub fn spent_outputs(&self) -> impl Iterator<Item = (&OutputId, &SpentOutput)> + '_ {
+        self.spent_outputs.iter()
+    }
+
+    /// Returns the unspent output that currently holds the state of a chain.
+    pub fn chain_output(&self, chain_id: &ChainId) -> Option<(&OutputId, &Output)> {
+        self.chains
+            .get(chain_id)
+            .and_then(|output_id| self.unspent_outputs.get_key_value(output_id))
+    }
+
+    /// Returns the unspent output that currently holds the state of an alias.
+    pub fn alias_output(&self, alias_id: &AliasId) -> Option<(&OutputId, &Output)> {
+        self.chain_output(&ChainId::from(*alias_id))
+    }
+
+    /// Returns the unspent output that currently holds the state of a foundry.
+    pub fn foundry_output(&self, foundry_id: &FoundryId) -> Option<(&OutputId, &Output)> {
+        self.chain_output(&ChainId::from(*foundry_id))
+    }
+
+    /// Returns the unspent output that currently holds the state of an NFT.
+    pub fn nft_output(&self, nft_id: &NftId) -> Option<(&OutputId, &Output)> {
+        self.chain_output(&ChainId::from(*nft_id))
+    }
+
+    /// Checks a transaction against the ledger without applying it.
+    pub fn validate_transaction(&self, transaction: &TransactionPayload) -> ConflictReason {
+        let mut inputs = Vec::new();
+
+        for output_id in input_ids(transaction) {
+            match (self.unspent_outputs.get(&output_id), self.spent_outputs.get(&output_id)) {
+                (Some(output), _) => inputs.push((output_id, output)),
+                (None, Some(spent_output)) if spent_output.milestone_index == self.milestone_index => {
+                    return ConflictReason::InputUtxoAlreadySpentInThisMilestone;
+                }
+                (None, Some(_)) => return ConflictReason::InputUtxoAlreadySpent,
+                (None, None) => return ConflictReason::InputUtxoNotFound,
+            }
+        }
+
+        let transaction_id = transaction.id();
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        let inputs = inputs.iter().map(|(id, output)| (id, *output)).collect::<Vec<_>>();
+        let context = ValidationContext::new(
+            &transaction_id,
+            essence,
+            inputs.iter().copied(),
+            transaction.unlocks(),
+            self.milestone_timestamp,
+        );
+
+        semantic_validation(context, &inputs, transaction.unlocks()).unwrap_or(ConflictReason::SemanticValidationFailed)
+    }
+
+    /// Applies a transaction to the ledger in the current milestone: consumes its inputs, creates its outputs and
+    /// moves the chains it transitions. The ledger is left untouched if the transaction conflicts.
+    pub fn apply_transaction(&mut self, transaction: &TransactionPayload) -> Result<(), ConflictReason> {
+        match self.validate_transaction(transaction) {
+            ConflictReason::None => {}
+            conflict_reason => return Err(conflict_reason),
+        }
+
+        let transaction_id = transaction.id();
+
+        for output_id in input_ids(transaction) {
+            if let Some(output) = self.unspent_outputs.remove(&output_id) {
+                if let Some(chain_id) = output.chain_id() {
+                    self.chains.remove(&chain_id.or_from_output_id(&output_id));
+                }
+                self.spent_outputs.insert(
+                    output_id,
+                    SpentOutput {
+                        output,
+                        transaction_id,
+                        milestone_index: self.milestone_index,
+                        milestone_timestamp: self.milestone_timestamp,
+                    },
+                );
+            }
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        for (index, output) in essence.outputs().iter().enumerate() {
+            // PANIC: the output count is bounded by the transaction essence.
+            let output_id = OutputId::new(transaction_id, index as u16).unwrap();
+            self.add_output(output_id, output.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Forgets all outputs that were spent in milestones up to and including the given index.
+    pub fn prune(&mut self, milestone_index: u32) {
+        self.spent_outputs
+            .retain(|_, spent_output| spent_output.milestone_index > milestone_index);
+    }
+
+    /// Takes a snapshot of the current state of the ledger.
+    pub fn snapshot(&self) -> UtxoLedgerSnapshot {
+        UtxoLedgerSnapshot(self.clone())
+    }
+
+    /// Restores the ledger to the state of a snapshot, discarding everything applied since.
+    pub fn restore(&mut self, snapshot: UtxoLedgerSnapshot) {
+        *self = snapshot.0;
+    }
+}
+
+/// A snapshot of a [`UtxoLedger`], see [`UtxoLedger::snapshot()`] and [`UtxoLedger::restore()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtxoLedgerSnapshot(UtxoLedger);
+
+fn input_ids(transaction: &TransactionPayload) -> Vec<OutputId> {
+    let TransactionEssence::Regular(essence) = transaction.essence();
+    essence
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::Utxo(input) => Some(*input.output_id()),
+            Input::Treasury(_) => None,
+        })
+        .collect()
+}
@@ -9,6 +9,7 @@ extern crate std;
 #[cfg(feature = "serde")]
 pub mod api;
 pub mod block;
+pub mod ledger;
 
 use core::ops::Deref;
 
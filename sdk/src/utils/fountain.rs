@@ -0,0 +1,454 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fountain coding of packed data into a sequence of parts, e.g. to show them as animated QR codes.
+//!
+//! The first [`Encoder::sequence_count()`] parts contain the fragments of the message in order, every following part
+//! is the XOR of a pseudo-randomly chosen set of fragments. A [`Decoder`] can reassemble the message from any
+//! sufficiently large set of parts, in any order, so missed frames don't have to be waited for again. Parts can be
+//! represented as UR-style strings: `ur:<type>/<sequence>-<sequence count>/<packed part as hex>`.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{convert::Infallible, fmt};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{
+    error::UnpackError,
+    prefix::{BoxedSlicePrefix, UnpackPrefixError},
+    Packable, PackableExt,
+};
+
+/// The maximum number of fragments a message can be split into.
+pub const MAX_SEQUENCE_COUNT: u32 = 1 << 16;
+
+/// Errors of the fountain encoder and decoder.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Empty messages can't be encoded.
+    EmptyMessage,
+    /// Not enough parts were received to decode the message.
+    IncompleteMessage,
+    /// Invalid fragment length.
+    InvalidFragmentLength(usize),
+    /// Invalid number of parts to generate.
+    InvalidPartCount(u32),
+    /// The checksum of the decoded message doesn't match.
+    InvalidChecksum,
+    /// Invalid part.
+    InvalidPart(String),
+    /// Invalid UR string.
+    InvalidUr(String),
+    /// The part belongs to another message.
+    MismatchingPart,
+    /// The decoded message can't be unpacked.
+    Unpack(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyMessage => write!(f, "can't encode an empty message"),
+            Self::IncompleteMessage => write!(f, "not enough parts were received to decode the message"),
+            Self::InvalidFragmentLength(length) => write!(f, "invalid fragment length: {length}"),
+            Self::InvalidPartCount(count) => write!(f, "invalid part count: {count}"),
+            Self::InvalidChecksum => write!(f, "the checksum of the decoded message doesn't match"),
+            Self::InvalidPart(reason) => write!(f, "invalid part: {reason}"),
+            Self::InvalidUr(ur) => write!(f, "invalid UR: {ur}"),
+            Self::MismatchingPart => write!(f, "the part belongs to another message"),
+            Self::Unpack(error) => write!(f, "can't unpack the decoded message: {error}"),
+        }
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+/// A part of a fountain coded message.
+#[derive(Clone, Debug, Eq, PartialEq, Packable)]
+#[packable(unpack_error = Error)]
+pub struct Part {
+    sequence: u32,
+    sequence_count: u32,
+    message_length: u32,
+    checksum: u32,
+    #[packable(unpack_error_with = |e: UnpackPrefixError<Infallible, Infallible>| Error::InvalidPart(format!("{e:?}")))]
+    data: BoxedSlicePrefix<u8, u32>,
+}
+
+impl Part {
+    /// Returns the sequence number of the part, starting at 1.
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// Returns the number of fragments of the message.
+    pub fn sequence_count(&self) -> u32 {
+        self.sequence_count
+    }
+
+    /// Returns the length of the encoded message.
+    pub fn message_length(&self) -> u32 {
+        self.message_length
+    }
+
+    /// Returns the checksum of the encoded message.
+    pub fn checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    /// Returns the data of the part.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the part as UR-style string with the given type.
+    pub fn to_ur(&self, ur_type: &str) -> String {
+        format!(
+            "ur:{ur_type}/{}-{}/{}",
+            self.sequence,
+            self.sequence_count,
+            prefix_hex::encode(self.pack_to_vec()).trim_start_matches("0x")
+        )
+    }
+
+    /// Parses a part from a UR-style string, case insensitive. Returns the UR type and the part.
+    pub fn try_from_ur(ur: &str) -> Result<(String, Self), Error> {
+        let invalid_ur = || Error::InvalidUr(ur.into());
+        let lowercase_ur = ur.trim().to_ascii_lowercase();
+        let mut components = lowercase_ur.strip_prefix("ur:").ok_or_else(invalid_ur)?.split('/');
+        let (Some(ur_type), Some(sequence), Some(data), None) =
+            (components.next(), components.next(), components.next(), components.next())
+        else {
+            return Err(invalid_ur());
+        };
+        if ur_type.is_empty()
+            || !ur_type
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(invalid_ur());
+        }
+
+        let bytes = prefix_hex::decode::<Vec<u8>>(format!("0x{data}")).map_err(|_| invalid_ur())?;
+        let part = Self::unpack_verified(bytes, &()).map_err(|e| match e {
+            UnpackError::Packable(e) => e,
+            UnpackError::Unpacker(_) => invalid_ur(),
+        })?;
+        if sequence != format!("{}-{}", part.sequence, part.sequence_count) {
+            return Err(invalid_ur());
+        }
+
+        Ok((ur_type.into(), part))
+    }
+}
+
+/// Encodes a message into an endless sequence of [`Part`]s.
+#[derive(Clone, Debug)]
+pub struct Encoder {
+    message_length: u32,
+    checksum: u32,
+    fragments: Vec<Vec<u8>>,
+    sequence: u32,
+}
+
+impl Encoder {
+    /// Creates an encoder which splits the message in fragments of at most `max_fragment_length` bytes.
+    pub fn new(message: &[u8], max_fragment_length: usize) -> Result<Self, Error> {
+        if message.is_empty() {
+            return Err(Error::EmptyMessage);
+        }
+        if max_fragment_length == 0 {
+            return Err(Error::InvalidFragmentLength(max_fragment_length));
+        }
+        let message_length =
+            u32::try_from(message.len()).map_err(|_| Error::InvalidFragmentLength(max_fragment_length))?;
+
+        // Fragments of equal length, so the padding of the last one is minimal
+        let fragment_count = message.len().div_ceil(max_fragment_length);
+        if fragment_count > MAX_SEQUENCE_COUNT as usize {
+            return Err(Error::InvalidFragmentLength(max_fragment_length));
+        }
+        let fragment_length = message.len().div_ceil(fragment_count);
+        let fragments = message
+            .chunks(fragment_length)
+            .map(|chunk| {
+                let mut fragment = chunk.to_vec();
+                fragment.resize(fragment_length, 0);
+                fragment
+            })
+            .collect();
+
+        Ok(Self {
+            message_length,
+            checksum: checksum(message),
+            fragments,
+            sequence: 0,
+        })
+    }
+
+    /// Creates an encoder for the packed bytes of a value.
+    pub fn from_packable<P: PackableExt>(value: &P, max_fragment_length: usize) -> Result<Self, Error> {
+        Self::new(&value.pack_to_vec(), max_fragment_length)
+    }
+
+    /// Returns the number of fragments of the message, a decoder needs at least as many parts.
+    pub fn sequence_count(&self) -> u32 {
+        self.fragments.len() as u32
+    }
+
+    /// Returns the sequence number of the last returned part.
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// Returns the next part.
+    pub fn next_part(&mut self) -> Part {
+        self.sequence = self.sequence.wrapping_add(1).max(1);
+        self.part(self.sequence)
+    }
+
+    /// Returns the part with the given sequence number, starting at 1.
+    pub fn part(&self, sequence: u32) -> Part {
+        let sequence = sequence.max(1);
+        let mut data = alloc::vec![0; self.fragments[0].len()];
+        for index in choose_fragments(sequence, self.sequence_count(), self.checksum) {
+            xor_into(&mut data, &self.fragments[index]);
+        }
+
+        Part {
+            sequence,
+            sequence_count: self.sequence_count(),
+            message_length: self.message_length,
+            checksum: self.checksum,
+            // The length of a fragment is bounded by the message length, which fits into a u32
+            data: data.into_boxed_slice().try_into().unwrap(),
+        }
+    }
+}
+
+/// Decodes a message from [`Part`]s received in any order.
+#[derive(Clone, Debug, Default)]
+pub struct Decoder {
+    // Sequence count, message length and checksum of the message, set by the first part
+    expected: Option<(u32, u32, u32)>,
+    ur_type: Option<String>,
+    fragment_length: usize,
+    fragments: BTreeMap<usize, Vec<u8>>,
+    mixed_parts: Vec<(BTreeSet<usize>, Vec<u8>)>,
+    message: Option<Vec<u8>>,
+}
+
+impl Decoder {
+    /// Creates a new decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a part, returns whether the message is complete.
+    pub fn receive(&mut self, part: Part) -> Result<bool, Error> {
+        if self.message.is_some() {
+            return Ok(true);
+        }
+        if part.sequence == 0 || part.sequence_count == 0 || part.message_length == 0 || part.data.is_empty() {
+            return Err(Error::InvalidPart("empty part".into()));
+        }
+        let expected = (part.sequence_count, part.message_length, part.checksum);
+        match self.expected {
+            Some(e) if e != expected || self.fragment_length != part.data.len() => return Err(Error::MismatchingPart),
+            Some(_) => {}
+            None => {
+                // The sequence count is untrusted and determines the allocations for every part
+                if part.sequence_count > MAX_SEQUENCE_COUNT {
+                    return Err(Error::InvalidPart(format!(
+                        "sequence count {} exceeds {MAX_SEQUENCE_COUNT}",
+                        part.sequence_count
+                    )));
+                }
+                if part.sequence_count as usize != (part.message_length as usize).div_ceil(part.data.len()) {
+                    return Err(Error::InvalidPart(
+                        "sequence count doesn't match the message and fragment length".into(),
+                    ));
+                }
+                self.expected = Some(expected);
+                self.fragment_length = part.data.len();
+            }
+        }
+
+        let indexes = choose_fragments(part.sequence, part.sequence_count, part.checksum)
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        self.add_part(indexes, part.data.to_vec());
+
+        if self.fragments.len() == part.sequence_count as usize {
+            let mut message = self.fragments.values().flatten().copied().collect::<Vec<_>>();
+            message.truncate(part.message_length as usize);
+            if checksum(&message) != part.checksum {
+                return Err(Error::InvalidChecksum);
+            }
+            self.message = Some(message);
+            self.mixed_parts.clear();
+        }
+
+        Ok(self.is_complete())
+    }
+
+    /// Adds a part from a UR-style string, all parts need to have the same type. Returns whether the message is
+    /// complete.
+    pub fn receive_ur(&mut self, ur: &str) -> Result<bool, Error> {
+        let (ur_type, part) = Part::try_from_ur(ur)?;
+        match &self.ur_type {
+            Some(expected) if expected != &ur_type => return Err(Error::MismatchingPart),
+            Some(_) => {}
+            None => self.ur_type = Some(ur_type),
+        }
+
+        self.receive(part)
+    }
+
+    /// Returns whether the message is complete.
+    pub fn is_complete(&self) -> bool {
+        self.message.is_some()
+    }
+
+    /// Returns the share of fragments which are already known, between 0 and 1.
+    pub fn progress(&self) -> f64 {
+        match self.expected {
+            _ if self.is_complete() => 1.0,
+            Some((sequence_count, _, _)) => self.fragments.len() as f64 / sequence_count as f64,
+            None => 0.0,
+        }
+    }
+
+    /// Returns the UR type of the received parts.
+    pub fn ur_type(&self) -> Option<&str> {
+        self.ur_type.as_deref()
+    }
+
+    /// Returns the decoded message, once it's complete.
+    pub fn message(&self) -> Option<&[u8]> {
+        self.message.as_deref()
+    }
+
+    /// Unpacks a value from the decoded message.
+    pub fn unpack<P: PackableExt>(&self, visitor: &P::UnpackVisitor) -> Result<P, Error>
+    where
+        P::UnpackError: fmt::Debug,
+    {
+        let message = self.message().ok_or(Error::IncompleteMessage)?;
+
+        P::unpack_verified(message, visitor).map_err(|e| Error::Unpack(format!("{e:?}")))
+    }
+
+    // Reduces a part by the known fragments and stores it, newly found fragments reduce the stored mixed parts.
+    fn add_part(&mut self, indexes: BTreeSet<usize>, data: Vec<u8>) {
+        let mut queue = alloc::vec![(indexes, data)];
+
+        while let Some((mut indexes, mut data)) = queue.pop() {
+            for (index, fragment) in &self.fragments {
+                if indexes.remove(index) {
+                    xor_into(&mut data, fragment);
+                }
+            }
+
+            match indexes.len() {
+                // Nothing new
+                0 => {}
+                1 => {
+                    let index = *indexes.first().unwrap();
+                    // Mixed parts containing the new fragment are reduced again
+                    let (reducible, mixed_parts) = core::mem::take(&mut self.mixed_parts)
+                        .into_iter()
+                        .partition::<Vec<_>, _>(|(indexes, _)| indexes.contains(&index));
+                    self.mixed_parts = mixed_parts;
+                    self.fragments.insert(index, data);
+                    queue.extend(reducible);
+                }
+                _ => {
+                    if !self.mixed_parts.iter().any(|(mixed, _)| mixed == &indexes) {
+                        self.mixed_parts.push((indexes, data));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The first 4 bytes of the Blake2b-256 hash of the message.
+fn checksum(message: &[u8]) -> u32 {
+    let hash = Blake2b256::digest(message);
+    u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+fn xor_into(data: &mut [u8], fragment: &[u8]) {
+    data.iter_mut().zip(fragment).for_each(|(a, b)| *a ^= b);
+}
+
+// Returns the indexes of the fragments which are mixed into the part with the given sequence number. The first parts
+// are the fragments themselves, the following ones mix a degree of fragments which is 1/d distributed.
+fn choose_fragments(sequence: u32, sequence_count: u32, checksum: u32) -> Vec<usize> {
+    let count = sequence_count as usize;
+    if sequence <= sequence_count {
+        return alloc::vec![sequence as usize - 1];
+    }
+
+    let mut rng = SplitMix64::new(sequence, checksum);
+
+    let total = (1..=count).map(|d| 1.0 / d as f64).sum::<f64>();
+    let mut target = rng.next_f64() * total;
+    let mut degree = count;
+    for d in 1..=count {
+        target -= 1.0 / d as f64;
+        if target < 0.0 {
+            degree = d;
+            break;
+        }
+    }
+
+    let mut indexes = (0..count).collect::<Vec<_>>();
+    for i in 0..degree {
+        let j = i + (rng.next_u64() % (count - i) as u64) as usize;
+        indexes.swap(i, j);
+    }
+    indexes.truncate(degree);
+
+    indexes
+}
+
+// Deterministic pseudo-random numbers, so encoder and decoder choose the same fragments for a part.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(sequence: u32, checksum: u32) -> Self {
+        let mut seed = [0; 8];
+        seed[..4].copy_from_slice(&sequence.to_le_bytes());
+        seed[4..].copy_from_slice(&checksum.to_le_bytes());
+        let hash = Blake2b256::digest(seed);
+        let mut state = [0; 8];
+        state.copy_from_slice(&hash[..8]);
+
+        Self(u64::from_le_bytes(state))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
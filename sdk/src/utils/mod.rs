@@ -1,6 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod fountain;
 #[cfg(feature = "serde")]
 pub mod serde;
 
@@ -3,6 +3,10 @@
 
 use std::collections::{HashMap, HashSet};
 
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::PublicKey,
+};
 use tokio::sync::RwLock;
 
 use crate::{
@@ -19,6 +23,8 @@ pub struct AccountBuilder<S: SecretManage = SecretManager> {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<Hrp>,
+    watch_only: bool,
+    public_key_addresses: Vec<(u32, Ed25519Address)>,
     wallet: Wallet<S>,
 }
 
@@ -32,6 +38,8 @@ where
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            watch_only: false,
+            public_key_addresses: Vec::new(),
             wallet,
         }
     }
@@ -43,6 +51,55 @@ where
         self
     }
 
+    /// Create a watch-only account, which doesn't use the secret manager to generate addresses or sign transactions.
+    /// The addresses have to be set with [`AccountBuilder::with_addresses()`], e.g. exported with
+    /// [`Account::addresses()`] from the account with the keys, which has to be at the same account index for
+    /// transactions prepared by the watch-only account to be signed offline.
+    ///
+    /// Ed25519 keys are derived with hardened derivation only, so there is no extended public key from which further
+    /// addresses could be derived, they have to be exported from the account with the keys too.
+    pub fn with_watch_only(mut self, watch_only: bool) -> Self {
+        self.watch_only = watch_only;
+        self
+    }
+
+    /// Create a watch-only account for the given public addresses and their key indexes in the account with the keys.
+    /// See [`AccountBuilder::with_watch_only()`].
+    pub fn with_watch_only_addresses(mut self, addresses: impl IntoIterator<Item = (u32, Bech32Address)>) -> Self {
+        self.addresses = Some(
+            addresses
+                .into_iter()
+                .map(|(key_index, address)| AccountAddress {
+                    address,
+                    key_index,
+                    internal: false,
+                    used: false,
+                })
+                .collect(),
+        );
+        self.watch_only = true;
+        self
+    }
+
+    /// Create a watch-only account for the given Ed25519 public keys and their key indexes in the account with the
+    /// keys, the public addresses are derived from the public keys. See [`AccountBuilder::with_watch_only()`].
+    ///
+    /// This replaces an extended public key, which doesn't exist for Ed25519: every public key has to be exported,
+    /// e.g. from the signatures of the account with the keys.
+    pub fn with_watch_only_public_keys(mut self, public_keys: impl IntoIterator<Item = (u32, PublicKey)>) -> Self {
+        self.public_key_addresses = public_keys
+            .into_iter()
+            .map(|(key_index, public_key)| {
+                (
+                    key_index,
+                    Ed25519Address::new(Blake2b256::digest(public_key.to_bytes()).into()),
+                )
+            })
+            .collect();
+        self.watch_only = true;
+        self
+    }
+
     /// Set the alias
     pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
         self.alias = Some(alias.into());
@@ -79,19 +136,55 @@ where
 
         let coin_type = self.wallet.coin_type.load(core::sync::atomic::Ordering::Relaxed);
 
+        if !self.public_key_addresses.is_empty() {
+            let bech32_hrp = match self.bech32_hrp {
+                Some(bech32_hrp) => bech32_hrp,
+                None => self.wallet.client().get_bech32_hrp().await?,
+            };
+            let addresses = self.addresses.get_or_insert_with(Vec::new);
+            for (key_index, address) in &self.public_key_addresses {
+                let address = Bech32Address::new(bech32_hrp, *address);
+                if !addresses.iter().any(|a| a.address == address) {
+                    addresses.push(AccountAddress {
+                        address,
+                        key_index: *key_index,
+                        internal: false,
+                        used: false,
+                    });
+                }
+            }
+            self.public_key_addresses.clear();
+        }
+
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
         let addresses = match &self.addresses {
             Some(addresses) => addresses.clone(),
+            None if self.watch_only => return Err(Error::MissingParameter("addresses")),
             None => {
                 let mut bech32_hrp = self.bech32_hrp;
-                if let Some(first_account) = accounts.first() {
-                    let first_account_coin_type = *first_account.details().await.coin_type();
+                // Watch-only accounts weren't generated with the secret manager, so they can't be compared
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !*account.details().await.watch_only() {
+                        first_account = Some(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
+                    let (first_account_coin_type, first_account_index) = {
+                        let details = first_account.details().await;
+                        (*details.coin_type(), *details.index())
+                    };
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
                     // seeds
-                    let first_account_public_address =
-                        get_first_public_address(&self.wallet.secret_manager, first_account_coin_type, 0).await?;
+                    let first_account_public_address = get_first_public_address(
+                        &self.wallet.secret_manager,
+                        first_account_coin_type,
+                        first_account_index,
+                    )
+                    .await?;
                     let first_account_addresses = first_account.public_addresses().await;
 
                     if Address::Ed25519(first_account_public_address)
@@ -136,12 +229,22 @@ where
             }
         };
 
+        // Addresses exported from another account can contain its internal addresses
+        let (internal_addresses, public_addresses) = if self.watch_only {
+            addresses.into_iter().partition(|address| address.internal)
+        } else {
+            (Vec::new(), addresses)
+        };
+        if self.watch_only && public_addresses.is_empty() {
+            return Err(Error::MissingParameter("public addresses"));
+        }
+
         let account = AccountDetails {
             index: account_index,
             coin_type,
             alias: account_alias,
-            public_addresses: addresses,
-            internal_addresses: Vec::new(),
+            public_addresses,
+            internal_addresses,
             addresses_with_unspent_outputs: Vec::new(),
             outputs: HashMap::new(),
             locked_outputs: HashSet::new(),
@@ -151,6 +254,7 @@ where
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: self.watch_only,
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
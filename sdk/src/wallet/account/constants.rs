@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::types::block::output::OUTPUT_COUNT_MAX;
+
 /// Amount at which outputs on a single addresses will get consolidated by default if consolidatioin is enabled
 pub(crate) const DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 100;
 /// Amount at which outputs on a single addresses will get consolidated by default with a ledger secret_manager if
@@ -15,5 +17,8 @@ pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
+/// Maximum amount of outputs of a batch which are sent in a single transaction, so there is space for a remainder
+pub(crate) const BATCH_OUTPUTS_PER_TRANSACTION: usize = OUTPUT_COUNT_MAX as usize - 1;
+
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
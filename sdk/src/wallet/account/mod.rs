@@ -24,6 +24,10 @@ use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
+#[cfg(feature = "storage")]
+pub use self::operations::transaction::high_level::scheduled_payments::{
+    PaymentSchedule, ScheduledPayment, ScheduledPaymentRun,
+};
 use self::types::{
     address::{AccountAddress, AddressWithUnspentOutputs},
     Balance, OutputData, Transaction, TransactionDto,
@@ -46,10 +50,20 @@ pub use self::{
                     },
                     mint_nfts::MintNftParams,
                 },
+                send_batch::{Batch, BatchPayment, BatchPaymentStatus},
+            },
+            multisig::{
+                MultisigPolicy, MultisigTransaction, MultisigTransactionDto, PartialUnlocks, PartialUnlocksDto,
             },
             prepare_output::{Assets, Features, OutputParams, ReturnStrategy, StorageDeposit, Unlocks},
+            simulate_transaction::{
+                AddressBalanceChange, ChainTransition, ChainTransitionKind, OutputTimeConditions, TransactionSimulation,
+            },
             RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
         },
+        transaction_history::{
+            TransactionDirection, TransactionHistoryEntry, TransactionHistoryFilter, TransactionHistoryFormat,
+        },
     },
     types::OutputDataDto,
 };
@@ -110,31 +124,35 @@ pub struct AccountDetails {
     // addresses
     addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
     /// Outputs
-    // stored separated from the account for performance?
-    outputs: HashMap<OutputId, OutputData>,
+    // with storage, spent outputs which aren't related to pending transactions are only kept in the storage after
+    // syncing and loaded from there when requested
+    pub(crate) outputs: HashMap<OutputId, OutputData>,
     /// Unspent outputs that are currently used as input for transactions
     // outputs used in transactions should be locked here so they don't get used again, which would result in a
     // conflicting transaction
     pub(crate) locked_outputs: HashSet<OutputId>,
     /// Unspent outputs
     // have unspent outputs in a separated hashmap so we don't need to iterate over all outputs we have
-    unspent_outputs: HashMap<OutputId, OutputData>,
+    pub(crate) unspent_outputs: HashMap<OutputId, OutputData>,
     /// Sent transactions
-    // stored separated from the account for performance and only the transaction id here? where to add the network id?
-    // transactions: HashSet<TransactionId>,
-    transactions: HashMap<TransactionId, Transaction>,
+    // with storage, only pending transactions are kept in memory after syncing
+    pub(crate) transactions: HashMap<TransactionId, Transaction>,
     /// Pending transactions
     // Maybe pending transactions even additionally separated?
     pending_transactions: HashSet<TransactionId>,
     /// Transaction payloads for received outputs with inputs when not pruned before syncing, can be used to determine
     /// the sender address(es)
-    incoming_transactions: HashMap<TransactionId, Transaction>,
+    // with storage, these are only kept in memory until they're saved
+    pub(crate) incoming_transactions: HashMap<TransactionId, Transaction>,
     /// Some incoming transactions can be pruned by the node before we requested them, then this node can never return
     /// it. To avoid useless requests, these transaction ids are stored here and cleared when new client options are
     /// set, because another node might still have them.
     inaccessible_incoming_transactions: HashSet<TransactionId>,
     /// Foundries for native tokens in outputs
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Whether the account only watches its addresses, without using the secret manager to generate addresses or sign
+    /// transactions
+    watch_only: bool,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -157,6 +175,30 @@ impl<S: SecretManage> Account<S> {
     pub fn get_secret_manager(&self) -> &Arc<RwLock<S>> {
         self.wallet.get_secret_manager()
     }
+
+    /// Returns the account details together with the spent outputs and older transactions which are only kept in the
+    /// storage
+    #[cfg(all(feature = "storage", feature = "stronghold"))]
+    pub(crate) async fn details_with_stored_records(&self) -> Result<AccountDetails> {
+        let mut account_details = self.details().await.clone();
+        let storage_manager = self.wallet.storage_manager.read().await;
+        for (output_id, output_data) in storage_manager.get_outputs(account_details.index).await? {
+            account_details.outputs.entry(output_id).or_insert(output_data);
+        }
+        for (transaction_id, transaction) in storage_manager.get_transactions(account_details.index).await? {
+            account_details.transactions.entry(transaction_id).or_insert(transaction);
+        }
+        for (transaction_id, transaction) in storage_manager
+            .get_incoming_transactions(account_details.index)
+            .await?
+        {
+            account_details
+                .incoming_transactions
+                .entry(transaction_id)
+                .or_insert(transaction);
+        }
+        Ok(account_details)
+    }
 }
 
 #[derive(Debug)]
@@ -167,6 +209,9 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    // mutex to prevent sending the same due scheduled payment from multiple calls at the same time
+    #[cfg(feature = "storage")]
+    pub(crate) scheduled_payments_lock: Mutex<()>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -201,6 +246,8 @@ where
                 details: RwLock::new(details),
                 last_synced: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                #[cfg(feature = "storage")]
+                scheduled_payments_lock: Default::default(),
             }),
         })
     }
@@ -230,6 +277,173 @@ where
         Ok(output_response.output().to_owned())
     }
 
+    /// Get the [`OutputData`] of an output stored in the account
+    pub async fn get_output(&self, output_id: &OutputId) -> Option<OutputData> {
+        self.try_get_output(output_id).await.unwrap_or_else(|e| {
+            log::error!("[STORAGE] failed to load output {output_id}: {e}");
+            None
+        })
+    }
+
+    /// Get the [`OutputData`] of an output stored in the account, or the error of loading it from the storage
+    pub async fn try_get_output(&self, output_id: &OutputId) -> Result<Option<OutputData>> {
+        let account_details = self.details().await;
+        if let Some(output_data) = account_details.outputs().get(output_id) {
+            return Ok(Some(output_data.clone()));
+        }
+        #[cfg(feature = "storage")]
+        let output_data = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_output(account_details.index, output_id)
+            .await?;
+        #[cfg(not(feature = "storage"))]
+        let output_data = None;
+        Ok(output_data)
+    }
+
+    /// Get the [`Transaction`] of a transaction stored in the account
+    pub async fn get_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
+        self.try_get_transaction(transaction_id).await.unwrap_or_else(|e| {
+            log::error!("[STORAGE] failed to load transaction {transaction_id}: {e}");
+            None
+        })
+    }
+
+    /// Get the [`Transaction`] of a transaction stored in the account, or the error of loading it from the storage
+    pub async fn try_get_transaction(&self, transaction_id: &TransactionId) -> Result<Option<Transaction>> {
+        let account_details = self.details().await;
+        if let Some(transaction) = account_details.transactions().get(transaction_id) {
+            return Ok(Some(transaction.clone()));
+        }
+        #[cfg(feature = "storage")]
+        let transaction = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_transaction(account_details.index, transaction_id)
+            .await?;
+        #[cfg(not(feature = "storage"))]
+        let transaction = None;
+        Ok(transaction)
+    }
+
+    /// Get the transaction with inputs of an incoming transaction stored in the account
+    /// List might not be complete, if the node pruned the data already
+    pub async fn get_incoming_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
+        self.try_get_incoming_transaction(transaction_id)
+            .await
+            .unwrap_or_else(|e| {
+                log::error!("[STORAGE] failed to load incoming transaction {transaction_id}: {e}");
+                None
+            })
+    }
+
+    /// Get the transaction with inputs of an incoming transaction stored in the account, or the error of loading it
+    /// from the storage
+    pub async fn try_get_incoming_transaction(&self, transaction_id: &TransactionId) -> Result<Option<Transaction>> {
+        let account_details = self.details().await;
+        if let Some(transaction) = account_details.incoming_transactions().get(transaction_id) {
+            return Ok(Some(transaction.clone()));
+        }
+        #[cfg(feature = "storage")]
+        let transaction = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_incoming_transaction(account_details.index, transaction_id)
+            .await?;
+        #[cfg(not(feature = "storage"))]
+        let transaction = None;
+        Ok(transaction)
+    }
+
+    /// Returns outputs of the account
+    pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
+        let account_details = self.details().await;
+        #[cfg(feature = "storage")]
+        let outputs = {
+            let mut outputs = self
+                .wallet
+                .storage_manager
+                .read()
+                .await
+                .get_outputs(account_details.index)
+                .await?;
+            outputs.extend(account_details.outputs.clone());
+            outputs
+        };
+        #[cfg(not(feature = "storage"))]
+        let outputs = account_details.outputs.clone();
+        self.filter_outputs(outputs.values(), filter)
+    }
+
+    /// Returns all incoming transactions of the account
+    pub async fn incoming_transactions(&self) -> Vec<Transaction> {
+        match self.try_incoming_transactions().await {
+            Ok(transactions) => transactions,
+            Err(e) => {
+                log::error!("[STORAGE] failed to load the incoming transactions: {e}");
+                self.details().await.incoming_transactions.values().cloned().collect()
+            }
+        }
+    }
+
+    /// Returns all incoming transactions of the account, or the error of loading them from the storage
+    pub async fn try_incoming_transactions(&self) -> Result<Vec<Transaction>> {
+        let account_details = self.details().await;
+        #[cfg(feature = "storage")]
+        let transactions = {
+            let mut transactions = self
+                .wallet
+                .storage_manager
+                .read()
+                .await
+                .get_incoming_transactions(account_details.index)
+                .await?;
+            transactions.extend(account_details.incoming_transactions.clone());
+            transactions
+        };
+        #[cfg(not(feature = "storage"))]
+        let transactions = account_details.incoming_transactions.clone();
+        Ok(transactions.into_values().collect())
+    }
+
+    /// Returns all transactions of the account
+    pub async fn transactions(&self) -> Vec<Transaction> {
+        match self.try_transactions().await {
+            Ok(transactions) => transactions,
+            Err(e) => {
+                log::error!("[STORAGE] failed to load the transactions: {e}");
+                self.details().await.transactions.values().cloned().collect()
+            }
+        }
+    }
+
+    /// Returns all transactions of the account, or the error of loading them from the storage
+    pub async fn try_transactions(&self) -> Result<Vec<Transaction>> {
+        let account_details = self.details().await;
+        #[cfg(feature = "storage")]
+        let transactions = {
+            let mut transactions = self
+                .wallet
+                .storage_manager
+                .read()
+                .await
+                .get_transactions(account_details.index)
+                .await?;
+            transactions.extend(account_details.transactions.clone());
+            transactions
+        };
+        #[cfg(not(feature = "storage"))]
+        let transactions = account_details.transactions.clone();
+        Ok(transactions.into_values().collect())
+    }
+
     /// Save the account to the database, accepts the updated_account as option so we don't need to drop it before
     /// saving
     #[cfg(feature = "storage")]
@@ -271,26 +485,6 @@ impl AccountInner {
         self.details().await.alias.clone()
     }
 
-    /// Get the [`OutputData`] of an output stored in the account
-    pub async fn get_output(&self, output_id: &OutputId) -> Option<OutputData> {
-        self.details().await.outputs().get(output_id).cloned()
-    }
-
-    /// Get the [`Transaction`] of a transaction stored in the account
-    pub async fn get_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
-        self.details().await.transactions().get(transaction_id).cloned()
-    }
-
-    /// Get the transaction with inputs of an incoming transaction stored in the account
-    /// List might not be complete, if the node pruned the data already
-    pub async fn get_incoming_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
-        self.details()
-            .await
-            .incoming_transactions()
-            .get(transaction_id)
-            .cloned()
-    }
-
     /// Returns all addresses of the account
     pub async fn addresses(&self) -> Result<Vec<AccountAddress>> {
         let account_details = self.details().await;
@@ -380,11 +574,6 @@ impl AccountInner {
         }
     }
 
-    /// Returns outputs of the account
-    pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.details().await.outputs.values(), filter)
-    }
-
     /// Returns unspent outputs of the account
     pub async fn unspent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
         self.filter_outputs(self.details().await.unspent_outputs.values(), filter)
@@ -420,16 +609,6 @@ impl AccountInner {
         .map(|res| res.get(0).cloned())
     }
 
-    /// Returns all incoming transactions of the account
-    pub async fn incoming_transactions(&self) -> Vec<Transaction> {
-        self.details().await.incoming_transactions.values().cloned().collect()
-    }
-
-    /// Returns all transactions of the account
-    pub async fn transactions(&self) -> Vec<Transaction> {
-        self.details().await.transactions.values().cloned().collect()
-    }
-
     /// Returns all pending transactions of the account
     pub async fn pending_transactions(&self) -> Vec<Transaction> {
         let mut transactions = Vec::new();
@@ -498,6 +677,9 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Whether the account is watch-only
+    #[serde(default)]
+    pub watch_only: bool,
 }
 
 impl TryFromDto for AccountDetails {
@@ -543,6 +725,7 @@ impl TryFromDto for AccountDetails {
                 .into_iter()
                 .map(|(id, o)| Ok((id, FoundryOutput::try_from_dto_with_params(o, &params)?)))
                 .collect::<crate::wallet::Result<_>>()?,
+            watch_only: dto.watch_only,
         })
     }
 }
@@ -583,6 +766,7 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            watch_only: value.watch_only,
         }
     }
 }
@@ -683,6 +867,7 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        watch_only: false,
     };
 
     let deser_account = AccountDetails::try_from_dto(
@@ -726,6 +911,7 @@ impl AccountDetails {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: false,
         }
     }
 }
@@ -47,6 +47,9 @@ where
         }
 
         let account_details = self.details().await;
+        if account_details.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount(account_details.alias().clone()));
+        }
 
         // get the highest index for the public or internal addresses
         let highest_current_index_plus_one = if options.internal {
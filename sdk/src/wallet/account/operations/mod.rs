@@ -24,3 +24,5 @@ pub(crate) mod retry;
 pub(crate) mod syncing;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module for the transaction history
+pub(crate) mod transaction_history;
@@ -48,7 +48,7 @@ where
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[retry_transaction_until_included]");
 
-        let transaction = self.details().await.transactions.get(transaction_id).cloned();
+        let transaction = self.try_get_transaction(transaction_id).await?;
 
         if let Some(transaction) = transaction {
             if transaction.inclusion_state == InclusionState::Confirmed {
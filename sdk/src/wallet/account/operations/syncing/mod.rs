@@ -3,6 +3,8 @@
 
 pub(crate) mod addresses;
 pub(crate) mod foundries;
+#[cfg(feature = "mqtt")]
+pub(crate) mod mqtt;
 pub(crate) mod options;
 pub(crate) mod outputs;
 pub(crate) mod transactions;
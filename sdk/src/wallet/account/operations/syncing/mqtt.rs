@@ -0,0 +1,271 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    client::{
+        node_api::mqtt::{MqttPayload, Topic, TopicEvent},
+        secret::SecretManage,
+    },
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            output::{FoundryId, Output, OutputWithMetadata},
+            payload::transaction::TransactionId,
+            Block, BlockId,
+        },
+        TryFromDto,
+    },
+    wallet::account::{
+        operations::syncing::{transactions::updated_transaction_and_outputs, SyncOptions},
+        types::{AddressWithUnspentOutputs, InclusionState},
+        Account,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the MQTT topics for new and spent outputs of all account addresses and for the inclusion of the pending
+    /// transactions
+    pub(crate) async fn mqtt_topics(&self) -> crate::wallet::Result<HashSet<Topic>> {
+        let account_details = self.details().await;
+        let mut topics = HashSet::new();
+        for address in account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+        {
+            topics.insert(Topic::new(format!("outputs/unlock/address/{}", address.address))?);
+            topics.insert(Topic::new(format!("outputs/unlock/address/{}/spent", address.address))?);
+        }
+        for transaction_id in &account_details.pending_transactions {
+            topics.insert(Topic::new(format!("transactions/{transaction_id}/included-block"))?);
+        }
+        Ok(topics)
+    }
+
+    /// Applies an event of one of the [`Account::mqtt_topics()`] to the account
+    pub(crate) async fn handle_mqtt_event(
+        &self,
+        event: &TopicEvent,
+        options: &SyncOptions,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[MQTT] handle event of topic {}", event.topic);
+        match &event.payload {
+            MqttPayload::Json(value) => {
+                if let Some(address) = event.topic.strip_prefix("outputs/unlock/address/") {
+                    let address = address.trim_end_matches("/spent");
+                    let output_response = serde_json::from_value::<OutputWithMetadataResponse>(value.clone())?;
+                    self.handle_mqtt_output(address, output_response, options).await?;
+                }
+            }
+            MqttPayload::Block(block_dto) => {
+                if let Some(transaction_id) = event
+                    .topic
+                    .strip_prefix("transactions/")
+                    .and_then(|topic| topic.strip_suffix("/included-block"))
+                {
+                    let transaction_id = TransactionId::from_str(transaction_id)?;
+                    let protocol_parameters = self.client().get_protocol_parameters().await?;
+                    let block = Block::try_from_dto_with_params(block_dto.clone(), protocol_parameters)?;
+                    self.handle_mqtt_transaction_inclusion(transaction_id, block.id())
+                        .await?;
+                }
+            }
+            _ => log::debug!("[MQTT] unexpected payload for topic {}", event.topic),
+        }
+        Ok(())
+    }
+
+    // Apply a new or spent output of an account address
+    async fn handle_mqtt_output(
+        &self,
+        bech32_address: &str,
+        output_response: OutputWithMetadataResponse,
+        options: &SyncOptions,
+    ) -> crate::wallet::Result<()> {
+        let account_details = self.details().await;
+        let Some(account_address) = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .find(|a| a.address == bech32_address)
+        else {
+            return Ok(());
+        };
+        let address = account_details
+            .addresses_with_unspent_outputs
+            .iter()
+            .find(|a| a.address == account_address.address)
+            .cloned()
+            .unwrap_or_else(|| AddressWithUnspentOutputs {
+                address: account_address.address,
+                key_index: account_address.key_index,
+                internal: account_address.internal,
+                output_ids: Vec::new(),
+            });
+        drop(account_details);
+
+        let token_supply = self.client().get_token_supply().await?;
+        let output_with_meta = OutputWithMetadata::new(
+            Output::try_from_dto_with_params(output_response.output, token_supply)?,
+            output_response.metadata,
+        );
+        let output_id = *output_with_meta.metadata().output_id();
+
+        if output_with_meta.metadata().is_spent() {
+            log::debug!("[MQTT] spent output {output_id}");
+            return self
+                .update_account_with_address_outputs(
+                    address,
+                    Vec::new(),
+                    HashMap::from([(output_id, Some(*output_with_meta.metadata()))]),
+                )
+                .await;
+        }
+
+        log::debug!("[MQTT] new output {output_id}");
+        let outputs_data = self
+            .output_response_to_output_data(vec![output_with_meta], &address)
+            .await?;
+
+        if options.sync_incoming_transactions {
+            self.request_incoming_transaction_data(vec![*output_id.transaction_id()])
+                .await?;
+        }
+
+        if options.sync_native_token_foundries {
+            let native_token_foundry_ids = outputs_data
+                .iter()
+                .filter_map(|output| output.output.native_tokens())
+                .flat_map(|native_tokens| {
+                    native_tokens
+                        .iter()
+                        .map(|native_token| FoundryId::from(*native_token.token_id()))
+                })
+                .collect::<HashSet<_>>();
+            self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
+        }
+
+        self.update_account_with_address_outputs(address, outputs_data, HashMap::new())
+            .await
+    }
+
+    // Set a pending transaction as confirmed, its inputs are spent then
+    async fn handle_mqtt_transaction_inclusion(
+        &self,
+        transaction_id: TransactionId,
+        block_id: BlockId,
+    ) -> crate::wallet::Result<()> {
+        let account_details = self.details().await;
+        if !account_details.pending_transactions.contains(&transaction_id) {
+            return Ok(());
+        }
+        let Some(transaction) = account_details.transactions.get(&transaction_id).cloned() else {
+            return Ok(());
+        };
+        drop(account_details);
+
+        log::debug!("[MQTT] confirmed transaction {transaction_id} in block {block_id}");
+        let mut updated_transactions = Vec::new();
+        let mut spent_output_ids = Vec::new();
+        updated_transaction_and_outputs(
+            transaction,
+            Some(block_id),
+            InclusionState::Confirmed,
+            &mut updated_transactions,
+            &mut spent_output_ids,
+        );
+
+        self.update_account_with_transactions(updated_transactions, spent_output_ids, Vec::new())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "test-utils"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            mock_node::MockNode,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+            Client,
+        },
+        types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+        wallet::{ClientOptions, Wallet},
+    };
+
+    async fn output_event(node: &MockNode, topic: String, output_id: &OutputId) -> TopicEvent {
+        let output = node.output(output_id).await.unwrap();
+        TopicEvent {
+            topic,
+            payload: MqttPayload::Json(serde_json::to_value(OutputWithMetadataResponse::from(output)).unwrap()),
+        }
+    }
+
+    #[tokio::test]
+    async fn handle_output_events() {
+        let node = MockNode::builder().finish().await.unwrap();
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic().unwrap()).unwrap();
+        #[allow(unused_mut)]
+        let mut wallet_builder = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(ClientOptions::new().with_node(&node.url()).unwrap())
+            .with_coin_type(SHIMMER_COIN_TYPE);
+        #[cfg(feature = "storage")]
+        {
+            wallet_builder = wallet_builder.with_storage_options(crate::wallet::storage::StorageOptions::new(
+                "test-storage/handle_output_events".into(),
+                crate::wallet::storage::StorageKind::Memory,
+            ));
+        }
+        let wallet = wallet_builder.finish().await.unwrap();
+        let account = wallet.create_account().finish().await.unwrap();
+        let options = SyncOptions::default();
+
+        let address = *account.addresses().await.unwrap()[0].address();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(node.protocol_parameters().token_supply())
+            .unwrap();
+        let output_id = node.add_output(output).await;
+
+        // A new output of an account address is added to the account without syncing
+        let event = output_event(&node, format!("outputs/unlock/address/{address}"), &output_id).await;
+        account.handle_mqtt_event(&event, &options).await.unwrap();
+        assert!(!account.get_output(&output_id).await.unwrap().is_spent);
+        assert_eq!(account.balance().await.unwrap().base_coin().total(), 1_000_000);
+
+        // Spend the output on the node, the account only learns about it from the event
+        let other_address = *account.generate_ed25519_addresses(1, None).await.unwrap()[0].address();
+        account.send(500_000, other_address, None).await.unwrap();
+        node.issue_milestone().await;
+        assert!(
+            account
+                .unspent_outputs(None)
+                .await
+                .unwrap()
+                .iter()
+                .any(|output| output.output_id == output_id)
+        );
+
+        let event = output_event(&node, format!("outputs/unlock/address/{address}/spent"), &output_id).await;
+        account.handle_mqtt_event(&event, &options).await.unwrap();
+        assert!(account.get_output(&output_id).await.unwrap().is_spent);
+        assert!(
+            account
+                .unspent_outputs(None)
+                .await
+                .unwrap()
+                .iter()
+                .all(|output| output.output_id != output_id)
+        );
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
+
 use crypto::keys::bip44::Bip44;
 use instant::Instant;
 
@@ -38,15 +40,52 @@ where
         let network_id = self.client().get_network_id().await?;
         let account_details = self.details().await;
 
+        // transactions that aren't pending anymore are only in the storage, so look up the ones that created new
+        // outputs
+        #[cfg(feature = "storage")]
+        let stored_transaction_ids = {
+            let transaction_ids = outputs_with_meta
+                .iter()
+                .filter(|output_with_meta| {
+                    !account_details
+                        .outputs
+                        .contains_key(output_with_meta.metadata().output_id())
+                })
+                .map(|output_with_meta| *output_with_meta.metadata().transaction_id())
+                .filter(|transaction_id| !account_details.transactions.contains_key(transaction_id))
+                .collect::<HashSet<_>>();
+            if transaction_ids.is_empty() {
+                HashSet::new()
+            } else {
+                self.wallet
+                    .storage_manager
+                    .read()
+                    .await
+                    .stored_transaction_ids(account_details.index, transaction_ids.into_iter().collect())
+                    .await?
+            }
+        };
+        #[cfg(not(feature = "storage"))]
+        let stored_transaction_ids = HashSet::<TransactionId>::new();
+
         Ok(outputs_with_meta
             .into_iter()
             .map(|output_with_meta| {
                 // check if we know the transaction that created this output and if we created it (if we store incoming
                 // transactions separated, then this check wouldn't be required)
+                let transaction_id = output_with_meta.metadata().transaction_id();
                 let remainder = account_details
-                    .transactions
-                    .get(output_with_meta.metadata().transaction_id())
-                    .map_or(false, |tx| !tx.incoming);
+                    .outputs
+                    .get(output_with_meta.metadata().output_id())
+                    .map_or_else(
+                        || {
+                            account_details
+                                .transactions
+                                .get(transaction_id)
+                                .map_or_else(|| stored_transaction_ids.contains(transaction_id), |tx| !tx.incoming)
+                        },
+                        |output_data| output_data.remainder,
+                    );
 
                 // BIP 44 (HD wallets) and 4218 is the registered index for IOTA https://github.com/satoshilabs/slips/blob/master/slip-0044.md
                 let chain = Bip44::new(account_details.coin_type)
@@ -132,6 +171,20 @@ where
                     .inaccessible_incoming_transactions
                     .contains(transaction_id))
         });
+        #[cfg(feature = "storage")]
+        if !transaction_ids.is_empty() {
+            // incoming and older sent transactions are only kept in the storage
+            let storage_manager = self.wallet.storage_manager.read().await;
+            let mut stored_transaction_ids = storage_manager
+                .stored_incoming_transaction_ids(account_details.index, transaction_ids.clone())
+                .await?;
+            stored_transaction_ids.extend(
+                storage_manager
+                    .stored_transaction_ids(account_details.index, transaction_ids.clone())
+                    .await?,
+            );
+            transaction_ids.retain(|transaction_id| !stored_transaction_ids.contains(transaction_id));
+        }
         drop(account_details);
 
         // Limit parallel requests to 100, to avoid timeouts
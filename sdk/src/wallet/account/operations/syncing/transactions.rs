@@ -203,7 +203,9 @@ where
 
         for mut transaction in transactions_to_reattach {
             log::debug!("[SYNC] reattach transaction");
-            let reattached_block = self.submit_transaction_payload(transaction.payload.clone()).await?;
+            let reattached_block = self
+                .submit_transaction_payload(transaction.payload.clone(), None)
+                .await?;
             transaction.block_id.replace(reattached_block);
             updated_transactions.push(transaction);
         }
@@ -217,7 +219,7 @@ where
 }
 
 // Set the outputs as spent so they will not be used as input again
-fn updated_transaction_and_outputs(
+pub(super) fn updated_transaction_and_outputs(
     mut transaction: Transaction,
     block_id: Option<BlockId>,
     inclusion_state: InclusionState,
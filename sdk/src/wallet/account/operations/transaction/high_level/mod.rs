@@ -4,6 +4,9 @@
 pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
 pub(crate) mod minting;
+#[cfg(feature = "storage")]
+pub(crate) mod scheduled_payments;
 pub(crate) mod send;
+pub(crate) mod send_batch;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
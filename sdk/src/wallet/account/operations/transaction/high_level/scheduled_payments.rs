@@ -0,0 +1,536 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use getset::{CopyGetters, Getters};
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{
+    ScheduledPaymentExecutedEvent, ScheduledPaymentFailedEvent, ScheduledPaymentSkippedEvent, WalletEvent,
+};
+use crate::{
+    client::secret::SecretManage,
+    types::block::payload::transaction::TransactionId,
+    wallet::{
+        account::{
+            operations::transaction::high_level::send_batch::BatchPayment, types::InclusionState, Account,
+            TransactionOptions,
+        },
+        Error,
+    },
+};
+
+// Upper bound of the steps to find the next time matching a cron expression, enough to skip several years
+const MAX_CRON_STEPS: usize = 100_000;
+// Seconds until a failed run is tried again, doubled with every failed attempt
+const FAILED_RUN_RETRY_DELAY: u32 = 60;
+// How many times a run is tried before it's given up and the next run is scheduled
+const MAX_FAILED_RUN_ATTEMPTS: u32 = 5;
+
+/// When a [`ScheduledPayment`] is sent.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PaymentSchedule {
+    /// Once, at the given UNIX timestamp in seconds.
+    Once { time: u32 },
+    /// Every `interval` seconds, starting at the given UNIX timestamp in seconds.
+    Interval { start: u32, interval: u32 },
+    /// At the times matching a cron expression with the five fields minute, hour, day of month, month and day of week,
+    /// evaluated in UTC. Fields support `*`, numbers, ranges like `1-5`, lists like `1,15` and steps like `*/10`. Like
+    /// in cron, a time matches if either the day of month or the day of week matches when both are restricted, a day
+    /// field is unrestricted if it matches every day, like `*` or `1-31`.
+    Cron { expression: String },
+}
+
+impl PaymentSchedule {
+    /// Creates a schedule with the given cron expression, checking that it's valid.
+    pub fn cron(expression: impl Into<String>) -> crate::wallet::Result<Self> {
+        let expression = expression.into();
+        CronExpression::parse(&expression)?;
+        Ok(Self::Cron { expression })
+    }
+
+    /// Returns the first time of the schedule at or after the given UNIX timestamp, `None` if there is none.
+    pub fn first_run(&self, time: u32) -> crate::wallet::Result<Option<u32>> {
+        match self {
+            Self::Once { time: run } => Ok(Some(*run)),
+            Self::Interval { start, .. } if *start >= time => Ok(Some(*start)),
+            _ => self.next_run_after(time.saturating_sub(1)),
+        }
+    }
+
+    /// Returns the first time of the schedule after the given UNIX timestamp, `None` if there is none.
+    pub fn next_run_after(&self, time: u32) -> crate::wallet::Result<Option<u32>> {
+        match self {
+            Self::Once { time: run } => Ok((*run > time).then_some(*run)),
+            Self::Interval { start, interval } => {
+                if *interval == 0 {
+                    return Err(Error::InvalidPaymentSchedule("interval must not be zero".to_string()));
+                }
+                if *start > time {
+                    return Ok(Some(*start));
+                }
+                let elapsed_intervals = (time - start) / interval + 1;
+                Ok(elapsed_intervals
+                    .checked_mul(*interval)
+                    .and_then(|offset| start.checked_add(offset)))
+            }
+            Self::Cron { expression } => Ok(CronExpression::parse(expression)?.next_run_after(time)),
+        }
+    }
+}
+
+/// The result of the last run of a [`ScheduledPayment`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScheduledPaymentRun {
+    /// The payment was sent.
+    #[serde(rename_all = "camelCase")]
+    Executed { time: u32, transaction_id: TransactionId },
+    /// The payment wasn't sent because the account didn't have enough funds, it's sent at the next scheduled time.
+    /// Payments without a later scheduled time, like [`PaymentSchedule::Once`], stay due and are tried again the next
+    /// time scheduled payments are processed.
+    Skipped { time: u32, error: String },
+    /// Sending the payment failed, it's tried again after a delay of a minute which doubles with every failed attempt.
+    /// After 5 failed attempts the run is given up and the payment is sent at the next scheduled time. Payments
+    /// without a later scheduled time, like [`PaymentSchedule::Once`], end with this run and a `next_run` of `None`.
+    Failed { time: u32, error: String },
+}
+
+/// A payment which is sent repeatedly or at a later time, see [`Account::schedule_payment()`].
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledPayment {
+    /// The id of the scheduled payment.
+    #[getset(get = "pub")]
+    id: String,
+    /// The payment that is sent.
+    #[getset(get = "pub")]
+    payment: BatchPayment,
+    /// When the payment is sent.
+    #[getset(get = "pub")]
+    schedule: PaymentSchedule,
+    /// The time the payment is sent next, `None` if the schedule ended.
+    #[getset(get_copy = "pub")]
+    next_run: Option<u32>,
+    /// How many times the payment was sent.
+    #[getset(get_copy = "pub")]
+    executed_runs: u32,
+    /// How many times the payment is sent at most.
+    #[getset(get_copy = "pub")]
+    max_runs: Option<u32>,
+    /// The result of the last run.
+    #[getset(get = "pub")]
+    last_run: Option<ScheduledPaymentRun>,
+    /// How many times the current run failed.
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    failed_attempts: u32,
+    /// The transaction of the current run, recorded before it's submitted so the payment isn't sent again when the
+    /// run fails afterwards.
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    pending_transaction_id: Option<TransactionId>,
+}
+
+impl ScheduledPayment {
+    /// Returns whether the payment is due at the given time.
+    pub fn is_due(&self, time: u32) -> bool {
+        self.next_run.map_or(false, |next_run| next_run <= time)
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Schedules a payment which is sent at the times of the schedule, at most `max_runs` times.
+    ///
+    /// Scheduled payments are stored in the wallet storage and sent when they are due by
+    /// [`Account::process_scheduled_payments()`], which is called by the background syncing. Runs that were missed
+    /// while the wallet wasn't running are sent once and the next run is scheduled after the current time. Every run
+    /// builds its outputs again, so outputs with a timelock or expiration relative to the current time can be sent
+    /// by using an [`OutputParams`](crate::wallet::account::OutputParams) payment.
+    /// ```ignore
+    /// let scheduled_payment = account
+    ///     .schedule_payment(
+    ///         SendParams::new(1_000_000, address)?,
+    ///         PaymentSchedule::cron("0 9 1 * *")?,
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn schedule_payment(
+        &self,
+        payment: impl Into<BatchPayment> + Send,
+        schedule: PaymentSchedule,
+        max_runs: impl Into<Option<u32>> + Send,
+    ) -> crate::wallet::Result<ScheduledPayment> {
+        log::debug!("[TRANSACTION] schedule_payment");
+        let current_time = self.client().get_time_checked().await?;
+
+        let mut id = [0u8; 16];
+        crypto::utils::rand::fill(&mut id)?;
+        let max_runs = max_runs.into();
+        let scheduled_payment = ScheduledPayment {
+            id: prefix_hex::encode(id),
+            payment: payment.into(),
+            next_run: match max_runs {
+                Some(0) => None,
+                _ => schedule.first_run(current_time)?,
+            },
+            schedule,
+            executed_runs: 0,
+            max_runs,
+            last_run: None,
+            failed_attempts: 0,
+            pending_transaction_id: None,
+        };
+        self.save_scheduled_payment(&scheduled_payment).await?;
+
+        Ok(scheduled_payment)
+    }
+
+    /// Sends the scheduled payments that are due and returns them with the result of their run.
+    ///
+    /// Payments for which the account doesn't have enough funds are skipped until their next scheduled time, payments
+    /// that failed for other reasons are tried again with an increasing delay, see [`ScheduledPaymentRun::Failed`].
+    pub async fn process_scheduled_payments(&self) -> crate::wallet::Result<Vec<ScheduledPayment>> {
+        // Held until the payments are saved, so a due payment isn't sent by multiple calls
+        let _scheduled_payments_lock = self.scheduled_payments_lock.lock().await;
+        let current_time = self.client().get_time_checked().await?;
+        let mut processed_payments = Vec::new();
+
+        for mut scheduled_payment in self.scheduled_payments().await? {
+            if !scheduled_payment.is_due(current_time) {
+                continue;
+            }
+            log::debug!("[TRANSACTION] sending scheduled payment {}", scheduled_payment.id);
+
+            // A transaction recorded by a failed attempt is only sent again if it wasn't submitted
+            let submitted_transaction_id = match scheduled_payment.pending_transaction_id {
+                Some(transaction_id) => self
+                    .is_transaction_submitted(&transaction_id)
+                    .await
+                    .map(|submitted| submitted.then_some(transaction_id)),
+                None => Ok(None),
+            };
+            let result = match submitted_transaction_id {
+                Ok(Some(transaction_id)) => Ok(transaction_id),
+                Ok(None) => self.send_scheduled_payment(&mut scheduled_payment).await,
+                Err(err) => Err(err),
+            };
+
+            let run = match result {
+                Ok(transaction_id) => {
+                    scheduled_payment.executed_runs += 1;
+                    ScheduledPaymentRun::Executed {
+                        time: current_time,
+                        transaction_id,
+                    }
+                }
+                Err(err @ Error::InsufficientFunds { .. }) => ScheduledPaymentRun::Skipped {
+                    time: current_time,
+                    error: err.to_string(),
+                },
+                Err(Error::Client(err))
+                    if matches!(
+                        *err,
+                        crate::client::Error::InputSelection(
+                            crate::client::api::input_selection::Error::InsufficientNativeTokenAmount { .. }
+                        )
+                    ) =>
+                {
+                    ScheduledPaymentRun::Skipped {
+                        time: current_time,
+                        error: err.to_string(),
+                    }
+                }
+                Err(err) => ScheduledPaymentRun::Failed {
+                    time: current_time,
+                    error: err.to_string(),
+                },
+            };
+
+            if matches!(run, ScheduledPaymentRun::Failed { .. }) {
+                scheduled_payment.failed_attempts += 1;
+            }
+            if matches!(run, ScheduledPaymentRun::Failed { .. })
+                && scheduled_payment.failed_attempts < MAX_FAILED_RUN_ATTEMPTS
+            {
+                let delay = FAILED_RUN_RETRY_DELAY << (scheduled_payment.failed_attempts - 1);
+                scheduled_payment.next_run = Some(current_time.saturating_add(delay));
+            } else {
+                scheduled_payment.failed_attempts = 0;
+                scheduled_payment.pending_transaction_id = None;
+                let next_run = match scheduled_payment.max_runs {
+                    Some(max_runs) if scheduled_payment.executed_runs >= max_runs => None,
+                    _ => scheduled_payment.schedule.next_run_after(current_time)?,
+                };
+                // A skipped payment without a later run stays due, so it's sent once there are enough funds
+                if next_run.is_some() || !matches!(run, ScheduledPaymentRun::Skipped { .. }) {
+                    scheduled_payment.next_run = next_run;
+                }
+            }
+            scheduled_payment.last_run = Some(run);
+            self.save_scheduled_payment(&scheduled_payment).await?;
+
+            #[cfg(feature = "events")]
+            self.emit_scheduled_payment_run(&scheduled_payment).await;
+
+            processed_payments.push(scheduled_payment);
+        }
+
+        Ok(processed_payments)
+    }
+
+    /// Returns the scheduled payments of the account, in the order they were created.
+    pub async fn scheduled_payments(&self) -> crate::wallet::Result<Vec<ScheduledPayment>> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .get_scheduled_payments(account_index)
+            .await
+    }
+
+    /// Returns a scheduled payment of the account.
+    pub async fn get_scheduled_payment(&self, id: &str) -> crate::wallet::Result<Option<ScheduledPayment>> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .get_scheduled_payment(account_index, id)
+            .await
+    }
+
+    /// Removes a scheduled payment of the account, so it isn't sent anymore.
+    pub async fn remove_scheduled_payment(&self, id: &str) -> crate::wallet::Result<()> {
+        // Wait for a running processing, which would save the payment again
+        let _scheduled_payments_lock = self.scheduled_payments_lock.lock().await;
+        let account_index = *self.details().await.index();
+        let storage_manager = self.wallet.storage_manager.write().await;
+        if storage_manager
+            .get_scheduled_payment(account_index, id)
+            .await?
+            .is_none()
+        {
+            return Err(Error::ScheduledPaymentNotFound(id.to_string()));
+        }
+        storage_manager.remove_scheduled_payment(account_index, id).await
+    }
+
+    async fn save_scheduled_payment(&self, scheduled_payment: &ScheduledPayment) -> crate::wallet::Result<()> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .write()
+            .await
+            .save_scheduled_payment(account_index, scheduled_payment)
+            .await
+    }
+
+    // Sends the payment, recording its transaction in the scheduled payment before submitting it
+    async fn send_scheduled_payment(
+        &self,
+        scheduled_payment: &mut ScheduledPayment,
+    ) -> crate::wallet::Result<TransactionId> {
+        let options = TransactionOptions::default();
+        let outputs = match &scheduled_payment.payment {
+            BatchPayment::Send(params) => self.send_params_to_outputs([*params.clone()], Some(&options)).await?,
+            BatchPayment::Output(params) => vec![self.prepare_output(*params.clone(), options.clone()).await?],
+        };
+        let prepared_transaction_data = self.prepare_transaction(outputs, options.clone()).await?;
+
+        let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
+            Ok(signed_transaction_data) => signed_transaction_data,
+            Err(err) => {
+                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                return Err(err);
+            }
+        };
+        scheduled_payment.pending_transaction_id = Some(signed_transaction_data.transaction_payload.id());
+        if let Err(err) = self.save_scheduled_payment(scheduled_payment).await {
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
+
+        let transaction = self
+            .submit_and_store_transaction(signed_transaction_data, options)
+            .await?;
+        Ok(transaction.transaction_id)
+    }
+
+    // Whether a transaction is stored in the account and didn't conflict, or was included by the node
+    async fn is_transaction_submitted(&self, transaction_id: &TransactionId) -> crate::wallet::Result<bool> {
+        if let Some(transaction) = self.try_get_transaction(transaction_id).await? {
+            return Ok(transaction.inclusion_state != InclusionState::Conflicting);
+        }
+        match self.client().get_included_block(transaction_id).await {
+            Ok(_) => Ok(true),
+            Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    #[cfg(feature = "events")]
+    async fn emit_scheduled_payment_run(&self, scheduled_payment: &ScheduledPayment) {
+        let scheduled_payment_id = scheduled_payment.id.clone();
+        let event = match &scheduled_payment.last_run {
+            Some(ScheduledPaymentRun::Executed { transaction_id, .. }) => {
+                WalletEvent::ScheduledPaymentExecuted(ScheduledPaymentExecutedEvent {
+                    scheduled_payment_id,
+                    transaction_id: *transaction_id,
+                    next_run: scheduled_payment.next_run,
+                })
+            }
+            Some(ScheduledPaymentRun::Skipped { error, .. }) => {
+                WalletEvent::ScheduledPaymentSkipped(ScheduledPaymentSkippedEvent {
+                    scheduled_payment_id,
+                    error: error.clone(),
+                    next_run: scheduled_payment.next_run,
+                })
+            }
+            Some(ScheduledPaymentRun::Failed { error, .. }) => {
+                WalletEvent::ScheduledPaymentFailed(ScheduledPaymentFailedEvent {
+                    scheduled_payment_id,
+                    error: error.clone(),
+                    next_run: scheduled_payment.next_run,
+                })
+            }
+            None => return,
+        };
+        self.emit(*self.details().await.index(), event).await;
+    }
+}
+
+// A parsed cron expression, with a bit set for every matching value of a field
+struct CronExpression {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // Whether the day fields match every day, which changes how they're combined
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl CronExpression {
+    fn parse(expression: &str) -> crate::wallet::Result<Self> {
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            return Err(Error::InvalidPaymentSchedule(format!(
+                "cron expression `{expression}` must have 5 fields"
+            )));
+        };
+        let days_of_month = parse_cron_field(days_of_month, 1, 31)?;
+        let mut days_of_week = parse_cron_field(days_of_week, 0, 7)?;
+        // 7 is an alias for sunday
+        if is_set(days_of_week, 7) {
+            days_of_week |= 1;
+        }
+
+        Ok(Self {
+            minutes: parse_cron_field(minutes, 0, 59)?,
+            hours: parse_cron_field(hours, 0, 23)?,
+            days_of_month,
+            months: parse_cron_field(months, 1, 12)?,
+            days_of_week,
+            any_day_of_month: days_of_month == range_bits(1, 31),
+            any_day_of_week: days_of_week & range_bits(0, 6) == range_bits(0, 6),
+        })
+    }
+
+    fn next_run_after(&self, time: u32) -> Option<u32> {
+        let mut timestamp = (time as i64 / 60 + 1) * 60;
+
+        for _ in 0..MAX_CRON_STEPS {
+            let date_time = OffsetDateTime::from_unix_timestamp(timestamp).ok()?;
+            if !is_set(self.months, date_time.month() as u8) {
+                let (year, month) = match date_time.month() {
+                    time::Month::December => (date_time.year() + 1, time::Month::January),
+                    month => (date_time.year(), month.next()),
+                };
+                timestamp = Date::from_calendar_date(year, month, 1)
+                    .ok()?
+                    .midnight()
+                    .assume_utc()
+                    .unix_timestamp();
+            } else if !self.matches_day(&date_time) {
+                timestamp = (timestamp / 86_400 + 1) * 86_400;
+            } else if !is_set(self.hours, date_time.hour()) {
+                timestamp = (timestamp / 3_600 + 1) * 3_600;
+            } else if !is_set(self.minutes, date_time.minute()) {
+                timestamp += 60;
+            } else {
+                return u32::try_from(timestamp).ok();
+            }
+        }
+
+        None
+    }
+
+    fn matches_day(&self, date_time: &OffsetDateTime) -> bool {
+        let day_of_month = is_set(self.days_of_month, date_time.day());
+        let day_of_week = is_set(self.days_of_week, date_time.weekday().number_days_from_sunday());
+
+        if self.any_day_of_month || self.any_day_of_week {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        }
+    }
+}
+
+fn is_set(bits: u64, value: u8) -> bool {
+    bits & (1 << value) != 0
+}
+
+// The bits of all values from min to max
+fn range_bits(min: u8, max: u8) -> u64 {
+    (min..=max).fold(0, |bits, value| bits | 1 << value)
+}
+
+// Parses a comma separated list of `*`, values or ranges with an optional step into a bit set
+fn parse_cron_field(field: &str, min: u8, max: u8) -> crate::wallet::Result<u64> {
+    let invalid = || Error::InvalidPaymentSchedule(format!("invalid cron field `{field}`"));
+    let parse_value = |value: &str| {
+        value
+            .parse::<u8>()
+            .ok()
+            .filter(|value| (min..=max).contains(value))
+            .ok_or_else(invalid)
+    };
+
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<u8>().ok().filter(|step| *step > 0).ok_or_else(invalid)?,
+            ),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+                // A single value with a step runs until the maximum, like in cron
+                None if step > 1 => (parse_value(range)?, max),
+                None => (parse_value(range)?, parse_value(range)?),
+            },
+        };
+        if start > end {
+            return Err(invalid());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+
+    Ok(bits)
+}
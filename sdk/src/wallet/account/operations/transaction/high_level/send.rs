@@ -12,7 +12,7 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, MinimumStorageDepositBasicOutput,
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, Output,
         },
         ConvertTo,
     },
@@ -137,6 +137,20 @@ where
     {
         log::debug!("[TRANSACTION] prepare_send");
         let options = options.into();
+        let outputs = self.send_params_to_outputs(params, options.as_ref()).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    // Builds the outputs for the send params, with a storage deposit return and expiration if necessary
+    pub(crate) async fn send_params_to_outputs<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>>
+    where
+        I::IntoIter: Send,
+    {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
@@ -189,7 +203,7 @@ where
                     .with_expiration()?
                     .finish()?;
 
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
+                if !options.map(|o| o.allow_micro_amount).unwrap_or_default() {
                     return Err(Error::InsufficientFunds {
                         available: amount,
                         required: amount + storage_deposit_amount,
@@ -216,6 +230,6 @@ where
             }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
     }
 }
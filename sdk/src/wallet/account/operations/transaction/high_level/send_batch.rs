@@ -0,0 +1,421 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "storage")]
+use crate::wallet::account::types::InclusionState;
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        output::{Output, OutputId, OutputMetadata},
+        parent::Parents,
+        payload::transaction::{TransactionEssence, TransactionId},
+        BlockId,
+    },
+    wallet::{
+        account::{
+            constants::BATCH_OUTPUTS_PER_TRANSACTION,
+            operations::transaction::{high_level::send::SendParams, prepare_output::OutputParams, Transaction},
+            types::OutputData,
+            Account, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// A payment of a [`Batch`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "params", rename_all = "camelCase")]
+pub enum BatchPayment {
+    /// Base coins sent like with [`Account::send_with_params()`].
+    Send(Box<SendParams>),
+    /// An output built like with [`Account::prepare_output()`].
+    Output(Box<OutputParams>),
+}
+
+impl From<SendParams> for BatchPayment {
+    fn from(params: SendParams) -> Self {
+        Self::Send(Box::new(params))
+    }
+}
+
+impl From<OutputParams> for BatchPayment {
+    fn from(params: OutputParams) -> Self {
+        Self::Output(Box::new(params))
+    }
+}
+
+/// The status of a [`BatchPayment`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BatchPaymentStatus {
+    /// Not sent yet, or the transaction sending it got conflicting.
+    Pending,
+    /// Sent in a transaction, its inclusion state can be checked with [`Account::get_transaction()`].
+    Sent {
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+    },
+}
+
+/// Payments which are sent in as many transactions as needed.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct Batch {
+    /// The id of the batch.
+    id: String,
+    /// The payments of the batch.
+    payments: Vec<BatchPayment>,
+    /// The status of each payment, in the same order as the payments.
+    statuses: Vec<BatchPaymentStatus>,
+}
+
+impl Batch {
+    /// Returns whether all payments of the batch were sent.
+    pub fn is_finished(&self) -> bool {
+        !self.statuses.contains(&BatchPaymentStatus::Pending)
+    }
+
+    /// Returns the ids of the transactions sent for the batch, in the order they were sent.
+    pub fn transaction_ids(&self) -> Vec<TransactionId> {
+        let mut transaction_ids = Vec::new();
+        for status in &self.statuses {
+            if let BatchPaymentStatus::Sent { transaction_id } = status {
+                if !transaction_ids.contains(transaction_id) {
+                    transaction_ids.push(*transaction_id);
+                }
+            }
+        }
+        transaction_ids
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends the payments in as many transactions as needed to stay within the maximum amount of inputs and outputs of
+    /// a transaction.
+    ///
+    /// Every transaction uses the remainder output of the previous one as input and references its block, so the
+    /// transactions are sent one after another without waiting for confirmations. With storage enabled, the status of
+    /// the payments is stored after each transaction, so an interrupted batch can be continued with
+    /// [`Account::resume_batch()`]. Custom and mandatory inputs of the options are only used for the first
+    /// transaction.
+    /// ```ignore
+    /// let payments = addresses
+    ///     .into_iter()
+    ///     .map(|address| Ok(SendParams::new(1_000_000, address)?.into()))
+    ///     .collect::<Result<Vec<BatchPayment>>>()?;
+    ///
+    /// let batch = account.send_batch(payments, None).await?;
+    /// for transaction_id in batch.transaction_ids() {
+    ///     println!("Transaction sent: {transaction_id}");
+    /// }
+    /// ```
+    pub async fn send_batch<I: IntoIterator<Item = BatchPayment> + Send>(
+        &self,
+        payments: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Batch>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] send_batch");
+        let payments = payments.into_iter().collect::<Vec<_>>();
+
+        let mut id = [0u8; 16];
+        crypto::utils::rand::fill(&mut id)?;
+        let mut batch = Batch {
+            id: prefix_hex::encode(id),
+            statuses: vec![BatchPaymentStatus::Pending; payments.len()],
+            payments,
+        };
+        #[cfg(feature = "storage")]
+        self.save_batch(&batch).await?;
+
+        self.process_batch(&mut batch, options.into()).await?;
+
+        Ok(batch)
+    }
+
+    /// Sends the pending payments of a stored batch, for example after the wallet got restarted while sending it.
+    ///
+    /// Payments of transactions which got conflicting are sent again, so the account should be synced before.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn resume_batch(
+        &self,
+        batch_id: &str,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Batch> {
+        log::debug!("[TRANSACTION] resume_batch {batch_id}");
+        let mut batch = self
+            .get_batch(batch_id)
+            .await?
+            .ok_or_else(|| Error::BatchNotFound(batch_id.to_string()))?;
+
+        for transaction_id in batch.transaction_ids() {
+            let conflicting = self
+                .try_get_transaction(&transaction_id)
+                .await?
+                .map_or(false, |transaction| {
+                    transaction.inclusion_state == InclusionState::Conflicting
+                });
+            if conflicting {
+                log::debug!("[TRANSACTION] sending payments of conflicting transaction {transaction_id} again");
+                for status in batch.statuses.iter_mut() {
+                    if *status == (BatchPaymentStatus::Sent { transaction_id }) {
+                        *status = BatchPaymentStatus::Pending;
+                    }
+                }
+            }
+        }
+
+        self.process_batch(&mut batch, options.into()).await?;
+
+        Ok(batch)
+    }
+
+    /// Returns the stored batches of the account, in the order they were created.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn batches(&self) -> crate::wallet::Result<Vec<Batch>> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .get_batches(account_index)
+            .await
+    }
+
+    /// Returns a stored batch of the account.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn get_batch(&self, batch_id: &str) -> crate::wallet::Result<Option<Batch>> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .get_batch(account_index, batch_id)
+            .await
+    }
+
+    /// Removes a stored batch of the account.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn remove_batch(&self, batch_id: &str) -> crate::wallet::Result<()> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .write()
+            .await
+            .remove_batch(account_index, batch_id)
+            .await
+    }
+
+    #[cfg(feature = "storage")]
+    async fn save_batch(&self, batch: &Batch) -> crate::wallet::Result<()> {
+        let account_index = *self.details().await.index();
+        self.wallet
+            .storage_manager
+            .write()
+            .await
+            .save_batch(account_index, batch)
+            .await
+    }
+
+    // Sends the pending payments of the batch, halving the amount of outputs per transaction if the input selection
+    // fails for them
+    async fn process_batch(
+        &self,
+        batch: &mut Batch,
+        mut options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<()> {
+        let mut outputs_per_transaction = BATCH_OUTPUTS_PER_TRANSACTION;
+        // The unconfirmed remainder output of the previous transaction and the block it was sent in
+        let mut chained_remainder: Option<(OutputData, BlockId)> = None;
+
+        loop {
+            let payment_indexes = batch
+                .statuses
+                .iter()
+                .enumerate()
+                .filter(|(_, status)| **status == BatchPaymentStatus::Pending)
+                .map(|(index, _)| index)
+                .take(outputs_per_transaction)
+                .collect::<Vec<_>>();
+            if payment_indexes.is_empty() {
+                return Ok(());
+            }
+
+            let mut transaction_options = options.clone().unwrap_or_default();
+            if let Some((remainder, _)) = &chained_remainder {
+                transaction_options.custom_inputs = None;
+                transaction_options.mandatory_inputs = Some(vec![remainder.output_id]);
+            }
+
+            let outputs = self
+                .batch_outputs(batch, &payment_indexes, &transaction_options)
+                .await?;
+            // The remainder isn't confirmed yet, so it's not in the unspent outputs of the account
+            let additional_inputs = chained_remainder
+                .as_ref()
+                .map_or(&[][..], |(remainder, _)| std::slice::from_ref(remainder));
+            let prepared_transaction_data = match self
+                .prepare_transaction_internal(outputs, transaction_options.clone(), additional_inputs)
+                .await
+            {
+                Ok(prepared_transaction_data) => prepared_transaction_data,
+                Err(err) if payment_indexes.len() > 1 && is_transaction_size_error(&err) => {
+                    log::debug!(
+                        "[TRANSACTION] failed to prepare batch transaction with {} outputs: {err}",
+                        payment_indexes.len()
+                    );
+                    outputs_per_transaction = payment_indexes.len() / 2;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
+                Ok(res) => res,
+                Err(err) => {
+                    // unlock outputs so they are available for a new transaction
+                    self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                    return Err(err);
+                }
+            };
+
+            let parents = match &chained_remainder {
+                Some((_, block_id)) => Some(self.parents_with(*block_id).await?),
+                None => None,
+            };
+            let transaction = self
+                .submit_and_store_transaction_with_parents(
+                    signed_transaction_data,
+                    transaction_options.clone(),
+                    parents,
+                )
+                .await?;
+
+            for index in payment_indexes {
+                batch.statuses[index] = BatchPaymentStatus::Sent {
+                    transaction_id: transaction.transaction_id,
+                };
+            }
+            #[cfg(feature = "storage")]
+            self.save_batch(batch).await?;
+
+            if batch.is_finished() {
+                return Ok(());
+            }
+            // The next transaction can't reference a block that wasn't sent
+            if transaction.block_id.is_none() {
+                return Err(Error::BatchInterrupted {
+                    batch_id: batch.id.clone(),
+                    transaction_id: transaction.transaction_id,
+                });
+            }
+            chained_remainder = chained_remainder_output(&prepared_transaction_data, &transaction);
+
+            // Custom and mandatory inputs are only used for the first transaction
+            if let Some(options) = &mut options {
+                options.custom_inputs = None;
+                options.mandatory_inputs = None;
+            }
+        }
+    }
+
+    // Builds the outputs for the payments with the given indexes
+    async fn batch_outputs(
+        &self,
+        batch: &Batch,
+        payment_indexes: &[usize],
+        options: &TransactionOptions,
+    ) -> crate::wallet::Result<Vec<Output>> {
+        let mut outputs = Vec::new();
+        let mut send_params = Vec::new();
+        for index in payment_indexes {
+            match &batch.payments[*index] {
+                BatchPayment::Send(params) => send_params.push(*params.clone()),
+                BatchPayment::Output(params) => {
+                    outputs.push(self.prepare_output(*params.clone(), options.clone()).await?);
+                }
+            }
+        }
+        if !send_params.is_empty() {
+            outputs.extend(self.send_params_to_outputs(send_params, Some(options)).await?);
+        }
+        Ok(outputs)
+    }
+
+    // Tips of the node together with the given block, so the transaction of the block is known before a transaction
+    // spending its outputs
+    async fn parents_with(&self, block_id: BlockId) -> crate::wallet::Result<Parents> {
+        let mut parents = self.client().get_tips().await?;
+        parents.retain(|parent| parent != &block_id);
+        parents.truncate(*Parents::COUNT_RANGE.end() as usize - 1);
+        parents.push(block_id);
+        Ok(Parents::from_vec(parents)?)
+    }
+}
+
+// The remainder output of a sent transaction, to use it as input for the next transaction of the batch
+fn chained_remainder_output(
+    prepared_transaction_data: &PreparedTransactionData,
+    transaction: &Transaction,
+) -> Option<(OutputData, BlockId)> {
+    let remainder = prepared_transaction_data.remainder.as_ref()?;
+    let block_id = transaction.block_id?;
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+    let index = essence
+        .outputs()
+        .iter()
+        .position(|output| output == &remainder.output)?;
+    let output_id = OutputId::new(transaction.transaction_id, index as u16).ok()?;
+
+    Some((
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(block_id, output_id, false, None, None, None, 0, 0, 0),
+            output: remainder.output.clone(),
+            is_spent: false,
+            address: remainder.address,
+            network_id: transaction.network_id,
+            remainder: true,
+            chain: remainder.chain,
+        },
+        block_id,
+    ))
+}
+
+// Errors which can be avoided by sending fewer outputs in a transaction
+fn is_transaction_size_error(error: &Error) -> bool {
+    use crate::{client::api::input_selection::Error as InputSelectionError, types::block::Error as BlockError};
+
+    let is_count_error = |error: &BlockError| {
+        matches!(
+            error,
+            BlockError::InvalidInputCount(_) | BlockError::InvalidOutputCount(_)
+        )
+    };
+
+    match error {
+        Error::Client(error) => match &**error {
+            crate::client::Error::InputSelection(
+                InputSelectionError::InvalidInputCount(_) | InputSelectionError::InvalidOutputCount(_),
+            )
+            | crate::client::Error::InvalidTransactionPayloadLength { .. } => true,
+            crate::client::Error::InputSelection(InputSelectionError::Block(error)) => is_count_error(error),
+            _ => false,
+        },
+        Error::Block(error) => is_count_error(error),
+        _ => false,
+    }
+}
@@ -1,13 +1,13 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{hash_map::Values, HashSet};
+use std::{collections::HashSet, sync::Arc};
 
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::input_selection::{is_alias_transition, Burn, InputSelection, Selected},
+        api::input_selection::{is_alias_transition, Burn, CoinSelectionStrategy, InputSelection, Selected},
         secret::{types::InputSigningData, SecretManage},
     },
     types::block::{
@@ -23,7 +23,9 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    /// Selects inputs for a transaction and locks them in the account, so they don't get used again. The additional
+    /// inputs are selected from together with the unspent outputs of the account.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
@@ -31,6 +33,8 @@ where
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        coin_selection_strategy: Option<&Arc<dyn CoinSelectionStrategy>>,
+        additional_inputs: &[OutputData],
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -73,7 +77,7 @@ where
         // still locked.
         let available_outputs_signing_data = filter_inputs(
             &account_details,
-            account_details.unspent_outputs.values(),
+            account_details.unspent_outputs.values().chain(additional_inputs),
             current_time,
             &outputs,
             burn,
@@ -110,6 +114,10 @@ where
                 input_selection = input_selection.burn(burn.clone());
             }
 
+            if let Some(strategy) = coin_selection_strategy {
+                input_selection = input_selection.coin_selection_strategy(strategy.clone());
+            }
+
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
@@ -145,6 +153,10 @@ where
                 input_selection = input_selection.burn(burn.clone());
             }
 
+            if let Some(strategy) = coin_selection_strategy {
+                input_selection = input_selection.coin_selection_strategy(strategy.clone());
+            }
+
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
@@ -176,6 +188,10 @@ where
             input_selection = input_selection.burn(burn.clone());
         }
 
+        if let Some(strategy) = coin_selection_strategy {
+            input_selection = input_selection.coin_selection_strategy(strategy.clone());
+        }
+
         let selected_transaction_data = match input_selection.select() {
             Ok(r) => r,
             // TODO this error doesn't exist with the new ISA
@@ -222,9 +238,9 @@ where
 /// | [Address, StorageDepositReturn, ...]                | no                |
 /// | [Address, StorageDepositReturn, expired Expiration] | yes               |
 #[allow(clippy::too_many_arguments)]
-fn filter_inputs(
+pub(crate) fn filter_inputs<'a>(
     account: &AccountDetails,
-    available_outputs: Values<'_, OutputId, OutputData>,
+    available_outputs: impl IntoIterator<Item = &'a OutputData>,
     current_time: u32,
     outputs: &[Output],
     burn: Option<&Burn>,
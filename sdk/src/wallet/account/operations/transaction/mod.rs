@@ -3,11 +3,13 @@
 
 mod build_transaction;
 pub(crate) mod high_level;
-mod input_selection;
+pub(crate) mod input_selection;
+pub(crate) mod multisig;
 mod options;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
 mod sign_transaction;
+pub(crate) mod simulate_transaction;
 pub(crate) mod submit_transaction;
 
 pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
@@ -21,6 +23,7 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             output::{dto::OutputDto, Output},
+            parent::Parents,
             payload::transaction::TransactionPayload,
             semantic::ConflictReason,
         },
@@ -124,6 +127,17 @@ where
         &self,
         signed_transaction_data: SignedTransactionData,
         options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.submit_and_store_transaction_with_parents(signed_transaction_data, options, None)
+            .await
+    }
+
+    // Validates the transaction, submits it in a block with the given parents and stores it in the account
+    pub(crate) async fn submit_and_store_transaction_with_parents(
+        &self,
+        signed_transaction_data: SignedTransactionData,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        parents: Option<Parents>,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!(
             "[TRANSACTION] submit_and_store_transaction {}",
@@ -152,7 +166,7 @@ where
 
         // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
         let block_id = match self
-            .submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
+            .submit_transaction_payload(signed_transaction_data.transaction_payload.clone(), parents)
             .await
         {
             Ok(block_id) => Some(block_id),
@@ -188,21 +202,32 @@ where
             inputs,
         };
 
+        self.store_transaction(transaction.clone()).await?;
+
+        Ok(transaction)
+    }
+
+    // Store a sent transaction as pending transaction of the account
+    pub(crate) async fn store_transaction(&self, transaction: Transaction) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
 
-        account_details.transactions.insert(transaction_id, transaction.clone());
-        account_details.pending_transactions.insert(transaction_id);
+        account_details
+            .pending_transactions
+            .insert(transaction.transaction_id);
+        account_details
+            .transactions
+            .insert(transaction.transaction_id, transaction);
         #[cfg(feature = "storage")]
         {
             log::debug!("[TRANSACTION] storing account {}", account_details.index());
             self.save(Some(&account_details)).await?;
         }
 
-        Ok(transaction)
+        Ok(())
     }
 
     // unlock outputs
-    async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
+    pub(crate) async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
         for input_signing_data in inputs {
             let output_id = input_signing_data.output_id();
@@ -0,0 +1,455 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stardust has no native multi-signature address, shared custody is modelled by a coordinator which holds the inputs
+//! (for example an alias output) and only submits a transaction once enough signers of a [`MultisigPolicy`] approved
+//! it. Signers receive the [`PreparedTransactionDataDto`] as signing request and return [`PartialUnlocks`], which
+//! are merged into a [`MultisigTransaction`].
+//!
+//! The M-of-N policy is only enforced off-chain by the coordinator: on the ledger the inputs are still unlocked by
+//! the keys of their addresses, so whoever controls those keys can spend them without the approvals of the other
+//! signers. The policy protects against a coordinator that follows the protocol, not against one that doesn't.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crypto::keys::bip44::Bip44;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{
+            input_selection::is_alias_transition, PreparedTransactionData, PreparedTransactionDataDto,
+            SignedTransactionData,
+        },
+        secret::SecretManage,
+    },
+    types::{
+        block::{
+            address::{Address, Bech32Address, Ed25519Address},
+            output::{unlock_condition::GovernorAddressUnlockCondition, AliasId, AliasOutputBuilder, Output},
+            payload::transaction::{TransactionEssence, TransactionPayload},
+            signature::{dto::Ed25519SignatureDto, Ed25519Signature, Signature},
+            unlock::{dto::UnlockDto, AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{types::Transaction, Account, TransactionOptions},
+        Error, Result,
+    },
+};
+
+/// An M-of-N policy: a [`MultisigTransaction`] needs the approvals of at least `threshold` of the `signers`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct MultisigPolicy {
+    /// The minimum number of signers which need to approve a transaction.
+    threshold: u8,
+    /// The addresses of the keys which can approve a transaction.
+    signers: Vec<Ed25519Address>,
+}
+
+impl MultisigPolicy {
+    /// Creates a new [`MultisigPolicy`], the threshold can't be zero or exceed the number of unique signers.
+    pub fn new(threshold: u8, signers: impl IntoIterator<Item = Ed25519Address>) -> Result<Self> {
+        let signers = signers.into_iter().collect::<Vec<_>>();
+
+        if signers
+            .iter()
+            .enumerate()
+            .any(|(i, signer)| signers[..i].contains(signer))
+        {
+            return Err(Error::InvalidMultisigPolicy("duplicate signer".to_string()));
+        }
+        if threshold == 0 || threshold as usize > signers.len() {
+            return Err(Error::InvalidMultisigPolicy(format!(
+                "threshold {threshold} must be between 1 and the number of signers {}",
+                signers.len()
+            )));
+        }
+
+        Ok(Self { threshold, signers })
+    }
+}
+
+/// The contribution of a single signer to a [`MultisigTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct PartialUnlocks {
+    /// Signature of the essence hash with the key of a [`MultisigPolicy`] signer.
+    approval: Ed25519Signature,
+    /// Signature unlocks for the inputs controlled by the signer, by input index.
+    unlocks: BTreeMap<u16, Unlock>,
+}
+
+impl PartialUnlocks {
+    /// Approves the transaction with the key at `approval_chain` and signs all inputs which require an ed25519
+    /// address the secret manager controls. Inputs of other signers are skipped. The required addresses are resolved
+    /// at `time`, which should be the node time from [`Client::get_time_checked()`](crate::client::Client).
+    pub async fn sign<S: SecretManage>(
+        secret_manager: &S,
+        prepared_transaction_data: &PreparedTransactionData,
+        approval_chain: Bip44,
+        time: u32,
+    ) -> Result<Self>
+    where
+        crate::wallet::Error: From<S::Error>,
+    {
+        let hashed_essence = prepared_transaction_data.essence.hash();
+        let approval = secret_manager.sign_ed25519(&hashed_essence, approval_chain).await?;
+
+        let mut unlocks = BTreeMap::new();
+        for (index, address) in signature_inputs(prepared_transaction_data, time)? {
+            let Some(chain) = prepared_transaction_data.inputs_data[index as usize].chain else {
+                continue;
+            };
+            let signature = secret_manager.sign_ed25519(&hashed_essence, chain).await?;
+            // The chain of an input of another signer derives a key of this secret manager which doesn't match the
+            // address
+            if signature.is_valid(&hashed_essence, &address).is_ok() {
+                unlocks.insert(
+                    index,
+                    Unlock::Signature(SignatureUnlock::new(Signature::from(signature))),
+                );
+            }
+        }
+
+        Ok(Self { approval, unlocks })
+    }
+}
+
+/// Dto for [`PartialUnlocks`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialUnlocksDto {
+    pub approval: Ed25519SignatureDto,
+    pub unlocks: BTreeMap<u16, UnlockDto>,
+}
+
+impl From<&PartialUnlocks> for PartialUnlocksDto {
+    fn from(value: &PartialUnlocks) -> Self {
+        Self {
+            approval: Ed25519SignatureDto::from(&value.approval),
+            unlocks: value
+                .unlocks
+                .iter()
+                .map(|(index, unlock)| (*index, UnlockDto::from(unlock)))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<PartialUnlocksDto> for PartialUnlocks {
+    type Error = Error;
+
+    fn try_from(dto: PartialUnlocksDto) -> Result<Self> {
+        Ok(Self {
+            approval: Ed25519Signature::try_from(dto.approval)?,
+            unlocks: dto
+                .unlocks
+                .into_iter()
+                .map(|(index, unlock)| Ok((index, Unlock::try_from(unlock)?)))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// A transaction which is only submitted once the approvals and signatures of several signers were merged.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct MultisigTransaction {
+    /// The transaction to approve.
+    prepared_transaction_data: PreparedTransactionData,
+    /// The policy which has to be met before the transaction can be submitted.
+    policy: MultisigPolicy,
+    /// Approvals of the signers so far.
+    approvals: Vec<Ed25519Signature>,
+    /// Merged signature unlocks, by input index.
+    signature_unlocks: BTreeMap<u16, Unlock>,
+    /// The time at which the addresses required to unlock the inputs are resolved.
+    time: u32,
+}
+
+impl MultisigTransaction {
+    /// Creates a new [`MultisigTransaction`] without any approvals. The addresses required to unlock the inputs are
+    /// resolved at `time`, which should be the node time from [`Client::get_time_checked()`](crate::client::Client).
+    pub fn new(prepared_transaction_data: PreparedTransactionData, policy: MultisigPolicy, time: u32) -> Self {
+        Self {
+            prepared_transaction_data,
+            policy,
+            approvals: Vec::new(),
+            signature_unlocks: BTreeMap::new(),
+            time,
+        }
+    }
+
+    /// Returns the signing request which is handed out to the signers.
+    pub fn signing_request(&self) -> PreparedTransactionDataDto {
+        PreparedTransactionDataDto::from(&self.prepared_transaction_data)
+    }
+
+    /// Verifies and merges the [`PartialUnlocks`] of a signer. The approval has to be signed by a signer of the policy
+    /// and every signature unlock has to match the address required to unlock its input.
+    pub fn add_partial_unlocks(&mut self, partial_unlocks: PartialUnlocks) -> Result<()> {
+        let hashed_essence = self.prepared_transaction_data.essence.hash();
+
+        let signer = self
+            .policy
+            .signers
+            .iter()
+            .find(|signer| partial_unlocks.approval.is_valid(&hashed_essence, signer).is_ok())
+            .ok_or_else(|| {
+                Error::InvalidPartialUnlocks("approval isn't signed by a signer of the policy".to_string())
+            })?;
+
+        let signature_inputs = signature_inputs(&self.prepared_transaction_data, self.time)?;
+        for (index, unlock) in &partial_unlocks.unlocks {
+            let address = signature_inputs.get(index).ok_or_else(|| {
+                Error::InvalidPartialUnlocks(format!("input {index} doesn't need a signature unlock"))
+            })?;
+            let Unlock::Signature(signature_unlock) = unlock else {
+                return Err(Error::InvalidPartialUnlocks(format!(
+                    "unlock for input {index} isn't a signature unlock"
+                )));
+            };
+            let Signature::Ed25519(signature) = signature_unlock.signature();
+            signature
+                .is_valid(&hashed_essence, address)
+                .map_err(|e| Error::InvalidPartialUnlocks(format!("invalid signature for input {index}: {e}")))?;
+        }
+
+        if !self
+            .approvals
+            .iter()
+            .any(|approval| approval.is_valid(&hashed_essence, signer).is_ok())
+        {
+            log::debug!("[MULTISIG] approval by {signer}");
+            self.approvals.push(partial_unlocks.approval);
+        }
+        self.signature_unlocks.extend(partial_unlocks.unlocks);
+
+        Ok(())
+    }
+
+    /// Returns the signers of the policy which approved the transaction.
+    pub fn approvers(&self) -> Vec<Ed25519Address> {
+        let hashed_essence = self.prepared_transaction_data.essence.hash();
+
+        self.policy
+            .signers
+            .iter()
+            .filter(|signer| {
+                self.approvals
+                    .iter()
+                    .any(|approval| approval.is_valid(&hashed_essence, signer).is_ok())
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Checks if enough signers approved the transaction and all inputs can be unlocked.
+    pub fn is_policy_met(&self) -> Result<bool> {
+        Ok(self.approvers().len() >= self.policy.threshold as usize
+            && signature_inputs(&self.prepared_transaction_data, self.time)?
+                .keys()
+                .all(|index| self.signature_unlocks.contains_key(index)))
+    }
+
+    /// Builds the [`Unlocks`] of the transaction, fails if the policy isn't met yet.
+    pub fn unlocks(&self) -> Result<Unlocks> {
+        let approvals = self.approvers().len();
+        if approvals < self.policy.threshold as usize {
+            return Err(Error::MultisigPolicyNotMet {
+                approvals,
+                threshold: self.policy.threshold,
+            });
+        }
+
+        let mut unlocks = Vec::new();
+        let mut unlock_indexes = HashMap::<Address, u16>::new();
+
+        for (index, (input, address)) in self
+            .prepared_transaction_data
+            .inputs_data
+            .iter()
+            .zip(required_addresses(&self.prepared_transaction_data, self.time)?)
+            .enumerate()
+        {
+            let index = index as u16;
+
+            match unlock_indexes.get(&address) {
+                Some(unlock_index) => unlocks.push(match address {
+                    Address::Alias(_) => Unlock::Alias(AliasUnlock::new(*unlock_index)?),
+                    Address::Ed25519(_) => Unlock::Reference(ReferenceUnlock::new(*unlock_index)?),
+                    Address::Nft(_) => Unlock::Nft(NftUnlock::new(*unlock_index)?),
+                }),
+                None => {
+                    let unlock = self
+                        .signature_unlocks
+                        .get(&index)
+                        .ok_or(Error::MissingSignatureUnlock(index))?;
+                    unlocks.push(unlock.clone());
+                    unlock_indexes.insert(address, index);
+                }
+            }
+
+            match &input.output {
+                Output::Alias(alias_output) => {
+                    unlock_indexes.insert(Address::Alias(alias_output.alias_address(input.output_id())), index);
+                }
+                Output::Nft(nft_output) => {
+                    unlock_indexes.insert(Address::Nft(nft_output.nft_address(input.output_id())), index);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Unlocks::new(unlocks)?)
+    }
+}
+
+/// Dto for [`MultisigTransaction`], used to pass the transaction between the coordinator and signers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigTransactionDto {
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    pub policy: MultisigPolicy,
+    pub approvals: Vec<Ed25519SignatureDto>,
+    pub signature_unlocks: BTreeMap<u16, UnlockDto>,
+    pub time: u32,
+}
+
+impl From<&MultisigTransaction> for MultisigTransactionDto {
+    fn from(value: &MultisigTransaction) -> Self {
+        Self {
+            prepared_transaction_data: PreparedTransactionDataDto::from(&value.prepared_transaction_data),
+            policy: value.policy.clone(),
+            approvals: value.approvals.iter().map(Ed25519SignatureDto::from).collect(),
+            signature_unlocks: value
+                .signature_unlocks
+                .iter()
+                .map(|(index, unlock)| (*index, UnlockDto::from(unlock)))
+                .collect(),
+            time: value.time,
+        }
+    }
+}
+
+impl TryFrom<MultisigTransactionDto> for MultisigTransaction {
+    type Error = Error;
+
+    fn try_from(dto: MultisigTransactionDto) -> Result<Self> {
+        Ok(Self {
+            prepared_transaction_data: PreparedTransactionData::try_from_dto(dto.prepared_transaction_data)?,
+            policy: MultisigPolicy::new(dto.policy.threshold, dto.policy.signers)?,
+            approvals: dto
+                .approvals
+                .into_iter()
+                .map(Ed25519Signature::try_from)
+                .collect::<core::result::Result<_, _>>()?,
+            signature_unlocks: dto
+                .signature_unlocks
+                .into_iter()
+                .map(|(index, unlock)| Ok((index, Unlock::try_from(unlock)?)))
+                .collect::<Result<_>>()?,
+            time: dto.time,
+        })
+    }
+}
+
+// Returns the addresses required to unlock the inputs at the given time, in the order of the inputs.
+fn required_addresses(prepared_transaction_data: &PreparedTransactionData, time: u32) -> Result<Vec<Address>> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+
+    prepared_transaction_data
+        .inputs_data
+        .iter()
+        .map(|input| {
+            let alias_transition = is_alias_transition(&input.output, *input.output_id(), essence.outputs(), None);
+            let (address, _) = input
+                .output
+                .required_and_unlocked_address(time, input.output_id(), alias_transition)?;
+            Ok(address)
+        })
+        .collect()
+}
+
+// Returns the inputs which need a signature unlock, that's the first input for every ed25519 address.
+fn signature_inputs(
+    prepared_transaction_data: &PreparedTransactionData,
+    time: u32,
+) -> Result<BTreeMap<u16, Ed25519Address>> {
+    let mut signature_inputs = BTreeMap::new();
+
+    for (index, address) in required_addresses(prepared_transaction_data, time)?
+        .into_iter()
+        .enumerate()
+    {
+        if let Address::Ed25519(address) = address {
+            if !signature_inputs.values().any(|a| a == &address) {
+                signature_inputs.insert(index as u16, address);
+            }
+        }
+    }
+
+    Ok(signature_inputs)
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Submits a [`MultisigTransaction`] once its policy is met and stores it in the account.
+    pub async fn submit_multisig_transaction(
+        &self,
+        multisig_transaction: &MultisigTransaction,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<Transaction> {
+        log::debug!("[TRANSACTION] submit_multisig_transaction");
+        let unlocks = multisig_transaction.unlocks()?;
+
+        let PreparedTransactionData {
+            essence, inputs_data, ..
+        } = multisig_transaction.prepared_transaction_data.clone();
+        let signed_transaction_data = SignedTransactionData {
+            transaction_payload: TransactionPayload::new(essence, unlocks)?,
+            inputs_data,
+        };
+
+        self.submit_and_store_transaction(signed_transaction_data, options)
+            .await
+    }
+
+    /// Prepares a governance transition of an alias output controlled by the account, which replaces its governor.
+    /// Wrapped into a [`MultisigTransaction`], the governor of a coordinator-held alias is only rotated by quorum.
+    pub async fn prepare_rotate_alias_governor(
+        &self,
+        alias_id: AliasId,
+        governor: Bech32Address,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_rotate_alias_governor");
+        self.client().bech32_hrp_matches(governor.hrp()).await?;
+
+        let output_data = self
+            .unspent_alias_output(&alias_id)
+            .await?
+            .ok_or_else(|| Error::InvalidOutputKind(format!("alias output {alias_id} is not available")))?;
+        let Output::Alias(alias_output) = &output_data.output else {
+            return Err(Error::InvalidOutputKind(format!(
+                "output {} of alias {alias_id} is not an alias output",
+                output_data.output_id
+            )));
+        };
+
+        // The state index stays the same, so this is a governance transition
+        let output = AliasOutputBuilder::from(alias_output)
+            .with_alias_id(alias_output.alias_id_non_null(&output_data.output_id))
+            .replace_unlock_condition(GovernorAddressUnlockCondition::new(governor))
+            .finish_output(self.client().get_token_supply().await?)?;
+
+        self.prepare_transaction([output], options).await
+    }
+}
@@ -1,10 +1,12 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::input_selection::{Burn, BurnDto, CoinSelectionStrategy, CoinSelectionStrategyDto},
     types::block::{
         output::OutputId,
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
@@ -25,6 +27,8 @@ pub struct TransactionOptions {
     pub burn: Option<Burn>,
     pub note: Option<String>,
     pub allow_micro_amount: bool,
+    // Decides which inputs are used to cover the amount of the outputs, `SmallestFirst` if not provided.
+    pub coin_selection_strategy: Option<Arc<dyn CoinSelectionStrategy>>,
 }
 
 impl TransactionOptions {
@@ -38,6 +42,7 @@ impl TransactionOptions {
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            coin_selection_strategy: value.coin_selection_strategy.map(Into::into),
         })
     }
 }
@@ -60,6 +65,8 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: Option<CoinSelectionStrategyDto>,
 }
 
 #[allow(clippy::enum_variant_names)]
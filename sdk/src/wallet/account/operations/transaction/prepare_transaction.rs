@@ -16,6 +16,7 @@ use crate::{
     },
     wallet::account::{
         operations::transaction::{RemainderValueStrategy, TransactionOptions},
+        types::OutputData,
         Account,
     },
 };
@@ -29,6 +30,17 @@ where
         &self,
         outputs: impl Into<Vec<Output>> + Send,
         options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        self.prepare_transaction_internal(outputs, options, &[]).await
+    }
+
+    /// Prepares a transaction which can also use the given outputs of the account as inputs, which aren't in its
+    /// unspent outputs yet, like the unconfirmed remainder of a previous transaction.
+    pub(crate) async fn prepare_transaction_internal(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        additional_inputs: &[OutputData],
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_transaction");
         let options = options.into();
@@ -113,6 +125,10 @@ where
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .and_then(|options| options.coin_selection_strategy.as_ref()),
+                additional_inputs,
             )
             .await?;
 
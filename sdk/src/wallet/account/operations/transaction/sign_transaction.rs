@@ -31,6 +31,11 @@ where
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+        if *self.details().await.watch_only() {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(crate::wallet::Error::WatchOnlyAccount(self.alias().await));
+        }
         #[cfg(feature = "events")]
         self.emit(
             self.details().await.index,
@@ -0,0 +1,398 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashSet};
+
+use getset::{CopyGetters, Getters};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{input_selection::Burn, verify_semantic_unsigned, PreparedTransactionData},
+        secret::SecretManage,
+    },
+    types::block::{
+        address::{Address, Bech32Address, Hrp, ToBech32Ext},
+        output::{ChainId, NativeTokens, Output, TokenId, TokenScheme},
+        payload::transaction::TransactionEssence,
+        semantic::ConflictReason,
+    },
+    wallet::account::Account,
+};
+
+/// The net effect of a transaction on an address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalanceChange {
+    /// The address.
+    #[getset(get = "pub")]
+    address: Bech32Address,
+    /// Whether the address belongs to the account.
+    #[getset(get_copy = "pub")]
+    own: bool,
+    /// The base coins of the consumed outputs owned by the address.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    base_coin_spent: u64,
+    /// The base coins of the created outputs owned by the address.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    base_coin_received: u64,
+    /// The native tokens of the consumed outputs owned by the address.
+    #[getset(get = "pub")]
+    native_tokens_spent: BTreeMap<TokenId, U256>,
+    /// The native tokens of the created outputs owned by the address.
+    #[getset(get = "pub")]
+    native_tokens_received: BTreeMap<TokenId, U256>,
+}
+
+impl AddressBalanceChange {
+    /// Returns the base coins received minus the base coins spent.
+    pub fn base_coin_difference(&self) -> i128 {
+        self.base_coin_received as i128 - self.base_coin_spent as i128
+    }
+}
+
+/// What happens to an alias, foundry or NFT in a transaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChainTransitionKind {
+    /// The chain is created by an output of the transaction.
+    Created,
+    /// The chain is consumed and continued by an output of the transaction.
+    Transitioned,
+    /// The chain is consumed without being continued.
+    Destroyed,
+}
+
+/// An alias, foundry or NFT created, transitioned or destroyed by a transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get_copy = "pub")]
+pub struct ChainTransition {
+    /// The id of the chain, `None` for aliases and NFTs created by the transaction, as their id is derived from the
+    /// id of the signed transaction.
+    chain_id: Option<ChainId>,
+    /// The kind of the chain output, e.g. [`AliasOutput::KIND`](crate::types::block::output::AliasOutput::KIND).
+    output_kind: u8,
+    /// What happens to the chain.
+    kind: ChainTransitionKind,
+    /// The index of the output holding the new state of the chain, `None` if it's destroyed.
+    output_index: Option<u16>,
+}
+
+/// The expiration and timelock unlock conditions of an output created by a transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputTimeConditions {
+    /// The index of the output.
+    #[getset(get_copy = "pub")]
+    output_index: u16,
+    /// The address the output is sent to.
+    #[getset(get = "pub")]
+    address: Bech32Address,
+    /// The address the output returns to once it expired.
+    #[getset(get = "pub")]
+    expiration_return_address: Option<Bech32Address>,
+    /// The unix time in seconds at which the output expires.
+    #[getset(get_copy = "pub")]
+    expiration_time: Option<u32>,
+    /// The unix time in seconds until which the output is timelocked.
+    #[getset(get_copy = "pub")]
+    timelock_time: Option<u32>,
+}
+
+/// The net effect of a prepared transaction, returned from [`Account::simulate_transaction()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSimulation {
+    /// The balance changes of all addresses owning consumed or created outputs.
+    #[getset(get = "pub")]
+    balance_changes: Vec<AddressBalanceChange>,
+    /// The storage deposits that have to be returned to this account once the created outputs are claimed.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    storage_deposit_locked: u64,
+    /// The storage deposits that are returned to their senders by claiming the consumed outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    storage_deposit_returned: u64,
+    /// The aliases, foundries and NFTs created, transitioned or destroyed.
+    #[getset(get = "pub")]
+    chain_transitions: Vec<ChainTransition>,
+    /// The expiration and timelock conditions of the created outputs that have any.
+    #[getset(get = "pub")]
+    time_conditions: Vec<OutputTimeConditions>,
+    /// The native tokens minted by foundries.
+    #[getset(get = "pub")]
+    minted_native_tokens: BTreeMap<TokenId, U256>,
+    /// The native tokens melted by foundries.
+    #[getset(get = "pub")]
+    melted_native_tokens: BTreeMap<TokenId, U256>,
+    /// The native tokens that are consumed without being created again, melted or kept in a foundry.
+    #[getset(get = "pub")]
+    burned_native_tokens: BTreeMap<TokenId, U256>,
+    /// The result of the semantic validation a node would do, assuming the inputs are signed by their owners.
+    #[getset(get_copy = "pub")]
+    conflict_reason: ConflictReason,
+}
+
+impl TransactionSimulation {
+    /// Returns the [`Burn`] implied by the transaction: the destroyed aliases, foundries and NFTs and the burned native
+    /// tokens.
+    pub fn burn(&self) -> Burn {
+        let mut burn = Burn::new();
+        for transition in &self.chain_transitions {
+            match (transition.kind, transition.chain_id) {
+                (ChainTransitionKind::Destroyed, Some(ChainId::Alias(alias_id))) => burn = burn.add_alias(alias_id),
+                (ChainTransitionKind::Destroyed, Some(ChainId::Foundry(foundry_id))) => {
+                    burn = burn.add_foundry(foundry_id)
+                }
+                (ChainTransitionKind::Destroyed, Some(ChainId::Nft(nft_id))) => burn = burn.add_nft(nft_id),
+                _ => {}
+            }
+        }
+        for (token_id, amount) in &self.burned_native_tokens {
+            burn = burn.add_native_token(*token_id, *amount);
+        }
+        burn
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Simulates a prepared transaction and reports its net effect, to be shown to a user before sending it.
+    ///
+    /// The semantic validation a node would do is run with placeholder signatures, so the secret manager isn't used and
+    /// watch-only accounts or secret managers that require user interaction to sign, like a Ledger Nano, can simulate
+    /// transactions too.
+    /// ```ignore
+    /// let prepared_transaction = account.prepare_send(params, None).await?;
+    /// let simulation = account.simulate_transaction(&prepared_transaction).await?;
+    /// for change in simulation.balance_changes() {
+    ///     println!("{}: {}", change.address(), change.base_coin_difference());
+    /// }
+    /// ```
+    pub async fn simulate_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<TransactionSimulation> {
+        log::debug!("[TRANSACTION] simulate_transaction");
+        let current_time = self.client().get_time_checked().await?;
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let own_addresses = self
+            .addresses()
+            .await?
+            .into_iter()
+            .map(|address| *address.address().inner())
+            .collect::<HashSet<_>>();
+
+        let mut simulation = simulate(prepared_transaction_data, current_time, bech32_hrp, &own_addresses);
+        simulation.conflict_reason = verify_semantic_unsigned(prepared_transaction_data, current_time)?;
+
+        Ok(simulation)
+    }
+}
+
+// Computes the net effect of a transaction from its inputs and outputs.
+fn simulate(
+    prepared_transaction_data: &PreparedTransactionData,
+    current_time: u32,
+    bech32_hrp: Hrp,
+    own_addresses: &HashSet<Address>,
+) -> TransactionSimulation {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+
+    let mut balance_changes = BTreeMap::<Address, AddressBalanceChange>::new();
+    let mut storage_deposit_returned = 0;
+    let mut input_chains = BTreeMap::new();
+    let mut native_token_balance = BTreeMap::<TokenId, (U256, U256)>::new();
+
+    for input in &prepared_transaction_data.inputs_data {
+        let output = &input.output;
+        if let Some(owner) = owner(output, current_time) {
+            let change = balance_change(&mut balance_changes, owner, bech32_hrp, own_addresses);
+            change.base_coin_spent += output.amount();
+            add_native_tokens(&mut change.native_tokens_spent, output.native_tokens());
+        }
+        if let Some(native_tokens) = output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                native_token_balance.entry(*native_token.token_id()).or_default().0 += native_token.amount();
+            }
+        }
+
+        if let Some(storage_deposit_return) = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+        {
+            // Expired outputs are claimed by the return address without having to return the deposit.
+            let expired = output
+                .unlock_conditions()
+                .map_or(false, |unlock_conditions| unlock_conditions.is_expired(current_time));
+            if !expired {
+                storage_deposit_returned += storage_deposit_return.amount();
+            }
+        }
+
+        if let Some(chain_id) = output.chain_id() {
+            input_chains.insert(chain_id.or_from_output_id(input.output_id()), output);
+        }
+    }
+
+    let mut storage_deposit_locked = 0;
+    let mut chain_transitions = Vec::new();
+    let mut time_conditions = Vec::new();
+    let mut minted_native_tokens = BTreeMap::<TokenId, U256>::new();
+    let mut melted_native_tokens = BTreeMap::<TokenId, U256>::new();
+
+    for (index, output) in essence.outputs().iter().enumerate() {
+        let output_index = index as u16;
+        let Some(owner) = owner(output, current_time) else {
+            continue;
+        };
+        let change = balance_change(&mut balance_changes, owner, bech32_hrp, own_addresses);
+        change.base_coin_received += output.amount();
+        add_native_tokens(&mut change.native_tokens_received, output.native_tokens());
+        if let Some(native_tokens) = output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                native_token_balance.entry(*native_token.token_id()).or_default().1 += native_token.amount();
+            }
+        }
+
+        if let Some(unlock_conditions) = output.unlock_conditions() {
+            if let Some(storage_deposit_return) = unlock_conditions.storage_deposit_return() {
+                if own_addresses.contains(storage_deposit_return.return_address()) {
+                    storage_deposit_locked += storage_deposit_return.amount();
+                }
+            }
+
+            let expiration = unlock_conditions.expiration();
+            let timelock = unlock_conditions.timelock();
+            if expiration.is_some() || timelock.is_some() {
+                time_conditions.push(OutputTimeConditions {
+                    output_index,
+                    address: owner.to_bech32(bech32_hrp),
+                    expiration_return_address: expiration
+                        .map(|expiration| expiration.return_address().to_bech32(bech32_hrp)),
+                    expiration_time: expiration.map(|expiration| expiration.timestamp()),
+                    timelock_time: timelock.map(|timelock| timelock.timestamp()),
+                });
+            }
+        }
+
+        let Some(chain_id) = output.chain_id() else {
+            continue;
+        };
+        let input = if chain_id.is_null() {
+            None
+        } else {
+            input_chains.remove(&chain_id)
+        };
+
+        if let Output::Foundry(foundry) = output {
+            let TokenScheme::Simple(token_scheme) = foundry.token_scheme();
+            let (minted, melted) = match input {
+                Some(Output::Foundry(input)) => {
+                    let TokenScheme::Simple(input_token_scheme) = input.token_scheme();
+                    (
+                        token_scheme
+                            .minted_tokens()
+                            .saturating_sub(input_token_scheme.minted_tokens()),
+                        token_scheme
+                            .melted_tokens()
+                            .saturating_sub(input_token_scheme.melted_tokens()),
+                    )
+                }
+                _ => (token_scheme.minted_tokens(), token_scheme.melted_tokens()),
+            };
+            let token_id = TokenId::from(foundry.id());
+            if !minted.is_zero() {
+                *minted_native_tokens.entry(token_id).or_default() += minted;
+            }
+            if !melted.is_zero() {
+                *melted_native_tokens.entry(token_id).or_default() += melted;
+            }
+        }
+
+        chain_transitions.push(ChainTransition {
+            chain_id: (!chain_id.is_null()).then_some(chain_id),
+            output_kind: output.kind(),
+            kind: if input.is_some() {
+                ChainTransitionKind::Transitioned
+            } else {
+                ChainTransitionKind::Created
+            },
+            output_index: Some(output_index),
+        });
+    }
+
+    for (chain_id, output) in input_chains {
+        chain_transitions.push(ChainTransition {
+            chain_id: Some(chain_id),
+            output_kind: output.kind(),
+            kind: ChainTransitionKind::Destroyed,
+            output_index: None,
+        });
+    }
+
+    let burned_native_tokens = native_token_balance
+        .into_iter()
+        .filter_map(|(token_id, (consumed, created))| {
+            let minted = minted_native_tokens.get(&token_id).copied().unwrap_or_default();
+            let melted = melted_native_tokens.get(&token_id).copied().unwrap_or_default();
+            let burned = (consumed + minted).saturating_sub(melted + created);
+            (!burned.is_zero()).then_some((token_id, burned))
+        })
+        .collect();
+
+    TransactionSimulation {
+        balance_changes: balance_changes.into_values().collect(),
+        storage_deposit_locked,
+        storage_deposit_returned,
+        chain_transitions,
+        time_conditions,
+        minted_native_tokens,
+        melted_native_tokens,
+        burned_native_tokens,
+        conflict_reason: ConflictReason::None,
+    }
+}
+
+// The address that controls an output: the state controller of an alias, the alias of a foundry and the address, or the
+// return address once expired, of other outputs.
+fn owner(output: &Output, current_time: u32) -> Option<Address> {
+    match output {
+        Output::Basic(basic) => Some(*basic.unlock_conditions().locked_address(basic.address(), current_time)),
+        Output::Alias(alias) => Some(*alias.state_controller_address()),
+        Output::Foundry(foundry) => Some(Address::Alias(*foundry.alias_address())),
+        Output::Nft(nft) => Some(*nft.unlock_conditions().locked_address(nft.address(), current_time)),
+        Output::Treasury(_) => None,
+    }
+}
+
+fn balance_change<'a>(
+    balance_changes: &'a mut BTreeMap<Address, AddressBalanceChange>,
+    address: Address,
+    bech32_hrp: Hrp,
+    own_addresses: &HashSet<Address>,
+) -> &'a mut AddressBalanceChange {
+    balance_changes.entry(address).or_insert_with(|| AddressBalanceChange {
+        address: address.to_bech32(bech32_hrp),
+        own: own_addresses.contains(&address),
+        base_coin_spent: 0,
+        base_coin_received: 0,
+        native_tokens_spent: BTreeMap::new(),
+        native_tokens_received: BTreeMap::new(),
+    })
+}
+
+fn add_native_tokens(balance: &mut BTreeMap<TokenId, U256>, native_tokens: Option<&NativeTokens>) {
+    if let Some(native_tokens) = native_tokens {
+        for native_token in native_tokens.iter() {
+            *balance.entry(*native_token.token_id()).or_default() += native_token.amount();
+        }
+    }
+}
@@ -5,7 +5,7 @@
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::secret::SecretManage,
-    types::block::{payload::Payload, BlockId},
+    types::block::{parent::Parents, payload::Payload, BlockId},
     wallet::account::{operations::transaction::TransactionPayload, Account},
 };
 
@@ -13,10 +13,11 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Submits a payload in a block
+    /// Submits a payload in a block, with the given parents or tips of the node
     pub(crate) async fn submit_transaction_payload(
         &self,
         transaction_payload: TransactionPayload,
+        parents: Option<Parents>,
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[TRANSACTION] send_payload");
         #[cfg(feature = "events")]
@@ -34,7 +35,7 @@ where
         }
         let block = self
             .client()
-            .finish_block_builder(None, Some(Payload::from(transaction_payload)))
+            .finish_block_builder(parents, Some(Payload::from(transaction_payload)))
             .await?;
 
         #[cfg(feature = "events")]
@@ -0,0 +1,583 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+};
+
+use getset::{CopyGetters, Getters};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::{
+        block::{
+            address::{Address, AliasAddress, Bech32Address, Hrp, NftAddress, ToBech32Ext},
+            output::{NativeTokens, Output, TokenId},
+            payload::transaction::{TransactionEssence, TransactionId},
+            BlockId, Error as BlockError,
+        },
+        TryFromDto,
+    },
+    wallet::account::{
+        types::{InclusionState, OutputData, Transaction},
+        Account,
+    },
+};
+
+/// The direction of a transfer, seen from the account.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionDirection {
+    /// Funds were sent to the account by someone else.
+    Incoming,
+    /// Funds were sent from the account to someone else.
+    Outgoing,
+    /// Funds were moved between addresses of the account.
+    Internal,
+}
+
+impl TransactionDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Incoming => "incoming",
+            Self::Outgoing => "outgoing",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+/// Options to filter the transaction history
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHistoryFilter {
+    /// Filter all entries where the milestone timestamp is below the specified timestamp
+    pub lower_bound_milestone_timestamp: Option<u32>,
+    /// Filter all entries where the milestone timestamp is above the specified timestamp
+    pub upper_bound_milestone_timestamp: Option<u32>,
+    /// Return only entries with these directions.
+    pub directions: Option<Vec<TransactionDirection>>,
+    /// Return only entries with these inclusion states.
+    pub inclusion_states: Option<Vec<InclusionState>>,
+}
+
+/// A transfer of the account, returned from [`Account::transaction_history()`].
+///
+/// The amounts are those of the consumed and created outputs owned by the account, so they include storage deposits
+/// that have to be returned.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHistoryEntry {
+    /// The id of the transaction.
+    #[getset(get = "pub")]
+    transaction_id: TransactionId,
+    /// The id of the block containing the transaction, if known.
+    #[getset(get = "pub")]
+    block_id: Option<BlockId>,
+    /// The inclusion state of the transaction.
+    #[getset(get_copy = "pub")]
+    inclusion_state: InclusionState,
+    /// The direction of the transfer.
+    #[getset(get_copy = "pub")]
+    direction: TransactionDirection,
+    /// The timestamp of the milestone that confirmed the transaction, `None` if it's unknown to the account.
+    #[getset(get_copy = "pub")]
+    milestone_timestamp: Option<u32>,
+    /// The time in milliseconds at which the transaction was created or received by the account, `None` for outputs
+    /// received without syncing their transaction.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    #[getset(get_copy = "pub")]
+    timestamp: Option<u128>,
+    /// The senders of an incoming transfer, from the sender features and the inputs, or the recipients of an
+    /// outgoing transfer.
+    #[getset(get = "pub")]
+    counterparties: Vec<Bech32Address>,
+    /// The base coins of the consumed outputs owned by the account.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    base_coin_spent: u64,
+    /// The base coins of the created outputs owned by the account.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    base_coin_received: u64,
+    /// The native tokens of the consumed outputs owned by the account.
+    #[getset(get = "pub")]
+    native_tokens_spent: BTreeMap<TokenId, U256>,
+    /// The native tokens of the created outputs owned by the account.
+    #[getset(get = "pub")]
+    native_tokens_received: BTreeMap<TokenId, U256>,
+    /// The storage deposits in created outputs owned by others, which are returned to the account once claimed.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    storage_deposit_sent: u64,
+    /// The storage deposits in created outputs owned by the account, which have to be returned to their senders.
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    storage_deposit_received: u64,
+    /// The note of the transaction.
+    #[getset(get = "pub")]
+    note: Option<String>,
+}
+
+impl TransactionHistoryEntry {
+    /// Returns the base coins received minus the base coins spent.
+    pub fn base_coin_delta(&self) -> i128 {
+        self.base_coin_received as i128 - self.base_coin_spent as i128
+    }
+
+    fn matches(&self, filter: &TransactionHistoryFilter) -> bool {
+        if let Some(lower_bound) = filter.lower_bound_milestone_timestamp {
+            if self
+                .milestone_timestamp
+                .map_or(true, |timestamp| timestamp < lower_bound)
+            {
+                return false;
+            }
+        }
+        if let Some(upper_bound) = filter.upper_bound_milestone_timestamp {
+            if self
+                .milestone_timestamp
+                .map_or(true, |timestamp| timestamp > upper_bound)
+            {
+                return false;
+            }
+        }
+        if let Some(directions) = &filter.directions {
+            if !directions.contains(&self.direction) {
+                return false;
+            }
+        }
+        if let Some(inclusion_states) = &filter.inclusion_states {
+            if !inclusion_states.contains(&self.inclusion_state) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// The formats the transaction history can be exported to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionHistoryFormat {
+    /// Comma separated values with a header row and one row per entry. Native token deltas are written as
+    /// `<token id>:<signed amount>` and counterparties are separated by `;`. Text fields starting with `=`, `+`, `-`,
+    /// `@`, a tab or a carriage return are prefixed with `'`, so spreadsheet applications don't evaluate them as
+    /// formulas.
+    Csv,
+    /// A JSON array of [`TransactionHistoryEntry`]s.
+    Json,
+}
+
+impl TransactionHistoryFormat {
+    const CSV_HEADER: [&'static str; 14] = [
+        "transaction_id",
+        "block_id",
+        "inclusion_state",
+        "direction",
+        "milestone_timestamp",
+        "timestamp",
+        "base_coin_spent",
+        "base_coin_received",
+        "base_coin_delta",
+        "native_token_deltas",
+        "storage_deposit_sent",
+        "storage_deposit_received",
+        "counterparties",
+        "note",
+    ];
+
+    /// Exports transaction history entries in this format.
+    pub fn export(&self, entries: &[TransactionHistoryEntry]) -> crate::wallet::Result<String> {
+        match self {
+            Self::Csv => Ok(to_csv(entries)),
+            Self::Json => Ok(serde_json::to_string_pretty(entries)?),
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns one entry per transfer of the account, ordered by milestone timestamp, with pending transactions last.
+    ///
+    /// Entries are built from the sent and incoming transactions and from the received outputs, so incoming transfers
+    /// are also listed without [`SyncOptions::sync_incoming_transactions`](crate::wallet::account::SyncOptions), but
+    /// then without the input addresses of the senders.
+    pub async fn transaction_history(
+        &self,
+        filter: impl Into<Option<TransactionHistoryFilter>> + Send,
+    ) -> crate::wallet::Result<Vec<TransactionHistoryEntry>> {
+        log::debug!("[transaction_history]");
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let outputs = self.outputs(None).await?;
+        let own_addresses = own_addresses(self.addresses().await?.iter().map(|a| a.address().inner()), &outputs);
+
+        let mut transactions = self.try_transactions().await?;
+        let sent_transaction_ids = transactions
+            .iter()
+            .map(|transaction| transaction.transaction_id)
+            .collect::<HashSet<_>>();
+        transactions.extend(
+            self.try_incoming_transactions()
+                .await?
+                .into_iter()
+                .filter(|transaction| !sent_transaction_ids.contains(&transaction.transaction_id)),
+        );
+
+        // The milestone timestamps of transactions, known from the outputs they created or consumed
+        let mut milestone_timestamps = HashMap::new();
+        for output_data in &outputs {
+            milestone_timestamps.insert(
+                *output_data.output_id.transaction_id(),
+                output_data.metadata.milestone_timestamp_booked(),
+            );
+            if let (Some(transaction_id), Some(timestamp)) = (
+                output_data.metadata.transaction_id_spent(),
+                output_data.metadata.milestone_timestamp_spent(),
+            ) {
+                milestone_timestamps.insert(*transaction_id, timestamp);
+            }
+        }
+
+        let mut entries = Vec::new();
+        let transaction_ids = transactions
+            .iter()
+            .map(|transaction| transaction.transaction_id)
+            .collect::<HashSet<_>>();
+
+        for transaction in &transactions {
+            let milestone_timestamp = milestone_timestamps.get(&transaction.transaction_id).copied();
+            entries.push(transaction_entry(
+                transaction,
+                milestone_timestamp,
+                bech32_hrp,
+                &own_addresses,
+            )?);
+        }
+
+        // Received outputs of transactions which aren't stored in the account
+        let mut received_outputs = BTreeMap::<TransactionId, Vec<&OutputData>>::new();
+        for output_data in &outputs {
+            let transaction_id = output_data.output_id.transaction_id();
+            if !transaction_ids.contains(transaction_id) {
+                received_outputs.entry(*transaction_id).or_default().push(output_data);
+            }
+        }
+        for (transaction_id, outputs) in received_outputs {
+            entries.push(received_outputs_entry(
+                transaction_id,
+                &outputs,
+                bech32_hrp,
+                &own_addresses,
+            )?);
+        }
+
+        if let Some(filter) = filter.into() {
+            entries.retain(|entry| entry.matches(&filter));
+        }
+        entries.sort_by_key(|entry| (entry.milestone_timestamp.unwrap_or(u32::MAX), entry.timestamp));
+
+        Ok(entries)
+    }
+
+    /// Returns the transaction history of the account exported in the given format.
+    pub async fn export_transaction_history(
+        &self,
+        filter: impl Into<Option<TransactionHistoryFilter>> + Send,
+        format: TransactionHistoryFormat,
+    ) -> crate::wallet::Result<String> {
+        format.export(&self.transaction_history(filter).await?)
+    }
+}
+
+// The addresses of the account and the aliases and NFTs it owns, which can own outputs too.
+fn own_addresses<'a>(addresses: impl Iterator<Item = &'a Address>, outputs: &[OutputData]) -> HashSet<Address> {
+    let mut own_addresses = addresses.copied().collect::<HashSet<_>>();
+    for output_data in outputs {
+        match &output_data.output {
+            Output::Alias(alias) => {
+                own_addresses.insert(Address::Alias(AliasAddress::new(
+                    alias.alias_id_non_null(&output_data.output_id),
+                )));
+            }
+            Output::Nft(nft) => {
+                own_addresses.insert(Address::Nft(NftAddress::new(
+                    nft.nft_id_non_null(&output_data.output_id),
+                )));
+            }
+            _ => {}
+        }
+    }
+    own_addresses
+}
+
+fn transaction_entry(
+    transaction: &Transaction,
+    milestone_timestamp: Option<u32>,
+    bech32_hrp: Hrp,
+    own_addresses: &HashSet<Address>,
+) -> crate::wallet::Result<TransactionHistoryEntry> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let mut entry = TransactionHistoryEntry {
+        transaction_id: transaction.transaction_id,
+        block_id: transaction.block_id,
+        inclusion_state: transaction.inclusion_state,
+        direction: TransactionDirection::Incoming,
+        milestone_timestamp,
+        timestamp: Some(transaction.timestamp),
+        counterparties: Vec::new(),
+        base_coin_spent: 0,
+        base_coin_received: 0,
+        native_tokens_spent: BTreeMap::new(),
+        native_tokens_received: BTreeMap::new(),
+        storage_deposit_sent: 0,
+        storage_deposit_received: 0,
+        note: transaction.note.clone(),
+    };
+    let mut senders = BTreeSet::new();
+    let mut recipients = BTreeSet::new();
+
+    for input in &transaction.inputs {
+        let output = Output::try_from_dto(input.output.clone())?;
+        let Some(owner) = owner(&output) else {
+            continue;
+        };
+        if own_addresses.contains(&owner) {
+            entry.base_coin_spent = entry
+                .base_coin_spent
+                .checked_add(output.amount())
+                .ok_or(BlockError::ConsumedAmountOverflow)?;
+            add_native_tokens(&mut entry.native_tokens_spent, output.native_tokens())?;
+        } else {
+            senders.insert(owner);
+        }
+    }
+
+    for output in essence.outputs() {
+        let Some(owner) = owner(output) else {
+            continue;
+        };
+        if own_addresses.contains(&owner) {
+            entry.add_received_output(output, own_addresses, &mut senders)?;
+        } else {
+            recipients.insert(owner);
+            if let Some(storage_deposit_return) = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            {
+                if own_addresses.contains(storage_deposit_return.return_address()) {
+                    entry.storage_deposit_sent = entry
+                        .storage_deposit_sent
+                        .checked_add(storage_deposit_return.amount())
+                        .ok_or(BlockError::StorageDepositReturnOverflow)?;
+                }
+            }
+        }
+    }
+
+    let counterparties = if transaction.incoming {
+        senders
+    } else if recipients.is_empty() {
+        entry.direction = TransactionDirection::Internal;
+        BTreeSet::new()
+    } else {
+        entry.direction = TransactionDirection::Outgoing;
+        recipients
+    };
+    entry.counterparties = counterparties
+        .into_iter()
+        .map(|address| address.to_bech32(bech32_hrp))
+        .collect();
+
+    Ok(entry)
+}
+
+// Outputs are only in the ledger of the node once the transaction creating them is confirmed by a milestone, so the
+// transactions of received outputs are always confirmed.
+fn received_outputs_entry(
+    transaction_id: TransactionId,
+    outputs: &[&OutputData],
+    bech32_hrp: Hrp,
+    own_addresses: &HashSet<Address>,
+) -> crate::wallet::Result<TransactionHistoryEntry> {
+    let mut entry = TransactionHistoryEntry {
+        transaction_id,
+        block_id: outputs.first().map(|output_data| *output_data.metadata.block_id()),
+        inclusion_state: InclusionState::Confirmed,
+        direction: TransactionDirection::Incoming,
+        milestone_timestamp: outputs
+            .first()
+            .map(|output_data| output_data.metadata.milestone_timestamp_booked()),
+        timestamp: None,
+        counterparties: Vec::new(),
+        base_coin_spent: 0,
+        base_coin_received: 0,
+        native_tokens_spent: BTreeMap::new(),
+        native_tokens_received: BTreeMap::new(),
+        storage_deposit_sent: 0,
+        storage_deposit_received: 0,
+        note: None,
+    };
+    let mut senders = BTreeSet::new();
+    for output_data in outputs {
+        entry.add_received_output(&output_data.output, own_addresses, &mut senders)?;
+    }
+    entry.counterparties = senders
+        .into_iter()
+        .map(|address| address.to_bech32(bech32_hrp))
+        .collect();
+
+    Ok(entry)
+}
+
+impl TransactionHistoryEntry {
+    fn add_received_output(
+        &mut self,
+        output: &Output,
+        own_addresses: &HashSet<Address>,
+        senders: &mut BTreeSet<Address>,
+    ) -> crate::wallet::Result<()> {
+        self.base_coin_received = self
+            .base_coin_received
+            .checked_add(output.amount())
+            .ok_or(BlockError::CreatedAmountOverflow)?;
+        add_native_tokens(&mut self.native_tokens_received, output.native_tokens())?;
+        if let Some(sender) = output.features().and_then(|features| features.sender()) {
+            if !own_addresses.contains(sender.address()) {
+                senders.insert(*sender.address());
+            }
+        }
+        if let Some(storage_deposit_return) = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+        {
+            if !own_addresses.contains(storage_deposit_return.return_address()) {
+                self.storage_deposit_received = self
+                    .storage_deposit_received
+                    .checked_add(storage_deposit_return.amount())
+                    .ok_or(BlockError::StorageDepositReturnOverflow)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The address that owns an output: the state controller of an alias, the alias of a foundry and the address of other
+// outputs.
+fn owner(output: &Output) -> Option<Address> {
+    match output {
+        Output::Basic(basic) => Some(*basic.address()),
+        Output::Alias(alias) => Some(*alias.state_controller_address()),
+        Output::Foundry(foundry) => Some(Address::Alias(*foundry.alias_address())),
+        Output::Nft(nft) => Some(*nft.address()),
+        Output::Treasury(_) => None,
+    }
+}
+
+fn add_native_tokens(
+    balance: &mut BTreeMap<TokenId, U256>,
+    native_tokens: Option<&NativeTokens>,
+) -> crate::wallet::Result<()> {
+    if let Some(native_tokens) = native_tokens {
+        for native_token in native_tokens.iter() {
+            let amount = balance.entry(*native_token.token_id()).or_default();
+            *amount = amount
+                .checked_add(native_token.amount())
+                .ok_or(BlockError::NativeTokensOverflow)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn to_csv(entries: &[TransactionHistoryEntry]) -> String {
+    let mut csv = TransactionHistoryFormat::CSV_HEADER.join(",");
+    csv.push('\n');
+
+    for entry in entries {
+        let native_token_deltas = entry
+            .native_tokens_spent
+            .keys()
+            .chain(entry.native_tokens_received.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|token_id| {
+                let spent = entry.native_tokens_spent.get(token_id).copied().unwrap_or_default();
+                let received = entry.native_tokens_received.get(token_id).copied().unwrap_or_default();
+                match spent.cmp(&received) {
+                    Ordering::Greater => Some(format!("{token_id}:-{}", spent - received)),
+                    Ordering::Less => Some(format!("{token_id}:{}", received - spent)),
+                    Ordering::Equal => None,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(";");
+        let counterparties = entry
+            .counterparties
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(";");
+        let row = [
+            entry.transaction_id.to_string(),
+            entry.block_id.map(|block_id| block_id.to_string()).unwrap_or_default(),
+            inclusion_state_name(entry.inclusion_state).to_string(),
+            entry.direction.as_str().to_string(),
+            entry
+                .milestone_timestamp
+                .map(|timestamp| timestamp.to_string())
+                .unwrap_or_default(),
+            entry
+                .timestamp
+                .map(|timestamp| timestamp.to_string())
+                .unwrap_or_default(),
+            entry.base_coin_spent.to_string(),
+            entry.base_coin_received.to_string(),
+            entry.base_coin_delta().to_string(),
+            native_token_deltas,
+            entry.storage_deposit_sent.to_string(),
+            entry.storage_deposit_received.to_string(),
+            counterparties,
+            entry.note.clone().unwrap_or_default(),
+        ];
+        csv.push_str(
+            &row.iter()
+                .map(|field| escape_csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    csv
+}
+
+// The serialized name of the inclusion state, so the CSV matches the JSON export.
+fn inclusion_state_name(inclusion_state: InclusionState) -> &'static str {
+    match inclusion_state {
+        InclusionState::Pending => "Pending",
+        InclusionState::Confirmed => "Confirmed",
+        InclusionState::Conflicting => "Conflicting",
+        InclusionState::UnknownPruned => "UnknownPruned",
+    }
+}
+
+fn escape_csv_field(field: &str) -> String {
+    // Numbers like a negative delta are kept as they are, any other text that a spreadsheet would evaluate as formula
+    // is prefixed
+    let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) && field.parse::<i128>().is_err() {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     wallet::account::{
         operations::syncing::options::SyncOptions,
         types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
-        Account, AccountAddress,
+        Account, AccountAddress, AccountDetails,
     },
 };
 #[cfg(feature = "events")]
@@ -46,8 +46,6 @@ where
 
         let network_id = self.client().get_network_id().await?;
         let mut account_details = self.details_mut().await;
-        #[cfg(feature = "events")]
-        let account_index = account_details.index;
 
         // update used field of the addresses
         for address_with_unspent_outputs in addresses_with_unspent_outputs.iter() {
@@ -99,79 +97,18 @@ where
 
         // Update spent outputs
         for (output_id, output_metadata_response_opt) in spent_or_unsynced_output_metadata_map {
-            // If we got the output response and it's still unspent, skip it
-            if let Some(output_metadata_response) = output_metadata_response_opt {
-                if output_metadata_response.is_spent() {
-                    account_details.unspent_outputs.remove(&output_id);
-                    if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
-                        output_data.metadata = output_metadata_response;
-                    }
-                } else {
-                    // not spent, just not synced, skip
-                    continue;
-                }
-            }
-
-            if let Some(output) = account_details.outputs.get(&output_id) {
-                // Could also be outputs from other networks after we switched the node, so we check that first
-                if output.network_id == network_id {
-                    log::debug!("[SYNC] Spent output {}", output_id);
-                    account_details.locked_outputs.remove(&output_id);
-                    account_details.unspent_outputs.remove(&output_id);
-                    // Update spent data fields
-                    if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
-                        output_data.metadata.set_spent(true);
-                        output_data.is_spent = true;
-                        #[cfg(feature = "events")]
-                        {
-                            self.emit(
-                                account_index,
-                                WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
-                                    output: OutputDataDto::from(&*output_data),
-                                })),
-                            )
-                            .await;
-                        }
-                    }
-                }
-            }
+            self.update_spent_output(
+                &mut account_details,
+                output_id,
+                output_metadata_response_opt,
+                network_id,
+            )
+            .await;
         }
 
         // Add new synced outputs
         for output_data in unspent_outputs {
-            // Insert output, if it's unknown emit the NewOutputEvent
-            if account_details
-                .outputs
-                .insert(output_data.output_id, output_data.clone())
-                .is_none()
-            {
-                #[cfg(feature = "events")]
-                {
-                    let transaction = account_details
-                        .incoming_transactions
-                        .get(output_data.output_id.transaction_id());
-                    self.emit(
-                        account_index,
-                        WalletEvent::NewOutput(Box::new(NewOutputEvent {
-                            output: OutputDataDto::from(&output_data),
-                            transaction: transaction.as_ref().map(|tx| TransactionPayloadDto::from(&tx.payload)),
-                            transaction_inputs: transaction.as_ref().map(|tx| {
-                                tx.inputs
-                                    .clone()
-                                    .into_iter()
-                                    .map(OutputWithMetadataResponse::from)
-                                    .collect()
-                            }),
-                        })),
-                    )
-                    .await;
-                }
-            };
-            if !output_data.is_spent {
-                account_details
-                    .unspent_outputs
-                    .insert(output_data.output_id, output_data);
-            }
+            self.update_unspent_output(&mut account_details, output_data).await;
         }
 
         #[cfg(feature = "storage")]
@@ -181,10 +118,185 @@ where
                 account_details.alias()
             );
             self.save(Some(&account_details)).await?;
+            // spent outputs and older transactions are only needed in memory until they're saved
+            self.wallet
+                .storage_manager
+                .write()
+                .await
+                .evict_stored_records(&mut account_details);
+        }
+        Ok(())
+    }
+
+    /// Update account with new and spent outputs of a single address, received from MQTT events, and emit events for
+    /// the outputs
+    #[cfg(feature = "mqtt")]
+    pub(crate) async fn update_account_with_address_outputs(
+        &self,
+        address: AddressWithUnspentOutputs,
+        unspent_outputs: Vec<OutputData>,
+        spent_output_metadata_map: HashMap<OutputId, Option<OutputMetadata>>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[MQTT] Update account with outputs of {}", address.address);
+
+        let network_id = self.client().get_network_id().await?;
+        let mut account_details = self.details_mut().await;
+
+        if !unspent_outputs.is_empty() {
+            let addresses = if address.internal {
+                &mut account_details.internal_addresses
+            } else {
+                &mut account_details.public_addresses
+            };
+            let account_address = addresses
+                .iter_mut()
+                .find(|a| a.address == address.address)
+                .ok_or(crate::wallet::Error::AddressNotFoundInAccount(address.address))?;
+            account_address.used = true;
+        }
+
+        // Only update the output ids of this address, the ones of all other addresses stay as they are
+        let mut address_with_unspent_outputs = account_details
+            .addresses_with_unspent_outputs
+            .iter()
+            .position(|a| a.address == address.address)
+            .map_or(address, |position| {
+                account_details.addresses_with_unspent_outputs.remove(position)
+            });
+        address_with_unspent_outputs
+            .output_ids
+            .retain(|output_id| !spent_output_metadata_map.contains_key(output_id));
+        for output_data in &unspent_outputs {
+            if !output_data.is_spent && !address_with_unspent_outputs.output_ids.contains(&output_data.output_id) {
+                address_with_unspent_outputs.output_ids.push(output_data.output_id);
+            }
+        }
+        if !address_with_unspent_outputs.output_ids.is_empty() {
+            account_details
+                .addresses_with_unspent_outputs
+                .push(address_with_unspent_outputs);
+        }
+
+        for (output_id, output_metadata) in spent_output_metadata_map {
+            self.update_spent_output(&mut account_details, output_id, output_metadata, network_id)
+                .await;
+        }
+
+        for output_data in unspent_outputs {
+            self.update_unspent_output(&mut account_details, output_data).await;
+        }
+
+        #[cfg(feature = "storage")]
+        {
+            log::debug!("[MQTT] storing account {} with new outputs", account_details.alias());
+            self.save(Some(&account_details)).await?;
+            // spent outputs and older transactions are only needed in memory until they're saved
+            self.wallet
+                .storage_manager
+                .write()
+                .await
+                .evict_stored_records(&mut account_details);
         }
         Ok(())
     }
 
+    // Set an output as spent if it's known, unless the metadata shows that it's actually unspent and just wasn't synced
+    async fn update_spent_output(
+        &self,
+        account_details: &mut AccountDetails,
+        output_id: OutputId,
+        output_metadata_response_opt: Option<OutputMetadata>,
+        network_id: u64,
+    ) {
+        // If we got the output response and it's still unspent, skip it
+        if let Some(output_metadata_response) = output_metadata_response_opt {
+            if output_metadata_response.is_spent() {
+                account_details.unspent_outputs.remove(&output_id);
+                if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
+                    output_data.metadata = output_metadata_response;
+                }
+            } else {
+                // not spent, just not synced, skip
+                return;
+            }
+        }
+
+        if let Some(output) = account_details.outputs.get(&output_id) {
+            // Could also be outputs from other networks after we switched the node, so we check that first
+            if output.network_id == network_id {
+                log::debug!("[SYNC] Spent output {}", output_id);
+                account_details.locked_outputs.remove(&output_id);
+                account_details.unspent_outputs.remove(&output_id);
+                // Update spent data fields
+                if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
+                    output_data.metadata.set_spent(true);
+                    output_data.is_spent = true;
+                    #[cfg(feature = "events")]
+                    {
+                        self.emit(
+                            account_details.index,
+                            WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
+                                output: OutputDataDto::from(&*output_data),
+                            })),
+                        )
+                        .await;
+                    }
+                }
+            }
+        }
+    }
+
+    // Insert a synced output, if it's unknown emit the NewOutputEvent
+    async fn update_unspent_output(&self, account_details: &mut AccountDetails, output_data: OutputData) {
+        if account_details
+            .outputs
+            .insert(output_data.output_id, output_data.clone())
+            .is_none()
+        {
+            #[cfg(feature = "events")]
+            {
+                let transaction_id = output_data.output_id.transaction_id();
+                let transaction = account_details.incoming_transactions.get(transaction_id).cloned();
+                // Stored incoming transactions are evicted from the account details
+                #[cfg(feature = "storage")]
+                let transaction = match transaction {
+                    Some(transaction) => Some(transaction),
+                    None => self
+                        .wallet
+                        .storage_manager
+                        .read()
+                        .await
+                        .get_incoming_transaction(account_details.index, transaction_id)
+                        .await
+                        .unwrap_or_else(|e| {
+                            log::error!("[SYNC] failed to load incoming transaction {transaction_id}: {e}");
+                            None
+                        }),
+                };
+                self.emit(
+                    account_details.index,
+                    WalletEvent::NewOutput(Box::new(NewOutputEvent {
+                        output: OutputDataDto::from(&output_data),
+                        transaction: transaction.as_ref().map(|tx| TransactionPayloadDto::from(&tx.payload)),
+                        transaction_inputs: transaction.as_ref().map(|tx| {
+                            tx.inputs
+                                .clone()
+                                .into_iter()
+                                .map(OutputWithMetadataResponse::from)
+                                .collect()
+                        }),
+                    })),
+                )
+                .await;
+            }
+        };
+        if !output_data.is_spent {
+            account_details
+                .unspent_outputs
+                .insert(output_data.output_id, output_data);
+        }
+    }
+
     /// Update account with newly synced transactions
     pub(crate) async fn update_account_with_transactions(
         &self,
@@ -248,6 +360,12 @@ where
                 account_details.alias()
             );
             self.save(Some(&account_details)).await?;
+            // spent outputs and older transactions are only needed in memory until they're saved
+            self.wallet
+                .storage_manager
+                .write()
+                .await
+                .evict_stored_records(&mut account_details);
         }
         Ok(())
     }
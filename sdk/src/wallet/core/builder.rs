@@ -15,12 +15,14 @@ use tokio::sync::RwLock;
 use super::operations::storage::SaveLoadWallet;
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
-#[cfg(all(feature = "storage", not(feature = "rocksdb")))]
-use crate::wallet::storage::adapter::memory::Memory;
+#[cfg(feature = "rocksdb")]
+use crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter;
+#[cfg(feature = "sqlite")]
+use crate::wallet::storage::adapter::sqlite::SqliteStorageAdapter;
 #[cfg(feature = "storage")]
 use crate::wallet::{
     account::AccountDetails,
-    storage::{StorageManager, StorageOptions},
+    storage::{adapter::memory::Memory, StorageKind, StorageManager, StorageOptions},
 };
 use crate::{
     client::secret::{SecretManage, SecretManager},
@@ -134,14 +136,29 @@ where
             }
         }
 
-        #[cfg(all(feature = "rocksdb", feature = "storage"))]
-        let storage =
-            crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.path.clone())?;
-        #[cfg(all(not(feature = "rocksdb"), feature = "storage"))]
-        let storage = Memory::default();
-
         #[cfg(feature = "storage")]
-        let mut storage_manager = StorageManager::new(storage, storage_options.encryption_key.clone()).await?;
+        let mut storage_manager = {
+            let encryption_key = storage_options.encryption_key.clone();
+            match storage_options.kind {
+                #[cfg(feature = "rocksdb")]
+                StorageKind::Rocksdb => {
+                    StorageManager::new(
+                        RocksdbStorageAdapter::new(storage_options.path.clone())?,
+                        encryption_key,
+                    )
+                    .await?
+                }
+                #[cfg(feature = "sqlite")]
+                StorageKind::Sqlite => {
+                    StorageManager::new(SqliteStorageAdapter::new(storage_options.path.clone())?, encryption_key)
+                        .await?
+                }
+                StorageKind::Memory => StorageManager::new(Memory::default(), encryption_key).await?,
+                // The wasm storage adapter isn't available yet, so the data is only kept in memory for now.
+                #[cfg(target_family = "wasm")]
+                StorageKind::Wasm => StorageManager::new(Memory::default(), encryption_key).await?,
+            }
+        };
 
         #[cfg(feature = "storage")]
         let read_manager_builder = Self::load(&storage_manager).await?;
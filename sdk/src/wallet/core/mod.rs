@@ -11,8 +11,11 @@ use std::sync::{
 
 use crypto::keys::bip39::{Mnemonic, MnemonicRef};
 use tokio::sync::RwLock;
+use zeroize::Zeroizing;
 
 pub use self::builder::WalletBuilder;
+#[cfg(feature = "storage")]
+pub use self::operations::address_book::Contact;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
@@ -23,6 +26,7 @@ use crate::wallet::storage::{StorageManager, StorageOptions};
 use crate::{
     client::{
         secret::{SecretManage, SecretManager},
+        slip39::Slip39Group,
         verify_mnemonic, Client,
     },
     wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::Balance, Account},
@@ -206,6 +210,22 @@ impl<S: SecretManage> WalletInner<S> {
         Ok(())
     }
 
+    /// Splits a mnemonic into groups of SLIP-39 mnemonic shares, `group_threshold` groups are required to recover it.
+    pub fn mnemonic_to_slip39_shares(
+        &self,
+        mnemonic: &MnemonicRef,
+        passphrase: &str,
+        group_threshold: u8,
+        groups: &[Slip39Group],
+    ) -> crate::wallet::Result<Vec<Vec<Zeroizing<String>>>> {
+        Ok(Client::mnemonic_to_slip39_shares(
+            mnemonic,
+            passphrase,
+            group_threshold,
+            groups,
+        )?)
+    }
+
     #[cfg(feature = "events")]
     pub(crate) async fn emit(&self, account_index: u32, event: crate::wallet::events::types::WalletEvent) {
         self.event_emitter.read().await.emit(account_index, event);
@@ -39,8 +39,8 @@ where
 
         // Search for addresses in current accounts
         for account in self.accounts.read().await.iter() {
-            // If the gap limit is 0, there is no need to search for funds
-            if address_gap_limit > 0 {
+            // If the gap limit is 0, there is no need to search for funds, watch-only accounts can't generate addresses
+            if address_gap_limit > 0 && !*account.details().await.watch_only() {
                 account
                     .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
                     .await?;
@@ -0,0 +1,125 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "stronghold")]
+use crate::types::block::address::Hrp;
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::{Error, Wallet},
+};
+
+/// A labelled address in the address book of the wallet, see [`Wallet::add_contact()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct Contact {
+    /// The unique label of the contact.
+    label: String,
+    /// The address of the contact.
+    address: Bech32Address,
+    /// Tags to group contacts.
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl Contact {
+    /// Creates a new contact without tags.
+    pub fn new(label: impl Into<String>, address: impl Into<Bech32Address>) -> Self {
+        Self {
+            label: label.into(),
+            address: address.into(),
+            tags: Vec::new(),
+        }
+    }
+
+    /// Sets the tags of the contact.
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Adds a contact to the address book. The label has to be unique and the HRP of the address has to match the one
+    /// of the network.
+    pub async fn add_contact(&self, contact: Contact) -> crate::wallet::Result<()> {
+        self.client().bech32_hrp_matches(contact.address.hrp()).await?;
+
+        let storage_manager = self.storage_manager.write().await;
+        let mut contacts = storage_manager.get_contacts().await?;
+        if contacts.iter().any(|c| c.label == contact.label) {
+            return Err(Error::ContactAlreadyExists(contact.label));
+        }
+        log::debug!("[ADDRESS BOOK] add contact {}", contact.label);
+        contacts.push(contact);
+        storage_manager.save_contacts(&contacts).await
+    }
+
+    /// Replaces the contact with the given label, the label of the contact itself can be changed too.
+    pub async fn update_contact(&self, label: &str, contact: Contact) -> crate::wallet::Result<()> {
+        self.client().bech32_hrp_matches(contact.address.hrp()).await?;
+
+        let storage_manager = self.storage_manager.write().await;
+        let mut contacts = storage_manager.get_contacts().await?;
+        if contact.label != label && contacts.iter().any(|c| c.label == contact.label) {
+            return Err(Error::ContactAlreadyExists(contact.label));
+        }
+        let existing = contacts
+            .iter_mut()
+            .find(|c| c.label == label)
+            .ok_or_else(|| Error::ContactNotFound(label.to_string()))?;
+        log::debug!("[ADDRESS BOOK] update contact {label}");
+        *existing = contact;
+        storage_manager.save_contacts(&contacts).await
+    }
+
+    /// Removes the contact with the given label from the address book.
+    pub async fn remove_contact(&self, label: &str) -> crate::wallet::Result<()> {
+        let storage_manager = self.storage_manager.write().await;
+        let mut contacts = storage_manager.get_contacts().await?;
+        let len = contacts.len();
+        contacts.retain(|c| c.label != label);
+        if contacts.len() == len {
+            return Err(Error::ContactNotFound(label.to_string()));
+        }
+        log::debug!("[ADDRESS BOOK] remove contact {label}");
+        storage_manager.save_contacts(&contacts).await
+    }
+
+    /// Replaces the address book with the contacts of a backup, only keeping the ones with the expected HRP if given.
+    #[cfg(feature = "stronghold")]
+    pub(crate) async fn restore_contacts(
+        &self,
+        mut contacts: Vec<Contact>,
+        expected_bech32_hrp: Option<&Hrp>,
+    ) -> crate::wallet::Result<()> {
+        if let Some(expected_bech32_hrp) = expected_bech32_hrp {
+            contacts.retain(|contact| contact.address.hrp() == expected_bech32_hrp);
+        }
+        self.storage_manager.write().await.save_contacts(&contacts).await
+    }
+
+    /// Returns all contacts of the address book, in the order they were added.
+    pub async fn contacts(&self) -> crate::wallet::Result<Vec<Contact>> {
+        self.storage_manager.read().await.get_contacts().await
+    }
+
+    /// Returns the contacts which have the given tag.
+    pub async fn contacts_with_tag(&self, tag: &str) -> crate::wallet::Result<Vec<Contact>> {
+        let mut contacts = self.contacts().await?;
+        contacts.retain(|c| c.tags.iter().any(|t| t == tag));
+        Ok(contacts)
+    }
+
+    /// Returns the contact with the given label.
+    pub async fn get_contact(&self, label: &str) -> crate::wallet::Result<Option<Contact>> {
+        Ok(self.contacts().await?.into_iter().find(|c| c.label == label))
+    }
+}
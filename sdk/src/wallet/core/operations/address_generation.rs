@@ -109,6 +109,17 @@ impl Wallet {
                     )
                     .await?
             }
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => {
+                pkcs11
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
             #[cfg(feature = "private_key_secret_manager")]
             SecretManager::PrivateKey(private_key) => {
                 private_key
@@ -120,6 +131,17 @@ impl Wallet {
                     )
                     .await?
             }
+            #[cfg(feature = "remote_secret_manager")]
+            SecretManager::Remote(remote) => {
+                remote
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
             SecretManager::Placeholder => return Err(crate::client::Error::PlaceholderSecretManager.into()),
         };
 
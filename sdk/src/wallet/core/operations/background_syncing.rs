@@ -17,7 +17,8 @@ impl<S: 'static + SecretManage> Wallet<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Start the background syncing process for all accounts, default interval is 7 seconds
+    /// Start the background syncing process for all accounts, default interval is 7 seconds. With storage enabled,
+    /// due scheduled payments of the accounts are sent after syncing them.
     pub async fn start_background_syncing(
         &self,
         options: Option<SyncOptions>,
@@ -56,7 +57,13 @@ where
                             break 'outer;
                         }
                         match account.sync(options.clone()).await {
-                            Ok(_) => {}
+                            Ok(_) =>
+                            {
+                                #[cfg(feature = "storage")]
+                                if let Err(err) = account.process_scheduled_payments().await {
+                                    log::debug!("[background_syncing] scheduled payments error: {}", err);
+                                }
+                            }
                             Err(err) => log::debug!("[background_syncing] error: {}", err),
                         };
                     }
@@ -44,6 +44,8 @@ where
             pow_worker_count,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests,
+            verification_options,
+            cache_options,
         } = client_options;
 
         // Only check bech32 if something in the node_manager_builder changed
@@ -56,6 +58,8 @@ where
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
         #[cfg(not(target_family = "wasm"))]
         self.client.request_pool.resize(max_parallel_api_requests).await;
+        self.client.set_verification_options(verification_options).await;
+        self.client.set_cache_options(cache_options).await;
         #[cfg(not(target_family = "wasm"))]
         {
             *self.client.pow_worker_count.write().await = pow_worker_count;
@@ -71,7 +75,15 @@ where
             if let Ok(info) = self.client.get_info().await {
                 network_info.protocol_parameters = info.node_info.protocol;
             }
-            *self.client.network_info.write().await = network_info;
+            let mut current_network_info = self.client.network_info.write().await;
+            let network_changed =
+                current_network_info.protocol_parameters.network_id() != network_info.protocol_parameters.network_id();
+            *current_network_info = network_info;
+            drop(current_network_info);
+
+            if network_changed {
+                self.client.clear_memory_cache().await;
+            }
 
             for account in self.accounts.write().await.iter_mut() {
                 account.update_account_bech32_hrp().await?;
@@ -2,16 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod account_recovery;
+#[cfg(feature = "storage")]
+pub(crate) mod address_book;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
 pub(crate) mod get_account;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+#[cfg(feature = "mqtt")]
+pub(crate) mod mqtt_syncing;
+#[cfg(feature = "pkcs11")]
+pub(crate) mod pkcs11;
 pub(crate) mod storage;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold_backup;
+pub(crate) mod transaction;
 #[cfg(debug_assertions)]
 pub(crate) mod verify_integrity;
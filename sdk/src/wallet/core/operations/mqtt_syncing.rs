@@ -0,0 +1,209 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{atomic::Ordering, Arc},
+    time::Duration,
+};
+
+use tokio::time::sleep;
+
+use crate::{
+    client::{
+        node_api::mqtt::{remove_topic_handler, MqttEvent, Topic, TopicEvent, TopicHandler},
+        secret::SecretManage,
+    },
+    wallet::{account::operations::syncing::SyncOptions, Wallet},
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start syncing all accounts based on MQTT events of the node, instead of polling it in an interval.
+    /// New and spent outputs of the account addresses and the inclusion of pending transactions are applied when the
+    /// node publishes them. The accounts are synced once when starting and again after the MQTT connection got
+    /// reestablished, because events could have been missed in the meantime. Outputs owned by alias and NFT outputs are
+    /// only updated by these syncs. Can be stopped with [`Wallet::stop_background_syncing()`].
+    pub async fn start_mqtt_syncing(&self, options: Option<SyncOptions>) -> crate::wallet::Result<()> {
+        log::debug!("[start_mqtt_syncing]");
+        // stop existing process if running
+        if self.background_syncing_status.load(Ordering::Relaxed) == 1 {
+            self.background_syncing_status.store(2, Ordering::Relaxed);
+        };
+        while self.background_syncing_status.load(Ordering::Relaxed) == 2 {
+            log::debug!("[mqtt_syncing]: waiting for the old process to stop");
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        self.background_syncing_status.store(1, Ordering::Relaxed);
+        let wallet = self.clone();
+        let _mqtt_syncing = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                wallet.mqtt_syncing(options).await;
+                wallet.background_syncing_status.store(0, Ordering::Relaxed);
+                log::debug!("[mqtt_syncing]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    async fn mqtt_syncing(&self, options: Option<SyncOptions>) {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        // Other subscribers of the client can use the same topics, so only this handler is removed from them
+        let handler: Arc<TopicHandler> = Arc::new(Box::new(move |event: &TopicEvent| {
+            // The receiver only gets dropped when the syncing stopped
+            sender.send(event.clone()).ok();
+        }));
+        // Subscribed topics with the index of the account they belong to
+        let mut subscribed_topics = HashMap::new();
+        let mut mqtt_events = self.client().mqtt_event_receiver().await;
+        mqtt_events.borrow_and_update();
+        let mut connected_before = false;
+        let mut sync_accounts = true;
+
+        loop {
+            if self.background_syncing_status.load(Ordering::Relaxed) == 2 {
+                log::debug!("[mqtt_syncing]: stopping");
+                break;
+            }
+
+            // New addresses and transactions could have been added in the meantime
+            if let Err(err) = self.update_mqtt_subscriptions(&mut subscribed_topics, &handler).await {
+                log::debug!("[mqtt_syncing] error: {}", err);
+            }
+
+            if sync_accounts {
+                log::debug!("[mqtt_syncing]: syncing accounts");
+                sync_accounts = false;
+                for account in self.accounts.read().await.iter() {
+                    // Check if the process should stop before syncing each account so it stops faster
+                    if self.background_syncing_status.load(Ordering::Relaxed) == 2 {
+                        break;
+                    }
+                    if let Err(err) = account.sync(options.clone()).await {
+                        log::debug!("[mqtt_syncing] error: {}", err);
+                    }
+                }
+            }
+
+            // wait at most a second for events, so stopping the process doesn't have to wait long
+            tokio::select! {
+                Some(event) = receiver.recv() => {
+                    if let Err(err) = self.handle_mqtt_event(&subscribed_topics, &event, &options).await {
+                        log::debug!("[mqtt_syncing] error handling event of topic {}: {}", event.topic, err);
+                        // Don't miss the changes of the event
+                        sync_accounts = true;
+                    }
+                }
+                changed = mqtt_events.changed() => {
+                    if changed.is_err() {
+                        // The client options were updated, so we need the new receiver
+                        mqtt_events = self.client().mqtt_event_receiver().await;
+                    }
+                    let mqtt_event = mqtt_events.borrow_and_update().clone();
+                    match mqtt_event {
+                        MqttEvent::Connected => {
+                            if connected_before {
+                                log::debug!("[mqtt_syncing]: reconnected");
+                                sync_accounts = true;
+                            }
+                            connected_before = true;
+                        }
+                        MqttEvent::Disconnected => {
+                            log::debug!("[mqtt_syncing]: disconnected");
+                            // Subscribing again connects to a node again
+                            remove_topic_handler(
+                                &mut *self.client().mqtt.topic_handlers.write().await,
+                                subscribed_topics.drain().map(|(topic, _)| topic),
+                                &handler,
+                            );
+                        }
+                    }
+                }
+                _ = sleep(Duration::from_secs(1)) => {}
+            }
+        }
+
+        if let Err(err) = self
+            .client()
+            .subscriber()
+            .with_topics(subscribed_topics.into_keys())
+            .unsubscribe_handler(&handler)
+            .await
+        {
+            log::debug!("[mqtt_syncing] error: {}", err);
+        }
+    }
+
+    // Subscribe to the topics of new addresses and pending transactions and unsubscribe from the ones which aren't
+    // needed anymore
+    async fn update_mqtt_subscriptions(
+        &self,
+        subscribed_topics: &mut HashMap<Topic, u32>,
+        handler: &Arc<TopicHandler>,
+    ) -> crate::wallet::Result<()> {
+        let mut topics = HashMap::new();
+        for account in self.accounts.read().await.iter() {
+            let account_index = *account.details().await.index();
+            for topic in account.mqtt_topics().await? {
+                topics.insert(topic, account_index);
+            }
+        }
+
+        let old_topics = subscribed_topics
+            .keys()
+            .filter(|topic| !topics.contains_key(*topic))
+            .cloned()
+            .collect::<HashSet<_>>();
+        if !old_topics.is_empty() {
+            self.client()
+                .subscriber()
+                .with_topics(old_topics.iter().cloned())
+                .unsubscribe_handler(handler)
+                .await?;
+            subscribed_topics.retain(|topic, _| !old_topics.contains(topic));
+        }
+
+        let new_topics = topics
+            .into_iter()
+            .filter(|(topic, _)| !subscribed_topics.contains_key(topic))
+            .collect::<HashMap<_, _>>();
+        if !new_topics.is_empty() {
+            log::debug!("[mqtt_syncing]: subscribing to {} topics", new_topics.len());
+            self.client()
+                .subscriber()
+                .with_topics(new_topics.keys().cloned())
+                .subscribe_handler(handler)
+                .await?;
+            subscribed_topics.extend(new_topics);
+        }
+        Ok(())
+    }
+
+    async fn handle_mqtt_event(
+        &self,
+        subscribed_topics: &HashMap<Topic, u32>,
+        event: &TopicEvent,
+        options: &Option<SyncOptions>,
+    ) -> crate::wallet::Result<()> {
+        let Some(account_index) = subscribed_topics.get(&Topic::new_unchecked(event.topic.as_str())) else {
+            return Ok(());
+        };
+        for account in self.accounts.read().await.iter() {
+            if account.details().await.index() == account_index {
+                let options = match options {
+                    Some(options) => options.clone(),
+                    None => account.default_sync_options().await,
+                };
+                account.handle_mqtt_event(event, &options).await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::{
+        secret::{pkcs11::Pkcs11SecretManager, SecretManager},
+        utils::Password,
+    },
+    wallet::Wallet,
+};
+
+impl Wallet<Pkcs11SecretManager> {
+    /// Logs into the PKCS#11 token with the user PIN
+    pub async fn set_pkcs11_pin(&self, pin: impl Into<Password> + Send) -> crate::wallet::Result<()> {
+        Ok(self.secret_manager.read().await.login(pin).await?)
+    }
+}
+
+impl Wallet {
+    /// Logs into the PKCS#11 token with the user PIN
+    pub async fn set_pkcs11_pin(&self, pin: impl Into<Password> + Send) -> crate::wallet::Result<()> {
+        if let SecretManager::Pkcs11(pkcs11) = &*self.secret_manager.read().await {
+            Ok(pkcs11.login(pin).await?)
+        } else {
+            Err(crate::client::Error::SecretManagerMismatch.into())
+        }
+    }
+}
@@ -3,7 +3,7 @@
 
 use std::time::Duration;
 
-use crypto::keys::bip39::Mnemonic;
+use crypto::keys::bip39::{Mnemonic, Passphrase};
 
 use crate::{
     client::{secret::SecretManager, stronghold::StrongholdAdapter, utils::Password},
@@ -61,6 +61,34 @@ impl Wallet {
         }
     }
 
+    /// Stores the seed of a mnemonic and a BIP-39 passphrase into the Stronghold vault, the passphrase isn't persisted
+    pub async fn store_mnemonic_with_passphrase(
+        &self,
+        mnemonic: Mnemonic,
+        passphrase: impl Into<Passphrase> + Send,
+    ) -> crate::wallet::Result<()> {
+        if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
+            stronghold.store_mnemonic_with_passphrase(mnemonic, passphrase).await?;
+            Ok(())
+        } else {
+            Err(crate::client::Error::SecretManagerMismatch.into())
+        }
+    }
+
+    /// Stores a mnemonic recovered from SLIP-39 shares and their SLIP-39 passphrase into the Stronghold vault
+    pub async fn store_slip39_shares(
+        &self,
+        shares: &[impl AsRef<str> + Sync],
+        passphrase: &str,
+    ) -> crate::wallet::Result<()> {
+        if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
+            stronghold.store_slip39_shares(shares, passphrase).await?;
+            Ok(())
+        } else {
+            Err(crate::client::Error::SecretManagerMismatch.into())
+        }
+    }
+
     /// Clears the Stronghold password from memory.
     pub async fn clear_stronghold_password(&self) -> crate::wallet::Result<()> {
         log::debug!("[clear_stronghold_password]");
@@ -112,6 +140,34 @@ impl Wallet<StrongholdAdapter> {
         Ok(self.secret_manager.write().await.store_mnemonic(mnemonic).await?)
     }
 
+    /// Stores the seed of a mnemonic and a BIP-39 passphrase into the Stronghold vault, the passphrase isn't persisted
+    pub async fn store_mnemonic_with_passphrase(
+        &self,
+        mnemonic: Mnemonic,
+        passphrase: impl Into<Passphrase> + Send,
+    ) -> crate::wallet::Result<()> {
+        Ok(self
+            .secret_manager
+            .write()
+            .await
+            .store_mnemonic_with_passphrase(mnemonic, passphrase)
+            .await?)
+    }
+
+    /// Stores a mnemonic recovered from SLIP-39 shares and their SLIP-39 passphrase into the Stronghold vault
+    pub async fn store_slip39_shares(
+        &self,
+        shares: &[impl AsRef<str> + Sync],
+        passphrase: &str,
+    ) -> crate::wallet::Result<()> {
+        Ok(self
+            .secret_manager
+            .write()
+            .await
+            .store_slip39_shares(shares, passphrase)
+            .await?)
+    }
+
     /// Clears the Stronghold password from memory.
     pub async fn clear_stronghold_password(&self) -> crate::wallet::Result<()> {
         log::debug!("[clear_stronghold_password]");
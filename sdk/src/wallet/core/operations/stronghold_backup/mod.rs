@@ -9,9 +9,11 @@ use futures::{future::try_join_all, FutureExt};
 
 use self::stronghold_snapshot::read_data_from_stronghold_snapshot;
 #[cfg(feature = "storage")]
+use self::stronghold_snapshot::ADDRESS_BOOK_KEY;
+#[cfg(feature = "storage")]
 use crate::{
     client::storage::StorageAdapter,
-    wallet::{migration::chrysalis::CHRYSALIS_STORAGE_KEY, WalletBuilder},
+    wallet::{migration::chrysalis::CHRYSALIS_STORAGE_KEY, Contact, WalletBuilder},
 };
 use crate::{
     client::{
@@ -184,6 +186,12 @@ impl Wallet {
                             .map(|a| Account::new(a, self.inner.clone()).boxed()),
                     )
                     .await?;
+                    // the restored accounts replace the current ones, so their stored records have to be removed
+                    #[cfg(feature = "storage")]
+                    for account in accounts.iter() {
+                        let account_index = *account.details().await.index();
+                        self.storage_manager.write().await.remove_account(account_index).await?;
+                    }
                     *accounts = restored_account;
                 }
             }
@@ -211,6 +219,11 @@ impl Wallet {
             for account in accounts.iter() {
                 account.save(None).await?;
             }
+            if !ignore_backup_values {
+                if let Some(read_contacts) = new_stronghold.get::<Vec<Contact>>(ADDRESS_BOOK_KEY).await? {
+                    self.restore_contacts(read_contacts, ignore_if_bech32_hrp_mismatch.as_ref()).await?;
+                }
+            }
             if let Some(chrysalis_data) = chrysalis_data {
                 self.storage_manager
                     .read()
@@ -357,6 +370,12 @@ impl Wallet<StrongholdSecretManager> {
                             .map(|a| Account::new(a, self.inner.clone()).boxed()),
                     )
                     .await?;
+                    // the restored accounts replace the current ones, so their stored records have to be removed
+                    #[cfg(feature = "storage")]
+                    for account in accounts.iter() {
+                        let account_index = *account.details().await.index();
+                        self.storage_manager.write().await.remove_account(account_index).await?;
+                    }
                     *accounts = restored_account;
                 }
             }
@@ -384,6 +403,12 @@ impl Wallet<StrongholdSecretManager> {
             for account in accounts.iter() {
                 account.save(None).await?;
             }
+            if !ignore_backup_values {
+                if let Some(read_contacts) = new_stronghold.get::<Vec<Contact>>(ADDRESS_BOOK_KEY).await? {
+                    let expected_bech32_hrp = ignore_if_bech32_hrp_mismatch.map(str::parse::<Hrp>).transpose()?;
+                    self.restore_contacts(read_contacts, expected_bech32_hrp.as_ref()).await?;
+                }
+            }
             if let Some(chrysalis_data) = chrysalis_data {
                 self.storage_manager
                     .read()
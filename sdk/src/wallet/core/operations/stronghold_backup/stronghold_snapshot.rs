@@ -24,6 +24,8 @@ pub(crate) const CLIENT_OPTIONS_KEY: &str = "client_options";
 pub(crate) const COIN_TYPE_KEY: &str = "coin_type";
 pub(crate) const SECRET_MANAGER_KEY: &str = "secret_manager";
 pub(crate) const ACCOUNTS_KEY: &str = "accounts";
+#[cfg(feature = "storage")]
+pub(crate) const ADDRESS_BOOK_KEY: &str = "address_book";
 
 impl<S: 'static + SecretManagerConfig> Wallet<S> {
     pub(crate) async fn store_data_to_stronghold(&self, stronghold: &StrongholdAdapter) -> crate::wallet::Result<()> {
@@ -44,13 +46,21 @@ impl<S: 'static + SecretManagerConfig> Wallet<S> {
 
         let mut serialized_accounts = Vec::new();
         for account in self.accounts.read().await.iter() {
-            serialized_accounts.push(serde_json::to_value(&AccountDetailsDto::from(
-                &*account.details().await,
-            ))?);
+            #[cfg(feature = "storage")]
+            let account_details = account.details_with_stored_records().await?;
+            #[cfg(not(feature = "storage"))]
+            let account_details = account.details().await.clone();
+            serialized_accounts.push(serde_json::to_value(AccountDetailsDto::from(&account_details))?);
         }
 
         stronghold.set(ACCOUNTS_KEY, &serialized_accounts).await?;
 
+        #[cfg(feature = "storage")]
+        {
+            let contacts = self.storage_manager.read().await.get_contacts().await?;
+            stronghold.set(ADDRESS_BOOK_KEY, &contacts).await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,287 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use packable::bounded::TryIntoBoundedU16Error;
+
+use crate::{
+    client::{
+        api::{input_selection::InputSelection, PreparedTransactionData},
+        secret::{types::InputSigningData, SecretManage},
+    },
+    types::block::{
+        input::INPUT_COUNT_RANGE,
+        output::{Output, OUTPUT_COUNT_RANGE},
+    },
+    wallet::{
+        account::{
+            operations::transaction::input_selection::filter_inputs,
+            types::{AccountIdentifier, Transaction},
+            Account, RemainderValueStrategy, TransactionOptions,
+        },
+        Error, Wallet,
+    },
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Prepares a transaction with inputs from multiple accounts of the wallet, so funds of several accounts can be
+    /// moved in a single transaction.
+    ///
+    /// Inputs are selected from the unspent outputs of all given accounts and locked in the account they belong to.
+    /// A [`RemainderValueStrategy::ChangeAddress`] generates the remainder address in the first given account.
+    /// ```ignore
+    /// let prepared_transaction = wallet
+    ///     .prepare_transaction(["Treasury A", "Treasury B"], outputs, None)
+    ///     .await?;
+    /// let transaction = wallet.sign_and_submit_transaction(prepared_transaction, None).await?;
+    /// ```
+    pub async fn prepare_transaction<I: IntoIterator + Send>(
+        &self,
+        account_identifiers: I,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData>
+    where
+        I::Item: Into<AccountIdentifier> + Send,
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] prepare multi account transaction");
+        let options = options.into();
+        let outputs = outputs.into();
+
+        let mut accounts = Vec::new();
+        for identifier in account_identifiers {
+            let account = self.get_account(identifier).await?;
+            let account_index = *account.details().await.index();
+            if !accounts.iter().any(|(index, _)| *index == account_index) {
+                accounts.push((account_index, account));
+            }
+        }
+        let first_account = accounts
+            .first()
+            .map(|(_, account)| account.clone())
+            .ok_or(Error::MissingParameter("accounts"))?;
+        // Always lock the accounts in the same order to prevent deadlocks with other transactions
+        accounts.sort_by_key(|(index, _)| *index);
+        let accounts = accounts.into_iter().map(|(_, account)| account).collect::<Vec<_>>();
+
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        // Check if the outputs have enough amount to cover the storage deposit
+        for output in &outputs {
+            output.verify_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )?;
+        }
+
+        let burn = options.as_ref().and_then(|options| options.burn.as_ref());
+        // Validate the number of outputs. The validation shouldn't be performed if [`Burn`] is present.
+        if !OUTPUT_COUNT_RANGE.contains(&(outputs.len() as u16)) && burn.is_none() {
+            return Err(crate::types::block::Error::InvalidOutputCount(
+                TryIntoBoundedU16Error::Truncated(outputs.len()),
+            ))?;
+        }
+
+        // Custom and mandatory inputs are both required inputs for the input selection
+        let mut required_inputs = HashSet::new();
+        if let Some(options) = &options {
+            for inputs in [&options.custom_inputs, &options.mandatory_inputs]
+                .into_iter()
+                .flatten()
+            {
+                // validate inputs amount
+                if !INPUT_COUNT_RANGE.contains(&(inputs.len() as u16)) {
+                    return Err(crate::types::block::Error::InvalidInputCount(
+                        TryIntoBoundedU16Error::Truncated(inputs.len()),
+                    ))?;
+                }
+                required_inputs.extend(inputs.iter().copied());
+            }
+        }
+
+        let remainder_address = match options.as_ref().map(|options| &options.remainder_value_strategy) {
+            Some(RemainderValueStrategy::ChangeAddress) => {
+                Some(first_account.generate_remainder_address().await?.address().inner)
+            }
+            Some(RemainderValueStrategy::CustomAddress(address)) => Some(address.address().inner),
+            // The input selection will select an address from the inputs
+            Some(RemainderValueStrategy::ReuseAddress) | None => None,
+        };
+
+        // Voting outputs need to be requested before locking the accounts to prevent a deadlock
+        #[cfg(feature = "participation")]
+        let mut voting_output_ids = Vec::new();
+        #[cfg(feature = "participation")]
+        for account in &accounts {
+            if let Some(voting_output) = account.get_voting_output().await? {
+                voting_output_ids.push(voting_output.output_id);
+            }
+        }
+
+        let current_time = self.client().get_time_checked().await?;
+
+        // lock all accounts so the same inputs can't be selected in multiple transactions
+        let mut accounts_details = Vec::new();
+        for account in &accounts {
+            accounts_details.push(account.details_mut().await);
+        }
+
+        let mut forbidden_inputs = HashSet::new();
+        let mut addresses = Vec::new();
+        let mut available_inputs = Vec::new();
+        // The position of the account an input belongs to
+        let mut input_accounts = HashMap::new();
+        for (position, account_details) in accounts_details.iter().enumerate() {
+            forbidden_inputs.extend(account_details.locked_outputs.iter().copied());
+            addresses.extend(
+                account_details
+                    .public_addresses()
+                    .iter()
+                    .chain(account_details.internal_addresses().iter())
+                    .map(|address| *address.address.as_ref()),
+            );
+
+            let inputs = filter_inputs(
+                account_details,
+                account_details.unspent_outputs.values(),
+                current_time,
+                &outputs,
+                burn,
+                None,
+                Some(&required_inputs),
+            )?;
+            for input in &inputs {
+                input_accounts.insert(*input.output_id(), position);
+            }
+            available_inputs.extend(inputs);
+        }
+
+        // Check that no input got already locked
+        for input in &required_inputs {
+            if forbidden_inputs.contains(input) {
+                return Err(crate::wallet::Error::CustomInput(format!(
+                    "provided custom input {input} is already used in another transaction",
+                )));
+            }
+        }
+
+        // Prevent consuming voting outputs if not actually wanted
+        #[cfg(feature = "participation")]
+        forbidden_inputs.extend(
+            voting_output_ids
+                .into_iter()
+                .filter(|output_id| !required_inputs.contains(output_id)),
+        );
+
+        let mut input_selection = InputSelection::new(available_inputs, outputs, addresses, protocol_parameters)
+            .forbidden_inputs(forbidden_inputs);
+
+        if !required_inputs.is_empty() {
+            input_selection = input_selection.required_inputs(required_inputs);
+        }
+
+        if let Some(address) = remainder_address {
+            input_selection = input_selection.remainder_address(address);
+        }
+
+        if let Some(burn) = burn {
+            input_selection = input_selection.burn(burn.clone());
+        }
+
+        if let Some(strategy) = options
+            .as_ref()
+            .and_then(|options| options.coin_selection_strategy.as_ref())
+        {
+            input_selection = input_selection.coin_selection_strategy(strategy.clone());
+        }
+
+        let selected_transaction_data = input_selection.select()?;
+
+        // lock outputs in their accounts so they don't get used by another transaction
+        for input in &selected_transaction_data.inputs {
+            log::debug!("[TRANSACTION] locking: {}", input.output_id());
+            if let Some(position) = input_accounts.get(input.output_id()) {
+                accounts_details[*position].locked_outputs.insert(*input.output_id());
+            }
+        }
+        drop(accounts_details);
+
+        match first_account
+            .build_transaction_essence(selected_transaction_data.clone(), options)
+            .await
+        {
+            Ok(prepared_transaction_data) => Ok(prepared_transaction_data),
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                unlock_inputs(&accounts, &selected_transaction_data.inputs).await?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Signs a transaction prepared with [`Wallet::prepare_transaction()`], submits it to a node and stores it in
+    /// every account it has inputs of.
+    pub async fn sign_and_submit_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] sign and submit multi account transaction");
+        // The inputs are locked in the accounts they belong to
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            let account_details = account.details().await;
+            if prepared_transaction_data
+                .inputs_data
+                .iter()
+                .any(|input| account_details.locked_outputs.contains(input.output_id()))
+            {
+                accounts.push(account.clone());
+            }
+        }
+        let (first_account, other_accounts) = accounts
+            .split_first()
+            .ok_or(Error::MissingParameter("accounts with the transaction inputs"))?;
+
+        // Every error until the transaction is submitted ends up here, so no input stays locked
+        let transaction = async {
+            let signed_transaction_data = first_account.sign_transaction_essence(&prepared_transaction_data).await?;
+            first_account
+                .submit_and_store_transaction(signed_transaction_data, options)
+                .await
+        }
+        .await;
+        let transaction = match transaction {
+            Ok(transaction) => transaction,
+            Err(err) => {
+                // unlock outputs in all accounts so they are available for a new transaction
+                unlock_inputs(&accounts, &prepared_transaction_data.inputs_data).await?;
+                return Err(err);
+            }
+        };
+
+        for account in other_accounts {
+            account.store_transaction(transaction.clone()).await?;
+        }
+
+        Ok(transaction)
+    }
+}
+
+// Unlock the inputs in all accounts
+async fn unlock_inputs<S: 'static + SecretManage>(
+    accounts: &[Account<S>],
+    inputs: &[InputSigningData],
+) -> crate::wallet::Result<()>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    for account in accounts {
+        account.unlock_inputs(inputs).await?;
+    }
+    Ok(())
+}
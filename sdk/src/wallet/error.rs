@@ -28,6 +28,15 @@ pub enum Error {
     /// Error from block crate.
     #[error("{0}")]
     Block(Box<crate::types::block::Error>),
+    /// A batch stopped because the transaction for the next one to build upon wasn't sent
+    #[error("batch {batch_id} interrupted, transaction {transaction_id} wasn't sent yet")]
+    BatchInterrupted {
+        batch_id: String,
+        transaction_id: TransactionId,
+    },
+    /// Batch not found
+    #[error("batch {0} not found")]
+    BatchNotFound(String),
     /// Burning or melting failed
     #[error("burning or melting failed: {0}")]
     BurningOrMeltingFailed(String),
@@ -37,6 +46,12 @@ pub enum Error {
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
+    /// Contact with the same label already exists in the address book
+    #[error("contact {0} already exists")]
+    ContactAlreadyExists(String),
+    /// Contact not found in the address book
+    #[error("contact {0} not found")]
+    ContactNotFound(String),
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
@@ -58,6 +73,15 @@ pub enum Error {
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
+    /// Invalid multisig policy
+    #[error("invalid multisig policy: {0}")]
+    InvalidMultisigPolicy(String),
+    /// Partial unlocks which can't be merged into a multisig transaction
+    #[error("invalid partial unlocks: {0}")]
+    InvalidPartialUnlocks(String),
+    /// Invalid payment schedule
+    #[error("invalid payment schedule: {0}")]
+    InvalidPaymentSchedule(String),
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
@@ -76,6 +100,12 @@ pub enum Error {
     /// Missing parameter.
     #[error("missing parameter: {0}")]
     MissingParameter(&'static str),
+    /// A multisig transaction misses the signature unlock of an input
+    #[error("missing signature unlock for input {0}")]
+    MissingSignatureUnlock(u16),
+    /// Multisig transaction can't be submitted before enough signers approved it
+    #[error("multisig policy not met: {approvals}/{threshold} approvals")]
+    MultisigPolicyNotMet { approvals: usize, threshold: u8 },
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
@@ -97,6 +127,9 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("participation error {0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
+    /// Scheduled payment not found
+    #[error("scheduled payment {0} not found")]
+    ScheduledPaymentNotFound(String),
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -109,6 +142,9 @@ pub enum Error {
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Watch-only accounts can't use the secret manager
+    #[error("account {0} is watch-only, it can't generate addresses or sign transactions")]
+    WatchOnlyAccount(String),
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -183,9 +219,30 @@ impl From<crate::client::secret::ledger_nano::Error> for Error {
     }
 }
 
+#[cfg(feature = "pkcs11")]
+impl From<crate::client::secret::pkcs11::Error> for Error {
+    fn from(error: crate::client::secret::pkcs11::Error) -> Self {
+        Self::Client(Box::new(crate::client::Error::Pkcs11(error)))
+    }
+}
+
+#[cfg(feature = "mqtt")]
+impl From<crate::client::node_api::mqtt::Error> for Error {
+    fn from(error: crate::client::node_api::mqtt::Error) -> Self {
+        Self::Client(Box::new(crate::client::Error::Mqtt(error)))
+    }
+}
+
 #[cfg(feature = "rocksdb")]
 impl From<rocksdb::Error> for Error {
     fn from(error: rocksdb::Error) -> Self {
         Self::Storage(error.to_string())
     }
 }
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Storage(error.to_string())
+    }
+}
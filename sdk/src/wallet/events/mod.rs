@@ -42,6 +42,9 @@ impl EventEmitter {
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
+                WalletEventType::ScheduledPaymentExecuted,
+                WalletEventType::ScheduledPaymentSkipped,
+                WalletEventType::ScheduledPaymentFailed,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
             ] {
@@ -75,6 +78,9 @@ impl EventEmitter {
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+            WalletEvent::ScheduledPaymentExecuted(_) => WalletEventType::ScheduledPaymentExecuted,
+            WalletEvent::ScheduledPaymentSkipped(_) => WalletEventType::ScheduledPaymentSkipped,
+            WalletEvent::ScheduledPaymentFailed(_) => WalletEventType::ScheduledPaymentFailed,
             #[cfg(feature = "ledger_nano")]
             WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
         };
@@ -36,6 +36,9 @@ pub enum WalletEvent {
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    ScheduledPaymentExecuted(ScheduledPaymentExecutedEvent),
+    ScheduledPaymentSkipped(ScheduledPaymentSkippedEvent),
+    ScheduledPaymentFailed(ScheduledPaymentFailedEvent),
 }
 
 impl Serialize for WalletEvent {
@@ -58,6 +61,9 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a ScheduledPaymentExecutedEvent),
+            T7(&'a ScheduledPaymentSkippedEvent),
+            T8(&'a ScheduledPaymentFailedEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -92,6 +98,18 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::ScheduledPaymentExecuted(e) => TypedWalletEvent_ {
+                kind: WalletEventType::ScheduledPaymentExecuted as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::ScheduledPaymentSkipped(e) => TypedWalletEvent_ {
+                kind: WalletEventType::ScheduledPaymentSkipped as u8,
+                event: WalletEvent_::T7(e),
+            },
+            Self::ScheduledPaymentFailed(e) => TypedWalletEvent_ {
+                kind: WalletEventType::ScheduledPaymentFailed as u8,
+                event: WalletEvent_::T8(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -143,6 +161,21 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::ScheduledPaymentExecuted => {
+                    Self::ScheduledPaymentExecuted(ScheduledPaymentExecutedEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize ScheduledPaymentExecuted: {e}"))
+                    })?)
+                }
+                WalletEventType::ScheduledPaymentSkipped => {
+                    Self::ScheduledPaymentSkipped(ScheduledPaymentSkippedEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize ScheduledPaymentSkipped: {e}"))
+                    })?)
+                }
+                WalletEventType::ScheduledPaymentFailed => {
+                    Self::ScheduledPaymentFailed(ScheduledPaymentFailedEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize ScheduledPaymentFailed: {e}"))
+                    })?)
+                }
             },
         )
     }
@@ -160,6 +193,9 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    ScheduledPaymentExecuted = 6,
+    ScheduledPaymentSkipped = 7,
+    ScheduledPaymentFailed = 8,
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -174,6 +210,9 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::ScheduledPaymentExecuted,
+            7 => Self::ScheduledPaymentSkipped,
+            8 => Self::ScheduledPaymentFailed,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -206,6 +245,39 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledPaymentExecutedEvent {
+    /// The id of the scheduled payment.
+    pub scheduled_payment_id: String,
+    /// The transaction that sent the payment.
+    pub transaction_id: TransactionId,
+    /// The time the payment is sent next, `None` if the schedule ended.
+    pub next_run: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledPaymentSkippedEvent {
+    /// The id of the scheduled payment.
+    pub scheduled_payment_id: String,
+    /// Why the account didn't have enough funds.
+    pub error: String,
+    /// The time the payment is sent next, `None` if the schedule ended.
+    pub next_run: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledPaymentFailedEvent {
+    /// The id of the scheduled payment.
+    pub scheduled_payment_id: String,
+    /// The error sending the payment.
+    pub error: String,
+    /// The time the payment is tried again or sent next, `None` if the schedule ended.
+    pub next_run: Option<u32>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TransactionProgressEvent {
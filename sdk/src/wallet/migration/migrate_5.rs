@@ -0,0 +1,208 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+use crate::wallet::storage::{
+    constants::{
+        ACCOUNTS_INDEXATION_KEY, ACCOUNT_ADDRESSES, ACCOUNT_INCOMING_TRANSACTION, ACCOUNT_INCOMING_TRANSACTION_IDS,
+        ACCOUNT_INDEXATION_KEY, ACCOUNT_OUTPUT, ACCOUNT_OUTPUT_IDS, ACCOUNT_TRANSACTION, ACCOUNT_TRANSACTION_IDS,
+        ACCOUNT_UNSPENT_OUTPUT_IDS,
+    },
+    Storage,
+};
+
+pub(crate) struct Migrate;
+
+#[async_trait]
+impl MigrationData for Migrate {
+    const ID: usize = 5;
+    const SDK_VERSION: &'static str = "1.1.1";
+    const DATE: time::Date = time::macros::date!(2023 - 10 - 02);
+}
+
+#[async_trait]
+impl Migration<Storage> for Migrate {
+    async fn migrate(storage: &Storage) -> Result<()> {
+        if let Some(account_indexes) = storage.get::<Vec<u32>>(ACCOUNTS_INDEXATION_KEY).await? {
+            for account_index in account_indexes {
+                let account_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
+                if let Some(mut account) = storage.get::<serde_json::Value>(&account_key).await? {
+                    migrate_account(storage, &account_key, &mut account).await?;
+
+                    storage.set(&account_key, &account).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Moves the addresses, outputs and transactions out of the account into their own records
+async fn migrate_account(storage: &Storage, account_key: &str, account: &mut serde_json::Value) -> Result<()> {
+    let account = account
+        .as_object_mut()
+        .ok_or(Error::Storage("malformatted account".to_owned()))?;
+
+    let mut addresses = serde_json::Map::new();
+    for field in ["publicAddresses", "internalAddresses"] {
+        let field_addresses = account
+            .get_mut(field)
+            .map(|addresses| std::mem::replace(addresses, serde_json::Value::Array(Vec::new())))
+            .unwrap_or_else(|| serde_json::Value::Array(Vec::new()));
+        addresses.insert(field.to_owned(), field_addresses);
+    }
+    storage
+        .set(&format!("{account_key}-{ACCOUNT_ADDRESSES}"), &addresses)
+        .await?;
+
+    let unspent_outputs = take_records(account, "unspentOutputs")?;
+    storage
+        .set(
+            &format!("{account_key}-{ACCOUNT_UNSPENT_OUTPUT_IDS}"),
+            &unspent_outputs.keys().collect::<Vec<_>>(),
+        )
+        .await?;
+
+    let mut outputs = take_records(account, "outputs")?;
+    // Unspent outputs should also be in the outputs, but don't lose them if they aren't
+    for (output_id, output) in unspent_outputs {
+        outputs.entry(output_id).or_insert(output);
+    }
+
+    for (field, records, records_ids, records_to_store) in [
+        ("outputs", ACCOUNT_OUTPUT, ACCOUNT_OUTPUT_IDS, Some(outputs)),
+        ("transactions", ACCOUNT_TRANSACTION, ACCOUNT_TRANSACTION_IDS, None),
+        (
+            "incomingTransactions",
+            ACCOUNT_INCOMING_TRANSACTION,
+            ACCOUNT_INCOMING_TRANSACTION_IDS,
+            None,
+        ),
+    ] {
+        let records_to_store = match records_to_store {
+            Some(records_to_store) => records_to_store,
+            None => take_records(account, field)?,
+        };
+        // Ids are split into buckets by their first byte
+        let mut buckets = HashMap::<String, HashSet<String>>::new();
+        for (id, record) in records_to_store {
+            storage.set(&format!("{account_key}-{records}-{id}"), &record).await?;
+            let bucket = id.get(2..4).unwrap_or_default().to_owned();
+            buckets.entry(bucket).or_default().insert(id);
+        }
+        for (bucket, ids) in &buckets {
+            storage
+                .set(&format!("{account_key}-{records_ids}-{bucket}"), ids)
+                .await?;
+        }
+        // The index key lists the buckets in use
+        storage
+            .set(
+                &format!("{account_key}-{records_ids}"),
+                &buckets.keys().collect::<HashSet<_>>(),
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+// Replaces the map of the field with an empty one and returns its records
+fn take_records(
+    account: &mut serde_json::Map<String, serde_json::Value>,
+    field: &str,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    match account.insert(field.to_owned(), serde_json::Value::Object(serde_json::Map::new())) {
+        Some(serde_json::Value::Object(records)) => Ok(records),
+        None | Some(serde_json::Value::Null) => Ok(serde_json::Map::new()),
+        Some(_) => Err(Error::Storage(format!("malformatted account {field}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::storage::adapter::memory::Memory;
+
+    #[tokio::test]
+    async fn split_account_records() {
+        const OUTPUT_ID: &str = "0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a0000";
+        const TRANSACTION_ID: &str = "0x24a1f46bdb6b2bf38f1c59f73cdd4ae5b418804bb231d76d06fbf246498d5883";
+
+        let storage = Storage {
+            inner: Box::<Memory>::default(),
+            encryption_key: None,
+        };
+        let account_key = format!("{ACCOUNT_INDEXATION_KEY}0");
+        storage.set(ACCOUNTS_INDEXATION_KEY, &[0u32]).await.unwrap();
+        storage
+            .set(
+                &account_key,
+                &serde_json::json!({
+                    "index": 0,
+                    "publicAddresses": [{ "keyIndex": 0 }],
+                    "internalAddresses": [],
+                    "outputs": { OUTPUT_ID: { "outputId": OUTPUT_ID } },
+                    "unspentOutputs": { OUTPUT_ID: { "outputId": OUTPUT_ID } },
+                    "transactions": { TRANSACTION_ID: { "transactionId": TRANSACTION_ID } },
+                    "pendingTransactions": [TRANSACTION_ID],
+                    "incomingTransactions": {},
+                }),
+            )
+            .await
+            .unwrap();
+
+        <Migrate as Migration<Storage>>::migrate(&storage).await.unwrap();
+
+        let account = storage.get::<serde_json::Value>(&account_key).await.unwrap().unwrap();
+        assert_eq!(account["outputs"], serde_json::json!({}));
+        assert_eq!(account["unspentOutputs"], serde_json::json!({}));
+        assert_eq!(account["transactions"], serde_json::json!({}));
+        assert_eq!(account["publicAddresses"], serde_json::json!([]));
+        assert_eq!(account["pendingTransactions"], serde_json::json!([TRANSACTION_ID]));
+
+        let addresses = storage
+            .get::<serde_json::Value>(&format!("{account_key}-{ACCOUNT_ADDRESSES}"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(addresses["publicAddresses"], serde_json::json!([{ "keyIndex": 0 }]));
+        assert_eq!(
+            storage
+                .get::<Vec<String>>(&format!("{account_key}-{ACCOUNT_UNSPENT_OUTPUT_IDS}"))
+                .await
+                .unwrap(),
+            Some(vec![OUTPUT_ID.to_owned()])
+        );
+        assert_eq!(
+            storage
+                .get::<serde_json::Value>(&format!("{account_key}-{ACCOUNT_OUTPUT}-{OUTPUT_ID}"))
+                .await
+                .unwrap(),
+            Some(serde_json::json!({ "outputId": OUTPUT_ID }))
+        );
+        assert_eq!(
+            storage
+                .get::<Vec<String>>(&format!("{account_key}-{ACCOUNT_OUTPUT_IDS}-13"))
+                .await
+                .unwrap(),
+            Some(vec![OUTPUT_ID.to_owned()])
+        );
+        assert_eq!(
+            storage
+                .get::<Vec<String>>(&format!("{account_key}-{ACCOUNT_OUTPUT_IDS}"))
+                .await
+                .unwrap(),
+            Some(vec!["13".to_owned()])
+        );
+        assert_eq!(
+            storage
+                .get::<Vec<String>>(&format!("{account_key}-{ACCOUNT_TRANSACTION_IDS}-24"))
+                .await
+                .unwrap(),
+            Some(vec![TRANSACTION_ID.to_owned()])
+        );
+    }
+}
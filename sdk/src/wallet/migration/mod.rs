@@ -8,6 +8,8 @@ mod migrate_1;
 mod migrate_2;
 mod migrate_3;
 pub(crate) mod migrate_4;
+#[cfg(feature = "storage")]
+mod migrate_5;
 
 use std::collections::HashMap;
 
@@ -34,7 +36,7 @@ static MIGRATIONS: Lazy<Map<dyn anymap::any::Any + Send + Sync>> = Lazy::new(||
     #[cfg(feature = "storage")]
     {
         use super::storage::Storage;
-        const STORAGE_MIGRATIONS: [(Option<usize>, &'static dyn DynMigration<Storage>); 5] = [
+        const STORAGE_MIGRATIONS: [(Option<usize>, &'static dyn DynMigration<Storage>); 6] = [
             // In order to add a new storage migration, add an entry at the bottom of this list
             // and change the list length above.
             // The entry should be in the form of a key-value pair, from previous migration to next.
@@ -44,6 +46,7 @@ static MIGRATIONS: Lazy<Map<dyn anymap::any::Any + Send + Sync>> = Lazy::new(||
             (Some(migrate_1::Migrate::ID), &migrate_2::Migrate),
             (Some(migrate_2::Migrate::ID), &migrate_3::Migrate),
             (Some(migrate_3::Migrate::ID), &migrate_4::Migrate),
+            (Some(migrate_4::Migrate::ID), &migrate_5::Migrate),
         ];
         migrations.insert(std::collections::HashMap::from(STORAGE_MIGRATIONS));
     }
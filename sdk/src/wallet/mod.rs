@@ -27,6 +27,8 @@ pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
 
+#[cfg(feature = "storage")]
+pub use self::core::Contact;
 pub use self::{
     account::{
         operations::transaction::high_level::{
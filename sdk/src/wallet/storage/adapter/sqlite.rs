@@ -0,0 +1,98 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::Path, sync::Arc};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio::sync::Mutex;
+
+use crate::{client::storage::StorageAdapter, wallet::storage::constants::SQLITE_FILENAME};
+
+/// Name of the table holding all key-value records.
+const TABLE_NAME: &str = "records";
+
+/// Key value storage adapter backed by a single SQLite database file in the storage directory.
+#[derive(Clone, Debug)]
+pub struct SqliteStorageAdapter {
+    pub(crate) connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorageAdapter {
+    /// Initialises the storage adapter in the given directory, creating the database file if it doesn't exist yet.
+    pub fn new(path: impl AsRef<Path>) -> crate::wallet::Result<Self> {
+        let path = path.as_ref();
+        std::fs::create_dir_all(path)?;
+        Self::init(Connection::open(path.join(SQLITE_FILENAME))?)
+    }
+
+    /// Initialises a storage adapter backed by an in-memory SQLite database.
+    pub fn in_memory() -> crate::wallet::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(connection: Connection) -> crate::wallet::Result<Self> {
+        connection.execute(
+            &format!("CREATE TABLE IF NOT EXISTS {TABLE_NAME} (key TEXT PRIMARY KEY NOT NULL, value BLOB NOT NULL)"),
+            [],
+        )?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageAdapter for SqliteStorageAdapter {
+    type Error = crate::wallet::Error;
+
+    async fn get_bytes(&self, key: &str) -> crate::wallet::Result<Option<Vec<u8>>> {
+        Ok(self
+            .connection
+            .lock()
+            .await
+            .query_row(
+                &format!("SELECT value FROM {TABLE_NAME} WHERE key = ?1"),
+                [key],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    async fn set_bytes(&self, key: &str, record: &[u8]) -> crate::wallet::Result<()> {
+        self.connection.lock().await.execute(
+            &format!(
+                "INSERT INTO {TABLE_NAME} (key, value) VALUES (?1, ?2) ON CONFLICT(key) DO UPDATE SET value = excluded.value"
+            ),
+            params![key, record],
+        )?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> crate::wallet::Result<()> {
+        self.connection
+            .lock()
+            .await
+            .execute(&format!("DELETE FROM {TABLE_NAME} WHERE key = ?1"), [key])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn get_set_delete() {
+        let storage = SqliteStorageAdapter::in_memory().unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), None);
+
+        storage.set_bytes("key", &[1, 2, 3]).await.unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), Some(vec![1, 2, 3]));
+
+        storage.set_bytes("key", &[4, 5]).await.unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), Some(vec![4, 5]));
+
+        storage.delete("key").await.unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), None);
+    }
+}
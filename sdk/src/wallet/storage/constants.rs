@@ -8,6 +8,10 @@ pub const DEFAULT_STORAGE_PATH: &str = "./storage";
 #[cfg(feature = "rocksdb")]
 pub(crate) const ROCKSDB_FOLDERNAME: &str = "walletdb";
 
+/// The SQLite database file name inside the storage path.
+#[cfg(feature = "sqlite")]
+pub(crate) const SQLITE_FILENAME: &str = "wallet.sqlite";
+
 pub const fn default_storage_path() -> &'static str {
     #[cfg(feature = "rocksdb")]
     return ROCKSDB_FOLDERNAME;
@@ -22,8 +26,24 @@ pub(crate) const SECRET_MANAGER_KEY: &str = "secret_manager";
 pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
+pub(crate) const ADDRESS_BOOK_KEY: &str = "iota-wallet-address-book";
+
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
 
+// Account records stored under their own keys, prefixed with the account key
+pub(crate) const ACCOUNT_ADDRESSES: &str = "addresses";
+pub(crate) const ACCOUNT_OUTPUT: &str = "output";
+pub(crate) const ACCOUNT_OUTPUT_IDS: &str = "output-ids";
+pub(crate) const ACCOUNT_UNSPENT_OUTPUT_IDS: &str = "unspent-output-ids";
+pub(crate) const ACCOUNT_TRANSACTION: &str = "transaction";
+pub(crate) const ACCOUNT_TRANSACTION_IDS: &str = "transaction-ids";
+pub(crate) const ACCOUNT_INCOMING_TRANSACTION: &str = "incoming-transaction";
+pub(crate) const ACCOUNT_INCOMING_TRANSACTION_IDS: &str = "incoming-transaction-ids";
+pub(crate) const ACCOUNT_BATCH: &str = "batch";
+pub(crate) const ACCOUNT_BATCH_IDS: &str = "batch-ids";
+pub(crate) const ACCOUNT_SCHEDULED_PAYMENT: &str = "scheduled-payment";
+pub(crate) const ACCOUNT_SCHEDULED_PAYMENT_IDS: &str = "scheduled-payment-ids";
+
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 
@@ -10,6 +10,9 @@ pub enum StorageKind {
     /// RocksDB storage.
     #[cfg(feature = "rocksdb")]
     Rocksdb,
+    /// SQLite storage, persisted in a single database file.
+    #[cfg(feature = "sqlite")]
+    Sqlite,
     /// Storage backed by a Map in memory.
     Memory,
     /// Wasm storage.
@@ -21,9 +24,11 @@ impl Default for StorageKind {
     fn default() -> Self {
         #[cfg(feature = "rocksdb")]
         return Self::Rocksdb;
+        #[cfg(all(feature = "sqlite", not(feature = "rocksdb")))]
+        return Self::Sqlite;
         #[cfg(target_family = "wasm")]
         return Self::Wasm;
-        #[cfg(not(any(feature = "rocksdb", target_family = "wasm")))]
+        #[cfg(not(any(feature = "rocksdb", feature = "sqlite", target_family = "wasm")))]
         Self::Memory
     }
 }
@@ -1,16 +1,35 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use futures::{StreamExt, TryStreamExt};
+use std::{
+    collections::{
+        hash_map::{DefaultHasher, Entry},
+        HashMap, HashSet,
+    },
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use crate::{
     client::storage::StorageAdapter,
-    types::TryFromDto,
+    types::{
+        block::{
+            output::{dto::FoundryOutputDto, OutputId},
+            payload::transaction::{TransactionEssence, TransactionId},
+        },
+        TryFromDto,
+    },
     wallet::{
-        account::{AccountDetails, AccountDetailsDto, SyncOptions},
+        account::{
+            types::{AccountAddress, OutputData, OutputDataDto, Transaction, TransactionDto},
+            AccountDetails, AccountDetailsDto, Batch, ScheduledPayment, SyncOptions,
+        },
         migration::migrate,
         storage::{constants::*, DynStorageAdapter, Storage},
+        Contact,
     },
 };
 
@@ -20,6 +39,27 @@ pub(crate) struct StorageManager {
     pub(crate) storage: Storage,
     // account indexes for accounts in the database
     account_indexes: Vec<u32>,
+    // hashes of the stored records of the accounts that are currently in memory, so only changed records get written
+    account_records: HashMap<u32, AccountRecords>,
+}
+
+/// The addresses of an account, stored separately from the other account details.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountAddresses {
+    public_addresses: Vec<AccountAddress>,
+    internal_addresses: Vec<AccountAddress>,
+}
+
+/// Hashes of the records of an account which are stored and also kept in memory.
+#[derive(Debug, Default)]
+struct AccountRecords {
+    details: Option<u64>,
+    addresses: Option<u64>,
+    unspent_output_ids: Option<u64>,
+    outputs: HashMap<OutputId, u64>,
+    transactions: HashMap<TransactionId, u64>,
+    incoming_transactions: HashSet<TransactionId>,
 }
 
 impl StorageManager {
@@ -51,11 +91,14 @@ impl StorageManager {
         let storage_manager = Self {
             storage,
             account_indexes,
+            account_records: HashMap::new(),
         };
 
         Ok(storage_manager)
     }
 
+    /// Loads the accounts with their unspent outputs and pending transactions, spent outputs and older transactions
+    /// stay in the storage until they're requested.
     pub(crate) async fn get_accounts(&mut self) -> crate::wallet::Result<Vec<AccountDetails>> {
         if let Some(account_indexes) = self.get(ACCOUNTS_INDEXATION_KEY).await? {
             if self.account_indexes.is_empty() {
@@ -65,33 +108,375 @@ impl StorageManager {
             return Ok(Vec::new());
         }
 
-        futures::stream::iter(&self.account_indexes)
-            .filter_map(|account_index| async {
-                let account_index = *account_index;
-                let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
-                self.get::<AccountDetailsDto>(&key).await.transpose()
-            })
-            .map(|res| AccountDetails::try_from_dto(res?))
-            .try_collect::<Vec<_>>()
-            .await
+        let mut accounts = Vec::with_capacity(self.account_indexes.len());
+        for account_index in self.account_indexes.clone() {
+            if let Some(account) = self.load_account(account_index).await? {
+                accounts.push(account);
+            }
+        }
+        Ok(accounts)
+    }
+
+    async fn load_account(&mut self, account_index: u32) -> crate::wallet::Result<Option<AccountDetails>> {
+        let Some(mut account) = self.get::<AccountDetailsDto>(&account_key(account_index)).await? else {
+            return Ok(None);
+        };
+
+        if let Some(addresses) = self
+            .get::<AccountAddresses>(&account_record_key(account_index, ACCOUNT_ADDRESSES))
+            .await?
+        {
+            account.public_addresses = addresses.public_addresses;
+            account.internal_addresses = addresses.internal_addresses;
+        }
+        for transaction_id in &account.pending_transactions {
+            if let Some(transaction) = self
+                .get::<TransactionDto>(&record_key(account_index, ACCOUNT_TRANSACTION, transaction_id))
+                .await?
+            {
+                account.transactions.insert(*transaction_id, transaction);
+            }
+        }
+        let mut account = AccountDetails::try_from_dto(account)?;
+
+        // Load the unspent outputs and the outputs that are used or created by pending transactions
+        let unspent_output_ids = self
+            .get::<Vec<OutputId>>(&account_record_key(account_index, ACCOUNT_UNSPENT_OUTPUT_IDS))
+            .await?
+            .unwrap_or_default();
+        let mut output_ids = unspent_output_ids
+            .iter()
+            .chain(&account.locked_outputs)
+            .copied()
+            .collect::<HashSet<_>>();
+        for transaction_id in account.pending_transactions() {
+            if let Some(transaction) = account.transactions.get(transaction_id) {
+                output_ids.extend(transaction.inputs.iter().map(|input| *input.metadata.output_id()));
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                for index in 0..essence.outputs().len() {
+                    output_ids.insert(OutputId::new(*transaction_id, index as u16)?);
+                }
+            }
+        }
+        for output_id in output_ids {
+            if let Entry::Vacant(entry) = account.outputs.entry(output_id) {
+                if let Some(output_data) = self.get_output(account_index, &output_id).await? {
+                    entry.insert(output_data);
+                }
+            }
+        }
+        for output_id in unspent_output_ids {
+            if let Some(output_data) = account.outputs.get(&output_id) {
+                account.unspent_outputs.insert(output_id, output_data.clone());
+            }
+        }
+
+        self.account_records.insert(
+            account_index,
+            AccountRecords {
+                details: None,
+                addresses: Some(addresses_hash(&account)),
+                unspent_output_ids: Some(unspent_output_ids_hash(&account)),
+                outputs: account
+                    .outputs
+                    .iter()
+                    .map(|(output_id, output_data)| (*output_id, output_hash(output_data)))
+                    .collect(),
+                transactions: account
+                    .transactions
+                    .iter()
+                    .map(|(transaction_id, transaction)| (*transaction_id, transaction_hash(transaction)))
+                    .collect(),
+                incoming_transactions: account.incoming_transactions.keys().copied().collect(),
+            },
+        );
+
+        Ok(Some(account))
     }
 
+    /// Saves the account, only records that changed since they were loaded or saved the last time are written.
     pub(crate) async fn save_account(&mut self, account: &AccountDetails) -> crate::wallet::Result<()> {
         // Only add account index if not already present
         if !self.account_indexes.contains(account.index()) {
             self.account_indexes.push(*account.index());
+            self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await?;
         }
 
-        self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await?;
-        self.set(
-            &format!("{ACCOUNT_INDEXATION_KEY}{}", account.index()),
-            &AccountDetailsDto::from(account),
+        let mut records = self.account_records.remove(account.index()).unwrap_or_default();
+        let res = self.save_account_records(account, &mut records).await;
+        self.account_records.insert(*account.index(), records);
+        res
+    }
+
+    async fn save_account_records(
+        &self,
+        account: &AccountDetails,
+        records: &mut AccountRecords,
+    ) -> crate::wallet::Result<()> {
+        let account_index = *account.index();
+
+        let hash = addresses_hash(account);
+        if records.addresses != Some(hash) {
+            let addresses = AccountAddresses {
+                public_addresses: account.public_addresses.clone(),
+                internal_addresses: account.internal_addresses.clone(),
+            };
+            self.set(&account_record_key(account_index, ACCOUNT_ADDRESSES), &addresses)
+                .await?;
+            records.addresses = Some(hash);
+        }
+
+        let mut new_output_ids = Vec::new();
+        for (output_id, output_data) in &account.outputs {
+            let hash = output_hash(output_data);
+            if records.outputs.get(output_id) != Some(&hash) {
+                self.set(
+                    &record_key(account_index, ACCOUNT_OUTPUT, output_id),
+                    &OutputDataDto::from(output_data),
+                )
+                .await?;
+                if records.outputs.insert(*output_id, hash).is_none() {
+                    new_output_ids.push(*output_id);
+                }
+            }
+        }
+        self.extend_id_index(&account_record_key(account_index, ACCOUNT_OUTPUT_IDS), new_output_ids)
+            .await?;
+
+        let hash = unspent_output_ids_hash(account);
+        if records.unspent_output_ids != Some(hash) {
+            self.set(
+                &account_record_key(account_index, ACCOUNT_UNSPENT_OUTPUT_IDS),
+                &account.unspent_outputs.keys().collect::<Vec<_>>(),
+            )
+            .await?;
+            records.unspent_output_ids = Some(hash);
+        }
+
+        let mut new_transaction_ids = Vec::new();
+        for (transaction_id, transaction) in &account.transactions {
+            let hash = transaction_hash(transaction);
+            if records.transactions.get(transaction_id) != Some(&hash) {
+                self.set(
+                    &record_key(account_index, ACCOUNT_TRANSACTION, transaction_id),
+                    &TransactionDto::from(transaction),
+                )
+                .await?;
+                if records.transactions.insert(*transaction_id, hash).is_none() {
+                    new_transaction_ids.push(*transaction_id);
+                }
+            }
+        }
+        self.extend_id_index(
+            &account_record_key(account_index, ACCOUNT_TRANSACTION_IDS),
+            new_transaction_ids,
+        )
+        .await?;
+
+        // Incoming transactions don't change, so they only need to be written once
+        let new_incoming_transaction_ids = account
+            .incoming_transactions
+            .keys()
+            .filter(|transaction_id| !records.incoming_transactions.contains(transaction_id))
+            .copied()
+            .collect::<Vec<_>>();
+        for transaction_id in &new_incoming_transaction_ids {
+            self.set(
+                &record_key(account_index, ACCOUNT_INCOMING_TRANSACTION, transaction_id),
+                &TransactionDto::from(&account.incoming_transactions[transaction_id]),
+            )
+            .await?;
+            records.incoming_transactions.insert(*transaction_id);
+        }
+        self.extend_id_index(
+            &account_record_key(account_index, ACCOUNT_INCOMING_TRANSACTION_IDS),
+            new_incoming_transaction_ids,
+        )
+        .await?;
+
+        // The remaining account details without the records stored under their own keys
+        let details = serde_json::to_vec(&AccountDetailsDto {
+            index: account_index,
+            coin_type: *account.coin_type(),
+            alias: account.alias().clone(),
+            public_addresses: Vec::new(),
+            internal_addresses: Vec::new(),
+            addresses_with_unspent_outputs: account.addresses_with_unspent_outputs().clone(),
+            outputs: HashMap::new(),
+            locked_outputs: account.locked_outputs.clone(),
+            unspent_outputs: HashMap::new(),
+            transactions: HashMap::new(),
+            pending_transactions: account.pending_transactions().clone(),
+            incoming_transactions: HashMap::new(),
+            native_token_foundries: account
+                .native_token_foundries()
+                .iter()
+                .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
+                .collect(),
+            watch_only: *account.watch_only(),
+        })?;
+        let hash = record_hash(&details);
+        if records.details != Some(hash) {
+            self.set_bytes(&account_key(account_index), &details).await?;
+            records.details = Some(hash);
+        }
+
+        Ok(())
+    }
+
+    /// Drops spent outputs, transactions that aren't pending anymore and incoming transactions from the account
+    /// details once they're saved, they can still be loaded from the storage when requested.
+    pub(crate) fn evict_stored_records(&mut self, account: &mut AccountDetails) {
+        let Some(records) = self.account_records.get_mut(account.index()) else {
+            return;
+        };
+
+        let pending_transactions = account.pending_transactions().clone();
+        let mut pending_output_ids = account.locked_outputs.clone();
+        for transaction_id in &pending_transactions {
+            if let Some(transaction) = account.transactions.get(transaction_id) {
+                pending_output_ids.extend(transaction.inputs.iter().map(|input| *input.metadata.output_id()));
+            }
+        }
+
+        account.outputs.retain(|output_id, output_data| {
+            let keep = !output_data.is_spent
+                || account.unspent_outputs.contains_key(output_id)
+                || pending_output_ids.contains(output_id)
+                || pending_transactions.contains(output_id.transaction_id())
+                || !records.outputs.contains_key(output_id);
+            if !keep {
+                records.outputs.remove(output_id);
+            }
+            keep
+        });
+        account.transactions.retain(|transaction_id, _| {
+            let keep =
+                pending_transactions.contains(transaction_id) || !records.transactions.contains_key(transaction_id);
+            if !keep {
+                records.transactions.remove(transaction_id);
+            }
+            keep
+        });
+        account
+            .incoming_transactions
+            .retain(|transaction_id, _| !records.incoming_transactions.remove(transaction_id));
+    }
+
+    pub(crate) async fn get_output(
+        &self,
+        account_index: u32,
+        output_id: &OutputId,
+    ) -> crate::wallet::Result<Option<OutputData>> {
+        self.get_record(&record_key(account_index, ACCOUNT_OUTPUT, output_id))
+            .await
+    }
+
+    pub(crate) async fn get_outputs(&self, account_index: u32) -> crate::wallet::Result<HashMap<OutputId, OutputData>> {
+        self.get_records(account_index, ACCOUNT_OUTPUT, ACCOUNT_OUTPUT_IDS)
+            .await
+    }
+
+    pub(crate) async fn get_transaction(
+        &self,
+        account_index: u32,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<Option<Transaction>> {
+        self.get_record(&record_key(account_index, ACCOUNT_TRANSACTION, transaction_id))
+            .await
+    }
+
+    pub(crate) async fn get_transactions(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<HashMap<TransactionId, Transaction>> {
+        self.get_records(account_index, ACCOUNT_TRANSACTION, ACCOUNT_TRANSACTION_IDS)
+            .await
+    }
+
+    pub(crate) async fn get_incoming_transaction(
+        &self,
+        account_index: u32,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<Option<Transaction>> {
+        self.get_record(&record_key(account_index, ACCOUNT_INCOMING_TRANSACTION, transaction_id))
+            .await
+    }
+
+    pub(crate) async fn get_incoming_transactions(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<HashMap<TransactionId, Transaction>> {
+        self.get_records(
+            account_index,
+            ACCOUNT_INCOMING_TRANSACTION,
+            ACCOUNT_INCOMING_TRANSACTION_IDS,
+        )
+        .await
+    }
+
+    /// Returns the ids of the given transactions which are stored as sent transactions of the account.
+    pub(crate) async fn stored_transaction_ids(
+        &self,
+        account_index: u32,
+        transaction_ids: Vec<TransactionId>,
+    ) -> crate::wallet::Result<HashSet<TransactionId>> {
+        self.filter_id_index(
+            &account_record_key(account_index, ACCOUNT_TRANSACTION_IDS),
+            transaction_ids,
+        )
+        .await
+    }
+
+    /// Returns the ids of the given transactions which are stored as incoming transactions of the account.
+    pub(crate) async fn stored_incoming_transaction_ids(
+        &self,
+        account_index: u32,
+        transaction_ids: Vec<TransactionId>,
+    ) -> crate::wallet::Result<HashSet<TransactionId>> {
+        self.filter_id_index(
+            &account_record_key(account_index, ACCOUNT_INCOMING_TRANSACTION_IDS),
+            transaction_ids,
         )
         .await
     }
 
     pub(crate) async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
-        self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}")).await?;
+        for (record, index) in [
+            (ACCOUNT_OUTPUT, ACCOUNT_OUTPUT_IDS),
+            (ACCOUNT_TRANSACTION, ACCOUNT_TRANSACTION_IDS),
+            (ACCOUNT_INCOMING_TRANSACTION, ACCOUNT_INCOMING_TRANSACTION_IDS),
+        ] {
+            let index_key = account_record_key(account_index, index);
+            for id in self.get_id_index::<String>(&index_key).await? {
+                self.delete(&record_key(account_index, record, id)).await?;
+            }
+            for bucket in self.get_id_index_buckets(&index_key).await? {
+                self.delete(&bucket_key(&index_key, &bucket)).await?;
+            }
+            self.delete(&index_key).await?;
+        }
+        for batch_id in self.get_batch_ids(account_index).await? {
+            self.delete(&record_key(account_index, ACCOUNT_BATCH, batch_id)).await?;
+        }
+        self.delete(&account_record_key(account_index, ACCOUNT_BATCH_IDS))
+            .await?;
+        for scheduled_payment_id in self.get_scheduled_payment_ids(account_index).await? {
+            self.delete(&record_key(
+                account_index,
+                ACCOUNT_SCHEDULED_PAYMENT,
+                scheduled_payment_id,
+            ))
+            .await?;
+        }
+        self.delete(&account_record_key(account_index, ACCOUNT_SCHEDULED_PAYMENT_IDS))
+            .await?;
+        self.delete(&account_record_key(account_index, ACCOUNT_UNSPENT_OUTPUT_IDS))
+            .await?;
+        self.delete(&account_record_key(account_index, ACCOUNT_ADDRESSES))
+            .await?;
+        self.delete(&account_key(account_index)).await?;
+        self.account_records.remove(&account_index);
         self.account_indexes.retain(|a| a != &account_index);
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
     }
@@ -112,6 +497,269 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.get(&key).await
     }
+
+    pub(crate) async fn save_batch(&self, account_index: u32, batch: &Batch) -> crate::wallet::Result<()> {
+        let mut batch_ids = self.get_batch_ids(account_index).await?;
+        if !batch_ids.contains(batch.id()) {
+            batch_ids.push(batch.id().clone());
+            self.set(&account_record_key(account_index, ACCOUNT_BATCH_IDS), &batch_ids)
+                .await?;
+        }
+        self.set(&record_key(account_index, ACCOUNT_BATCH, batch.id()), batch)
+            .await
+    }
+
+    pub(crate) async fn get_batch(&self, account_index: u32, batch_id: &str) -> crate::wallet::Result<Option<Batch>> {
+        self.get(&record_key(account_index, ACCOUNT_BATCH, batch_id)).await
+    }
+
+    pub(crate) async fn get_batches(&self, account_index: u32) -> crate::wallet::Result<Vec<Batch>> {
+        let mut batches = Vec::new();
+        for batch_id in self.get_batch_ids(account_index).await? {
+            if let Some(batch) = self.get_batch(account_index, &batch_id).await? {
+                batches.push(batch);
+            }
+        }
+        Ok(batches)
+    }
+
+    pub(crate) async fn remove_batch(&self, account_index: u32, batch_id: &str) -> crate::wallet::Result<()> {
+        let mut batch_ids = self.get_batch_ids(account_index).await?;
+        batch_ids.retain(|id| id != batch_id);
+        self.set(&account_record_key(account_index, ACCOUNT_BATCH_IDS), &batch_ids)
+            .await?;
+        self.delete(&record_key(account_index, ACCOUNT_BATCH, batch_id)).await
+    }
+
+    // The ids of the batches of an account, in the order they were created
+    async fn get_batch_ids(&self, account_index: u32) -> crate::wallet::Result<Vec<String>> {
+        Ok(self
+            .get(&account_record_key(account_index, ACCOUNT_BATCH_IDS))
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn save_scheduled_payment(
+        &self,
+        account_index: u32,
+        scheduled_payment: &ScheduledPayment,
+    ) -> crate::wallet::Result<()> {
+        let mut scheduled_payment_ids = self.get_scheduled_payment_ids(account_index).await?;
+        if !scheduled_payment_ids.contains(scheduled_payment.id()) {
+            scheduled_payment_ids.push(scheduled_payment.id().clone());
+            self.set(
+                &account_record_key(account_index, ACCOUNT_SCHEDULED_PAYMENT_IDS),
+                &scheduled_payment_ids,
+            )
+            .await?;
+        }
+        self.set(
+            &record_key(account_index, ACCOUNT_SCHEDULED_PAYMENT, scheduled_payment.id()),
+            scheduled_payment,
+        )
+        .await
+    }
+
+    pub(crate) async fn get_scheduled_payment(
+        &self,
+        account_index: u32,
+        scheduled_payment_id: &str,
+    ) -> crate::wallet::Result<Option<ScheduledPayment>> {
+        self.get(&record_key(
+            account_index,
+            ACCOUNT_SCHEDULED_PAYMENT,
+            scheduled_payment_id,
+        ))
+        .await
+    }
+
+    pub(crate) async fn get_scheduled_payments(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Vec<ScheduledPayment>> {
+        let mut scheduled_payments = Vec::new();
+        for scheduled_payment_id in self.get_scheduled_payment_ids(account_index).await? {
+            if let Some(scheduled_payment) = self.get_scheduled_payment(account_index, &scheduled_payment_id).await? {
+                scheduled_payments.push(scheduled_payment);
+            }
+        }
+        Ok(scheduled_payments)
+    }
+
+    pub(crate) async fn remove_scheduled_payment(
+        &self,
+        account_index: u32,
+        scheduled_payment_id: &str,
+    ) -> crate::wallet::Result<()> {
+        let mut scheduled_payment_ids = self.get_scheduled_payment_ids(account_index).await?;
+        scheduled_payment_ids.retain(|id| id != scheduled_payment_id);
+        self.set(
+            &account_record_key(account_index, ACCOUNT_SCHEDULED_PAYMENT_IDS),
+            &scheduled_payment_ids,
+        )
+        .await?;
+        self.delete(&record_key(
+            account_index,
+            ACCOUNT_SCHEDULED_PAYMENT,
+            scheduled_payment_id,
+        ))
+        .await
+    }
+
+    // The ids of the scheduled payments of an account, in the order they were created
+    async fn get_scheduled_payment_ids(&self, account_index: u32) -> crate::wallet::Result<Vec<String>> {
+        Ok(self
+            .get(&account_record_key(account_index, ACCOUNT_SCHEDULED_PAYMENT_IDS))
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn get_contacts(&self) -> crate::wallet::Result<Vec<Contact>> {
+        Ok(self.get(ADDRESS_BOOK_KEY).await?.unwrap_or_default())
+    }
+
+    pub(crate) async fn save_contacts(&self, contacts: &[Contact]) -> crate::wallet::Result<()> {
+        self.set(ADDRESS_BOOK_KEY, contacts).await
+    }
+
+    async fn get_record<T>(&self, key: &str) -> crate::wallet::Result<Option<T>>
+    where
+        T: TryFromDto,
+        T::Dto: DeserializeOwned,
+        crate::wallet::Error: From<T::Error>,
+    {
+        Ok(self.get::<T::Dto>(key).await?.map(T::try_from_dto).transpose()?)
+    }
+
+    async fn get_records<K, T>(
+        &self,
+        account_index: u32,
+        record: &str,
+        index: &str,
+    ) -> crate::wallet::Result<HashMap<K, T>>
+    where
+        K: Display + Eq + Hash + DeserializeOwned,
+        T: TryFromDto,
+        T::Dto: DeserializeOwned,
+        crate::wallet::Error: From<T::Error>,
+    {
+        let mut records = HashMap::new();
+        for id in self
+            .get_id_index::<K>(&account_record_key(account_index, index))
+            .await?
+        {
+            if let Some(record) = self.get_record(&record_key(account_index, record, &id)).await? {
+                records.insert(id, record);
+            }
+        }
+        Ok(records)
+    }
+
+    // Id indexes are split into buckets by the first byte of the ids, so adding an id doesn't rewrite all of them.
+    // The index key itself holds the list of the buckets in use.
+    async fn get_id_index<K>(&self, index_key: &str) -> crate::wallet::Result<HashSet<K>>
+    where
+        K: Eq + Hash + DeserializeOwned,
+    {
+        let mut ids = HashSet::new();
+        for bucket in self.get_id_index_buckets(index_key).await? {
+            if let Some(bucket_ids) = self.get::<Vec<K>>(&bucket_key(index_key, &bucket)).await? {
+                ids.extend(bucket_ids);
+            }
+        }
+        Ok(ids)
+    }
+
+    async fn get_id_index_buckets(&self, index_key: &str) -> crate::wallet::Result<HashSet<String>> {
+        Ok(self.get::<HashSet<String>>(index_key).await?.unwrap_or_default())
+    }
+
+    async fn extend_id_index<K>(&self, index_key: &str, ids: Vec<K>) -> crate::wallet::Result<()>
+    where
+        K: Display + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+    {
+        let mut buckets = self.get_id_index_buckets(index_key).await?;
+        let buckets_len = buckets.len();
+        for (bucket, ids) in group_by_bucket(ids) {
+            let bucket_key = bucket_key(index_key, &bucket);
+            let mut bucket_ids = self.get::<HashSet<K>>(&bucket_key).await?.unwrap_or_default();
+            let len = bucket_ids.len();
+            bucket_ids.extend(ids);
+            if bucket_ids.len() != len {
+                self.set(&bucket_key, &bucket_ids).await?;
+            }
+            buckets.insert(bucket);
+        }
+        if buckets.len() != buckets_len {
+            self.set(index_key, &buckets).await?;
+        }
+        Ok(())
+    }
+
+    async fn filter_id_index<K>(&self, index_key: &str, ids: Vec<K>) -> crate::wallet::Result<HashSet<K>>
+    where
+        K: Display + Eq + Hash + DeserializeOwned,
+    {
+        let mut stored_ids = HashSet::new();
+        for (bucket, ids) in group_by_bucket(ids) {
+            if let Some(bucket_ids) = self.get::<HashSet<K>>(&bucket_key(index_key, &bucket)).await? {
+                stored_ids.extend(ids.into_iter().filter(|id| bucket_ids.contains(id)));
+            }
+        }
+        Ok(stored_ids)
+    }
+}
+
+fn account_key(account_index: u32) -> String {
+    format!("{ACCOUNT_INDEXATION_KEY}{account_index}")
+}
+
+fn account_record_key(account_index: u32, record: &str) -> String {
+    format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{record}")
+}
+
+fn record_key(account_index: u32, record: &str, id: impl Display) -> String {
+    format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{record}-{id}")
+}
+
+fn bucket_key(index_key: &str, bucket: &str) -> String {
+    format!("{index_key}-{bucket}")
+}
+
+fn group_by_bucket<K: Display>(ids: Vec<K>) -> HashMap<String, Vec<K>> {
+    let mut buckets = HashMap::<String, Vec<K>>::new();
+    for id in ids {
+        // Ids are hex encoded with a 0x prefix
+        let bucket = id.to_string().get(2..4).unwrap_or_default().to_owned();
+        buckets.entry(bucket).or_default().push(id);
+    }
+    buckets
+}
+
+fn record_hash(record: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    record.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn addresses_hash(account: &AccountDetails) -> u64 {
+    record_hash(&(&account.public_addresses, &account.internal_addresses))
+}
+
+fn unspent_output_ids_hash(account: &AccountDetails) -> u64 {
+    let mut output_ids = account.unspent_outputs.keys().collect::<Vec<_>>();
+    output_ids.sort_unstable();
+    record_hash(&output_ids)
+}
+
+// Only the spent state and the metadata of an output change after it was created
+fn output_hash(output_data: &OutputData) -> u64 {
+    record_hash(&(output_data.is_spent, output_data.metadata))
+}
+
+// Only the inclusion state and the block id (after reattaching) of a transaction change after it was created
+fn transaction_hash(transaction: &Transaction) -> u64 {
+    record_hash(&(transaction.inclusion_state, transaction.block_id))
 }
 
 #[async_trait::async_trait]
@@ -178,6 +826,155 @@ mod tests {
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
     }
 
+    #[tokio::test]
+    async fn save_get_remove_batch() {
+        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        assert!(storage_manager.get_batches(0).await.unwrap().is_empty());
+
+        let batch = serde_json::from_value::<Batch>(serde_json::json!({
+            "id": "0x01",
+            "payments": [
+                {
+                    "type": "send",
+                    "params": {
+                        "amount": "1000000",
+                        "address": "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+                    },
+                },
+                {
+                    "type": "output",
+                    "params": {
+                        "recipientAddress": "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+                        "amount": "2000000",
+                    },
+                },
+            ],
+            "statuses": [
+                {
+                    "type": "sent",
+                    "transactionId": "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649",
+                },
+                { "type": "pending" },
+            ],
+        }))
+        .unwrap();
+        assert!(!batch.is_finished());
+        assert_eq!(batch.transaction_ids().len(), 1);
+
+        storage_manager.save_batch(0, &batch).await.unwrap();
+        storage_manager.save_batch(0, &batch).await.unwrap();
+        let batches = storage_manager.get_batches(0).await.unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].statuses(), batch.statuses());
+        assert!(storage_manager.get_batch(1, "0x01").await.unwrap().is_none());
+
+        storage_manager.remove_batch(0, "0x01").await.unwrap();
+        assert!(storage_manager.get_batch(0, "0x01").await.unwrap().is_none());
+        assert!(storage_manager.get_batches(0).await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[tokio::test]
+    async fn save_evict_load_records() {
+        use crate::types::block::{
+            address::{Address, Ed25519Address},
+            output::Output,
+            rand::output::{rand_basic_output, rand_output_id, rand_output_metadata},
+        };
+
+        let output_data = |is_spent| {
+            let mut metadata = rand_output_metadata();
+            metadata.set_spent(is_spent);
+            OutputData {
+                output_id: rand_output_id(),
+                metadata,
+                output: Output::from(rand_basic_output(1_813_620_509_061_365)),
+                is_spent,
+                address: Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH])),
+                network_id: 42,
+                remainder: false,
+                chain: None,
+            }
+        };
+        let unspent_output = output_data(false);
+        let spent_output = output_data(true);
+
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let mut account_details = AccountDetails::mock();
+        account_details
+            .outputs
+            .insert(unspent_output.output_id, unspent_output.clone());
+        account_details
+            .unspent_outputs
+            .insert(unspent_output.output_id, unspent_output.clone());
+        account_details
+            .outputs
+            .insert(spent_output.output_id, spent_output.clone());
+        storage_manager.save_account(&account_details).await.unwrap();
+
+        // Outputs and addresses aren't part of the account record
+        let account_record = storage_manager
+            .get::<AccountDetailsDto>(&account_key(0))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(account_record.outputs.is_empty());
+        assert!(account_record.public_addresses.is_empty());
+        assert_eq!(
+            storage_manager.get_output(0, &spent_output.output_id).await.unwrap(),
+            Some(spent_output.clone())
+        );
+
+        // Spent outputs are only kept in the storage
+        storage_manager.evict_stored_records(&mut account_details);
+        assert_eq!(account_details.outputs.len(), 1);
+        assert_eq!(storage_manager.get_outputs(0).await.unwrap().len(), 2);
+
+        // Unchanged records aren't written again
+        let unspent_output_key = record_key(0, ACCOUNT_OUTPUT, unspent_output.output_id);
+        storage_manager.delete(&unspent_output_key).await.unwrap();
+        storage_manager.save_account(&account_details).await.unwrap();
+        assert!(storage_manager.get_bytes(&unspent_output_key).await.unwrap().is_none());
+
+        // Changed records are
+        let output = account_details.outputs.get_mut(&unspent_output.output_id).unwrap();
+        output.metadata.set_spent(true);
+        output.is_spent = true;
+        account_details.unspent_outputs.clear();
+        storage_manager.save_account(&account_details).await.unwrap();
+        assert!(
+            storage_manager
+                .get_output(0, &unspent_output.output_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .is_spent
+        );
+
+        // Loading the account only loads the unspent outputs
+        account_details
+            .unspent_outputs
+            .insert(unspent_output.output_id, unspent_output.clone());
+        account_details
+            .outputs
+            .insert(unspent_output.output_id, unspent_output.clone());
+        storage_manager.save_account(&account_details).await.unwrap();
+        storage_manager.account_records.clear();
+        let accounts = storage_manager.get_accounts().await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].public_addresses, account_details.public_addresses);
+        assert_eq!(accounts[0].outputs.len(), 1);
+        assert_eq!(
+            accounts[0].unspent_outputs.get(&unspent_output.output_id),
+            Some(&unspent_output)
+        );
+
+        storage_manager.remove_account(0).await.unwrap();
+        assert!(storage_manager.get_outputs(0).await.unwrap().is_empty());
+        let output_ids_key = account_record_key(0, ACCOUNT_OUTPUT_IDS);
+        assert!(storage_manager.get_bytes(&output_ids_key).await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn save_get_wallet_data() {
         let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
@@ -28,7 +28,7 @@ use crate::client::storage::StorageAdapter;
 #[derive(Debug)]
 pub struct Storage {
     pub(crate) inner: Box<dyn DynStorageAdapter>,
-    encryption_key: Option<Zeroizing<[u8; 32]>>,
+    pub(crate) encryption_key: Option<Zeroizing<[u8; 32]>>,
 }
 
 #[async_trait]
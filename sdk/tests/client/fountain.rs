@@ -0,0 +1,126 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        api::{PreparedTransactionData, SignedTransactionData},
+        secret::{SecretManage, SecretManager},
+        Client, Result,
+    },
+    types::block::{payload::TransactionPayload, protocol::protocol_parameters},
+    utils::fountain::{Decoder, Encoder, Error, Part, MAX_SEQUENCE_COUNT},
+};
+use packable::PackableExt;
+
+use crate::client::signing_envelope::prepare_transaction;
+
+#[tokio::test]
+async fn fountain_prepared_transaction_out_of_order() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = prepare_transaction(&secret_manager, 1_000_000).await?;
+    let protocol_parameters = protocol_parameters();
+
+    let mut encoder = Encoder::from_packable(&prepared_transaction_data, 30).unwrap();
+    let sequence_count = encoder.sequence_count();
+    assert!(sequence_count > 3);
+
+    // Drop the first pure fragments and receive the remaining parts in reverse order
+    let mut parts = (0..sequence_count * 3)
+        .map(|_| encoder.next_part().to_ur("iota-prepared-tx"))
+        .skip(2)
+        .collect::<Vec<_>>();
+    parts.reverse();
+
+    let mut decoder = Decoder::new();
+    for part in &parts {
+        if decoder.receive_ur(part).unwrap() {
+            break;
+        }
+    }
+    assert!(decoder.is_complete());
+    assert_eq!(decoder.progress(), 1.0);
+    assert_eq!(decoder.ur_type(), Some("iota-prepared-tx"));
+    assert_eq!(
+        decoder.unpack::<PreparedTransactionData>(&protocol_parameters).unwrap(),
+        prepared_transaction_data
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fountain_signed_transaction() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = prepare_transaction(&secret_manager, 1_000_000).await?;
+    let protocol_parameters = protocol_parameters();
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, None)
+        .await?;
+    let signed_transaction_data = SignedTransactionData {
+        transaction_payload: TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?,
+        inputs_data: prepared_transaction_data.inputs_data,
+    };
+
+    for value in [
+        signed_transaction_data.transaction_payload.pack_to_vec(),
+        signed_transaction_data.pack_to_vec(),
+    ] {
+        let encoder = Encoder::new(&value, 50).unwrap();
+        let mut decoder = Decoder::new();
+        // Decode from mixed parts only
+        let mut sequence = encoder.sequence_count();
+        while !decoder.is_complete() {
+            sequence += 1;
+            decoder.receive(encoder.part(sequence)).unwrap();
+        }
+        assert_eq!(decoder.message(), Some(value.as_slice()));
+    }
+
+    let decoded =
+        SignedTransactionData::unpack_verified(signed_transaction_data.pack_to_vec(), &protocol_parameters).unwrap();
+    assert_eq!(decoded, signed_transaction_data);
+
+    Ok(())
+}
+
+#[test]
+fn fountain_errors() {
+    assert_eq!(Encoder::new(&[], 10).unwrap_err(), Error::EmptyMessage);
+    assert_eq!(Encoder::new(&[1, 2, 3], 0).unwrap_err(), Error::InvalidFragmentLength(0));
+
+    let mut first = Encoder::new(&[1; 100], 10).unwrap();
+    let mut second = Encoder::new(&[2; 100], 10).unwrap();
+    let part = first.next_part();
+    assert_eq!(Part::try_from_ur(&part.to_ur("bytes").to_uppercase()).unwrap(), ("bytes".to_string(), part.clone()));
+    assert!(matches!(Part::try_from_ur("ur:bytes/1-10"), Err(Error::InvalidUr(_))));
+
+    let mut decoder = Decoder::new();
+    assert!(!decoder.receive_ur(&part.to_ur("bytes")).unwrap());
+    assert_eq!(decoder.receive(second.next_part()).unwrap_err(), Error::MismatchingPart);
+    assert!(matches!(decoder.receive_ur(&first.next_part().to_ur("other")), Err(Error::MismatchingPart)));
+    assert_eq!(decoder.progress(), 0.1);
+    assert_eq!(decoder.unpack::<u8>(&()).unwrap_err(), Error::IncompleteMessage);
+
+    // A single crafted part must not make the decoder allocate for a huge sequence count
+    let crafted_part = |sequence_count: u32, message_length: u32| {
+        let mut bytes = Vec::new();
+        for value in [1, sequence_count, message_length, 0, 1] {
+            bytes.extend(u32::to_le_bytes(value));
+        }
+        bytes.push(0);
+        Part::unpack_verified(bytes, &()).unwrap()
+    };
+    assert!(matches!(
+        Decoder::new().receive(crafted_part(1 << 31, 1 << 31)),
+        Err(Error::InvalidPart(_))
+    ));
+    assert!(matches!(
+        Decoder::new().receive(crafted_part(MAX_SEQUENCE_COUNT, 1)),
+        Err(Error::InvalidPart(_))
+    ));
+    assert_eq!(
+        Encoder::new(&vec![0; MAX_SEQUENCE_COUNT as usize + 1], 1).unwrap_err(),
+        Error::InvalidFragmentLength(1)
+    );
+}
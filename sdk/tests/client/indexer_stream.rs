@@ -0,0 +1,130 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::{StreamExt, TryStreamExt};
+use iota_sdk::{
+    client::{mock_node::MockNode, node_api::indexer::query_parameters::QueryParameter, Client, Result},
+    types::block::{
+        address::{Address, Bech32Address, Ed25519Address, ToBech32Ext},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+    },
+};
+
+// Starts a mock node with `count` basic outputs owned by an address.
+async fn setup(count: u64) -> Result<(MockNode, Client, Bech32Address)> {
+    let node = MockNode::builder().finish().await?;
+    let client = Client::builder()
+        .with_node(&node.url())?
+        .with_ignore_node_health()
+        .finish()
+        .await?;
+    let address = Address::from(Ed25519Address::new([1; 32]));
+
+    for amount in 1..=count {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000 + amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(node.protocol_parameters().token_supply())?;
+        node.add_output(output).await;
+    }
+
+    let bech32_address = address.to_bech32(*node.protocol_parameters().bech32_hrp());
+
+    Ok((node, client, bech32_address))
+}
+
+#[tokio::test]
+async fn output_ids_stream_pages_lazily() -> Result<()> {
+    let (_node, client, address) = setup(25).await?;
+
+    let pages = client
+        .basic_output_ids_stream([QueryParameter::Address(address), QueryParameter::PageSize(10)])?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert_eq!(
+        pages.iter().map(|page| page.items.len()).collect::<Vec<_>>(),
+        [10, 10, 5]
+    );
+    assert!(pages[..2].iter().all(|page| page.cursor.is_some()));
+    assert!(pages[2].cursor.is_none());
+
+    let output_ids = pages.into_iter().flat_map(|page| page.items).collect::<Vec<_>>();
+    assert_eq!(
+        client.basic_output_ids([QueryParameter::Address(address)]).await?.items,
+        output_ids
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn output_ids_stream_resumes_from_cursor() -> Result<()> {
+    let (_node, client, address) = setup(25).await?;
+    let query_parameters = [QueryParameter::Address(address), QueryParameter::PageSize(10)];
+
+    let mut pages = Box::pin(client.basic_output_ids_stream(query_parameters.clone())?);
+    let first_page = pages.next().await.unwrap()?;
+    drop(pages);
+
+    let remaining = client
+        .basic_output_ids_stream(
+            query_parameters
+                .into_iter()
+                .chain([QueryParameter::Cursor(first_page.cursor.clone().unwrap())])
+                .collect::<Vec<_>>(),
+        )?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert_eq!(remaining.len(), 2);
+    let output_ids = first_page
+        .items
+        .into_iter()
+        .chain(remaining.into_iter().flat_map(|page| page.items))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        client.basic_output_ids([QueryParameter::Address(address)]).await?.items,
+        output_ids
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn outputs_stream_fetches_outputs() -> Result<()> {
+    let (_node, client, address) = setup(12).await?;
+
+    let pages = client
+        .get_outputs_stream(
+            client.basic_output_ids_stream([QueryParameter::Address(address), QueryParameter::PageSize(5)])?,
+        )
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert_eq!(pages.len(), 3);
+    assert_eq!(pages[2].cursor, None);
+
+    let outputs = pages.into_iter().flat_map(|page| page.items).collect::<Vec<_>>();
+    let output_ids = client.basic_output_ids([QueryParameter::Address(address)]).await?.items;
+    assert_eq!(
+        outputs
+            .iter()
+            .map(|output| *output.metadata().output_id())
+            .collect::<Vec<OutputId>>(),
+        output_ids
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn output_ids_stream_of_empty_query() -> Result<()> {
+    let (_node, client, _) = setup(0).await?;
+
+    let pages = client.nft_output_ids_stream([])?.try_collect::<Vec<_>>().await?;
+
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].items.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use iota_sdk::{
+    client::api::input_selection::{
+        BranchAndBound, CoinSelectionStrategy, CoinSelectionStrategyDto, InputSelection, LargestFirst,
+        PrivacyPreserving, SmallestFirst,
+    },
+    types::block::protocol::protocol_parameters,
+};
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq, Build::Basic,
+    BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
+};
+
+#[test]
+fn largest_first() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        2_500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(Arc::new(LargestFirst))
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.inputs.len(), 1);
+    assert_eq!(selected.inputs[0], inputs[2]);
+    assert!(is_remainder_or_return(
+        &selected.remainder.unwrap().output,
+        500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+    ));
+}
+
+#[test]
+fn smallest_first() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        2_500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(Arc::new(SmallestFirst))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs[1..]));
+    assert!(is_remainder_or_return(
+        &selected.remainder.unwrap().output,
+        500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+    ));
+}
+
+#[test]
+fn branch_and_bound_without_remainder() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(4_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        5_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(Arc::new(BranchAndBound::default()))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &[inputs[0].clone(), inputs[3].clone()]));
+    assert!(selected.remainder.is_none());
+    assert_eq!(selected.outputs, outputs);
+}
+
+#[test]
+fn branch_and_bound_without_exact_match() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(4_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        3_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(Arc::new(BranchAndBound::default()))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert!(is_remainder_or_return(
+        &selected.remainder.unwrap().output,
+        3_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+    ));
+}
+
+#[test]
+fn branch_and_bound_deep_search_on_small_stack() {
+    let mut inputs = build_inputs(
+        (0..20_000).map(|_| Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None)),
+    );
+
+    // Every branch goes as deep as the number of inputs, which must not depend on the stack size
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(move || BranchAndBound::default().order_inputs(&mut inputs, 1_500_000))
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn privacy_preserving_single_address() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(500_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(Arc::new(PrivacyPreserving))
+    .select()
+    .unwrap();
+
+    // The first address has the lowest sufficient amount, so the second one isn't linked to it
+    assert!(unsorted_eq(&selected.inputs, &inputs[..2]));
+}
+
+#[test]
+fn coin_selection_strategy_dto() {
+    let strategy = serde_json::from_str::<CoinSelectionStrategyDto>("\"branchAndBound\"").unwrap();
+    assert_eq!(strategy, CoinSelectionStrategyDto::BranchAndBound);
+    assert_eq!(
+        serde_json::to_string(&CoinSelectionStrategyDto::PrivacyPreserving).unwrap(),
+        "\"privacyPreserving\""
+    );
+
+    let strategy: Arc<dyn CoinSelectionStrategy> = strategy.into();
+    assert_eq!(format!("{strategy:?}"), format!("{:?}", BranchAndBound::default()));
+}
@@ -4,6 +4,7 @@
 mod alias_outputs;
 mod basic_outputs;
 mod burn;
+mod coin_selection;
 mod expiration;
 mod foundry_outputs;
 mod native_tokens;
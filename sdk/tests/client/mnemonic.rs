@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crypto::keys::bip39::Mnemonic;
-use iota_sdk::client::{Client, Result};
+use iota_sdk::client::{
+    slip39::{combine_shares, Slip39Group},
+    Client, Error, Result,
+};
 
 #[tokio::test]
 async fn mnemonic() -> Result<()> {
@@ -13,3 +16,75 @@ async fn mnemonic() -> Result<()> {
     assert!(Client::mnemonic_to_hex_seed(Mnemonic::from("invalid mnemonic".to_owned())).is_err());
     Ok(())
 }
+
+#[test]
+fn slip39_test_vectors() -> Result<()> {
+    // Valid mnemonic without sharing (128 bits)
+    let master_secret = combine_shares(
+        &[
+            "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard",
+        ],
+        "TREZOR",
+    )?;
+    assert_eq!(prefix_hex::encode(master_secret.as_slice()), "0xbb54aac4b89dc868ba37d9cc21b2cece");
+
+    // Basic sharing 2-of-3 (128 bits)
+    let master_secret = combine_shares(
+        &[
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ],
+        "TREZOR",
+    )?;
+    assert_eq!(prefix_hex::encode(master_secret.as_slice()), "0xb43ceb7e57a0ea8766221624d01b0864");
+
+    Ok(())
+}
+
+#[test]
+fn slip39_mnemonic_shares() -> Result<()> {
+    let mnemonic = Client::generate_mnemonic()?;
+    // Any two of the three groups are required
+    let groups = [Slip39Group::new(1, 1), Slip39Group::new(2, 3), Slip39Group::new(3, 5)];
+    let shares = Client::mnemonic_to_slip39_shares(&*mnemonic, "passphrase", 2, &groups)?;
+    assert_eq!(shares.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 3, 5]);
+    assert!(shares.iter().flatten().all(|share| share.split_whitespace().count() == 33));
+
+    // Shares of incomplete groups are ignored and the order doesn't matter
+    let recovery_shares = [
+        &shares[2][4],
+        &shares[1][2],
+        &shares[2][0],
+        &shares[1][0],
+        &shares[2][2],
+        &shares[0][0],
+    ];
+    assert_eq!(
+        Client::slip39_shares_to_mnemonic(&recovery_shares, "passphrase")?.as_ref(),
+        mnemonic.as_ref()
+    );
+    assert_ne!(
+        Client::slip39_shares_to_mnemonic(&recovery_shares, "other")?.as_ref(),
+        mnemonic.as_ref()
+    );
+
+    // Only one complete group
+    assert!(matches!(
+        Client::slip39_shares_to_mnemonic(&[&shares[1][0], &shares[1][1], &shares[2][0]], "passphrase"),
+        Err(Error::Slip39(_))
+    ));
+    // A changed word fails the checksum
+    let mut words = shares[0][0].split_whitespace().collect::<Vec<_>>();
+    words[10] = if words[10] == "academic" { "acid" } else { "academic" };
+    assert!(matches!(
+        Client::slip39_shares_to_mnemonic(&[words.join(" ")], "passphrase"),
+        Err(Error::Slip39(_))
+    ));
+    // Multiple member shares with a member threshold of 1
+    assert!(matches!(
+        Client::mnemonic_to_slip39_shares(&*mnemonic, "", 1, &[Slip39Group::new(1, 2)]),
+        Err(Error::Slip39(_))
+    ));
+
+    Ok(())
+}
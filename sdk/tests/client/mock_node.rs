@@ -0,0 +1,104 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{mock_node::MockNode, node_api::indexer::query_parameters::QueryParameter, Client, Result},
+    types::block::{
+        address::{Address, Ed25519Address, ToBech32Ext},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    },
+};
+
+#[tokio::test]
+async fn mock_node_indexer_pagination() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = Client::builder().with_node(&node.url())?.finish().await?;
+    let address = Address::from(Ed25519Address::new([1; 32]));
+
+    for _ in 0..5 {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(node.protocol_parameters().token_supply())?;
+        node.add_output(output).await;
+    }
+    let bech32_address = address.to_bech32(*node.protocol_parameters().bech32_hrp());
+
+    let output_ids = client
+        .basic_output_ids([QueryParameter::Address(bech32_address), QueryParameter::PageSize(2)])
+        .await?;
+    assert_eq!(output_ids.items.len(), 5);
+    assert!(output_ids.cursor.is_none());
+
+    let first_page = client
+        .basic_output_ids([
+            QueryParameter::Address(bech32_address),
+            QueryParameter::PageSize(2),
+            QueryParameter::Cursor("0.2".to_owned()),
+        ])
+        .await?;
+    assert_eq!(first_page.items, output_ids.items[..2]);
+    assert!(first_page.cursor.is_some());
+
+    assert!(client.nft_output_ids([]).await?.items.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_node_milestone_interval() -> Result<()> {
+    let node = MockNode::builder()
+        .with_milestone_interval(std::time::Duration::from_millis(50))
+        .finish()
+        .await?;
+    let milestone_index = node.confirmed_milestone_index().await;
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert!(node.confirmed_milestone_index().await > milestone_index);
+
+    node.shutdown().await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "participation")]
+#[tokio::test]
+async fn mock_node_participation_events() -> Result<()> {
+    use iota_sdk::types::api::plugins::participation::types::{ParticipationEvent, ParticipationEventType};
+
+    let node = MockNode::builder().finish().await?;
+    let client = Client::builder().with_node(&node.url())?.finish().await?;
+
+    let event: ParticipationEvent = serde_json::from_value(serde_json::json!({
+        "id": "0x80f57f6368933b61af9b3d8e1b152cf5d23bf4537f6362778b0a7302a7000d48",
+        "data": {
+            "name": "Staking",
+            "milestoneIndexCommence": 1,
+            "milestoneIndexStart": 2,
+            "milestoneIndexEnd": 100,
+            "payload": {
+                "type": 1,
+                "text": "Stake",
+                "symbol": "STK",
+                "numerator": 1,
+                "denominator": 1,
+                "requiredMinimumRewards": 10,
+                "additionalInfo": ""
+            },
+            "additionalInfo": ""
+        }
+    }))?;
+    node.add_participation_event(event.clone()).await;
+
+    assert_eq!(client.events(None).await?.event_ids, [event.id]);
+    assert!(
+        client
+            .events(Some(ParticipationEventType::Voting))
+            .await?
+            .event_ids
+            .is_empty()
+    );
+    assert_eq!(client.event(&event.id).await?, event.data);
+    assert_eq!(client.event_status(&event.id, None).await?.status(), "commencing");
+
+    Ok(())
+}
@@ -6,16 +6,30 @@ mod client_builder;
 mod common;
 mod consolidation;
 mod error;
+mod fountain;
 mod high_level;
+#[cfg(feature = "test-utils")]
+mod indexer_stream;
 mod input_selection;
 mod input_signing_data;
 mod mnemonic;
+#[cfg(feature = "test-utils")]
+mod mock_node;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+#[cfg(feature = "test-utils")]
+mod node_stats;
+#[cfg(feature = "test-utils")]
+mod response_cache;
+#[cfg(feature = "test-utils")]
+mod retry_policy;
 mod secret_manager;
 mod signing;
+mod signing_envelope;
 mod transactions;
+#[cfg(feature = "test-utils")]
+mod verification;
 
 use std::{
     collections::{BTreeSet, HashMap},
@@ -0,0 +1,85 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        mock_node::MockNode,
+        node_api::error::Error as NodeApiError,
+        node_manager::stats::{CircuitState, NodeStats},
+        Client, Error, Result,
+    },
+    types::block::rand::block::rand_block_id,
+};
+
+// Nothing listens on this port, so requests fail right away.
+const UNREACHABLE_NODE: &str = "http://127.0.0.1:1";
+
+fn stats_of<'a>(stats: &'a [NodeStats], url: &str) -> &'a NodeStats {
+    stats
+        .iter()
+        .find(|stats| stats.url.as_str().trim_end_matches('/') == url.trim_end_matches('/'))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn circuit_breaker_ejects_failing_node() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = Client::builder()
+        .with_primary_node(UNREACHABLE_NODE, None)?
+        .with_node(&node.url())?
+        .with_ignore_node_health()
+        .finish()
+        .await?;
+
+    for _ in 0..5 {
+        client.get_tips().await?;
+    }
+
+    let stats = client.node_stats().await;
+    let unreachable = stats_of(&stats, UNREACHABLE_NODE);
+    let mock = stats_of(&stats, &node.url());
+
+    // The primary node is only tried until its circuit breaker opens
+    assert_eq!(unreachable.requests, 3);
+    assert_eq!(unreachable.failures, 3);
+    assert_eq!(unreachable.consecutive_failures, 3);
+    assert_eq!(unreachable.error_rate, 1.0);
+    assert_eq!(unreachable.latency, None);
+    assert_eq!(unreachable.circuit_state, CircuitState::Open);
+
+    assert_eq!(mock.requests, 5);
+    assert_eq!(mock.failures, 0);
+    assert_eq!(mock.error_rate, 0.0);
+    assert!(mock.latency.is_some());
+    assert_eq!(mock.milestone_lag, Some(0));
+    assert_eq!(mock.circuit_state, CircuitState::Closed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn not_found_is_no_node_failure() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = Client::builder()
+        .with_node(&node.url())?
+        .with_ignore_node_health()
+        .finish()
+        .await?;
+
+    for _ in 0..3 {
+        assert!(matches!(
+            client.get_block(&rand_block_id()).await,
+            Err(Error::Node(NodeApiError::NotFound(_)))
+        ));
+    }
+
+    let stats = client.node_stats().await;
+    let mock = stats_of(&stats, &node.url());
+
+    assert_eq!(mock.requests, 3);
+    assert_eq!(mock.failures, 0);
+    assert_eq!(mock.consecutive_failures, 0);
+    assert_eq!(mock.circuit_state, CircuitState::Closed);
+
+    Ok(())
+}
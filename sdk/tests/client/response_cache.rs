@@ -0,0 +1,283 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::{
+    client::{
+        cache::{CacheMetrics, CacheOptions},
+        mock_node::MockNode,
+        Client, Error, Result,
+    },
+    types::block::{output::RentStructure, protocol::ProtocolParameters, BlockDto, BlockId},
+};
+
+async fn make_client(node: &MockNode, cache_options: CacheOptions) -> Result<Client> {
+    Client::builder()
+        .with_node(&node.url())?
+        .with_ignore_node_health()
+        .with_cache_options(cache_options)
+        .finish()
+        .await
+}
+
+async fn send_tagged_data_block(client: &Client) -> Result<BlockId> {
+    Ok(client
+        .build_block()
+        .with_tag(b"Hello".to_vec())
+        .with_data(b"Tangle".to_vec())
+        .finish()
+        .await?
+        .id())
+}
+
+#[tokio::test]
+async fn caches_immutable_responses() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = make_client(&node, CacheOptions::default()).await?;
+    let block_id = send_tagged_data_block(&client).await?;
+
+    let block = client.get_block(&block_id).await?;
+    assert_eq!(client.get_block(&block_id).await?, block);
+
+    // The metadata of a block that wasn't referenced yet can still change
+    let metadata = client.get_block_metadata(&block_id).await?;
+    assert_eq!(metadata.referenced_by_milestone_index, None);
+
+    let index = node.issue_milestone().await;
+    let metadata = client.get_block_metadata(&block_id).await?;
+    assert_eq!(metadata.referenced_by_milestone_index, Some(index));
+    assert_eq!(client.get_block_metadata(&block_id).await?, metadata);
+
+    let utxo_changes = client.get_utxo_changes_by_index(index).await?;
+    assert_eq!(client.get_utxo_changes_by_index(index).await?, utxo_changes);
+
+    let metrics = client.cache_metrics().await.unwrap();
+    assert_eq!(metrics.hits, 3);
+    assert_eq!(metrics.misses, 4);
+    assert_eq!(metrics.insertions, 3);
+    assert_eq!(metrics.entries, 3);
+    assert_eq!(metrics.evictions, 0);
+
+    client.clear_cache().await;
+    let metrics = client.cache_metrics().await.unwrap();
+    assert_eq!((metrics.entries, metrics.size), (0, 0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn evicts_least_recently_used() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = make_client(&node, CacheOptions::default().with_memory_limits(2, 1024 * 1024)).await?;
+    let indexes = [
+        node.issue_milestone().await,
+        node.issue_milestone().await,
+        node.issue_milestone().await,
+    ];
+
+    client.get_utxo_changes_by_index(indexes[0]).await?;
+    client.get_utxo_changes_by_index(indexes[1]).await?;
+    // Uses the first response again, so the second one is evicted
+    client.get_utxo_changes_by_index(indexes[0]).await?;
+    client.get_utxo_changes_by_index(indexes[2]).await?;
+    client.get_utxo_changes_by_index(indexes[0]).await?;
+    client.get_utxo_changes_by_index(indexes[1]).await?;
+
+    let metrics = client.cache_metrics().await.unwrap();
+    assert_eq!(metrics.hits, 2);
+    assert_eq!(metrics.misses, 4);
+    assert_eq!(metrics.evictions, 2);
+    assert_eq!(metrics.entries, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expires_after_ttl() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = make_client(&node, CacheOptions::default().with_ttl(Duration::from_millis(50))).await?;
+    let index = node.issue_milestone().await;
+
+    client.get_utxo_changes_by_index(index).await?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    client.get_utxo_changes_by_index(index).await?;
+
+    let metrics = client.cache_metrics().await.unwrap();
+    assert_eq!(metrics.hits, 0);
+    assert_eq!(metrics.misses, 2);
+    assert_eq!(metrics.evictions, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn keeps_responses_in_directory() -> Result<()> {
+    let directory = "test-storage/keeps_responses_in_directory";
+    std::fs::remove_dir_all(directory).ok();
+
+    let node = MockNode::builder().finish().await?;
+    let cache_options = CacheOptions::default().with_directory(directory, 1024 * 1024);
+    let client = make_client(&node, cache_options.clone()).await?;
+    let block_id = send_tagged_data_block(&client).await?;
+    let block = client.get_block(&block_id).await?;
+    let block_bytes = client.get_block_raw(&block_id).await?;
+
+    // The responses are loaded from the directory by another client
+    let client = make_client(&node, cache_options).await?;
+    assert_eq!(client.get_block(&block_id).await?, block);
+    assert_eq!(client.get_block_raw(&block_id).await?, block_bytes);
+    assert_eq!(
+        client.cache_metrics().await.unwrap(),
+        CacheMetrics {
+            hits: 2,
+            directory_hits: 2,
+            entries: 2,
+            size: client.cache_metrics().await.unwrap().size,
+            ..Default::default()
+        }
+    );
+
+    client.clear_cache().await;
+    assert_eq!(std::fs::read_dir(directory).unwrap().count(), 0);
+
+    std::fs::remove_dir_all(directory).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn keeps_responses_by_network() -> Result<()> {
+    let directory = "test-storage/keeps_responses_by_network";
+    std::fs::remove_dir_all(directory).ok();
+
+    let node = MockNode::builder().finish().await?;
+    let other_node = MockNode::builder()
+        .with_protocol_parameters(ProtocolParameters::new(
+            node.protocol_parameters().protocol_version(),
+            String::from("other"),
+            "rms",
+            0,
+            15,
+            RentStructure::default(),
+            1_813_620_509_061_365,
+        )?)
+        .finish()
+        .await?;
+    let cache_options = CacheOptions::default().with_directory(directory, 1024 * 1024);
+    let client = make_client(&node, cache_options.clone()).await?;
+    let block_id = send_tagged_data_block(&client).await?;
+    client.get_block(&block_id).await?;
+
+    // The block of the other network isn't served from the directory
+    let client = make_client(&other_node, cache_options).await?;
+    assert!(client.get_block(&block_id).await.is_err());
+    assert_eq!(client.cache_metrics().await.unwrap().directory_hits, 0);
+
+    std::fs::remove_dir_all(directory).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ignores_corrupted_files() -> Result<()> {
+    let directory = "test-storage/ignores_corrupted_files";
+    std::fs::remove_dir_all(directory).ok();
+
+    let node = MockNode::builder().finish().await?;
+    let cache_options = CacheOptions::default().with_directory(directory, 1024 * 1024);
+    let client = make_client(&node, cache_options.clone()).await?;
+    let block_id = send_tagged_data_block(&client).await?;
+    let block = client.get_block(&block_id).await?;
+
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        let mut bytes = std::fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+    }
+
+    // The corrupted file is removed and the block requested from the node again
+    let client = make_client(&node, cache_options).await?;
+    assert_eq!(client.get_block(&block_id).await?, block);
+    let metrics = client.cache_metrics().await.unwrap();
+    assert_eq!((metrics.directory_hits, metrics.misses), (0, 1));
+    assert_eq!(client.get_block(&block_id).await?, block);
+
+    std::fs::remove_dir_all(directory).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn doesnt_cache_invalid_responses() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = make_client(&node, CacheOptions::default()).await?;
+    let block_id = send_tagged_data_block(&client).await?;
+    let other_block_id = send_tagged_data_block(&client).await?;
+    let index = node.issue_milestone().await;
+    let other_index = node.issue_milestone().await;
+    let other_block = BlockDto::from(&client.get_block(&other_block_id).await?);
+    let other_utxo_changes = client.get_utxo_changes_by_index(other_index).await?;
+    client.clear_cache().await;
+    let metrics_before = client.cache_metrics().await.unwrap();
+
+    // Another block than the requested one
+    node.forge_response(&format!("api/core/v2/blocks/{block_id}"), &other_block)
+        .await;
+    for _ in 0..2 {
+        assert!(matches!(
+            client.get_block(&block_id).await,
+            Err(Error::ResponseMismatch { .. })
+        ));
+        assert!(client.get_block_raw(&block_id).await.is_err());
+    }
+
+    // A block that can't be converted
+    let malformed_block = BlockDto {
+        nonce: "malformed".to_owned(),
+        ..other_block
+    };
+    node.forge_response(&format!("api/core/v2/blocks/{other_block_id}"), &malformed_block)
+        .await;
+    assert!(client.get_block(&other_block_id).await.is_err());
+
+    // The UTXO changes of another milestone
+    node.forge_response(
+        &format!("api/core/v2/milestones/by-index/{index}/utxo-changes"),
+        &other_utxo_changes,
+    )
+    .await;
+    assert!(matches!(
+        client.get_utxo_changes_by_index(index).await,
+        Err(Error::ResponseMismatch { .. })
+    ));
+
+    // Nothing was cached, so every request went to the node
+    let metrics = client.cache_metrics().await.unwrap();
+    assert_eq!(metrics.hits, metrics_before.hits);
+    assert_eq!(metrics.misses - metrics_before.misses, 6);
+    assert_eq!(metrics.insertions, metrics_before.insertions);
+    assert_eq!(metrics.entries, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cache_options_from_json() -> Result<()> {
+    let client = Client::builder()
+        .from_json(r#"{ "nodes": ["http://localhost:14265"], "cacheOptions": { "maxEntries": 5 } }"#)?
+        .with_ignore_node_health()
+        .finish()
+        .await?;
+
+    assert_eq!(
+        client.cache_options().await,
+        Some(CacheOptions::default().with_memory_limits(5, CacheOptions::default().max_size))
+    );
+
+    client.set_cache_options(None).await;
+    assert_eq!(client.cache_metrics().await, None);
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::client::{
+    node_api::error::Error as NodeApiError,
+    node_manager::retry::{RetryPolicy, RetryableError},
+    Client, ClientBuilder, Error, Result,
+};
+
+// Nothing listens on this port, so requests fail right away.
+const UNREACHABLE_NODE: &str = "http://127.0.0.1:1";
+
+fn response_error(code: u16, retry_after: Option<Duration>) -> NodeApiError {
+    NodeApiError::ResponseError {
+        code,
+        text: String::new(),
+        url: UNREACHABLE_NODE.to_string(),
+        retry_after,
+    }
+}
+
+#[test]
+fn exponential_backoff() {
+    let policy = RetryPolicy::default()
+        .with_backoff(Duration::from_millis(100), Duration::from_millis(500))
+        .with_backoff_multiplier(3)
+        .with_jitter(false);
+    let error = response_error(503, None);
+
+    assert_eq!(policy.backoff(1, &error), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2, &error), Duration::from_millis(300));
+    assert_eq!(policy.backoff(3, &error), Duration::from_millis(500));
+    assert_eq!(policy.backoff(u32::MAX, &error), Duration::from_millis(500));
+}
+
+#[test]
+fn jitter_shortens_backoff() {
+    let policy = RetryPolicy::default().with_backoff(Duration::from_secs(1), Duration::from_secs(1));
+    let error = response_error(503, None);
+
+    for _ in 0..20 {
+        let backoff = policy.backoff(1, &error);
+        assert!(backoff >= Duration::from_millis(500) && backoff <= Duration::from_secs(1));
+    }
+}
+
+#[test]
+fn retry_after_capped_at_max_backoff() {
+    let policy = RetryPolicy::default()
+        .with_backoff(Duration::from_millis(100), Duration::from_secs(5))
+        .with_jitter(false);
+
+    assert_eq!(
+        policy.backoff(1, &response_error(429, Some(Duration::from_secs(2)))),
+        Duration::from_secs(2)
+    );
+    assert_eq!(
+        policy.backoff(1, &response_error(429, Some(Duration::from_secs(60)))),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        policy
+            .with_respect_retry_after(false)
+            .backoff(1, &response_error(429, Some(Duration::from_secs(2)))),
+        Duration::from_millis(100)
+    );
+}
+
+#[test]
+fn retryable_error_classes() {
+    assert_eq!(
+        RetryableError::from_node_error(&response_error(429, None)),
+        Some(RetryableError::TooManyRequests)
+    );
+    assert_eq!(
+        RetryableError::from_node_error(&response_error(502, None)),
+        Some(RetryableError::ServerError)
+    );
+    assert_eq!(RetryableError::from_node_error(&response_error(400, None)), None);
+    assert_eq!(
+        RetryableError::from_node_error(&response_error(503, Some(Duration::from_secs(1)))),
+        Some(RetryableError::ServerError)
+    );
+    assert_eq!(
+        RetryableError::from_node_error(&NodeApiError::NotFound(String::new())),
+        None
+    );
+}
+
+#[test]
+fn retry_policies_from_json() {
+    let client_builder = ClientBuilder::new()
+        .from_json(
+            r#"{
+            "nodes": ["http://localhost:14265"],
+            "retryPolicy": {
+                "maxAttempts": 5,
+                "initialBackoff": { "secs": 1, "nanos": 0 },
+                "retryOn": ["timeout", "tooManyRequests"]
+            }
+        }"#,
+        )
+        .unwrap();
+    let json = serde_json::to_value(&client_builder).unwrap();
+
+    assert_eq!(json["retryPolicy"]["maxAttempts"], 5);
+    assert_eq!(json["retryPolicy"]["initialBackoff"]["secs"], 1);
+    assert_eq!(json["retryPolicy"]["maxBackoff"]["secs"], 10);
+    assert_eq!(
+        json["retryPolicy"]["retryOn"],
+        serde_json::json!(["timeout", "tooManyRequests"])
+    );
+    // Requests are only retried if explicitly configured
+    assert_eq!(json["submitRetryPolicy"]["maxAttempts"], 1);
+    let default_json = serde_json::to_value(ClientBuilder::new()).unwrap();
+    assert_eq!(default_json["retryPolicy"]["maxAttempts"], 1);
+    assert_eq!(default_json["submitRetryPolicy"]["maxAttempts"], 1);
+
+    let deserialized = serde_json::from_value::<ClientBuilder>(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(deserialized).unwrap(), json);
+}
+
+#[tokio::test]
+async fn retries_unreachable_node() -> Result<()> {
+    let client = Client::builder()
+        .with_node(UNREACHABLE_NODE)?
+        .with_ignore_node_health()
+        .with_retry_policy(
+            RetryPolicy::default()
+                .with_max_attempts(2)
+                .with_backoff(Duration::from_millis(10), Duration::from_millis(10)),
+        )
+        .finish()
+        .await?;
+
+    assert!(matches!(
+        client.get_tips().await,
+        Err(Error::Node(NodeApiError::Reqwest(_)))
+    ));
+    assert_eq!(client.node_stats().await[0].requests, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_retry_for_excluded_errors() -> Result<()> {
+    let client = Client::builder()
+        .with_node(UNREACHABLE_NODE)?
+        .with_ignore_node_health()
+        .with_retry_policy(RetryPolicy::default().with_retry_on([RetryableError::ServerError]))
+        .finish()
+        .await?;
+
+    assert!(client.get_tips().await.is_err());
+    assert_eq!(client.node_stats().await[0].requests, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_retry_by_default() -> Result<()> {
+    let client = Client::builder()
+        .with_node(UNREACHABLE_NODE)?
+        .with_ignore_node_health()
+        .finish()
+        .await?;
+
+    assert!(client.get_tips().await.is_err());
+    assert_eq!(client.node_stats().await[0].requests, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn backoff_releases_request_permit() -> Result<()> {
+    let client = Client::builder()
+        .with_node(UNREACHABLE_NODE)?
+        .with_ignore_node_health()
+        .with_max_parallel_api_requests(1)
+        .with_retry_policy(
+            RetryPolicy::default()
+                .with_max_attempts(2)
+                .with_backoff(Duration::from_secs(2), Duration::from_secs(2))
+                .with_jitter(false),
+        )
+        .finish()
+        .await?;
+
+    let first_request = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_tips().await }
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let second_request = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_tips().await }
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    // The second request is sent while the first one waits to be retried
+    assert_eq!(client.node_stats().await[0].requests, 2);
+    assert!(first_request.await.unwrap().is_err());
+    assert!(second_request.await.unwrap().is_err());
+
+    Ok(())
+}
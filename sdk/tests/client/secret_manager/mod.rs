@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod mnemonic;
+#[cfg(feature = "pkcs11")]
+mod pkcs11;
 #[cfg(feature = "private_key_secret_manager")]
 mod private_key;
+#[cfg(all(feature = "remote_secret_manager", unix))]
+mod remote;
 #[cfg(feature = "stronghold")]
 mod stronghold;
@@ -0,0 +1,113 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// The ignored tests run against a SoftHSM2 token, initialized with
+// `softhsm2-util --init-token --free --label iota-sdk --pin 1234 --so-pin 1234`.
+// `SOFTHSM2_MODULE` can be set to the path of the module if it's not installed in the default location.
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::client::{
+    constants::SHIMMER_COIN_TYPE,
+    secret::{
+        pkcs11::{self, Pkcs11SecretManager},
+        SecretManage, SecretManager, SecretManagerConfig, SecretManagerDto,
+    },
+    Error, Result,
+};
+use serde_json::json;
+
+use crate::client::signing_envelope::prepare_transaction;
+
+const TOKEN_LABEL: &str = "iota-sdk";
+const PIN: &str = "1234";
+
+fn module_path() -> String {
+    std::env::var("SOFTHSM2_MODULE").unwrap_or_else(|_| "/usr/lib/softhsm/libsofthsm2.so".to_owned())
+}
+
+// Keys stay in the token, so every run uses new labels.
+fn key_label_prefix(name: &str) -> String {
+    format!(
+        "{name}-{}/",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    )
+}
+
+#[tokio::test]
+async fn pkcs11_missing_module() {
+    assert!(matches!(
+        Pkcs11SecretManager::open("/nonexistent/libpkcs11.so", TOKEN_LABEL),
+        Err(pkcs11::Error::Library(_))
+    ));
+}
+
+#[ignore]
+#[tokio::test]
+async fn pkcs11_secret_manager() -> Result<()> {
+    let secret_manager: SecretManager = json!({
+        "pkcs11": {
+            "modulePath": module_path(),
+            "tokenLabel": TOKEN_LABEL,
+            "pin": PIN,
+            "keyLabelPrefix": key_label_prefix("pkcs11_secret_manager"),
+            "generateKeys": true,
+        }
+    })
+    .to_string()
+    .parse()?;
+
+    // Keys are generated once and located afterwards
+    let addresses = SecretManage::generate_ed25519_addresses(&secret_manager, SHIMMER_COIN_TYPE, 0, 0..2, None).await?;
+    assert_ne!(addresses[0], addresses[1]);
+    assert_eq!(
+        SecretManage::generate_ed25519_addresses(&secret_manager, SHIMMER_COIN_TYPE, 0, 1..2, None).await?,
+        addresses[1..]
+    );
+
+    let signature = secret_manager
+        .sign_ed25519(b"message", Bip44::new(SHIMMER_COIN_TYPE).with_address_index(1))
+        .await?;
+    signature.is_valid(b"message", &addresses[1])?;
+
+    let prepared_transaction_data = prepare_transaction(&secret_manager, 1_000_000).await?;
+    secret_manager.sign_transaction(prepared_transaction_data).await?;
+
+    assert!(matches!(
+        secret_manager
+            .sign_secp256k1_ecdsa(b"message", Bip44::new(SHIMMER_COIN_TYPE))
+            .await,
+        Err(Error::Pkcs11(pkcs11::Error::UnsupportedOperation))
+    ));
+
+    // The PIN isn't part of the config
+    match secret_manager.to_config() {
+        Some(SecretManagerDto::Pkcs11(config)) => assert!(config.pin.is_none()),
+        config => panic!("unexpected config {config:?}"),
+    }
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn pkcs11_secret_manager_without_key_generation() -> Result<()> {
+    // Keys aren't generated by default
+    let secret_manager = Pkcs11SecretManager::open(module_path(), TOKEN_LABEL)?
+        .with_key_label_prefix(key_label_prefix("pkcs11_secret_manager_without_key_generation"));
+    secret_manager.login(PIN.to_owned()).await?;
+
+    let chain = Bip44::new(SHIMMER_COIN_TYPE);
+    assert!(matches!(
+        secret_manager.generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None).await,
+        Err(Error::Pkcs11(pkcs11::Error::KeyNotFound(label))) if label == secret_manager.key_label(chain)
+    ));
+    assert!(matches!(
+        secret_manager.sign_ed25519(b"message", chain).await,
+        Err(Error::Pkcs11(pkcs11::Error::KeyNotFound(_)))
+    ));
+
+    Ok(())
+}
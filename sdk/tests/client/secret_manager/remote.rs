@@ -0,0 +1,219 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::PathBuf, sync::Arc};
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        constants::SHIMMER_COIN_TYPE,
+        secret::{
+            remote::{self, RemoteSecretManager, RemoteSignerEndpoint, METHOD_NOT_FOUND},
+            GenerateAddressOptions, SecretManage, SecretManager,
+        },
+        Client, Error, Result,
+    },
+    types::{
+        block::{signature::dto::Ed25519SignatureDto, unlock::dto::UnlockDto},
+        TryFromDto,
+    },
+};
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, UnixListener},
+};
+
+use crate::client::signing_envelope::prepare_transaction;
+
+/// A stub signer answering JSON-RPC requests with a mnemonic secret manager.
+struct StubSigner {
+    secret_manager: SecretManager,
+    sign_transaction_essence: bool,
+}
+
+impl StubSigner {
+    async fn handle(&self, request: &str) -> String {
+        let request: Value = serde_json::from_str(request).unwrap();
+        let params = &request["params"];
+        let result = match request["method"].as_str().unwrap() {
+            "generateEd25519Addresses" => {
+                let addresses = SecretManage::generate_ed25519_addresses(
+                    &self.secret_manager,
+                    params["coinType"].as_u64().unwrap() as u32,
+                    params["accountIndex"].as_u64().unwrap() as u32,
+                    params["addressIndexes"]["start"].as_u64().unwrap() as u32
+                        ..params["addressIndexes"]["end"].as_u64().unwrap() as u32,
+                    serde_json::from_value::<GenerateAddressOptions>(params["options"].clone()).unwrap(),
+                )
+                .await
+                .unwrap();
+                Ok(json!(addresses.iter().map(ToString::to_string).collect::<Vec<_>>()))
+            }
+            "signEd25519" => {
+                let message = prefix_hex::decode::<Vec<u8>>(params["message"].as_str().unwrap()).unwrap();
+                let signature = self.secret_manager.sign_ed25519(&message, chain(params)).await.unwrap();
+                Ok(serde_json::to_value(Ed25519SignatureDto::from(&signature)).unwrap())
+            }
+            "signTransactionEssence" if self.sign_transaction_essence => {
+                let prepared_transaction_data = PreparedTransactionData::try_from_dto(
+                    serde_json::from_value::<PreparedTransactionDataDto>(params["preparedTransactionData"].clone())
+                        .unwrap(),
+                )
+                .unwrap();
+                let unlocks = self
+                    .secret_manager
+                    .sign_transaction_essence(&prepared_transaction_data, params["time"].as_u64().map(|t| t as u32))
+                    .await
+                    .unwrap();
+                Ok(serde_json::to_value(unlocks.iter().map(UnlockDto::from).collect::<Vec<_>>()).unwrap())
+            }
+            _ => Err(json!({ "code": METHOD_NOT_FOUND, "message": "method not found" })),
+        };
+
+        match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+            Err(error) => json!({ "jsonrpc": "2.0", "id": request["id"], "error": error }),
+        }
+        .to_string()
+    }
+
+    fn serve_unix_socket(self: Arc<Self>, name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("iota-sdk-{name}-{}.sock", std::process::id()));
+        std::fs::remove_file(&path).ok();
+        let listener = UnixListener::bind(&path).unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let signer = self.clone();
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    let mut request = String::new();
+                    stream.read_line(&mut request).await.unwrap();
+                    let response = signer.handle(&request).await;
+                    stream.get_mut().write_all(format!("{response}\n").as_bytes()).await.unwrap();
+                });
+            }
+        });
+
+        path
+    }
+
+    async fn serve_http(self: Arc<Self>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let signer = self.clone();
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        stream.read_line(&mut line).await.unwrap();
+                        if line.trim().is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    stream.read_exact(&mut body).await.unwrap();
+                    let response = signer.handle(std::str::from_utf8(&body).unwrap()).await;
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                        response.len()
+                    );
+                    stream.get_mut().write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+
+        url
+    }
+}
+
+fn chain(params: &Value) -> Bip44 {
+    let chain = &params["chain"];
+    Bip44::new(chain["coinType"].as_u64().unwrap() as u32)
+        .with_account(chain["account"].as_u64().unwrap() as u32)
+        .with_change(chain["change"].as_u64().unwrap() as u32)
+        .with_address_index(chain["addressIndex"].as_u64().unwrap() as u32)
+}
+
+#[tokio::test]
+async fn remote_secret_manager_unix_socket() -> Result<()> {
+    let mnemonic = Client::generate_mnemonic()?;
+    let local = SecretManager::try_from_mnemonic(mnemonic.clone())?;
+    let signer = Arc::new(StubSigner {
+        secret_manager: SecretManager::try_from_mnemonic(mnemonic)?,
+        sign_transaction_essence: true,
+    });
+    let path = signer.serve_unix_socket("remote-signer");
+
+    let remote: SecretManager = json!({ "remote": { "endpoint": { "unixSocket": path }, "timeout": 5 } })
+        .to_string()
+        .parse()?;
+
+    assert_eq!(
+        SecretManage::generate_ed25519_addresses(&remote, SHIMMER_COIN_TYPE, 1, 0..3, None).await?,
+        SecretManage::generate_ed25519_addresses(&local, SHIMMER_COIN_TYPE, 1, 0..3, None).await?
+    );
+    let chain = Bip44::new(SHIMMER_COIN_TYPE).with_address_index(2);
+    assert_eq!(
+        remote.sign_ed25519(b"message", chain).await?,
+        local.sign_ed25519(b"message", chain).await?
+    );
+
+    let prepared_transaction_data = prepare_transaction(&local, 1_000_000).await?;
+    let transaction_payload = remote.sign_transaction(prepared_transaction_data.clone()).await?;
+    assert_eq!(
+        transaction_payload,
+        local.sign_transaction(prepared_transaction_data).await?
+    );
+
+    // Methods the signer doesn't implement return its error
+    assert!(matches!(
+        remote.sign_secp256k1_ecdsa(b"message", chain).await,
+        Err(Error::RemoteSigner(remote::Error::Rpc {
+            code: METHOD_NOT_FOUND,
+            ..
+        }))
+    ));
+
+    std::fs::remove_file(path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remote_secret_manager_http() -> Result<()> {
+    let mnemonic = Client::generate_mnemonic()?;
+    let local = SecretManager::try_from_mnemonic(mnemonic.clone())?;
+    // Without `signTransactionEssence` every input is signed with `signEd25519`
+    let signer = Arc::new(StubSigner {
+        secret_manager: SecretManager::try_from_mnemonic(mnemonic)?,
+        sign_transaction_essence: false,
+    });
+    let url = signer.serve_http().await;
+
+    let remote = SecretManager::from(RemoteSecretManager::new(RemoteSignerEndpoint::Http(url.parse()?)));
+
+    assert_eq!(
+        SecretManage::generate_ed25519_addresses(&remote, SHIMMER_COIN_TYPE, 0, 0..2, None).await?,
+        SecretManage::generate_ed25519_addresses(&local, SHIMMER_COIN_TYPE, 0, 0..2, None).await?
+    );
+
+    let prepared_transaction_data = prepare_transaction(&local, 1_000_000).await?;
+    assert_eq!(
+        remote.sign_transaction(prepared_transaction_data.clone()).await?,
+        local.sign_transaction(prepared_transaction_data).await?
+    );
+
+    Ok(())
+}
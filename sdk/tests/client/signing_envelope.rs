@@ -0,0 +1,198 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{
+            GetAddressesOptions, PreparedTransactionData, SignedTransactionData, SigningEnvelope,
+            SigningEnvelopeContent,
+        },
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager},
+        Client, Error, Result,
+    },
+    types::block::{
+        address::ToBech32Ext,
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            TransactionPayload,
+        },
+        protocol::{protocol_parameters, ProtocolParameters},
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+pub(crate) async fn prepare_transaction(
+    secret_manager: &SecretManager,
+    amount: u64,
+) -> Result<PreparedTransactionData> {
+    let bech32_address = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        amount,
+        &bech32_address.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+    let outputs = build_outputs([Basic(
+        amount,
+        &bech32_address.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
+
+#[tokio::test]
+async fn signing_envelope_round_trip() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = prepare_transaction(&secret_manager, 1_000_000).await?;
+    let protocol_parameters = protocol_parameters();
+
+    let request = SigningEnvelope::request(prepared_transaction_data.clone(), &protocol_parameters)?;
+    assert_eq!(request.network_id(), protocol_parameters.network_id());
+    assert_eq!(request.chains(), vec![Some(Bip44::new(SHIMMER_COIN_TYPE))]);
+
+    let bytes = request.to_bytes()?;
+    assert_eq!(bytes[..4], SigningEnvelope::MAGIC);
+    assert_eq!(SigningEnvelope::try_from_bytes(&bytes)?, request);
+    assert_eq!(SigningEnvelope::try_from_text(&request.to_text()?)?, request);
+
+    // Chunks can be scanned in any order and more than once
+    let mut chunks = request.to_chunks(200)?;
+    assert!(chunks.len() > 1);
+    assert!(chunks.iter().all(|chunk| chunk.split_once(':').unwrap().1.len() <= 200));
+    chunks.reverse();
+    chunks.push(chunks[0].clone());
+    assert_eq!(SigningEnvelope::try_from_chunks(&chunks)?, request);
+
+    // The offline signer answers the request
+    let SigningEnvelopeContent::Request(prepared_transaction_data) = request.content() else {
+        panic!("expected a request");
+    };
+    let unlocks = secret_manager
+        .sign_transaction_essence(prepared_transaction_data, None)
+        .await?;
+    let signed_transaction_data = SignedTransactionData {
+        transaction_payload: TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?,
+        inputs_data: prepared_transaction_data.inputs_data.clone(),
+    };
+    let response = request.respond(signed_transaction_data.clone())?;
+
+    let response = SigningEnvelope::try_from_text(&response.to_text()?)?;
+    response.verify_protocol_parameters(&protocol_parameters)?;
+    assert_eq!(
+        response.into_content(),
+        SigningEnvelopeContent::Response(signed_transaction_data)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn signing_envelope_validation() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = prepare_transaction(&secret_manager, 1_000_000).await?;
+    let protocol_parameters = protocol_parameters();
+    let request = SigningEnvelope::request(prepared_transaction_data.clone(), &protocol_parameters)?;
+
+    // Corrupted in transfer
+    let mut bytes = request.to_bytes()?;
+    bytes[100] ^= 1;
+    assert!(matches!(
+        SigningEnvelope::try_from_bytes(&bytes),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+    assert!(matches!(
+        SigningEnvelope::try_from_bytes(&bytes[..10]),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+
+    // Missing chunk
+    let mut chunks = request.to_chunks(200)?;
+    chunks.remove(1);
+    assert!(matches!(
+        SigningEnvelope::try_from_chunks(&chunks),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+    // A huge chunk count is rejected without allocating for it
+    assert!(matches!(
+        SigningEnvelope::try_from_chunks(["1/999999999999:00"]),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+
+    // Bound to another network
+    let other_network = ProtocolParameters::new(
+        protocol_parameters.protocol_version(),
+        "other-network".to_string(),
+        *protocol_parameters.bech32_hrp(),
+        protocol_parameters.min_pow_score(),
+        protocol_parameters.below_max_depth(),
+        *protocol_parameters.rent_structure(),
+        protocol_parameters.token_supply(),
+    )?;
+    assert!(matches!(
+        request.verify_protocol_parameters(&other_network),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+    assert!(matches!(
+        SigningEnvelope::request(prepared_transaction_data, &other_network),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+
+    // A response has to sign the requested essence
+    let other_transaction = prepare_transaction(&secret_manager, 2_000_000).await?;
+    let unlocks = secret_manager
+        .sign_transaction_essence(&other_transaction, None)
+        .await?;
+    let signed_transaction_data = SignedTransactionData {
+        transaction_payload: TransactionPayload::new(other_transaction.essence, unlocks)?,
+        inputs_data: other_transaction.inputs_data,
+    };
+    assert!(matches!(
+        request.respond(signed_transaction_data),
+        Err(Error::InvalidSigningEnvelope(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,291 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        cache::CacheOptions,
+        mock_node::MockNode,
+        secret::SecretManager,
+        verification::{Error as VerificationError, VerificationOptions},
+        Client, Error, Result,
+    },
+    crypto::signatures::ed25519::SecretKey,
+    types::{
+        api::core::response::{LedgerInclusionState, OutputWithMetadataResponse},
+        block::{
+            input::UtxoInput,
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+            payload::Payload,
+            BlockId,
+        },
+    },
+};
+
+async fn make_client(node: &MockNode, verification_options: impl Into<Option<VerificationOptions>>) -> Result<Client> {
+    Client::builder()
+        .with_node(&node.url())?
+        .with_ignore_node_health()
+        .with_verification_options(verification_options)
+        .finish()
+        .await
+}
+
+async fn send_tagged_data_block(client: &Client, data: &str) -> Result<BlockId> {
+    Ok(client
+        .build_block()
+        .with_tag(b"Hello".to_vec())
+        .with_data(data.as_bytes().to_vec())
+        .finish()
+        .await?
+        .id())
+}
+
+// Funds the first address of the secret manager without a transaction.
+async fn fund_address(node: &MockNode, client: &Client, secret_manager: &SecretManager) -> Result<OutputId> {
+    let address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(client).await?.with_range(0..1))
+        .await?[0];
+
+    Ok(node
+        .add_output(
+            BasicOutputBuilder::new_with_amount(10_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(node.protocol_parameters().token_supply())?,
+        )
+        .await)
+}
+
+// Sends the amount of an output back to the first address of the secret manager and confirms the transaction.
+async fn send_output(
+    node: &MockNode,
+    client: &Client,
+    secret_manager: &SecretManager,
+    input: OutputId,
+) -> Result<OutputId> {
+    let address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(client).await?.with_range(0..1))
+        .await?[0];
+    let block = client
+        .build_block()
+        .with_secret_manager(secret_manager)
+        .with_input(UtxoInput::new(*input.transaction_id(), input.index())?)?
+        .with_outputs([BasicOutputBuilder::new_with_amount(10_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(node.protocol_parameters().token_supply())?])?
+        .finish()
+        .await?;
+    node.issue_milestone().await;
+
+    let Some(Payload::Transaction(transaction)) = block.payload() else {
+        panic!("missing transaction payload");
+    };
+
+    Ok(OutputId::new(transaction.id(), 0)?)
+}
+
+#[tokio::test]
+async fn verifies_proven_responses() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let sender = make_client(&node, None).await?;
+    let client = make_client(&node, node.verification_options()).await?;
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let funding_output_id = fund_address(&node, &sender, &secret_manager).await?;
+    let output_id = send_output(&node, &sender, &secret_manager, funding_output_id).await?;
+
+    // The funding output is spent, but wasn't created by a block
+    assert!(matches!(
+        client.get_output(&funding_output_id).await,
+        Err(Error::Verification(VerificationError::UnverifiableOutput(id))) if id == funding_output_id
+    ));
+
+    let block = client.get_included_block(output_id.transaction_id()).await?;
+    let metadata = client.get_block_metadata(&block.id()).await?;
+    assert_eq!(metadata.ledger_inclusion_state, Some(LedgerInclusionState::Included));
+
+    // That the output is unspent can't be proven
+    assert!(matches!(
+        client.get_output(&output_id).await,
+        Err(Error::Verification(VerificationError::UnverifiableUnspentOutput(id))) if id == output_id
+    ));
+    client
+        .set_verification_options(Some(node.verification_options().with_accept_unverifiable_state(true)))
+        .await;
+    assert!(!client.get_output(&output_id).await?.metadata().is_spent());
+
+    // Once spent, the output is proven by both transactions
+    let spending_output_id = send_output(&node, &sender, &secret_manager, output_id).await?;
+    client.set_verification_options(Some(node.verification_options())).await;
+    let output = client.get_output(&output_id).await?;
+    assert_eq!(
+        output.metadata().transaction_id_spent(),
+        Some(spending_output_id.transaction_id())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn verifies_cached_responses() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let sender = make_client(&node, None).await?;
+    let client = Client::builder()
+        .with_node(&node.url())?
+        .with_ignore_node_health()
+        .with_verification_options(node.verification_options())
+        .with_cache_options(CacheOptions::default())
+        .finish()
+        .await?;
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let funding_output_id = fund_address(&node, &sender, &secret_manager).await?;
+    let output_id = send_output(&node, &sender, &secret_manager, funding_output_id).await?;
+    send_output(&node, &sender, &secret_manager, output_id).await?;
+
+    let output = client.get_output(&output_id).await?;
+    let hits = client.cache_metrics().await.unwrap().hits;
+    assert_eq!(client.get_output(&output_id).await?.output(), output.output());
+    assert!(client.cache_metrics().await.unwrap().hits > hits);
+
+    // Cached responses are verified like the ones of the node
+    let other_node = MockNode::builder().finish().await?;
+    client
+        .set_verification_options(Some(other_node.verification_options()))
+        .await;
+    assert!(matches!(
+        client.get_output(&output_id).await,
+        Err(Error::Verification(VerificationError::InvalidMilestone { .. }))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejects_forged_output() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let sender = make_client(&node, None).await?;
+    let client = make_client(&node, node.verification_options().with_accept_unverifiable_state(true)).await?;
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let funding_output_id = fund_address(&node, &sender, &secret_manager).await?;
+    let output_id = send_output(&node, &sender, &secret_manager, funding_output_id).await?;
+
+    let path = format!("api/core/v2/outputs/{output_id}");
+    let response = OutputWithMetadataResponse::from(&node.output(&output_id).await.unwrap());
+    let mut forged = serde_json::to_value(&response).unwrap();
+    forged["output"]["amount"] = serde_json::json!("20000000");
+    node.forge_response(&path, &forged).await;
+
+    assert!(matches!(
+        client.get_output(&output_id).await,
+        Err(Error::Verification(VerificationError::OutputMismatch(id))) if id == output_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejects_forged_block_metadata() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let client = make_client(&node, node.verification_options()).await?;
+    let block_id = send_tagged_data_block(&client, "Tangle").await?;
+    let other_block_id = send_tagged_data_block(&client, "Tangle").await?;
+    let index = node.issue_milestone().await;
+    let path = format!("api/core/v2/blocks/{block_id}/metadata");
+    let metadata = node.block_metadata(&block_id).await.unwrap();
+
+    let mut forged = metadata.clone();
+    forged.referenced_by_milestone_index = Some(index - 1);
+    node.forge_response(&path, &forged).await;
+    assert!(matches!(
+        client.get_block_metadata(&block_id).await,
+        Err(Error::Verification(VerificationError::MilestoneIndexMismatch { expected, found }))
+            if expected == index - 1 && found == index
+    ));
+
+    let mut forged = metadata.clone();
+    forged.block_id = other_block_id;
+    node.forge_response(&path, &forged).await;
+    assert!(matches!(
+        client.get_block_metadata(&block_id).await,
+        Err(Error::Verification(VerificationError::BlockMismatch { expected, found }))
+            if expected == block_id && found == other_block_id
+    ));
+
+    // A node can't prove that a block is conflicting
+    let mut forged = metadata.clone();
+    forged.ledger_inclusion_state = Some(LedgerInclusionState::Conflicting);
+    node.forge_response(&path, &forged).await;
+    assert!(matches!(
+        client.get_block_metadata(&block_id).await,
+        Err(Error::Verification(VerificationError::UnverifiableConflict(id))) if id == block_id
+    ));
+    client
+        .set_verification_options(Some(node.verification_options().with_accept_unverifiable_state(true)))
+        .await;
+    assert_eq!(client.get_block_metadata(&block_id).await?, forged);
+
+    node.forge_response(&path, &metadata).await;
+    assert_eq!(client.get_block_metadata(&block_id).await?, metadata);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejects_untrusted_milestone_keys() -> Result<()> {
+    let node = MockNode::builder().finish().await?;
+    let other_node = MockNode::builder().finish().await?;
+    let client = make_client(&node, other_node.verification_options()).await?;
+    let block_id = send_tagged_data_block(&client, "Tangle").await?;
+    let index = node.issue_milestone().await;
+
+    assert!(matches!(
+        client.get_block_metadata(&block_id).await,
+        Err(Error::Verification(VerificationError::InvalidMilestone { index: milestone_index, .. }))
+            if milestone_index == index
+    ));
+
+    // Blocks that aren't referenced yet have nothing to prove
+    let block_id = send_tagged_data_block(&client, "Tangle").await?;
+    assert!(client.get_block_metadata(&block_id).await.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejects_broken_milestone_chain() -> Result<()> {
+    let milestone_keys = (0..3)
+        .map(|_| SecretKey::generate())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let node = MockNode::builder()
+        .with_milestone_keys(milestone_keys.clone())
+        .finish()
+        .await?;
+    let forked_node = MockNode::builder().with_milestone_keys(milestone_keys).finish().await?;
+    let client = make_client(&node, node.verification_options()).await?;
+    let forked_client = make_client(&forked_node, None).await?;
+
+    let block_id = send_tagged_data_block(&client, "Tangle").await?;
+    let forked_block_id = send_tagged_data_block(&forked_client, "Fork").await?;
+    let index = node.issue_milestone().await;
+    assert_eq!(forked_node.issue_milestone().await, index);
+    client.get_block_metadata(&block_id).await?;
+
+    // The forked milestone is signed by the trusted keys, but doesn't match the verified milestone with its index
+    node.forge_response(
+        &format!("api/core/v2/blocks/{forked_block_id}/metadata"),
+        &forked_client.get_block_metadata(&forked_block_id).await?,
+    )
+    .await;
+    node.forge_response(
+        &format!("api/poi/v1/create/{forked_block_id}"),
+        &forked_client.get_inclusion_proof(&forked_block_id).await?,
+    )
+    .await;
+
+    assert!(matches!(
+        client.get_block_metadata(&forked_block_id).await,
+        Err(Error::Verification(VerificationError::MilestoneChain(milestone_index))) if milestone_index == index
+    ));
+
+    Ok(())
+}
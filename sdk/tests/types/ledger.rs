@@ -0,0 +1,352 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+use iota_sdk::types::{
+    block::{
+        address::{Address, AliasAddress, Ed25519Address},
+        input::{Input, UtxoInput},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutput, AliasOutputBuilder, BasicOutputBuilder, FoundryId, FoundryOutputBuilder,
+            InputsCommitment, NativeToken, Output, OutputId, SimpleTokenScheme, TokenScheme,
+        },
+        payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
+        protocol::protocol_parameters,
+        rand::transaction::rand_transaction_id,
+        semantic::ConflictReason,
+        signature::{Ed25519Signature, Signature},
+        unlock::{AliasUnlock, SignatureUnlock, Unlock, Unlocks},
+    },
+    ledger::UtxoLedger,
+};
+
+const MILESTONE_TIMESTAMP: u32 = 100;
+
+fn address(secret_key: &SecretKey) -> Address {
+    Address::from(Ed25519Address::new(
+        Blake2b256::digest(secret_key.public_key().to_bytes()).into(),
+    ))
+}
+
+fn basic_output(amount: u64, address: Address) -> Output {
+    BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap()
+}
+
+// Builds a transaction whose first input is signed by the secret key and whose other inputs are unlocked by the given
+// unlocks.
+fn transaction(
+    secret_key: &SecretKey,
+    inputs: &[(OutputId, &Output)],
+    outputs: impl IntoIterator<Item = Output>,
+    other_unlocks: impl IntoIterator<Item = Unlock>,
+) -> TransactionPayload {
+    let protocol_parameters = protocol_parameters();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|(_, output)| *output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|(output_id, _)| Input::Utxo(UtxoInput::from(*output_id)))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs.into_iter().collect::<Vec<_>>())
+        .finish_with_params(&protocol_parameters)
+        .unwrap(),
+    );
+    let signature = Ed25519Signature::new(secret_key.public_key(), secret_key.sign(&essence.hash()));
+    let unlocks = core::iter::once(Unlock::Signature(SignatureUnlock::new(Signature::from(signature))))
+        .chain(other_unlocks)
+        .collect::<Vec<_>>();
+
+    TransactionPayload::new(essence, Unlocks::new(unlocks).unwrap()).unwrap()
+}
+
+fn transaction_spending(
+    secret_key: &SecretKey,
+    input_id: OutputId,
+    input: &Output,
+    address: Address,
+) -> TransactionPayload {
+    transaction(
+        secret_key,
+        &[(input_id, input)],
+        [basic_output(input.amount(), address)],
+        [],
+    )
+}
+
+fn output_id(transaction: &TransactionPayload, index: u16) -> OutputId {
+    OutputId::new(transaction.id(), index).unwrap()
+}
+
+fn funded_ledger(address: Address, amount: u64) -> (UtxoLedger, OutputId) {
+    let mut ledger = UtxoLedger::new(1, MILESTONE_TIMESTAMP);
+    let output_id = OutputId::new(rand_transaction_id(), 0).unwrap();
+    ledger.add_output(output_id, basic_output(amount, address));
+
+    (ledger, output_id)
+}
+
+#[test]
+fn apply_transaction() {
+    let secret_key = SecretKey::from_bytes(&[1; 32]);
+    let address = address(&secret_key);
+    let (mut ledger, input_id) = funded_ledger(address, 2_000_000);
+    let input = ledger.output(&input_id).unwrap().clone();
+
+    let transaction = transaction(
+        &secret_key,
+        &[(input_id, &input)],
+        [basic_output(1_500_000, address), basic_output(500_000, address)],
+        [],
+    );
+    assert_eq!(ledger.validate_transaction(&transaction), ConflictReason::None);
+    ledger.apply_transaction(&transaction).unwrap();
+
+    assert!(!ledger.is_unspent(&input_id));
+    let spent_output = ledger.spent_output(&input_id).unwrap();
+    assert_eq!(spent_output.transaction_id(), &transaction.id());
+    assert_eq!(spent_output.milestone_index(), 1);
+    assert_eq!(
+        ledger
+            .unspent_outputs()
+            .map(|(output_id, _)| *output_id)
+            .collect::<Vec<_>>(),
+        [output_id(&transaction, 0), output_id(&transaction, 1)]
+    );
+    assert_eq!(
+        ledger.unspent_outputs().map(|(_, output)| output.amount()).sum::<u64>(),
+        2_000_000
+    );
+}
+
+#[test]
+fn conflicts() {
+    let secret_key = SecretKey::from_bytes(&[1; 32]);
+    let address = address(&secret_key);
+    let (mut ledger, input_id) = funded_ledger(address, 2_000_000);
+    let input = ledger.output(&input_id).unwrap().clone();
+
+    let too_much = transaction(
+        &secret_key,
+        &[(input_id, &input)],
+        [basic_output(3_000_000, address)],
+        [],
+    );
+    assert_eq!(
+        ledger.apply_transaction(&too_much),
+        Err(ConflictReason::CreatedConsumedAmountMismatch)
+    );
+
+    let wrong_signer = transaction(
+        &SecretKey::from_bytes(&[2; 32]),
+        &[(input_id, &input)],
+        [basic_output(2_000_000, address)],
+        [],
+    );
+    assert_eq!(
+        ledger.apply_transaction(&wrong_signer),
+        Err(ConflictReason::InvalidSignature)
+    );
+    // Conflicting transactions leave the ledger untouched.
+    assert!(ledger.is_unspent(&input_id));
+
+    let unknown_input = OutputId::new(TransactionId::null(), 0).unwrap();
+    let not_found = transaction(
+        &secret_key,
+        &[(unknown_input, &input)],
+        [basic_output(2_000_000, address)],
+        [],
+    );
+    assert_eq!(
+        ledger.apply_transaction(&not_found),
+        Err(ConflictReason::InputUtxoNotFound)
+    );
+
+    let first = transaction(
+        &secret_key,
+        &[(input_id, &input)],
+        [basic_output(2_000_000, address)],
+        [],
+    );
+    let second = transaction(
+        &secret_key,
+        &[(input_id, &input)],
+        [basic_output(1_000_000, address), basic_output(1_000_000, address)],
+        [],
+    );
+    ledger.apply_transaction(&first).unwrap();
+    assert_eq!(
+        ledger.apply_transaction(&second),
+        Err(ConflictReason::InputUtxoAlreadySpentInThisMilestone)
+    );
+
+    assert_eq!(ledger.next_milestone(MILESTONE_TIMESTAMP + 10), 2);
+    assert_eq!(
+        ledger.apply_transaction(&second),
+        Err(ConflictReason::InputUtxoAlreadySpent)
+    );
+}
+
+#[test]
+fn alias_and_foundry_chains() {
+    let secret_key = SecretKey::from_bytes(&[1; 32]);
+    let address = address(&secret_key);
+    let token_supply = protocol_parameters().token_supply();
+    let (mut ledger, input_id) = funded_ledger(address, 3_000_000);
+    let input = ledger.output(&input_id).unwrap().clone();
+
+    // Create an alias.
+    let alias_output = AliasOutputBuilder::new_with_amount(2_000_000, AliasId::null())
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+        .finish_output(token_supply)
+        .unwrap();
+    let create_alias = transaction(
+        &secret_key,
+        &[(input_id, &input)],
+        [alias_output, basic_output(1_000_000, address)],
+        [],
+    );
+    ledger.apply_transaction(&create_alias).unwrap();
+
+    let alias_output_id = output_id(&create_alias, 0);
+    let alias_id = AliasId::from(&alias_output_id);
+    let (output_id_0, alias_output) = ledger.alias_output(&alias_id).unwrap();
+    assert_eq!(output_id_0, &alias_output_id);
+    let alias_output = alias_output.clone();
+
+    // Transition the alias to create a foundry minting native tokens.
+    let foundry_id = FoundryId::build(&AliasAddress::new(alias_id), 1, SimpleTokenScheme::KIND);
+    let transitioned_alias = AliasOutputBuilder::from(alias_output.as_alias())
+        .with_alias_id(alias_id)
+        .with_amount(1_000_000)
+        .with_state_index(alias_output.as_alias().state_index() + 1)
+        .with_foundry_counter(1)
+        .finish_output(token_supply)
+        .unwrap();
+    let foundry_output = FoundryOutputBuilder::new_with_amount(
+        1_000_000,
+        1,
+        TokenScheme::Simple(SimpleTokenScheme::new(100, 0, 1_000).unwrap()),
+    )
+    .add_native_token(NativeToken::new(foundry_id.into(), 100).unwrap())
+    .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::new(alias_id)))
+    .finish_output(token_supply)
+    .unwrap();
+    let create_foundry = transaction(
+        &secret_key,
+        &[(alias_output_id, &alias_output)],
+        [transitioned_alias, foundry_output],
+        [],
+    );
+    ledger.apply_transaction(&create_foundry).unwrap();
+
+    assert_eq!(
+        ledger.alias_output(&alias_id).unwrap().0,
+        &output_id(&create_foundry, 0)
+    );
+    assert_eq!(
+        ledger.foundry_output(&foundry_id).unwrap().0,
+        &output_id(&create_foundry, 1)
+    );
+    assert!(ledger.spent_output(&alias_output_id).is_some());
+
+    // Destroy the foundry, burning its native tokens.
+    let alias_output = ledger.alias_output(&alias_id).unwrap().1.clone();
+    let foundry_output = ledger.foundry_output(&foundry_id).unwrap().1.clone();
+    let destroy_foundry = transaction(
+        &secret_key,
+        &[
+            (output_id(&create_foundry, 0), &alias_output),
+            (output_id(&create_foundry, 1), &foundry_output),
+        ],
+        [AliasOutputBuilder::from(alias_output.as_alias())
+            .with_amount(2_000_000)
+            .with_state_index(alias_output.as_alias().state_index() + 1)
+            .finish_output(token_supply)
+            .unwrap()],
+        [Unlock::Alias(AliasUnlock::new(0).unwrap())],
+    );
+    ledger.apply_transaction(&destroy_foundry).unwrap();
+    assert_eq!(
+        ledger.alias_output(&alias_id).unwrap().0,
+        &output_id(&destroy_foundry, 0)
+    );
+    assert!(ledger.foundry_output(&foundry_id).is_none());
+
+    // A non-null alias id can't be created out of thin air.
+    let input = ledger.output(&output_id(&create_alias, 1)).unwrap().clone();
+    let duplicate_alias = transaction(
+        &secret_key,
+        &[(output_id(&create_alias, 1), &input)],
+        [AliasOutput::build_with_amount(1_000_000, alias_id)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+            .finish_output(token_supply)
+            .unwrap()],
+        [],
+    );
+    assert_eq!(
+        ledger.apply_transaction(&duplicate_alias),
+        Err(ConflictReason::InvalidChainStateTransition)
+    );
+}
+
+#[test]
+fn snapshot_restore() {
+    let secret_key = SecretKey::from_bytes(&[1; 32]);
+    let address = address(&secret_key);
+    let (mut ledger, input_id) = funded_ledger(address, 2_000_000);
+    let input = ledger.output(&input_id).unwrap().clone();
+    let snapshot = ledger.snapshot();
+
+    let transaction = transaction_spending(&secret_key, input_id, &input, address);
+    ledger.apply_transaction(&transaction).unwrap();
+    ledger.next_milestone(MILESTONE_TIMESTAMP + 10);
+    assert!(!ledger.is_unspent(&input_id));
+
+    ledger.restore(snapshot.clone());
+    assert!(ledger.is_unspent(&input_id));
+    assert_eq!(ledger.milestone_index(), 1);
+    assert_eq!(ledger.snapshot(), snapshot);
+    // The same transaction can be applied again after restoring.
+    ledger.apply_transaction(&transaction).unwrap();
+}
+
+#[test]
+fn prune() {
+    let secret_key = SecretKey::from_bytes(&[1; 32]);
+    let address = address(&secret_key);
+    let (mut ledger, input_id) = funded_ledger(address, 2_000_000);
+    let input = ledger.output(&input_id).unwrap().clone();
+
+    let transaction = transaction_spending(&secret_key, input_id, &input, address);
+    ledger.apply_transaction(&transaction).unwrap();
+    ledger.next_milestone(MILESTONE_TIMESTAMP + 10);
+
+    ledger.prune(0);
+    assert!(ledger.spent_output(&input_id).is_some());
+    ledger.prune(1);
+    assert!(ledger.spent_output(&input_id).is_none());
+    assert_eq!(ledger.spent_outputs().count(), 0);
+    assert!(ledger.is_unspent(&output_id(&transaction, 0)));
+    // Pruned outputs are unknown to the ledger.
+    assert_eq!(
+        ledger.validate_transaction(&transaction),
+        ConflictReason::InputUtxoNotFound
+    );
+}
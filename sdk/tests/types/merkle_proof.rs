@@ -0,0 +1,49 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    payload::milestone::{MerkleHasher, MerkleProof},
+    rand::block::rand_block_ids,
+};
+
+#[test]
+fn proof_leads_to_root() {
+    for len in 1..=9 {
+        let block_ids = rand_block_ids(len);
+        let root = MerkleHasher::digest(&block_ids);
+
+        for (index, block_id) in block_ids.iter().enumerate() {
+            let proof = MerkleHasher::proof(&block_ids, index).unwrap();
+
+            assert_eq!(proof.value(), Some(block_id));
+            assert_eq!(proof.root(), root);
+        }
+    }
+}
+
+#[test]
+fn proof_out_of_bounds() {
+    let block_ids = rand_block_ids(3);
+
+    assert!(MerkleHasher::proof(&block_ids, 3).is_none());
+    assert!(MerkleHasher::proof(&[], 0).is_none());
+}
+
+#[test]
+fn proof_of_other_block() {
+    let block_ids = rand_block_ids(4);
+    let other_block_ids = rand_block_ids(4);
+    let proof = MerkleHasher::proof(&other_block_ids, 1).unwrap();
+
+    assert_ne!(proof.root(), MerkleHasher::digest(&block_ids));
+}
+
+#[test]
+fn proof_json_roundtrip() {
+    let block_ids = rand_block_ids(5);
+    let proof = MerkleHasher::proof(&block_ids, 4).unwrap();
+
+    let json = serde_json::to_value(&proof).unwrap();
+    assert_eq!(json["r"]["value"], block_ids[4].to_string());
+    assert_eq!(serde_json::from_value::<MerkleProof>(json).unwrap(), proof);
+}
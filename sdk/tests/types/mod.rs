@@ -9,6 +9,8 @@ mod block_id;
 mod ed25519_signature;
 mod foundry_id;
 mod input;
+mod ledger;
+mod merkle_proof;
 mod migrated_funds_entry;
 mod milestone_id;
 mod milestone_index;
@@ -0,0 +1,78 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::mock_node::MockNode,
+    types::block::address::{Bech32Address, Hrp},
+    wallet::{Contact, Error, Result},
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn address_book() -> Result<()> {
+    let storage_path = "test-storage/address_book";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account = wallet.create_account().finish().await?;
+    let addresses = account.generate_ed25519_addresses(2, None).await?;
+    let alice = *addresses[0].address();
+    let bob = *addresses[1].address();
+
+    wallet.add_contact(Contact::new("alice", alice).with_tags(["team"])).await?;
+    wallet.add_contact(Contact::new("bob", bob)).await?;
+    assert!(matches!(
+        wallet.add_contact(Contact::new("alice", bob)).await,
+        Err(Error::ContactAlreadyExists(label)) if label == "alice"
+    ));
+    // Addresses of another network are rejected
+    let other_network = Bech32Address::new(Hrp::from_str_unchecked("iota"), alice);
+    assert!(matches!(
+        wallet.add_contact(Contact::new("carol", other_network)).await,
+        Err(Error::Client(_))
+    ));
+
+    let contacts = wallet.contacts().await?;
+    assert_eq!(contacts.len(), 2);
+    assert_eq!(contacts[0], Contact::new("alice", alice).with_tags(["team"]));
+    assert_eq!(wallet.contacts_with_tag("team").await?, [contacts[0].clone()]);
+
+    wallet.update_contact("bob", Contact::new("robert", bob)).await?;
+    assert!(wallet.get_contact("bob").await?.is_none());
+    assert_eq!(wallet.get_contact("robert").await?.unwrap().address(), &bob);
+    assert!(matches!(
+        wallet.update_contact("robert", Contact::new("alice", bob)).await,
+        Err(Error::ContactAlreadyExists(_))
+    ));
+
+    wallet.remove_contact("robert").await?;
+    assert!(matches!(
+        wallet.remove_contact("robert").await,
+        Err(Error::ContactNotFound(_))
+    ));
+
+    // Contacts are loaded from the storage
+    drop(account);
+    drop(wallet);
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    assert_eq!(wallet.contacts().await?, [Contact::new("alice", alice).with_tags(["team"])]);
+
+    // Contacts are included in the backup
+    #[cfg(feature = "stronghold")]
+    {
+        iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+        let backup_path = std::path::PathBuf::from(format!("{storage_path}/backup.stronghold"));
+        wallet.backup(backup_path.clone(), "password".to_owned()).await?;
+
+        let restore_storage_path = format!("{storage_path}/restored");
+        let restore_wallet = make_wallet(&restore_storage_path, None, Some(&node.url())).await?;
+        restore_wallet
+            .restore_backup(backup_path, "password".to_owned(), None, None)
+            .await?;
+        assert_eq!(restore_wallet.contacts().await?, wallet.contacts().await?);
+    }
+
+    tear_down(storage_path)
+}
@@ -13,6 +13,11 @@ use iota_sdk::{
     },
     wallet::{Account, ClientOptions, Result, Wallet},
 };
+#[cfg(feature = "test-utils")]
+use iota_sdk::{
+    client::mock_node::MockNode,
+    types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+};
 
 pub use self::constants::*;
 
@@ -67,6 +72,18 @@ pub(crate) async fn make_ledger_nano_wallet(storage_path: &str, node: Option<&st
     wallet_builder.finish().await
 }
 
+/// Adds a basic output with `amount` to the mock node, owned by the first address of the account.
+#[allow(dead_code)]
+#[cfg(feature = "test-utils")]
+pub(crate) async fn fund_account(node: &MockNode, account: &Account, amount: u64) -> Result<()> {
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(account.addresses().await?[0].address()))
+        .finish_output(node.protocol_parameters().token_supply())?;
+    node.add_output(output).await;
+
+    Ok(())
+}
+
 /// Create `amount` new accounts, request funds from the faucet and sync the accounts afterwards until the faucet output
 /// is available. Returns the new accounts.
 #[allow(dead_code)]
@@ -14,7 +14,8 @@ use iota_sdk::{
     wallet::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
+            AddressData, NewOutputEvent, ScheduledPaymentExecutedEvent, ScheduledPaymentFailedEvent,
+            ScheduledPaymentSkippedEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
             WalletEvent,
         },
     },
@@ -121,4 +122,22 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    assert_serde_eq(WalletEvent::ScheduledPaymentExecuted(ScheduledPaymentExecutedEvent {
+        scheduled_payment_id: "0x01".to_string(),
+        transaction_id: TransactionId::null(),
+        next_run: Some(1_700_000_000),
+    }));
+
+    assert_serde_eq(WalletEvent::ScheduledPaymentSkipped(ScheduledPaymentSkippedEvent {
+        scheduled_payment_id: "0x01".to_string(),
+        error: "insufficient funds".to_string(),
+        next_run: None,
+    }));
+
+    assert_serde_eq(WalletEvent::ScheduledPaymentFailed(ScheduledPaymentFailedEvent {
+        scheduled_payment_id: "0x01".to_string(),
+        error: "node unavailable".to_string(),
+        next_run: Some(1_700_000_060),
+    }));
 }
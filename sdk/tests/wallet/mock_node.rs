@@ -0,0 +1,197 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{cache::CacheOptions, mock_node::MockNode, Client},
+    types::{
+        api::core::response::LedgerInclusionState,
+        block::{payload::Payload, semantic::ConflictReason},
+    },
+    wallet::{account::types::InclusionState, ClientOptions, Result, SendParams},
+};
+
+use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn mock_node_send_amount() -> Result<()> {
+    let storage_path = "test-storage/mock_node_send_amount";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    fund_account(&node, &account_0, 10_000_000).await?;
+    assert_eq!(account_0.sync(None).await?.base_coin().available(), 10_000_000);
+
+    let amount = 1_000_000;
+    let transaction = account_0
+        .send_with_params(
+            [SendParams::new(amount, *account_1.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+
+    // Nothing is confirmed before the next milestone
+    assert_eq!(account_1.sync(None).await?.base_coin().available(), 0);
+
+    node.issue_milestone().await;
+
+    assert_eq!(account_0.sync(None).await?.base_coin().total(), 10_000_000 - amount);
+    assert_eq!(account_1.sync(None).await?.base_coin().available(), amount);
+    assert_eq!(
+        account_0
+            .get_transaction(&transaction.transaction_id)
+            .await
+            .unwrap()
+            .inclusion_state,
+        InclusionState::Confirmed
+    );
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn mock_node_conflicting_transactions() -> Result<()> {
+    let storage_path_0 = "test-storage/mock_node_conflicting_transactions_0";
+    let storage_path_1 = "test-storage/mock_node_conflicting_transactions_1";
+    setup(storage_path_0)?;
+    setup(storage_path_1)?;
+
+    let node = MockNode::builder().finish().await?;
+    let mnemonic = Client::generate_mnemonic()?;
+    // Two wallets with the same mnemonic don't know about each others locked outputs
+    let wallet_0 = make_wallet(storage_path_0, Some(mnemonic.clone()), Some(&node.url())).await?;
+    let wallet_1 = make_wallet(storage_path_1, Some(mnemonic), Some(&node.url())).await?;
+    let account_0 = wallet_0.create_account().finish().await?;
+    let account_1 = wallet_1.create_account().finish().await?;
+
+    fund_account(&node, &account_0, 10_000_000).await?;
+    account_0.sync(None).await?;
+    account_1.sync(None).await?;
+
+    let address = *account_0.addresses().await?[0].address();
+    let transaction_0 = account_0
+        .send_with_params([SendParams::new(1_000_000, address)?], None)
+        .await?;
+    let transaction_1 = account_1
+        .send_with_params([SendParams::new(2_000_000, address)?], None)
+        .await?;
+
+    node.issue_milestone().await;
+
+    let metadata_0 = node.block_metadata(&transaction_0.block_id.unwrap()).await.unwrap();
+    assert_eq!(metadata_0.ledger_inclusion_state, Some(LedgerInclusionState::Included));
+    let metadata_1 = node.block_metadata(&transaction_1.block_id.unwrap()).await.unwrap();
+    assert_eq!(
+        metadata_1.ledger_inclusion_state,
+        Some(LedgerInclusionState::Conflicting)
+    );
+    assert_eq!(
+        metadata_1.conflict_reason,
+        Some(ConflictReason::InputUtxoAlreadySpentInThisMilestone as u8)
+    );
+
+    account_1.sync(None).await?;
+    assert_eq!(
+        account_1
+            .get_transaction(&transaction_1.transaction_id)
+            .await
+            .unwrap()
+            .inclusion_state,
+        InclusionState::Conflicting
+    );
+
+    // A reattachment in a later milestone conflicts with the already spent input
+    let client = account_1.client();
+    let block = client
+        .finish_block_builder(None, Some(Payload::from(transaction_1.payload)))
+        .await?;
+    let block_id = client.post_block(&block).await?;
+    node.issue_milestone().await;
+
+    let metadata = client.get_block_metadata(&block_id).await?;
+    assert_eq!(metadata.ledger_inclusion_state, Some(LedgerInclusionState::Conflicting));
+    assert_eq!(
+        metadata.conflict_reason,
+        Some(ConflictReason::InputUtxoAlreadySpent as u8)
+    );
+
+    tear_down(storage_path_0)?;
+    tear_down(storage_path_1)
+}
+
+#[tokio::test]
+async fn mock_node_pruning() -> Result<()> {
+    let storage_path = "test-storage/mock_node_pruning";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account = wallet.create_account().finish().await?;
+
+    fund_account(&node, &account, 10_000_000).await?;
+    account.sync(None).await?;
+    let spent_output_id = account.unspent_outputs(None).await?[0].output_id;
+
+    let transaction = account
+        .send_with_params(
+            [SendParams::new(1_000_000, *account.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+    let milestone_index = node.issue_milestone().await;
+    node.issue_milestone().await;
+
+    assert!(node.prune(milestone_index + 1).await.is_err());
+    node.prune(milestone_index).await?;
+
+    let block_id = transaction.block_id.unwrap();
+    assert!(node.block_metadata(&block_id).await.is_none());
+    assert!(node.output(&spent_output_id).await.is_none());
+    assert!(account.client().get_block_metadata(&block_id).await.is_err());
+    assert_eq!(
+        account.client().get_info().await?.node_info.status.pruning_index,
+        milestone_index
+    );
+
+    // Unspent outputs are not pruned
+    assert_eq!(account.sync(None).await?.base_coin().available(), 10_000_000);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn mock_node_change_clears_cache() -> Result<()> {
+    let storage_path = "test-storage/mock_node_change_clears_cache";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let other_node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    wallet
+        .set_client_options(
+            ClientOptions::new()
+                .with_node(&node.url())?
+                .with_cache_options(CacheOptions::default()),
+        )
+        .await?;
+
+    let index = node.issue_milestone().await;
+    wallet.client().get_utxo_changes_by_index(index).await?;
+    assert_eq!(wallet.client().cache_metrics().await.unwrap().entries, 1);
+
+    // The responses of the previous node aren't served anymore
+    wallet
+        .set_client_options(
+            ClientOptions::new()
+                .with_node(&other_node.url())?
+                .with_cache_options(CacheOptions::default()),
+        )
+        .await?;
+    assert_eq!(wallet.client().cache_metrics().await.unwrap().entries, 0);
+    assert!(wallet.client().get_utxo_changes_by_index(index).await.is_err());
+
+    tear_down(storage_path)
+}
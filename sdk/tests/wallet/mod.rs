@@ -3,6 +3,8 @@
 
 mod account_recovery;
 mod accounts;
+#[cfg(all(feature = "storage", feature = "test-utils"))]
+mod address_book;
 mod address_generation;
 #[cfg(all(feature = "stronghold", feature = "storage"))]
 mod backup_restore;
@@ -21,10 +23,24 @@ mod error;
 mod events;
 #[cfg(feature = "stronghold")]
 mod migrate_stronghold_snapshot_v2_to_v3;
+#[cfg(feature = "test-utils")]
+mod mock_node;
+#[cfg(feature = "test-utils")]
+mod multisig;
 mod native_tokens;
 mod output_preparation;
+#[cfg(feature = "storage")]
+mod scheduled_payments;
+#[cfg(feature = "test-utils")]
+mod simulate_transaction;
+#[cfg(feature = "sqlite")]
+mod sqlite_storage;
 mod syncing;
+#[cfg(feature = "test-utils")]
+mod transaction_history;
 mod transactions;
 #[cfg(not(target_os = "windows"))]
 #[cfg(feature = "rocksdb")]
 mod wallet_storage;
+#[cfg(feature = "test-utils")]
+mod watch_only;
@@ -0,0 +1,115 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::PreparedTransactionData,
+        constants::SHIMMER_COIN_TYPE,
+        mock_node::MockNode,
+        secret::{mnemonic::MnemonicSecretManager, SecretManage},
+        Client,
+    },
+    types::{
+        block::{
+            address::{Address, Bech32Address},
+            output::Output,
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{MultisigPolicy, MultisigTransaction, MultisigTransactionDto, PartialUnlocks},
+        Error, Result,
+    },
+};
+
+use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn multisig_alias_governor_rotation() -> Result<()> {
+    let storage_path = "test-storage/multisig_alias_governor_rotation";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let coordinator_mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(coordinator_mnemonic.clone()), Some(&node.url())).await?;
+    let account = wallet.create_account().finish().await?;
+
+    fund_account(&node, &account, 10_000_000).await?;
+    account.sync(None).await?;
+    account.create_alias_output(None, None).await?;
+    node.issue_milestone().await;
+    let alias_id = account.sync(None).await?.aliases()[0];
+
+    let chain = Bip44::new(SHIMMER_COIN_TYPE);
+    let coordinator = MnemonicSecretManager::try_from_mnemonic(coordinator_mnemonic)?;
+    let signer_1 = MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let signer_2 = MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let outsider = MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let mut signers = Vec::new();
+    for secret_manager in [&coordinator, &signer_1, &signer_2] {
+        signers.push(
+            secret_manager
+                .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+                .await?[0],
+        );
+    }
+    assert!(matches!(
+        MultisigPolicy::new(3, [signers[0], signers[1], signers[1]]),
+        Err(Error::InvalidMultisigPolicy(_))
+    ));
+    let policy = MultisigPolicy::new(2, signers.clone())?;
+
+    let new_governor = Bech32Address::new(account.client().get_bech32_hrp().await?, signers[1]);
+    let prepared_transaction = account
+        .prepare_rotate_alias_governor(alias_id, new_governor, None)
+        .await?;
+    let time = account.client().get_time_checked().await?;
+    let mut multisig_transaction = MultisigTransaction::new(prepared_transaction.clone(), policy, time);
+
+    // Approvals of keys which aren't part of the policy are rejected
+    assert!(matches!(
+        multisig_transaction
+            .add_partial_unlocks(PartialUnlocks::sign(&outsider, &prepared_transaction, chain, time).await?),
+        Err(Error::InvalidPartialUnlocks(_))
+    ));
+
+    // The coordinator signs the alias input, but one approval isn't enough
+    multisig_transaction
+        .add_partial_unlocks(PartialUnlocks::sign(&coordinator, &prepared_transaction, chain, time).await?)?;
+    assert!(!multisig_transaction.is_policy_met()?);
+    assert!(matches!(
+        account.submit_multisig_transaction(&multisig_transaction, None).await,
+        Err(Error::MultisigPolicyNotMet {
+            approvals: 1,
+            threshold: 2
+        })
+    ));
+
+    // A signer approves the signing request it received as JSON
+    let signing_request = serde_json::to_string(&multisig_transaction.signing_request())?;
+    let prepared_transaction_for_signer =
+        PreparedTransactionData::try_from_dto(serde_json::from_str(&signing_request)?)?;
+    let partial_unlocks = PartialUnlocks::sign(&signer_2, &prepared_transaction_for_signer, chain, time).await?;
+    assert!(partial_unlocks.unlocks().is_empty());
+    multisig_transaction.add_partial_unlocks(partial_unlocks)?;
+    assert_eq!(multisig_transaction.approvers(), vec![signers[0], signers[2]]);
+    assert!(multisig_transaction.is_policy_met()?);
+
+    let dto = MultisigTransactionDto::from(&multisig_transaction);
+    let multisig_transaction = MultisigTransaction::try_from(serde_json::from_str::<MultisigTransactionDto>(
+        &serde_json::to_string(&dto)?,
+    )?)?;
+    account.submit_multisig_transaction(&multisig_transaction, None).await?;
+    node.issue_milestone().await;
+    account.sync(None).await?;
+
+    let alias_output = account.unspent_alias_output(&alias_id).await?.unwrap();
+    let Output::Alias(alias_output) = alias_output.output else {
+        panic!("expected an alias output");
+    };
+    assert_eq!(alias_output.governor_address(), &Address::from(signers[1]));
+    assert_eq!(alias_output.state_index(), 0);
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,314 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::wallet::{account::PaymentSchedule, Result};
+
+// Tuesday, 14 November 2023 22:13:20 UTC
+const TIME: u32 = 1_700_000_000;
+
+#[test]
+fn payment_schedule_once() -> Result<()> {
+    let schedule = PaymentSchedule::Once { time: TIME };
+
+    assert_eq!(schedule.first_run(TIME + 100)?, Some(TIME));
+    assert_eq!(schedule.next_run_after(TIME - 1)?, Some(TIME));
+    assert_eq!(schedule.next_run_after(TIME)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn payment_schedule_interval() -> Result<()> {
+    let schedule = PaymentSchedule::Interval {
+        start: TIME,
+        interval: 100,
+    };
+
+    assert_eq!(schedule.first_run(TIME - 50)?, Some(TIME));
+    assert_eq!(schedule.first_run(TIME + 200)?, Some(TIME + 200));
+    assert_eq!(schedule.next_run_after(TIME)?, Some(TIME + 100));
+    assert_eq!(schedule.next_run_after(TIME + 250)?, Some(TIME + 300));
+
+    let schedule = PaymentSchedule::Interval {
+        start: TIME,
+        interval: 0,
+    };
+    assert!(schedule.next_run_after(TIME).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn payment_schedule_cron() -> Result<()> {
+    // Every 15 minutes
+    assert_eq!(
+        PaymentSchedule::cron("*/15 * * * *")?.next_run_after(TIME)?,
+        Some(1_700_000_100)
+    );
+    // First day of the month at 09:00
+    assert_eq!(
+        PaymentSchedule::cron("0 9 1 * *")?.next_run_after(TIME)?,
+        Some(1_701_421_200)
+    );
+    // Sundays at midnight, 7 is an alias for 0
+    assert_eq!(
+        PaymentSchedule::cron("0 0 * * 0")?.next_run_after(TIME)?,
+        Some(1_700_352_000)
+    );
+    assert_eq!(
+        PaymentSchedule::cron("0 0 * * 7")?.next_run_after(TIME)?,
+        Some(1_700_352_000)
+    );
+    // Either the 13th or a friday
+    assert_eq!(
+        PaymentSchedule::cron("0 0 13 * 5")?.next_run_after(TIME)?,
+        Some(1_700_179_200)
+    );
+    // Only in leap years
+    assert_eq!(
+        PaymentSchedule::cron("0 0 29 2 *")?.next_run_after(TIME)?,
+        Some(1_709_164_800)
+    );
+    // Never
+    assert_eq!(PaymentSchedule::cron("0 0 31 2 *")?.next_run_after(TIME)?, None);
+    // Day fields matching every day are unrestricted like `*`
+    assert_eq!(
+        PaymentSchedule::cron("0 0 1-31 * 0")?.next_run_after(TIME)?,
+        Some(1_700_352_000)
+    );
+    assert_eq!(
+        PaymentSchedule::cron("0 9 1 * 0-7")?.next_run_after(TIME)?,
+        Some(1_701_421_200)
+    );
+
+    for expression in [
+        "* * * *",
+        "60 * * * *",
+        "5-1 * * * *",
+        "*/0 * * * *",
+        "0 0 0 * *",
+        "a * * * *",
+    ] {
+        assert!(PaymentSchedule::cron(expression).is_err(), "{expression}");
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn process_scheduled_payments() -> Result<()> {
+    use iota_sdk::{
+        client::mock_node::MockNode,
+        wallet::{account::ScheduledPaymentRun, SendParams},
+    };
+
+    use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+    let storage_path = "test-storage/process_scheduled_payments";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    #[cfg(feature = "events")]
+    let events = {
+        use iota_sdk::wallet::events::types::WalletEventType;
+
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let events_clone = events.clone();
+        wallet
+            .listen(
+                [
+                    WalletEventType::ScheduledPaymentExecuted,
+                    WalletEventType::ScheduledPaymentSkipped,
+                ],
+                move |event| events_clone.lock().unwrap().push(event.event.clone()),
+            )
+            .await;
+        events
+    };
+
+    fund_account(&node, &account_0, 10_000_000).await?;
+    account_0.sync(None).await?;
+
+    let amount = 1_000_000;
+    let address = *account_1.addresses().await?[0].address();
+    let current_time = account_0.client().get_time_checked().await?;
+    let scheduled_payment = account_0
+        .schedule_payment(
+            SendParams::new(amount, address)?,
+            PaymentSchedule::Interval {
+                start: current_time,
+                interval: 1_000,
+            },
+            2,
+        )
+        .await?;
+    assert_eq!(scheduled_payment.next_run(), Some(current_time));
+    let too_large_payment = account_0
+        .schedule_payment(
+            SendParams::new(100_000_000, address)?,
+            PaymentSchedule::Once { time: current_time },
+            None,
+        )
+        .await?;
+    assert_eq!(account_0.scheduled_payments().await?.len(), 2);
+
+    let processed_payments = account_0.process_scheduled_payments().await?;
+    assert_eq!(processed_payments.len(), 2);
+    assert_eq!(processed_payments[0].id(), scheduled_payment.id());
+    assert_eq!(processed_payments[1].id(), too_large_payment.id());
+    assert!(matches!(
+        processed_payments[0].last_run(),
+        Some(ScheduledPaymentRun::Executed { .. })
+    ));
+    assert_eq!(processed_payments[0].executed_runs(), 1);
+    assert_eq!(processed_payments[0].next_run(), Some(current_time + 1_000));
+    assert!(matches!(
+        processed_payments[1].last_run(),
+        Some(ScheduledPaymentRun::Skipped { .. })
+    ));
+    // The skipped payment has no later run, so it stays due
+    assert_eq!(processed_payments[1].next_run(), Some(current_time));
+
+    // Only the skipped payment is due until the next interval
+    let processed_payments = account_0.process_scheduled_payments().await?;
+    assert_eq!(processed_payments.len(), 1);
+    assert_eq!(processed_payments[0].id(), too_large_payment.id());
+
+    node.issue_milestone().await;
+    assert_eq!(account_1.sync(None).await?.base_coin().available(), amount);
+
+    #[cfg(feature = "events")]
+    {
+        use iota_sdk::wallet::events::types::WalletEvent;
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(
+            matches!(&events[0], WalletEvent::ScheduledPaymentExecuted(event) if event.scheduled_payment_id == *scheduled_payment.id())
+        );
+        assert!(
+            matches!(&events[1], WalletEvent::ScheduledPaymentSkipped(event) if event.scheduled_payment_id == *too_large_payment.id())
+        );
+    }
+
+    // The skipped payment is sent once the account has enough funds
+    fund_account(&node, &account_0, 100_000_000).await?;
+    account_0.sync(None).await?;
+    let processed_payments = account_0.process_scheduled_payments().await?;
+    assert_eq!(processed_payments.len(), 1);
+    assert!(matches!(
+        processed_payments[0].last_run(),
+        Some(ScheduledPaymentRun::Executed { .. })
+    ));
+    assert_eq!(processed_payments[0].next_run(), None);
+
+    account_0.remove_scheduled_payment(scheduled_payment.id()).await?;
+    assert!(account_0.get_scheduled_payment(scheduled_payment.id()).await?.is_none());
+    assert!(
+        account_0
+            .remove_scheduled_payment(scheduled_payment.id())
+            .await
+            .is_err()
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn retry_failed_scheduled_payment() -> Result<()> {
+    use iota_sdk::{
+        client::mock_node::MockNode,
+        types::block::address::Bech32Address,
+        wallet::{account::ScheduledPaymentRun, SendParams},
+    };
+
+    use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+    let storage_path = "test-storage/retry_failed_scheduled_payment";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account = wallet.create_account().finish().await?;
+    fund_account(&node, &account, 10_000_000).await?;
+    account.sync(None).await?;
+
+    // An address of another network can't be sent to
+    let address = Bech32Address::try_new("iota", *account.addresses().await?[0].address().inner())?;
+    let current_time = account.client().get_time_checked().await?;
+    let scheduled_payment = account
+        .schedule_payment(
+            SendParams::new(1_000_000, address)?,
+            PaymentSchedule::Once { time: current_time },
+            None,
+        )
+        .await?;
+
+    let processed_payments = account.process_scheduled_payments().await?;
+    assert_eq!(processed_payments.len(), 1);
+    assert!(matches!(
+        processed_payments[0].last_run(),
+        Some(ScheduledPaymentRun::Failed { .. })
+    ));
+    assert_eq!(processed_payments[0].failed_attempts(), 1);
+    assert_eq!(processed_payments[0].pending_transaction_id(), None);
+    // The failed run is tried again after a delay
+    assert_eq!(processed_payments[0].next_run(), Some(current_time + 60));
+    assert!(account.process_scheduled_payments().await?.is_empty());
+    assert_eq!(
+        account
+            .get_scheduled_payment(scheduled_payment.id())
+            .await?
+            .unwrap()
+            .failed_attempts(),
+        1
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn process_scheduled_payments_concurrently() -> Result<()> {
+    use iota_sdk::{client::mock_node::MockNode, wallet::SendParams};
+
+    use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+    let storage_path = "test-storage/process_scheduled_payments_concurrently";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    fund_account(&node, &account_0, 10_000_000).await?;
+    account_0.sync(None).await?;
+
+    let amount = 1_000_000;
+    let current_time = account_0.client().get_time_checked().await?;
+    account_0
+        .schedule_payment(
+            SendParams::new(amount, *account_1.addresses().await?[0].address())?,
+            PaymentSchedule::Once { time: current_time },
+            None,
+        )
+        .await?;
+
+    // The payment is only sent by one of the calls
+    let (processed_payments_0, processed_payments_1) = tokio::try_join!(
+        account_0.process_scheduled_payments(),
+        account_0.process_scheduled_payments()
+    )?;
+    assert_eq!(processed_payments_0.len() + processed_payments_1.len(), 1);
+
+    node.issue_milestone().await;
+    assert_eq!(account_1.sync(None).await?.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
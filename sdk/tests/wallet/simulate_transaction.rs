@@ -0,0 +1,107 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::mock_node::MockNode,
+    types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        semantic::ConflictReason,
+    },
+    wallet::{Result, SendParams},
+};
+
+use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn simulate_transaction() -> Result<()> {
+    let storage_path = "test-storage/simulate_transaction";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    fund_account(&node, &account_0, 10_000_000).await?;
+    account_0.sync(None).await?;
+
+    let amount = 1_000_000;
+    let address_0 = *account_0.addresses().await?[0].address();
+    let address_1 = *account_1.addresses().await?[0].address();
+    let prepared_transaction = account_0
+        .prepare_send([SendParams::new(amount, address_1)?], None)
+        .await?;
+    let simulation = account_0.simulate_transaction(&prepared_transaction).await?;
+
+    assert_eq!(simulation.conflict_reason(), ConflictReason::None);
+    assert!(simulation.chain_transitions().is_empty());
+    assert!(simulation.burned_native_tokens().is_empty());
+    assert_eq!(simulation.balance_changes().len(), 2);
+    let own_change = simulation
+        .balance_changes()
+        .iter()
+        .find(|change| change.address() == &address_0)
+        .unwrap();
+    assert!(own_change.own());
+    assert_eq!(own_change.base_coin_difference(), -(amount as i128));
+    let recipient_change = simulation
+        .balance_changes()
+        .iter()
+        .find(|change| change.address() == &address_1)
+        .unwrap();
+    assert!(!recipient_change.own());
+    assert_eq!(recipient_change.base_coin_received(), amount);
+
+    // Simulating doesn't submit anything
+    node.issue_milestone().await;
+    assert_eq!(account_1.sync(None).await?.base_coin().available(), 0);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn simulate_transaction_multiple_addresses() -> Result<()> {
+    let storage_path = "test-storage/simulate_transaction_multiple_addresses";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Fund two different addresses, so the transaction needs two signature unlocks
+    let address_0 = *account_0.addresses().await?[0].address();
+    let address_1 = *account_0.generate_ed25519_addresses(1, None).await?[0].address();
+    for address in [address_0, address_1] {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(node.protocol_parameters().token_supply())?;
+        node.add_output(output).await;
+    }
+    account_0.sync(None).await?;
+
+    let amount = 1_500_000;
+    let recipient_address = *account_1.addresses().await?[0].address();
+    let prepared_transaction = account_0
+        .prepare_send([SendParams::new(amount, recipient_address)?], None)
+        .await?;
+    assert_eq!(prepared_transaction.inputs_data.len(), 2);
+    let simulation = account_0.simulate_transaction(&prepared_transaction).await?;
+
+    assert_eq!(simulation.conflict_reason(), ConflictReason::None);
+    let recipient_change = simulation
+        .balance_changes()
+        .iter()
+        .find(|change| change.address() == &recipient_address)
+        .unwrap();
+    assert_eq!(recipient_change.base_coin_received(), amount);
+    let own_difference = simulation
+        .balance_changes()
+        .iter()
+        .filter(|change| change.own())
+        .map(|change| change.base_coin_difference())
+        .sum::<i128>();
+    assert_eq!(own_difference, -(amount as i128));
+
+    tear_down(storage_path)
+}
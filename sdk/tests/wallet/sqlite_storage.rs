@@ -0,0 +1,59 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client,
+    },
+    wallet::{
+        storage::{StorageKind, StorageOptions},
+        ClientOptions, Result, Wallet,
+    },
+};
+
+use crate::wallet::common::{setup, tear_down, NODE_LOCAL};
+
+#[tokio::test]
+async fn sqlite_storage_encrypted() -> Result<()> {
+    let storage_path = "test-storage/sqlite_storage_encrypted";
+    setup(storage_path)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let storage_options = StorageOptions::new(storage_path.into(), StorageKind::Sqlite);
+    let builder = |storage_options: StorageOptions| -> Result<_> {
+        Ok(Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+                mnemonic.clone(),
+            )?))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_options(storage_options))
+    };
+
+    {
+        let wallet = builder(storage_options.clone().with_encryption_key([1; 32]))?
+            .finish()
+            .await?;
+        wallet.create_account().with_alias("Alice").finish().await?;
+    }
+
+    // Everything is persisted in a single file in the storage directory and can be read again with the same encryption
+    // key
+    assert!(Path::new(storage_path).join("wallet.sqlite").is_file());
+    {
+        let wallet = builder(storage_options.clone().with_encryption_key([1; 32]))?
+            .finish()
+            .await?;
+        assert_eq!(wallet.get_accounts().await?.len(), 1);
+        assert_eq!(wallet.get_account("Alice").await?.alias().await, "Alice");
+    }
+
+    // Without the encryption key the records can't be read
+    assert!(builder(storage_options)?.finish().await.is_err());
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,135 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::mock_node::MockNode,
+    wallet::{
+        account::{
+            types::InclusionState, SyncOptions, TransactionDirection, TransactionHistoryEntry,
+            TransactionHistoryFilter, TransactionHistoryFormat, TransactionOptions,
+        },
+        Result, SendParams,
+    },
+};
+
+use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn transaction_history() -> Result<()> {
+    let storage_path = "test-storage/transaction_history";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    fund_account(&node, &account_0, 10_000_000).await?;
+    account_0.sync(None).await?;
+
+    let amount = 1_000_000;
+    let address_0 = *account_0.addresses().await?[0].address();
+    let address_1 = *account_1.addresses().await?[0].address();
+    let transaction = account_0
+        .send_with_params(
+            [SendParams::new(amount, address_1)?],
+            TransactionOptions {
+                note: Some("=invoice 42, paid".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+    node.issue_milestone().await;
+    account_0.sync(None).await?;
+    account_1
+        .sync(Some(SyncOptions {
+            sync_incoming_transactions: true,
+            ..Default::default()
+        }))
+        .await?;
+
+    let history = account_0.transaction_history(None).await?;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].direction(), TransactionDirection::Incoming);
+    assert_eq!(history[0].base_coin_delta(), 10_000_000);
+    assert!(history[0].milestone_timestamp().is_some());
+    let outgoing = &history[1];
+    assert_eq!(outgoing.transaction_id(), &transaction.transaction_id);
+    assert_eq!(outgoing.direction(), TransactionDirection::Outgoing);
+    assert_eq!(outgoing.inclusion_state(), InclusionState::Confirmed);
+    assert_eq!(outgoing.base_coin_delta(), -(amount as i128));
+    assert_eq!(outgoing.counterparties(), &[address_1]);
+    assert!(outgoing.milestone_timestamp().is_some());
+
+    let history = account_1.transaction_history(None).await?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].transaction_id(), &transaction.transaction_id);
+    assert_eq!(history[0].direction(), TransactionDirection::Incoming);
+    assert_eq!(history[0].base_coin_received(), amount);
+    assert_eq!(history[0].counterparties(), &[address_0]);
+
+    let filter = TransactionHistoryFilter {
+        directions: Some(vec![TransactionDirection::Outgoing]),
+        ..Default::default()
+    };
+    let csv = account_0
+        .export_transaction_history(filter.clone(), TransactionHistoryFormat::Csv)
+        .await?;
+    let mut lines = csv.lines();
+    assert!(lines.next().unwrap().starts_with("transaction_id,block_id,"));
+    let row = lines.next().unwrap();
+    assert!(row.starts_with(&transaction.transaction_id.to_string()));
+    assert!(row.contains(",Confirmed,outgoing,"));
+    assert!(row.contains(&format!(",{},", -(amount as i128))));
+    assert!(row.ends_with(&format!("{address_1},\"'=invoice 42, paid\"")));
+    assert!(lines.next().is_none());
+
+    let json = account_0
+        .export_transaction_history(filter.clone(), TransactionHistoryFormat::Json)
+        .await?;
+    let entries: Vec<TransactionHistoryEntry> = serde_json::from_str(&json)?;
+    assert_eq!(entries, account_0.transaction_history(filter).await?);
+
+    tear_down(storage_path)
+}
+
+#[test]
+fn csv_export_escapes_formulas() -> Result<()> {
+    let entry = |note: &str| {
+        serde_json::from_value::<TransactionHistoryEntry>(serde_json::json!({
+            "transactionId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "blockId": null,
+            "inclusionState": "Confirmed",
+            "direction": "outgoing",
+            "milestoneTimestamp": null,
+            "counterparties": [],
+            "baseCoinSpent": "1000000",
+            "baseCoinReceived": "0",
+            "nativeTokensSpent": {},
+            "nativeTokensReceived": {},
+            "storageDepositSent": "0",
+            "storageDepositReceived": "0",
+            "note": note,
+        }))
+    };
+
+    for (note, escaped) in [
+        ("=1+1", "'=1+1"),
+        ("+1+1", "'+1+1"),
+        ("-1+1", "'-1+1"),
+        ("@SUM(A1)", "'@SUM(A1)"),
+        ("\t=1+1", "'\t=1+1"),
+        ("\r=1+1", "\"'\r=1+1\""),
+        ("invoice 42", "invoice 42"),
+    ] {
+        let csv = TransactionHistoryFormat::Csv.export(&[entry(note)?])?;
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.ends_with(&format!(",{escaped}")), "{note:?} exported as {row:?}");
+    }
+
+    // Negative deltas stay numbers
+    let csv = TransactionHistoryFormat::Csv.export(&[entry("")?])?;
+    assert!(csv.contains(",-1000000,"));
+
+    Ok(())
+}
@@ -1,7 +1,13 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+use iota_sdk::{
+    types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    wallet::{
+        account::{BatchPayment, TransactionOptions},
+        Error, MintNftParams, Result, SendNftParams, SendParams,
+    },
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -70,6 +76,144 @@ async fn send_amount_127_outputs() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_batch() -> Result<()> {
+    let storage_path = "test-storage/send_batch";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let payments = vec![BatchPayment::from(SendParams::new(amount, *account_1.addresses().await?[0].address())?); 200];
+    let batch = account_0.send_batch(payments, None).await?;
+
+    // More outputs than fit into a single transaction
+    assert!(batch.is_finished());
+    let transaction_ids = batch.transaction_ids();
+    assert_eq!(transaction_ids.len(), 2);
+
+    for transaction_id in &transaction_ids {
+        account_0
+            .retry_transaction_until_included(transaction_id, None, None)
+            .await?;
+    }
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), 200 * amount);
+
+    #[cfg(feature = "storage")]
+    {
+        let stored_batch = account_0.get_batch(batch.id()).await?.unwrap();
+        assert_eq!(stored_batch.statuses(), batch.statuses());
+        account_0.remove_batch(batch.id()).await?;
+        assert!(account_0.batches().await?.is_empty());
+    }
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_from_multiple_accounts() -> Result<()> {
+    let storage_path = "test-storage/send_from_multiple_accounts";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+    let account_2 = wallet.create_account().finish().await?;
+
+    // More than a single account has available
+    let amount = accounts[0].balance().await?.base_coin().available() + 1_000_000;
+    let outputs = [BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(*account_2.addresses().await?[0].address()))
+        .finish_output(wallet.client().get_token_supply().await?)?];
+
+    let prepared_transaction = wallet.prepare_transaction([0, 1], outputs, None).await?;
+    let tx = wallet.sign_and_submit_transaction(prepared_transaction, None).await?;
+
+    // The transaction is stored in both accounts
+    for account in &accounts {
+        assert!(account.get_transaction(&tx.transaction_id).await.is_some());
+    }
+    accounts[0]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_2.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn prepare_transaction_without_accounts() -> Result<()> {
+    let storage_path = "test-storage/prepare_transaction_without_accounts";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet.create_account().with_alias("Alice").finish().await?;
+
+    assert!(matches!(
+        wallet.prepare_transaction(Vec::<u32>::new(), [], None).await,
+        Err(Error::MissingParameter("accounts"))
+    ));
+    assert!(matches!(
+        wallet.prepare_transaction(["Alice", "Bob"], [], None).await,
+        Err(Error::AccountNotFound(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn sign_and_submit_transaction_unlocks_inputs_on_failure() -> Result<()> {
+    use iota_sdk::{
+        client::{mock_node::MockNode, Error as ClientError},
+        types::block::payload::TaggedDataPayload,
+    };
+
+    use crate::wallet::common::fund_account;
+
+    let storage_path = "test-storage/sign_and_submit_transaction_unlocks_inputs_on_failure";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish().await?;
+    let wallet = make_wallet(storage_path, None, Some(&node.url())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    for account in [&account_0, &account_1] {
+        fund_account(&node, account, 1_000_000).await?;
+        account.sync(None).await?;
+    }
+
+    let outputs = [BasicOutputBuilder::new_with_amount(1_500_000)
+        .add_unlock_condition(AddressUnlockCondition::new(*account_1.addresses().await?[0].address()))
+        .finish_output(wallet.client().get_token_supply().await?)?];
+    // The essence with the tagged data can be prepared, but with the unlocks the transaction is too large
+    let options = TransactionOptions {
+        tagged_data_payload: Some(TaggedDataPayload::new(vec![], vec![0; 32_150])?),
+        ..Default::default()
+    };
+    let prepared_transaction = wallet.prepare_transaction([0, 1], outputs, options).await?;
+    assert!(!account_0.details().await.locked_outputs().is_empty());
+    assert!(!account_1.details().await.locked_outputs().is_empty());
+
+    assert!(matches!(
+        wallet.sign_and_submit_transaction(prepared_transaction, None).await,
+        Err(Error::Client(error)) if matches!(*error, ClientError::InvalidTransactionPayloadLength { .. })
+    ));
+    assert!(account_0.details().await.locked_outputs().is_empty());
+    assert!(account_1.details().await.locked_outputs().is_empty());
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_custom_input() -> Result<()> {
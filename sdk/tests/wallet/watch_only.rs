@@ -0,0 +1,103 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        mock_node::MockNode,
+        secret::{SecretManage, SecretManager},
+    },
+    types::block::semantic::ConflictReason,
+    wallet::{ClientOptions, Error, Result, SendParams, Wallet},
+};
+
+use crate::wallet::common::{fund_account, make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn watch_only_account() -> Result<()> {
+    let storage_path_cold = "test-storage/watch_only_account_cold";
+    let storage_path_watch = "test-storage/watch_only_account_watch";
+    setup(storage_path_cold)?;
+    setup(storage_path_watch)?;
+
+    let node = MockNode::builder().finish().await?;
+    let cold_wallet = make_wallet(storage_path_cold, None, Some(&node.url())).await?;
+    let cold_account = cold_wallet.create_account().finish().await?;
+    let recipient = *cold_wallet.create_account().finish().await?.addresses().await?[0].address();
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Placeholder)
+        .with_client_options(ClientOptions::new().with_node(&node.url())?)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path_watch);
+    }
+    let watch_wallet = wallet_builder.finish().await?;
+    let watch_account = watch_wallet
+        .create_account()
+        .with_watch_only_addresses(
+            cold_account
+                .addresses()
+                .await?
+                .into_iter()
+                .map(|a| (*a.key_index(), *a.address())),
+        )
+        .finish()
+        .await?;
+    assert!(watch_account.details().await.watch_only());
+    assert!(matches!(
+        watch_account.generate_ed25519_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+
+    // The public key of the first address, exported from a signature of the account with the keys
+    let public_key = *cold_wallet
+        .get_secret_manager()
+        .read()
+        .await
+        .sign_ed25519(&[], Bip44::new(SHIMMER_COIN_TYPE))
+        .await?
+        .public_key();
+    let public_key_account = watch_wallet
+        .create_account()
+        .with_watch_only_public_keys([(0, public_key)])
+        .finish()
+        .await?;
+    assert_eq!(public_key_account.addresses().await?, watch_account.addresses().await?);
+
+    fund_account(&node, &cold_account, 10_000_000).await?;
+    assert_eq!(watch_account.sync(None).await?.base_coin().available(), 10_000_000);
+    assert_eq!(public_key_account.sync(None).await?.base_coin().available(), 10_000_000);
+
+    let amount = 1_000_000;
+    let prepared_transaction = watch_account
+        .prepare_send([SendParams::new(amount, recipient)?], None)
+        .await?;
+    // Simulating doesn't need the keys
+    assert_eq!(
+        watch_account
+            .simulate_transaction(&prepared_transaction)
+            .await?
+            .conflict_reason(),
+        ConflictReason::None
+    );
+    assert!(matches!(
+        watch_account.sign_transaction_essence(&prepared_transaction).await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+
+    // The account with the keys signs the transaction prepared by the watch-only account
+    let signed_transaction = cold_account.sign_transaction_essence(&prepared_transaction).await?;
+    watch_account
+        .submit_and_store_transaction(signed_transaction, None)
+        .await?;
+    node.issue_milestone().await;
+
+    assert_eq!(watch_account.sync(None).await?.base_coin().total(), 10_000_000 - amount);
+
+    tear_down(storage_path_cold)?;
+    tear_down(storage_path_watch)
+}